@@ -61,30 +61,112 @@ export const Errors = {
   17: {message:"ShipAlreadySunk"},
   18: {message:"InvalidPublicInputsHash"},
   19: {message:"InvalidProof"},
-  20: {message:"TooManyHits"}
+  20: {message:"TooManyHits"},
+  21: {message:"RecommitNotConfigured"},
+  22: {message:"CommitmentUnchanged"},
+  23: {message:"TooManyActiveGames"},
+  24: {message:"FinalProofRequired"},
+  25: {message:"NotFinalShot"},
+  26: {message:"FinalVerifierNotConfigured"},
+  27: {message:"RevealNotConfigured"},
+  28: {message:"BoardAlreadyRevealed"},
+  29: {message:"BoardNotRevealed"},
+  30: {message:"InvalidLayout"},
+  31: {message:"SunkShipDisclosureRequired"},
+  32: {message:"DisclosureDisabled"},
+  33: {message:"InvalidSunkPlacement"},
+  34: {message:"InvalidSessionKey"},
+  35: {message:"RetractWindowClosed"},
+  36: {message:"NoRetractionsLeft"},
+  37: {message:"HubNotificationNotPending"},
+  38: {message:"NotPracticeGame"},
+  39: {message:"PracticeGameUnsupported"},
+  40: {message:"StatsNotArchived"},
+  41: {message:"InvalidStake"},
+  42: {message:"ShotNotRevealed"},
+  43: {message:"InvalidShotReveal"},
+  44: {message:"SeedAlreadyCommitted"},
+  45: {message:"SeedNotCommitted"},
+  46: {message:"InvalidSeedReveal"},
+  47: {message:"RandomStreamUnavailable"},
+  48: {message:"ChatRateLimited"},
+  49: {message:"ChatAnchorLimitReached"},
+  50: {message:"ReservedSessionId"},
+  51: {message:"SessionChatLimitReached"},
+  52: {message:"SeedRevealWindowOpen"},
+  53: {message:"NoSeedWithheld"}
+}
+
+
+export const BoardErrors = {
+  1: {message:"InvalidBoardSize"},
+  2: {message:"InvalidCell"},
+  3: {message:"InvalidShipLength"},
+  4: {message:"ShipNotInLine"}
+}
+
+export type GamePhase = {tag: "WaitingForBoards", values: void} | {tag: "InProgress", values: void} | {tag: "Ended", values: void};
+
+export type GameStatus = {tag: "Setup", values: void} | {tag: "InProgress", values: void} | {tag: "Ended", values: void} | {tag: "Cancelled", values: void};
+
+
+export interface Team {
+  lead: string;
+  partner: string;
+}
+
+
+export interface HubSeats {
+  player1: string;
+  player2: string;
+}
+
+
+export interface SeedExchange {
+  commitment_p1: Option<Buffer>;
+  commitment_p2: Option<Buffer>;
+  draws: u32;
+  reveal_deadline: u32;
+  seed_p1: Option<Buffer>;
+  seed_p2: Option<Buffer>;
+  stream: Option<Buffer>;
 }
 
 
 export interface Game {
+  adjudicated: boolean;
   board_commitment_p1: Option<Buffer>;
   board_commitment_p2: Option<Buffer>;
+  disclose_sunk_ships: boolean;
   hits_on_p1: u32;
   hits_on_p2: u32;
   hits_p1_to_p2: u128;
   hits_p2_to_p1: u128;
+  hub_seats: HubSeats;
   last_resolved_is_hit: boolean;
   last_resolved_shooter: Option<string>;
   last_resolved_sunk_ship: u32;
   last_resolved_x: u32;
   last_resolved_y: u32;
+  max_shots: u32;
+  move_count: u32;
+  pending_hub_notification: boolean;
+  pending_shot_commitment: Option<Buffer>;
+  pending_shot_ledger: u32;
   pending_shot_shooter: Option<string>;
   pending_shot_x: u32;
   pending_shot_y: u32;
   phase: GamePhase;
   player1: string;
+  player1_partner: Option<string>;
   player1_points: i128;
   player2: string;
+  player2_partner: Option<string>;
   player2_points: i128;
+  practice: boolean;
+  retractions_p1: u32;
+  retractions_p2: u32;
+  seeds: SeedExchange;
   shots_p1_to_p2: u128;
   shots_p2_to_p1: u128;
   sunk_ships_on_p1: u32;
@@ -95,28 +177,226 @@ export interface Game {
 
 export type ShipType = {tag: "Carrier", values: void} | {tag: "Battleship", values: void} | {tag: "Cruiser", values: void} | {tag: "Submarine", values: void} | {tag: "Destroyer", values: void};
 
-export type GamePhase = {tag: "WaitingForBoards", values: void} | {tag: "InProgress", values: void} | {tag: "Ended", values: void};
+
+export interface ShipPlacement {
+  dir: u32;
+  x: u32;
+  y: u32;
+}
+
+
+export interface BoardLayout {
+  ship_dir: Array<u32>;
+  ship_x: Array<u32>;
+  ship_y: Array<u32>;
+}
+
+export type SunkPlacement = {tag: "Undisclosed", values: void} | {tag: "Disclosed", values: readonly [ShipPlacement]};
+
+
+export interface MoveRecord {
+  index: u32;
+  is_hit: boolean;
+  ledger: u32;
+  shooter_is_player1: boolean;
+  sunk_placement: SunkPlacement;
+  sunk_ship: u32;
+  x: u32;
+  y: u32;
+}
+
+
+export interface SessionKey {
+  expiry: u32;
+  key: string;
+}
+
+export type StorageTier = {tag: "Temporary", values: void};
+
+
+export interface SessionMarker {
+  players: Array<string>;
+  start_ledger: u32;
+  tier: StorageTier;
+}
+
+
+export interface ChatAnchor {
+  hash: Buffer;
+  ledger: u32;
+}
+
+
+export interface ShooterStats {
+  accuracy_bps: u32;
+  hits: u32;
+  longest_hit_streak: u32;
+  shots: u32;
+}
+
+
+export interface ArchivedStats {
+  ended_ledger: u32;
+  player1: string;
+  player1_stats: ShooterStats;
+  player2: string;
+  player2_stats: ShooterStats;
+  winner: string;
+}
+
+
+export interface Metrics {
+  games_adjudicated: u32;
+  games_cancelled: u32;
+  games_ended: u32;
+  games_started: u32;
+  practice_games_ended: u32;
+  practice_games_started: u32;
+}
+
+
+export interface FinalShotArgs {
+  consistency_proof: Buffer;
+  defender: string;
+  is_hit: boolean;
+  proof_payload: Buffer;
+  public_inputs_hash: Buffer;
+  sunk_ship: u32;
+}
+
+
+export interface SinkingShotArgs {
+  consistency_proof: Option<Buffer>;
+  defender: string;
+  proof_payload: Buffer;
+  public_inputs_hash: Buffer;
+  sunk_placement: ShipPlacement;
+  sunk_ship: u32;
+}
+
+
+export interface ShotResult {
+  is_hit: boolean;
+  move_count: u32;
+  next_turn: Option<string>;
+  sunk_placement: SunkPlacement;
+  sunk_ship: u32;
+  winner: Option<string>;
+}
+
+
+export interface ResolutionCheck {
+  board_commitment: Option<Buffer>;
+  error_code: u32;
+  expected_hash: Option<Buffer>;
+  hash_matches: boolean;
+  proof_valid: boolean;
+  shooter: Option<string>;
+  verifier_error_code: u32;
+  x: u32;
+  y: u32;
+}
 
 
 export interface GameRules {
   battleship_len: u32;
   board_size: u32;
   carrier_len: u32;
+  chat_anchor_interval_ledgers: u32;
   cruiser_len: u32;
   destroyer_len: u32;
+  disclose_sunk_ships: boolean;
+  max_chat_anchors: u32;
+  max_session_chat_anchors: u32;
+  max_shot_retractions: u32;
+  max_shots: u32;
+  max_stake: i128;
+  min_stake: i128;
+  seed_reveal_window_ledgers: u32;
+  shot_retract_window_ledgers: u32;
   submarine_len: u32;
   total_ship_cells: u32;
 }
 
 
-export interface ShotResult {
-  is_hit: boolean;
-  next_turn: Option<string>;
-  sunk_ship: u32;
+export interface ContractInfo {
+  build_hash: string;
+  interfaces: Array<string>;
+  name: string;
+  version: string;
+}
+
+export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "GameState", values: readonly [u32]} | {tag: "GameHubAddress", values: void} | {tag: "VerifierAddress", values: void} | {tag: "RecommitVerifierAddress", values: void} | {tag: "FinalVerifierAddress", values: void} | {tag: "RevealVerifierAddress", values: void} | {tag: "DisclosureVerifierAddress", values: void} | {tag: "PlayerSessions", values: readonly [string]} | {tag: "MaxActiveGames", values: void} | {tag: "MaxShots", values: void} | {tag: "MinStake", values: void} | {tag: "MaxStake", values: void} | {tag: "Moves", values: readonly [u32, u32]} | {tag: "Reveal", values: readonly [u32, string]} | {tag: "SessionKey", values: readonly [u32, string]} | {tag: "PairNonce", values: readonly [string, string]} | {tag: "ArchivedStats", values: readonly [u32]} | {tag: "Metrics", values: void} | {tag: "SessionMarker", values: readonly [u32]} | {tag: "ChatAnchors", values: readonly [u32, string]} | {tag: "ChatAnchorCount", values: readonly [u32]} | {tag: "LastError", values: readonly [u32]};
+
+
+export interface GameSetup {
+  board_commitment_p1: Option<Buffer>;
+  board_commitment_p2: Option<Buffer>;
+  disclose_sunk_ships: boolean;
+  hub_seats: HubSeats;
+  max_shots: u32;
+  player1: string;
+  player1_partner: Option<string>;
+  player1_points: i128;
+  player2: string;
+  player2_partner: Option<string>;
+  player2_points: i128;
+  practice: boolean;
+}
+
+
+export interface GameState {
+  adjudicated: boolean;
+  hits_on_p1: u32;
+  hits_on_p2: u32;
+  hits_p1_to_p2: u128;
+  hits_p2_to_p1: u128;
+  last_resolved_is_hit: boolean;
+  last_resolved_shooter: Option<string>;
+  last_resolved_sunk_ship: u32;
+  last_resolved_x: u32;
+  last_resolved_y: u32;
+  move_count: u32;
+  pending_hub_notification: boolean;
+  pending_shot_commitment: Option<Buffer>;
+  pending_shot_ledger: u32;
+  pending_shot_shooter: Option<string>;
+  pending_shot_x: u32;
+  pending_shot_y: u32;
+  phase: GamePhase;
+  retractions_p1: u32;
+  retractions_p2: u32;
+  seeds: SeedExchange;
+  shots_p1_to_p2: u128;
+  shots_p2_to_p1: u128;
+  sunk_ships_on_p1: u32;
+  sunk_ships_on_p2: u32;
+  turn: Option<string>;
   winner: Option<string>;
 }
 
-export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "GameHubAddress", values: void} | {tag: "VerifierAddress", values: void} | {tag: "Admin", values: void};
+
+/**
+ * Last failure stored for a session, returned by `get_last_error_detail`.
+ * 
+ * A failing entrypoint rolls back its storage along with everything else, so only failures the
+ * contract absorbs while the call itself succeeds are stored: the Game Hub rejecting `end_game`
+ * on a winning shot or a seed forfeit.
+ */
+export interface ErrorDetail {
+  action: string;
+  address: Option<string>;
+  args: Array<u32>;
+  code: u32;
+  ledger: u32;
+  namespace: string;
+}
+
+/**
+ * Instance-storage keys. `Admin` encodes exactly like the `DataKey::Admin` variant the
+ * contracts used before, so deployed instances keep their admin across the upgrade.
+ */
+export type OwnableKey = {tag: "Admin", values: void} | {tag: "PendingAdmin", values: void} | {tag: "Role", values: readonly [string, string]};
 
 export interface Client {
   /**
@@ -144,11 +424,23 @@ export interface Client {
    */
   get_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Game>>>
 
+  /**
+   * Construct and simulate a get_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_info: (options?: MethodOptions) => Promise<AssembledTransaction<ContractInfo>>
+
   /**
    * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
   get_admin: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
 
+  /**
+   * Construct and simulate a get_moves transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Resolved moves in order, starting at `start`. Clients can poll with `start` set to the
+   * number of moves they already have to fetch only new ones.
+   */
+  get_moves: ({session_id, start, limit}: {session_id: u32, start: u32, limit: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Array<MoveRecord>>>>
+
   /**
    * Construct and simulate a get_rules transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
@@ -161,8 +453,77 @@ export interface Client {
 
   /**
    * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a game and return its session id. Passing `session_id = 0` derives the id from the
+   * players and their next pair nonce (see `get_session_id`), so clients never pick colliding ids.
+   * Derived ids have the high bit set; explicit ids with it fail with `ReservedSessionId`.
+   */
+  start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a cancel_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Abort a game that has not ended (e.g. a tournament no-show). Only the Game Hub can cancel,
+   * and since it initiated the cancel it is not notified back. The game ends with no winner.
+   */
+  cancel_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a cancel_shot transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Retract the caller's unresolved shot (e.g. a misclick) within `SHOT_RETRACT_WINDOW_LEDGERS`
+   * of firing, before the defender resolves it. Each player gets `MAX_SHOT_RETRACTIONS` per game.
+   */
+  cancel_shot: ({session_id, shooter}: {session_id: u32, shooter: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a commit_seed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Commit the caller's side to an RNG seed (see `build_seed_commitment`) while the boards are
+   * being placed. Optional; modes with random events need both sides' seeds.
+   */
+  commit_seed: ({session_id, player, commitment}: {session_id: u32, player: string, commitment: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a commit_shot transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Fire without exposing the cell: record `commitment` (see `build_shot_commitment`) as the
+   * pending shot. It takes the turn like `fire` and is opened with `reveal_shot` once the
+   * defender is ready to resolve, so the coordinate is not public while their proof is pending.
+   */
+  commit_shot: ({session_id, shooter, commitment}: {session_id: u32, shooter: string, commitment: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_metrics transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Games started and how they ended since deployment, for operator health checks without an
+   * indexer.
    */
-  start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  get_metrics: (options?: MethodOptions) => Promise<AssembledTransaction<Metrics>>
+
+  /**
+   * Construct and simulate a reveal_seed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal the caller's side seed once both sides have committed and before the boards are
+   * in. Fails with `InvalidSeedReveal` unless it hashes to the committed value, and with
+   * `InvalidPhase` once the game is in progress. The second reveal sets
+   * `seeds.stream` to keccak256(seed_p1 || seed_p2), the head of the game's random hash chain.
+   */
+  reveal_seed: ({session_id, player, seed}: {session_id: u32, player: string, seed: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a reveal_shot transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Open the caller's committed shot at (x, y). Fails with `InvalidShotReveal` unless the cell
+   * and `salt` hash to the committed value; the shot then resolves like a `fire` shot.
+   */
+  reveal_shot: ({session_id, shooter, x, y, salt}: {session_id: u32, shooter: string, x: u32, y: u32, salt: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a was_started transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Whether `session_id` was ever started here. Kept in persistent storage, so once `get_game`
+   * fails with `GameNotFound` for a started session, the hub knows the game expired mid-match
+   * and can refund the stakes.
+   */
+  was_started: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a accept_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Completes a transfer started with `propose_admin`; signed by the nominee.
+   */
+  accept_admin: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
 
   /**
    * Construct and simulate a commit_board transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -179,13 +540,346 @@ export interface Client {
    */
   resolve_shot: ({session_id, defender, is_hit, sunk_ship, proof_payload, public_inputs_hash}: {session_id: u32, defender: string, is_hit: boolean, sunk_ship: u32, proof_payload: Buffer, public_inputs_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<ShotResult>>>
 
+  /**
+   * Construct and simulate a reveal_board transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Disclose a player's board once the game has ended, so spectators and replays can render it.
+   * 
+   * `reveal_proof` is a `board_reveal` payload verified by the reveal adapter as
+   * `verify(board_commitment, build_reveal_hash(...), payload)`. The layout must also agree with
+   * every hit and miss the opponent recorded against this board.
+   */
+  reveal_board: ({session_id, player, layout, reveal_proof}: {session_id: u32, player: string, layout: BoardLayout, reveal_proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a set_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
   set_verifier: ({new_verifier}: {new_verifier: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
+  /**
+   * Construct and simulate a evaluate_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Winner of an ended game; None while the game is running or after `cancel_game`.
+   */
+  evaluate_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Option<string>>>>
+
+  /**
+   * Construct and simulate a get_max_shots transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Resolved shots (both sides together) after which new games end on the hit differential,
+   * with equal hits going to the defender of the capping shot; 0 means no cap.
+   */
+  get_max_shots: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_max_stake transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Largest stake `start_game` accepts from each player; 0 means no cap.
+   */
+  get_max_stake: (options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_min_stake transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Smallest stake `start_game` accepts from each player (1 unless set).
+   */
+  get_min_stake: (options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a propose_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * First step of an admin transfer; `new_admin` takes over once they call `accept_admin`.
+   */
+  propose_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_max_shots transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Applies to games started afterwards; running games keep the cap they started with.
+   */
+  set_max_shots: ({max_shots}: {max_shots: u32}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_max_stake transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * 0 removes the cap; otherwise it must not be below the min stake.
+   */
+  set_max_stake: ({max_stake}: {max_stake: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_min_stake transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Must be positive and, when a max is set, not above it.
+   */
+  set_min_stake: ({min_stake}: {min_stake: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_move_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Actions applied to the game so far; the `move_count` of the latest `GameMove` event.
+   */
+  get_move_count: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a get_session_id transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Session id `start_game(0, player1, player2, ..)` derives for the given pair nonce.
+   */
+  get_session_id: ({player1, player2, nonce}: {player1: string, player2: string, nonce: u32}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a recommit_board transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Replace a committed board with a fresh commitment to the same placement under a new salt.
+   * 
+   * `equivalence_proof` is a `board_recommit` proof payload verified by the recommit adapter
+   * as `verify(old_commitment, new_commitment, payload)`, i.e. public inputs 0..1 bind the
+   * current commitment and 2..3 bind `new_commitment`.
+   */
+  recommit_board: ({session_id, player, new_commitment, equivalence_proof}: {session_id: u32, player: string, new_commitment: Buffer, equivalence_proof: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a validate_board transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check a board in the `get_revealed_board` format (100 cells, row-major, `0` = water,
+   * `1..5` = Carrier..Destroyer) against the placement circuit's rules: every ship present
+   * with its length, on one straight run inside the board, and no overlap. Like the circuit,
+   * ships may touch.
+   */
+  validate_board: ({cells}: {cells: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_game_status transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_game_status: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<GameStatus>>>
+
+  /**
+   * Construct and simulate a get_session_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Session key currently authorized to `fire` for `player`, if not expired.
+   */
+  get_session_key: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Option<SessionKey>>>
+
+  /**
+   * Construct and simulate a start_team_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a 2v2 game. Each team's lead stakes its points and stands for the team at the Game
+   * Hub; lead and partner take turns firing for their side (lead first) and defend one shared
+   * board, which either of them commits. The winner is reported as the winning team's lead.
+   */
+  start_team_game: ({session_id, team1, team2, team1_points, team2_points}: {session_id: u32, team1: Team, team2: Team, team1_points: i128, team2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a check_resolution transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Simulate `resolve_shot` without changing state and report which check would fail: the
+   * expected public inputs hash, whether it matches, and the verifier's answer or abort code.
+   * Meant for prover debugging through transaction simulation before paying fees.
+   */
+  check_resolution: ({session_id, defender, is_hit, sunk_ship, proof_payload, public_inputs_hash}: {session_id: u32, defender: string, is_hit: boolean, sunk_ship: u32, proof_payload: Buffer, public_inputs_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<ResolutionCheck>>
+
+  /**
+   * Construct and simulate a get_chat_anchors transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Chat anchors `player` posted in the session, oldest first.
+   */
+  get_chat_anchors: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Array<ChatAnchor>>>
+
+  /**
+   * Construct and simulate a post_chat_anchor transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Anchor the hash of an off-chain chat message (game members only), so a transcript
+   * produced in a dispute can be proven authentic without putting message bodies on-chain.
+   * Rate-limited per poster to one anchor every `chat_anchor_interval_ledgers`, up to
+   * `max_chat_anchors` per poster and `max_session_chat_anchors` per game (see `get_rules`).
+   * Returns the anchor's index.
+   */
+  post_chat_anchor: ({session_id, player, hash}: {session_id: u32, player: string, hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a retry_hub_notify transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Re-sends the hub `end_game` that failed when the winning shot was resolved. Callable by
+   * anyone; fails with `HubNotificationNotPending` once the hub has been notified.
+   */
+  retry_hub_notify: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a build_reveal_hash transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  build_reveal_hash: ({session_id, player, layout, board_commitment}: {session_id: u32, player: string, layout: BoardLayout, board_commitment: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Buffer>>
+
+  /**
+   * Construct and simulate a get_pending_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_pending_admin: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a get_session_nonce transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pair nonce the next derived session id for (player1, player2) uses.
+   */
+  get_session_nonce: ({player1, player2}: {player1: string, player2: string}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_archived_stats transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Per-player shots, hits, accuracy and longest hit streak of a won game. Written when the
+   * game ends and kept in persistent storage, so it outlives `get_game` and `get_moves`.
+   */
+  get_archived_stats: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<ArchivedStats>>>
+
+  /**
+   * Construct and simulate a get_final_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_final_verifier: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a get_revealed_board transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Revealed board as 100 cells indexed `y * 10 + x`: 0 = water, 1..5 = Carrier..Destroyer.
+   * Only available after `reveal_board`.
+   */
+  get_revealed_board: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Array<u32>>>>
+
+  /**
+   * Construct and simulate a get_session_marker transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Members, start ledger and storage tier of a started session, for post-mortem audits of
+   * games whose state has expired. `None` if the session was never started.
+   */
+  get_session_marker: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<SessionMarker>>>
+
+  /**
+   * Construct and simulate a resolve_final_shot transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Resolve the game-winning (17th hit) shot with an additional `board_consistency` proof that
+   * every result reported against the defender's board, including this one, matches the
+   * committed placement. Required instead of `resolve_shot` once a final verifier is set.
+   * 
+   * The final adapter is called as `verify(board_commitment, consistency_hash, payload)`,
+   * where `consistency_hash` is `build_consistency_hash` over the shot history after this shot.
+   */
+  resolve_final_shot: ({session_id, args}: {session_id: u32, args: FinalShotArgs}, options?: MethodOptions) => Promise<AssembledTransaction<Result<ShotResult>>>
+
+  /**
+   * Construct and simulate a revoke_session_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Drop the player's session key; `fire` needs the main wallet again.
+   */
+  revoke_session_key: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_final_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_final_verifier: ({new_verifier}: {new_verifier: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_active_sessions transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Sessions the player has started that have not ended or expired yet.
+   */
+  get_active_sessions: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Array<u32>>>
+
+  /**
+   * Construct and simulate a get_reveal_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_reveal_verifier: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a set_reveal_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_reveal_verifier: ({new_verifier}: {new_verifier: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a start_practice_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start an open-board practice game: no points, no Game Hub, no proofs. Both players submit
+   * their layout in clear with `commit_practice_board` and `resolve_practice_shot` resolves
+   * each shot against it, for tutorials, UI work and integration tests without a prover.
+   */
+  start_practice_game: ({session_id, player1, player2}: {session_id: u32, player1: string, player2: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a get_max_active_games transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Maximum concurrent sessions per player; 0 means unlimited.
+   */
+  get_max_active_games: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a resolve_sinking_shot transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Resolve a shot that sinks `sunk_ship` and disclose that ship's placement. Required instead
+   * of `resolve_shot` / `resolve_final_shot` for sinking shots in games started with
+   * `disclose_sunk_ships`; pass `consistency_proof` when the shot also wins the game.
+   * 
+   * The placement is bound into `build_disclosure_hash`, proven by the disclosure verifier
+   * (`resolve_shot_disclosed` circuit) and must cover only cells the shooter has hit.
+   */
+  resolve_sinking_shot: ({session_id, args}: {session_id: u32, args: SinkingShotArgs}, options?: MethodOptions) => Promise<AssembledTransaction<Result<ShotResult>>>
+
+  /**
+   * Construct and simulate a set_max_active_games transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_max_active_games: ({max_active_games}: {max_active_games: u32}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a authorize_session_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Let `key` sign `fire` for `player` in this session until ledger `expiry`, so fast play
+   * doesn't prompt the main wallet on every shot. Replaces any previous key.
+   */
+  authorize_session_key: ({session_id, player, key, expiry}: {session_id: u32, player: string, key: string, expiry: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a build_disclosure_hash transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Public inputs hash for `resolve_sinking_shot`: the regular shot hash payload (with
+   * `is_hit = true`) followed by the sunk ship's x, y and dir bytes.
+   */
+  build_disclosure_hash: ({session_id, defender, shooter, x, y, sunk_ship, sunk_placement, board_commitment}: {session_id: u32, defender: string, shooter: string, x: u32, y: u32, sunk_ship: u32, sunk_placement: ShipPlacement, board_commitment: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Buffer>>
+
+  /**
+   * Construct and simulate a build_seed_commitment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  build_seed_commitment: ({session_id, player, seed}: {session_id: u32, player: string, seed: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Buffer>>
+
+  /**
+   * Construct and simulate a build_shot_commitment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  build_shot_commitment: ({session_id, shooter, x, y, salt}: {session_id: u32, shooter: string, x: u32, y: u32, salt: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Buffer>>
+
+  /**
+   * Construct and simulate a commit_practice_board transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Place a practice board in clear. The layout is published at once (`get_revealed_board`)
+   * and its reveal hash stands in for the board commitment.
+   */
+  commit_practice_board: ({session_id, player, layout}: {session_id: u32, player: string, layout: BoardLayout}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a forfeit_withheld_seed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * End the game once `seed_reveal_window_ledgers` have passed since the second seed
+   * commitment with only one seed revealed: the side withholding its seed forfeits. Callable
+   * by anyone; returns the winner. Fails with `SeedRevealWindowOpen` while the window runs and
+   * `NoSeedWithheld` unless exactly one side revealed.
+   */
+  forfeit_withheld_seed: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a get_last_error_detail transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Latest failure the contract absorbed in a session instead of reverting: the error
+   * namespace and code, the offending address and the ledger it happened in. Failed calls
+   * roll back their own storage, so their cause is only reported in the transaction result.
+   */
+  get_last_error_detail: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<ErrorDetail>>>
+
+  /**
+   * Construct and simulate a get_recommit_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_recommit_verifier: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a resolve_practice_shot transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Resolve the pending shot of a practice game from the defender's clear layout. Callable by
+   * anyone; hit, sunk ship and the win are derived the same way a proof would attest them.
+   */
+  resolve_practice_shot: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<ShotResult>>>
+
+  /**
+   * Construct and simulate a set_recommit_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_recommit_verifier: ({new_verifier}: {new_verifier: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a build_consistency_hash transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  build_consistency_hash: ({session_id, defender, shots, hits, board_commitment}: {session_id: u32, defender: string, shots: u128, hits: u128, board_commitment: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Buffer>>
+
+  /**
+   * Construct and simulate a get_disclosure_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Games started while this is set disclose sunk ship placements (see `resolve_sinking_shot`).
+   */
+  get_disclosure_verifier: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a set_disclosure_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_disclosure_verifier: ({new_verifier}: {new_verifier: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
   /**
    * Construct and simulate a build_public_inputs_hash transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Public inputs hash for `resolve_shot` / `resolve_final_shot`. The flat parameter list is
+   * the contract interface provers and the frontend bindings call, so it stays ungrouped.
    */
   build_public_inputs_hash: ({session_id, defender, shooter, x, y, is_hit, sunk_ship, board_commitment}: {session_id: u32, defender: string, shooter: string, x: u32, y: u32, is_hit: boolean, sunk_ship: u32, board_commitment: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Buffer>>
 
@@ -221,24 +915,112 @@ export class Client extends ContractClient {
         "AAAAAAAAAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
         "AAAAAAAAAAAAAAAHdXBncmFkZQAAAAABAAAAAAAAAA1uZXdfd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAA",
         "AAAAAAAAAAAAAAAIZ2V0X2dhbWUAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAfQAAAABEdhbWUAAAAD",
+        "AAAAAAAAAAAAAAAIZ2V0X2luZm8AAAAAAAAAAQAAB9AAAAAMQ29udHJhY3RJbmZv",
         "AAAAAAAAAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
+        "AAAAAAAAAJBSZXNvbHZlZCBtb3ZlcyBpbiBvcmRlciwgc3RhcnRpbmcgYXQgYHN0YXJ0YC4gQ2xpZW50cyBjYW4gcG9sbCB3aXRoIGBzdGFydGAgc2V0IHRvIHRoZQpudW1iZXIgb2YgbW92ZXMgdGhleSBhbHJlYWR5IGhhdmUgdG8gZmV0Y2ggb25seSBuZXcgb25lcy4AAAAJZ2V0X21vdmVzAAAAAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAFc3RhcnQAAAAAAAAEAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAD6QAAA+oAAAfQAAAACk1vdmVSZWNvcmQAAAAAAAM=",
         "AAAAAAAAAAAAAAAJZ2V0X3J1bGVzAAAAAAAAAAAAAAEAAAfQAAAACUdhbWVSdWxlcwAAAA==",
         "AAAAAAAAAAAAAAAJc2V0X2FkbWluAAAAAAAAAQAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAA=",
-        "AAAAAAAAAAAAAAAKc3RhcnRfZ2FtZQAAAAAABQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAQ5TdGFydCBhIGdhbWUgYW5kIHJldHVybiBpdHMgc2Vzc2lvbiBpZC4gUGFzc2luZyBgc2Vzc2lvbl9pZCA9IDBgIGRlcml2ZXMgdGhlIGlkIGZyb20gdGhlCnBsYXllcnMgYW5kIHRoZWlyIG5leHQgcGFpciBub25jZSAoc2VlIGBnZXRfc2Vzc2lvbl9pZGApLCBzbyBjbGllbnRzIG5ldmVyIHBpY2sgY29sbGlkaW5nIGlkcy4KRGVyaXZlZCBpZHMgaGF2ZSB0aGUgaGlnaCBiaXQgc2V0OyBleHBsaWNpdCBpZHMgd2l0aCBpdCBmYWlsIHdpdGggYFJlc2VydmVkU2Vzc2lvbklkYC4AAAAAAApzdGFydF9nYW1lAAAAAAAFAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAADnBsYXllcjJfcG9pbnRzAAAAAAALAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAAAAAAALNBYm9ydCBhIGdhbWUgdGhhdCBoYXMgbm90IGVuZGVkIChlLmcuIGEgdG91cm5hbWVudCBuby1zaG93KS4gT25seSB0aGUgR2FtZSBIdWIgY2FuIGNhbmNlbCwKYW5kIHNpbmNlIGl0IGluaXRpYXRlZCB0aGUgY2FuY2VsIGl0IGlzIG5vdCBub3RpZmllZCBiYWNrLiBUaGUgZ2FtZSBlbmRzIHdpdGggbm8gd2lubmVyLgAAAAALY2FuY2VsX2dhbWUAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAALlSZXRyYWN0IHRoZSBjYWxsZXIncyB1bnJlc29sdmVkIHNob3QgKGUuZy4gYSBtaXNjbGljaykgd2l0aGluIGBTSE9UX1JFVFJBQ1RfV0lORE9XX0xFREdFUlNgCm9mIGZpcmluZywgYmVmb3JlIHRoZSBkZWZlbmRlciByZXNvbHZlcyBpdC4gRWFjaCBwbGF5ZXIgZ2V0cyBgTUFYX1NIT1RfUkVUUkFDVElPTlNgIHBlciBnYW1lLgAAAAAAAAtjYW5jZWxfc2hvdAAAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAdzaG9vdGVyAAAAABMAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAKNDb21taXQgdGhlIGNhbGxlcidzIHNpZGUgdG8gYW4gUk5HIHNlZWQgKHNlZSBgYnVpbGRfc2VlZF9jb21taXRtZW50YCkgd2hpbGUgdGhlIGJvYXJkcyBhcmUKYmVpbmcgcGxhY2VkLiBPcHRpb25hbDsgbW9kZXMgd2l0aCByYW5kb20gZXZlbnRzIG5lZWQgYm90aCBzaWRlcycgc2VlZHMuAAAAAAtjb21taXRfc2VlZAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACmNvbW1pdG1lbnQAAAAAA+4AAAAgAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAQpGaXJlIHdpdGhvdXQgZXhwb3NpbmcgdGhlIGNlbGw6IHJlY29yZCBgY29tbWl0bWVudGAgKHNlZSBgYnVpbGRfc2hvdF9jb21taXRtZW50YCkgYXMgdGhlCnBlbmRpbmcgc2hvdC4gSXQgdGFrZXMgdGhlIHR1cm4gbGlrZSBgZmlyZWAgYW5kIGlzIG9wZW5lZCB3aXRoIGByZXZlYWxfc2hvdGAgb25jZSB0aGUKZGVmZW5kZXIgaXMgcmVhZHkgdG8gcmVzb2x2ZSwgc28gdGhlIGNvb3JkaW5hdGUgaXMgbm90IHB1YmxpYyB3aGlsZSB0aGVpciBwcm9vZiBpcyBwZW5kaW5nLgAAAAAAC2NvbW1pdF9zaG90AAAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3Nob290ZXIAAAAAEwAAAAAAAAAKY29tbWl0bWVudAAAAAAD7gAAACAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAGFHYW1lcyBzdGFydGVkIGFuZCBob3cgdGhleSBlbmRlZCBzaW5jZSBkZXBsb3ltZW50LCBmb3Igb3BlcmF0b3IgaGVhbHRoIGNoZWNrcyB3aXRob3V0IGFuCmluZGV4ZXIuAAAAAAAAC2dldF9tZXRyaWNzAAAAAAAAAAABAAAH0AAAAAdNZXRyaWNzAA==",
+        "AAAAAAAAAUpSZXZlYWwgdGhlIGNhbGxlcidzIHNpZGUgc2VlZCBvbmNlIGJvdGggc2lkZXMgaGF2ZSBjb21taXR0ZWQgYW5kIGJlZm9yZSB0aGUgYm9hcmRzIGFyZQppbi4gRmFpbHMgd2l0aCBgSW52YWxpZFNlZWRSZXZlYWxgIHVubGVzcyBpdCBoYXNoZXMgdG8gdGhlIGNvbW1pdHRlZCB2YWx1ZSwgYW5kIHdpdGgKYEludmFsaWRQaGFzZWAgb25jZSB0aGUgZ2FtZSBpcyBpbiBwcm9ncmVzcy4gVGhlIHNlY29uZCByZXZlYWwgc2V0cwpgc2VlZHMuc3RyZWFtYCB0byBrZWNjYWsyNTYoc2VlZF9wMSB8fCBzZWVkX3AyKSwgdGhlIGhlYWQgb2YgdGhlIGdhbWUncyByYW5kb20gaGFzaCBjaGFpbi4AAAAAAAtyZXZlYWxfc2VlZAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAABHNlZWQAAAPuAAAAIAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAK1PcGVuIHRoZSBjYWxsZXIncyBjb21taXR0ZWQgc2hvdCBhdCAoeCwgeSkuIEZhaWxzIHdpdGggYEludmFsaWRTaG90UmV2ZWFsYCB1bmxlc3MgdGhlIGNlbGwKYW5kIGBzYWx0YCBoYXNoIHRvIHRoZSBjb21taXR0ZWQgdmFsdWU7IHRoZSBzaG90IHRoZW4gcmVzb2x2ZXMgbGlrZSBhIGBmaXJlYCBzaG90LgAAAAAAAAtyZXZlYWxfc2hvdAAAAAAFAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAdzaG9vdGVyAAAAABMAAAAAAAAAAXgAAAAAAAAEAAAAAAAAAAF5AAAAAAAABAAAAAAAAAAEc2FsdAAAA+4AAAAgAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAM9XaGV0aGVyIGBzZXNzaW9uX2lkYCB3YXMgZXZlciBzdGFydGVkIGhlcmUuIEtlcHQgaW4gcGVyc2lzdGVudCBzdG9yYWdlLCBzbyBvbmNlIGBnZXRfZ2FtZWAKZmFpbHMgd2l0aCBgR2FtZU5vdEZvdW5kYCBmb3IgYSBzdGFydGVkIHNlc3Npb24sIHRoZSBodWIga25vd3MgdGhlIGdhbWUgZXhwaXJlZCBtaWQtbWF0Y2gKYW5kIGNhbiByZWZ1bmQgdGhlIHN0YWtlcy4AAAAAC3dhc19zdGFydGVkAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAAAQ==",
+        "AAAAAAAAAElDb21wbGV0ZXMgYSB0cmFuc2ZlciBzdGFydGVkIHdpdGggYHByb3Bvc2VfYWRtaW5gOyBzaWduZWQgYnkgdGhlIG5vbWluZWUuAAAAAAAADGFjY2VwdF9hZG1pbgAAAAAAAAABAAAAEw==",
         "AAAAAAAAAAAAAAAMY29tbWl0X2JvYXJkAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAABBib2FyZF9jb21taXRtZW50AAAD7gAAACAAAAABAAAD6QAAAAIAAAAD",
         "AAAAAAAAAAAAAAAMZ2V0X3ZlcmlmaWVyAAAAAAAAAAEAAAAT",
         "AAAAAAAAAAAAAAAMcmVzb2x2ZV9zaG90AAAABgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAIZGVmZW5kZXIAAAATAAAAAAAAAAZpc19oaXQAAAAAAAEAAAAAAAAACXN1bmtfc2hpcAAAAAAAAAQAAAAAAAAADXByb29mX3BheWxvYWQAAAAAAAAOAAAAAAAAABJwdWJsaWNfaW5wdXRzX2hhc2gAAAAAA+4AAAAgAAAAAQAAA+kAAAfQAAAAClNob3RSZXN1bHQAAAAAAAM=",
+        "AAAAAAAAAUNEaXNjbG9zZSBhIHBsYXllcidzIGJvYXJkIG9uY2UgdGhlIGdhbWUgaGFzIGVuZGVkLCBzbyBzcGVjdGF0b3JzIGFuZCByZXBsYXlzIGNhbiByZW5kZXIgaXQuCgpgcmV2ZWFsX3Byb29mYCBpcyBhIGBib2FyZF9yZXZlYWxgIHBheWxvYWQgdmVyaWZpZWQgYnkgdGhlIHJldmVhbCBhZGFwdGVyIGFzCmB2ZXJpZnkoYm9hcmRfY29tbWl0bWVudCwgYnVpbGRfcmV2ZWFsX2hhc2goLi4uKSwgcGF5bG9hZClgLiBUaGUgbGF5b3V0IG11c3QgYWxzbyBhZ3JlZSB3aXRoCmV2ZXJ5IGhpdCBhbmQgbWlzcyB0aGUgb3Bwb25lbnQgcmVjb3JkZWQgYWdhaW5zdCB0aGlzIGJvYXJkLgAAAAAMcmV2ZWFsX2JvYXJkAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAZsYXlvdXQAAAAAB9AAAAALQm9hcmRMYXlvdXQAAAAAAAAAAAxyZXZlYWxfcHJvb2YAAAAOAAAAAQAAA+kAAAACAAAAAw==",
         "AAAAAAAAAAAAAAAMc2V0X3ZlcmlmaWVyAAAAAQAAAAAAAAAMbmV3X3ZlcmlmaWVyAAAAEwAAAAA=",
         "AAAAAAAAAAAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAMAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAIZ2FtZV9odWIAAAATAAAAAAAAAAh2ZXJpZmllcgAAABMAAAAA",
-        "AAAAAAAAAAAAAAAYYnVpbGRfcHVibGljX2lucHV0c19oYXNoAAAACAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAIZGVmZW5kZXIAAAATAAAAAAAAAAdzaG9vdGVyAAAAABMAAAAAAAAAAXgAAAAAAAAEAAAAAAAAAAF5AAAAAAAABAAAAAAAAAAGaXNfaGl0AAAAAAABAAAAAAAAAAlzdW5rX3NoaXAAAAAAAAAEAAAAAAAAABBib2FyZF9jb21taXRtZW50AAAD7gAAACAAAAABAAAD7gAAACA=",
+        "AAAAAAAAAE9XaW5uZXIgb2YgYW4gZW5kZWQgZ2FtZTsgTm9uZSB3aGlsZSB0aGUgZ2FtZSBpcyBydW5uaW5nIG9yIGFmdGVyIGBjYW5jZWxfZ2FtZWAuAAAAAA1ldmFsdWF0ZV9nYW1lAAAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAD6AAAABMAAAAD",
+        "AAAAAAAAAKJSZXNvbHZlZCBzaG90cyAoYm90aCBzaWRlcyB0b2dldGhlcikgYWZ0ZXIgd2hpY2ggbmV3IGdhbWVzIGVuZCBvbiB0aGUgaGl0IGRpZmZlcmVudGlhbCwKd2l0aCBlcXVhbCBoaXRzIGdvaW5nIHRvIHRoZSBkZWZlbmRlciBvZiB0aGUgY2FwcGluZyBzaG90OyAwIG1lYW5zIG5vIGNhcC4AAAAAAA1nZXRfbWF4X3Nob3RzAAAAAAAAAAAAAAEAAAAE",
+        "AAAAAAAAAERMYXJnZXN0IHN0YWtlIGBzdGFydF9nYW1lYCBhY2NlcHRzIGZyb20gZWFjaCBwbGF5ZXI7IDAgbWVhbnMgbm8gY2FwLgAAAA1nZXRfbWF4X3N0YWtlAAAAAAAAAAAAAAEAAAAL",
+        "AAAAAAAAAERTbWFsbGVzdCBzdGFrZSBgc3RhcnRfZ2FtZWAgYWNjZXB0cyBmcm9tIGVhY2ggcGxheWVyICgxIHVubGVzcyBzZXQpLgAAAA1nZXRfbWluX3N0YWtlAAAAAAAAAAAAAAEAAAAL",
+        "AAAAAAAAAFZGaXJzdCBzdGVwIG9mIGFuIGFkbWluIHRyYW5zZmVyOyBgbmV3X2FkbWluYCB0YWtlcyBvdmVyIG9uY2UgdGhleSBjYWxsIGBhY2NlcHRfYWRtaW5gLgAAAAAADXByb3Bvc2VfYWRtaW4AAAAAAAABAAAAAAAAAAluZXdfYWRtaW4AAAAAAAATAAAAAA==",
+        "AAAAAAAAAFJBcHBsaWVzIHRvIGdhbWVzIHN0YXJ0ZWQgYWZ0ZXJ3YXJkczsgcnVubmluZyBnYW1lcyBrZWVwIHRoZSBjYXAgdGhleSBzdGFydGVkIHdpdGguAAAAAAANc2V0X21heF9zaG90cwAAAAAAAAEAAAAAAAAACW1heF9zaG90cwAAAAAAAAQAAAAA",
+        "AAAAAAAAAEAwIHJlbW92ZXMgdGhlIGNhcDsgb3RoZXJ3aXNlIGl0IG11c3Qgbm90IGJlIGJlbG93IHRoZSBtaW4gc3Rha2UuAAAADXNldF9tYXhfc3Rha2UAAAAAAAABAAAAAAAAAAltYXhfc3Rha2UAAAAAAAALAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAADZNdXN0IGJlIHBvc2l0aXZlIGFuZCwgd2hlbiBhIG1heCBpcyBzZXQsIG5vdCBhYm92ZSBpdC4AAAAAAA1zZXRfbWluX3N0YWtlAAAAAAAAAQAAAAAAAAAJbWluX3N0YWtlAAAAAAAACwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAFRBY3Rpb25zIGFwcGxpZWQgdG8gdGhlIGdhbWUgc28gZmFyOyB0aGUgYG1vdmVfY291bnRgIG9mIHRoZSBsYXRlc3QgYEdhbWVNb3ZlYCBldmVudC4AAAAOZ2V0X21vdmVfY291bnQAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAAFJTZXNzaW9uIGlkIGBzdGFydF9nYW1lKDAsIHBsYXllcjEsIHBsYXllcjIsIC4uKWAgZGVyaXZlcyBmb3IgdGhlIGdpdmVuIHBhaXIgbm9uY2UuAAAAAAAOZ2V0X3Nlc3Npb25faWQAAAAAAAMAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAAVub25jZQAAAAAAAAQAAAABAAAABA==",
+        "AAAAAAAAAT1SZXBsYWNlIGEgY29tbWl0dGVkIGJvYXJkIHdpdGggYSBmcmVzaCBjb21taXRtZW50IHRvIHRoZSBzYW1lIHBsYWNlbWVudCB1bmRlciBhIG5ldyBzYWx0LgoKYGVxdWl2YWxlbmNlX3Byb29mYCBpcyBhIGBib2FyZF9yZWNvbW1pdGAgcHJvb2YgcGF5bG9hZCB2ZXJpZmllZCBieSB0aGUgcmVjb21taXQgYWRhcHRlcgphcyBgdmVyaWZ5KG9sZF9jb21taXRtZW50LCBuZXdfY29tbWl0bWVudCwgcGF5bG9hZClgLCBpLmUuIHB1YmxpYyBpbnB1dHMgMC4uMSBiaW5kIHRoZQpjdXJyZW50IGNvbW1pdG1lbnQgYW5kIDIuLjMgYmluZCBgbmV3X2NvbW1pdG1lbnRgLgAAAAAAAA5yZWNvbW1pdF9ib2FyZAAAAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAA5uZXdfY29tbWl0bWVudAAAAAAD7gAAACAAAAAAAAAAEWVxdWl2YWxlbmNlX3Byb29mAAAAAAAADgAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAARVDaGVjayBhIGJvYXJkIGluIHRoZSBgZ2V0X3JldmVhbGVkX2JvYXJkYCBmb3JtYXQgKDEwMCBjZWxscywgcm93LW1ham9yLCBgMGAgPSB3YXRlciwKYDEuLjVgID0gQ2Fycmllci4uRGVzdHJveWVyKSBhZ2FpbnN0IHRoZSBwbGFjZW1lbnQgY2lyY3VpdCdzIHJ1bGVzOiBldmVyeSBzaGlwIHByZXNlbnQKd2l0aCBpdHMgbGVuZ3RoLCBvbiBvbmUgc3RyYWlnaHQgcnVuIGluc2lkZSB0aGUgYm9hcmQsIGFuZCBubyBvdmVybGFwLiBMaWtlIHRoZSBjaXJjdWl0LApzaGlwcyBtYXkgdG91Y2guAAAAAAAADnZhbGlkYXRlX2JvYXJkAAAAAAABAAAAAAAAAAVjZWxscwAAAAAAAA4AAAABAAAD6QAAAAIAAAfQAAAACkJvYXJkRXJyb3IAAA==",
+        "AAAAAAAAAAAAAAAPZ2V0X2dhbWVfc3RhdHVzAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAB9AAAAAKR2FtZVN0YXR1cwAAAAAAAw==",
+        "AAAAAAAAAEhTZXNzaW9uIGtleSBjdXJyZW50bHkgYXV0aG9yaXplZCB0byBgZmlyZWAgZm9yIGBwbGF5ZXJgLCBpZiBub3QgZXhwaXJlZC4AAAAPZ2V0X3Nlc3Npb25fa2V5AAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPoAAAH0AAAAApTZXNzaW9uS2V5AAA=",
+        "AAAAAAAAAQpTdGFydCBhIDJ2MiBnYW1lLiBFYWNoIHRlYW0ncyBsZWFkIHN0YWtlcyBpdHMgcG9pbnRzIGFuZCBzdGFuZHMgZm9yIHRoZSB0ZWFtIGF0IHRoZSBHYW1lCkh1YjsgbGVhZCBhbmQgcGFydG5lciB0YWtlIHR1cm5zIGZpcmluZyBmb3IgdGhlaXIgc2lkZSAobGVhZCBmaXJzdCkgYW5kIGRlZmVuZCBvbmUgc2hhcmVkCmJvYXJkLCB3aGljaCBlaXRoZXIgb2YgdGhlbSBjb21taXRzLiBUaGUgd2lubmVyIGlzIHJlcG9ydGVkIGFzIHRoZSB3aW5uaW5nIHRlYW0ncyBsZWFkLgAAAAAAD3N0YXJ0X3RlYW1fZ2FtZQAAAAAFAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAV0ZWFtMQAAAAAAB9AAAAAEVGVhbQAAAAAAAAAFdGVhbTIAAAAAAAfQAAAABFRlYW0AAAAAAAAADHRlYW0xX3BvaW50cwAAAAsAAAAAAAAADHRlYW0yX3BvaW50cwAAAAsAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAAP1TaW11bGF0ZSBgcmVzb2x2ZV9zaG90YCB3aXRob3V0IGNoYW5naW5nIHN0YXRlIGFuZCByZXBvcnQgd2hpY2ggY2hlY2sgd291bGQgZmFpbDogdGhlCmV4cGVjdGVkIHB1YmxpYyBpbnB1dHMgaGFzaCwgd2hldGhlciBpdCBtYXRjaGVzLCBhbmQgdGhlIHZlcmlmaWVyJ3MgYW5zd2VyIG9yIGFib3J0IGNvZGUuCk1lYW50IGZvciBwcm92ZXIgZGVidWdnaW5nIHRocm91Z2ggdHJhbnNhY3Rpb24gc2ltdWxhdGlvbiBiZWZvcmUgcGF5aW5nIGZlZXMuAAAAAAAAEGNoZWNrX3Jlc29sdXRpb24AAAAGAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAhkZWZlbmRlcgAAABMAAAAAAAAABmlzX2hpdAAAAAAAAQAAAAAAAAAJc3Vua19zaGlwAAAAAAAABAAAAAAAAAANcHJvb2ZfcGF5bG9hZAAAAAAAAA4AAAAAAAAAEnB1YmxpY19pbnB1dHNfaGFzaAAAAAAD7gAAACAAAAABAAAH0AAAAA9SZXNvbHV0aW9uQ2hlY2sA",
+        "AAAAAAAAADpDaGF0IGFuY2hvcnMgYHBsYXllcmAgcG9zdGVkIGluIHRoZSBzZXNzaW9uLCBvbGRlc3QgZmlyc3QuAAAAAAAQZ2V0X2NoYXRfYW5jaG9ycwAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPqAAAH0AAAAApDaGF0QW5jaG9yAAA=",
+        "AAAAAAAAAW9BbmNob3IgdGhlIGhhc2ggb2YgYW4gb2ZmLWNoYWluIGNoYXQgbWVzc2FnZSAoZ2FtZSBtZW1iZXJzIG9ubHkpLCBzbyBhIHRyYW5zY3JpcHQKcHJvZHVjZWQgaW4gYSBkaXNwdXRlIGNhbiBiZSBwcm92ZW4gYXV0aGVudGljIHdpdGhvdXQgcHV0dGluZyBtZXNzYWdlIGJvZGllcyBvbi1jaGFpbi4KUmF0ZS1saW1pdGVkIHBlciBwb3N0ZXIgdG8gb25lIGFuY2hvciBldmVyeSBgY2hhdF9hbmNob3JfaW50ZXJ2YWxfbGVkZ2Vyc2AsIHVwIHRvCmBtYXhfY2hhdF9hbmNob3JzYCBwZXIgcG9zdGVyIGFuZCBgbWF4X3Nlc3Npb25fY2hhdF9hbmNob3JzYCBwZXIgZ2FtZSAoc2VlIGBnZXRfcnVsZXNgKS4KUmV0dXJucyB0aGUgYW5jaG9yJ3MgaW5kZXguAAAAABBwb3N0X2NoYXRfYW5jaG9yAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAARoYXNoAAAD7gAAACAAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAAKZSZS1zZW5kcyB0aGUgaHViIGBlbmRfZ2FtZWAgdGhhdCBmYWlsZWQgd2hlbiB0aGUgd2lubmluZyBzaG90IHdhcyByZXNvbHZlZC4gQ2FsbGFibGUgYnkKYW55b25lOyBmYWlscyB3aXRoIGBIdWJOb3RpZmljYXRpb25Ob3RQZW5kaW5nYCBvbmNlIHRoZSBodWIgaGFzIGJlZW4gbm90aWZpZWQuAAAAAAAQcmV0cnlfaHViX25vdGlmeQAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAAAAAAARYnVpbGRfcmV2ZWFsX2hhc2gAAAAAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAABmxheW91dAAAAAAH0AAAAAtCb2FyZExheW91dAAAAAAAAAAAEGJvYXJkX2NvbW1pdG1lbnQAAAPuAAAAIAAAAAEAAAPuAAAAIA==",
+        "AAAAAAAAAAAAAAARZ2V0X3BlbmRpbmdfYWRtaW4AAAAAAAAAAAAAAQAAA+gAAAAT",
+        "AAAAAAAAAENQYWlyIG5vbmNlIHRoZSBuZXh0IGRlcml2ZWQgc2Vzc2lvbiBpZCBmb3IgKHBsYXllcjEsIHBsYXllcjIpIHVzZXMuAAAAABFnZXRfc2Vzc2lvbl9ub25jZQAAAAAAAAIAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAQAAAAQ=",
+        "AAAAAAAAAKxQZXItcGxheWVyIHNob3RzLCBoaXRzLCBhY2N1cmFjeSBhbmQgbG9uZ2VzdCBoaXQgc3RyZWFrIG9mIGEgd29uIGdhbWUuIFdyaXR0ZW4gd2hlbiB0aGUKZ2FtZSBlbmRzIGFuZCBrZXB0IGluIHBlcnNpc3RlbnQgc3RvcmFnZSwgc28gaXQgb3V0bGl2ZXMgYGdldF9nYW1lYCBhbmQgYGdldF9tb3Zlc2AuAAAAEmdldF9hcmNoaXZlZF9zdGF0cwAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAH0AAAAA1BcmNoaXZlZFN0YXRzAAAAAAAAAw==",
+        "AAAAAAAAAAAAAAASZ2V0X2ZpbmFsX3ZlcmlmaWVyAAAAAAAAAAAAAQAAA+gAAAAT",
+        "AAAAAAAAAHxSZXZlYWxlZCBib2FyZCBhcyAxMDAgY2VsbHMgaW5kZXhlZCBgeSAqIDEwICsgeGA6IDAgPSB3YXRlciwgMS4uNSA9IENhcnJpZXIuLkRlc3Ryb3llci4KT25seSBhdmFpbGFibGUgYWZ0ZXIgYHJldmVhbF9ib2FyZGAuAAAAEmdldF9yZXZlYWxlZF9ib2FyZAAAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAPqAAAABAAAAAM=",
+        "AAAAAAAAAJ5NZW1iZXJzLCBzdGFydCBsZWRnZXIgYW5kIHN0b3JhZ2UgdGllciBvZiBhIHN0YXJ0ZWQgc2Vzc2lvbiwgZm9yIHBvc3QtbW9ydGVtIGF1ZGl0cyBvZgpnYW1lcyB3aG9zZSBzdGF0ZSBoYXMgZXhwaXJlZC4gYE5vbmVgIGlmIHRoZSBzZXNzaW9uIHdhcyBuZXZlciBzdGFydGVkLgAAAAAAEmdldF9zZXNzaW9uX21hcmtlcgAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPoAAAH0AAAAA1TZXNzaW9uTWFya2VyAAAA",
+        "AAAAAAAAAbdSZXNvbHZlIHRoZSBnYW1lLXdpbm5pbmcgKDE3dGggaGl0KSBzaG90IHdpdGggYW4gYWRkaXRpb25hbCBgYm9hcmRfY29uc2lzdGVuY3lgIHByb29mIHRoYXQKZXZlcnkgcmVzdWx0IHJlcG9ydGVkIGFnYWluc3QgdGhlIGRlZmVuZGVyJ3MgYm9hcmQsIGluY2x1ZGluZyB0aGlzIG9uZSwgbWF0Y2hlcyB0aGUKY29tbWl0dGVkIHBsYWNlbWVudC4gUmVxdWlyZWQgaW5zdGVhZCBvZiBgcmVzb2x2ZV9zaG90YCBvbmNlIGEgZmluYWwgdmVyaWZpZXIgaXMgc2V0LgoKVGhlIGZpbmFsIGFkYXB0ZXIgaXMgY2FsbGVkIGFzIGB2ZXJpZnkoYm9hcmRfY29tbWl0bWVudCwgY29uc2lzdGVuY3lfaGFzaCwgcGF5bG9hZClgLAp3aGVyZSBgY29uc2lzdGVuY3lfaGFzaGAgaXMgYGJ1aWxkX2NvbnNpc3RlbmN5X2hhc2hgIG92ZXIgdGhlIHNob3QgaGlzdG9yeSBhZnRlciB0aGlzIHNob3QuAAAAABJyZXNvbHZlX2ZpbmFsX3Nob3QAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABGFyZ3MAAAfQAAAADUZpbmFsU2hvdEFyZ3MAAAAAAAABAAAD6QAAB9AAAAAKU2hvdFJlc3VsdAAAAAAAAw==",
+        "AAAAAAAAAEJEcm9wIHRoZSBwbGF5ZXIncyBzZXNzaW9uIGtleTsgYGZpcmVgIG5lZWRzIHRoZSBtYWluIHdhbGxldCBhZ2Fpbi4AAAAAABJyZXZva2Vfc2Vzc2lvbl9rZXkAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAA=",
+        "AAAAAAAAAAAAAAASc2V0X2ZpbmFsX3ZlcmlmaWVyAAAAAAABAAAAAAAAAAxuZXdfdmVyaWZpZXIAAAATAAAAAA==",
+        "AAAAAAAAAENTZXNzaW9ucyB0aGUgcGxheWVyIGhhcyBzdGFydGVkIHRoYXQgaGF2ZSBub3QgZW5kZWQgb3IgZXhwaXJlZCB5ZXQuAAAAABNnZXRfYWN0aXZlX3Nlc3Npb25zAAAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPqAAAABA==",
+        "AAAAAAAAAAAAAAATZ2V0X3JldmVhbF92ZXJpZmllcgAAAAAAAAAAAQAAA+gAAAAT",
+        "AAAAAAAAAAAAAAATc2V0X3JldmVhbF92ZXJpZmllcgAAAAABAAAAAAAAAAxuZXdfdmVyaWZpZXIAAAATAAAAAA==",
+        "AAAAAAAAAQZTdGFydCBhbiBvcGVuLWJvYXJkIHByYWN0aWNlIGdhbWU6IG5vIHBvaW50cywgbm8gR2FtZSBIdWIsIG5vIHByb29mcy4gQm90aCBwbGF5ZXJzIHN1Ym1pdAp0aGVpciBsYXlvdXQgaW4gY2xlYXIgd2l0aCBgY29tbWl0X3ByYWN0aWNlX2JvYXJkYCBhbmQgYHJlc29sdmVfcHJhY3RpY2Vfc2hvdGAgcmVzb2x2ZXMKZWFjaCBzaG90IGFnYWluc3QgaXQsIGZvciB0dXRvcmlhbHMsIFVJIHdvcmsgYW5kIGludGVncmF0aW9uIHRlc3RzIHdpdGhvdXQgYSBwcm92ZXIuAAAAAAATc3RhcnRfcHJhY3RpY2VfZ2FtZQAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAADpNYXhpbXVtIGNvbmN1cnJlbnQgc2Vzc2lvbnMgcGVyIHBsYXllcjsgMCBtZWFucyB1bmxpbWl0ZWQuAAAAAAAUZ2V0X21heF9hY3RpdmVfZ2FtZXMAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAAadSZXNvbHZlIGEgc2hvdCB0aGF0IHNpbmtzIGBzdW5rX3NoaXBgIGFuZCBkaXNjbG9zZSB0aGF0IHNoaXAncyBwbGFjZW1lbnQuIFJlcXVpcmVkIGluc3RlYWQKb2YgYHJlc29sdmVfc2hvdGAgLyBgcmVzb2x2ZV9maW5hbF9zaG90YCBmb3Igc2lua2luZyBzaG90cyBpbiBnYW1lcyBzdGFydGVkIHdpdGgKYGRpc2Nsb3NlX3N1bmtfc2hpcHNgOyBwYXNzIGBjb25zaXN0ZW5jeV9wcm9vZmAgd2hlbiB0aGUgc2hvdCBhbHNvIHdpbnMgdGhlIGdhbWUuCgpUaGUgcGxhY2VtZW50IGlzIGJvdW5kIGludG8gYGJ1aWxkX2Rpc2Nsb3N1cmVfaGFzaGAsIHByb3ZlbiBieSB0aGUgZGlzY2xvc3VyZSB2ZXJpZmllcgooYHJlc29sdmVfc2hvdF9kaXNjbG9zZWRgIGNpcmN1aXQpIGFuZCBtdXN0IGNvdmVyIG9ubHkgY2VsbHMgdGhlIHNob290ZXIgaGFzIGhpdC4AAAAAFHJlc29sdmVfc2lua2luZ19zaG90AAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAEYXJncwAAB9AAAAAPU2lua2luZ1Nob3RBcmdzAAAAAAEAAAPpAAAH0AAAAApTaG90UmVzdWx0AAAAAAAD",
+        "AAAAAAAAAAAAAAAUc2V0X21heF9hY3RpdmVfZ2FtZXMAAAABAAAAAAAAABBtYXhfYWN0aXZlX2dhbWVzAAAABAAAAAA=",
+        "AAAAAAAAAJ9MZXQgYGtleWAgc2lnbiBgZmlyZWAgZm9yIGBwbGF5ZXJgIGluIHRoaXMgc2Vzc2lvbiB1bnRpbCBsZWRnZXIgYGV4cGlyeWAsIHNvIGZhc3QgcGxheQpkb2Vzbid0IHByb21wdCB0aGUgbWFpbiB3YWxsZXQgb24gZXZlcnkgc2hvdC4gUmVwbGFjZXMgYW55IHByZXZpb3VzIGtleS4AAAAAFWF1dGhvcml6ZV9zZXNzaW9uX2tleQAAAAAAAAQAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAADa2V5AAAAABMAAAAAAAAABmV4cGlyeQAAAAAABAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAJNQdWJsaWMgaW5wdXRzIGhhc2ggZm9yIGByZXNvbHZlX3Npbmtpbmdfc2hvdGA6IHRoZSByZWd1bGFyIHNob3QgaGFzaCBwYXlsb2FkICh3aXRoCmBpc19oaXQgPSB0cnVlYCkgZm9sbG93ZWQgYnkgdGhlIHN1bmsgc2hpcCdzIHgsIHkgYW5kIGRpciBieXRlcy4AAAAAFWJ1aWxkX2Rpc2Nsb3N1cmVfaGFzaAAAAAAAAAgAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAACGRlZmVuZGVyAAAAEwAAAAAAAAAHc2hvb3RlcgAAAAATAAAAAAAAAAF4AAAAAAAABAAAAAAAAAABeQAAAAAAAAQAAAAAAAAACXN1bmtfc2hpcAAAAAAAAAQAAAAAAAAADnN1bmtfcGxhY2VtZW50AAAAAAfQAAAADVNoaXBQbGFjZW1lbnQAAAAAAAAAAAAAEGJvYXJkX2NvbW1pdG1lbnQAAAPuAAAAIAAAAAEAAAPuAAAAIA==",
+        "AAAAAAAAAAAAAAAVYnVpbGRfc2VlZF9jb21taXRtZW50AAAAAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAARzZWVkAAAD7gAAACAAAAABAAAD7gAAACA=",
+        "AAAAAAAAAAAAAAAVYnVpbGRfc2hvdF9jb21taXRtZW50AAAAAAAABQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAHc2hvb3RlcgAAAAATAAAAAAAAAAF4AAAAAAAABAAAAAAAAAABeQAAAAAAAAQAAAAAAAAABHNhbHQAAAPuAAAAIAAAAAEAAAPuAAAAIA==",
+        "AAAAAAAAAI9QbGFjZSBhIHByYWN0aWNlIGJvYXJkIGluIGNsZWFyLiBUaGUgbGF5b3V0IGlzIHB1Ymxpc2hlZCBhdCBvbmNlIChgZ2V0X3JldmVhbGVkX2JvYXJkYCkKYW5kIGl0cyByZXZlYWwgaGFzaCBzdGFuZHMgaW4gZm9yIHRoZSBib2FyZCBjb21taXRtZW50LgAAAAAVY29tbWl0X3ByYWN0aWNlX2JvYXJkAAAAAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAZsYXlvdXQAAAAAB9AAAAALQm9hcmRMYXlvdXQAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAATdFbmQgdGhlIGdhbWUgb25jZSBgc2VlZF9yZXZlYWxfd2luZG93X2xlZGdlcnNgIGhhdmUgcGFzc2VkIHNpbmNlIHRoZSBzZWNvbmQgc2VlZApjb21taXRtZW50IHdpdGggb25seSBvbmUgc2VlZCByZXZlYWxlZDogdGhlIHNpZGUgd2l0aGhvbGRpbmcgaXRzIHNlZWQgZm9yZmVpdHMuIENhbGxhYmxlCmJ5IGFueW9uZTsgcmV0dXJucyB0aGUgd2lubmVyLiBGYWlscyB3aXRoIGBTZWVkUmV2ZWFsV2luZG93T3BlbmAgd2hpbGUgdGhlIHdpbmRvdyBydW5zIGFuZApgTm9TZWVkV2l0aGhlbGRgIHVubGVzcyBleGFjdGx5IG9uZSBzaWRlIHJldmVhbGVkLgAAAAAVZm9yZmVpdF93aXRoaGVsZF9zZWVkAAAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAAEwAAAAM=",
+        "AAAAAAAAAP9MYXRlc3QgZmFpbHVyZSB0aGUgY29udHJhY3QgYWJzb3JiZWQgaW4gYSBzZXNzaW9uIGluc3RlYWQgb2YgcmV2ZXJ0aW5nOiB0aGUgZXJyb3IKbmFtZXNwYWNlIGFuZCBjb2RlLCB0aGUgb2ZmZW5kaW5nIGFkZHJlc3MgYW5kIHRoZSBsZWRnZXIgaXQgaGFwcGVuZWQgaW4uIEZhaWxlZCBjYWxscwpyb2xsIGJhY2sgdGhlaXIgb3duIHN0b3JhZ2UsIHNvIHRoZWlyIGNhdXNlIGlzIG9ubHkgcmVwb3J0ZWQgaW4gdGhlIHRyYW5zYWN0aW9uIHJlc3VsdC4AAAAAFWdldF9sYXN0X2Vycm9yX2RldGFpbAAAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6AAAB9AAAAALRXJyb3JEZXRhaWwA",
+        "AAAAAAAAAAAAAAAVZ2V0X3JlY29tbWl0X3ZlcmlmaWVyAAAAAAAAAAAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAALBSZXNvbHZlIHRoZSBwZW5kaW5nIHNob3Qgb2YgYSBwcmFjdGljZSBnYW1lIGZyb20gdGhlIGRlZmVuZGVyJ3MgY2xlYXIgbGF5b3V0LiBDYWxsYWJsZSBieQphbnlvbmU7IGhpdCwgc3VuayBzaGlwIGFuZCB0aGUgd2luIGFyZSBkZXJpdmVkIHRoZSBzYW1lIHdheSBhIHByb29mIHdvdWxkIGF0dGVzdCB0aGVtLgAAABVyZXNvbHZlX3ByYWN0aWNlX3Nob3QAAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAfQAAAAClNob3RSZXN1bHQAAAAAAAM=",
+        "AAAAAAAAAAAAAAAVc2V0X3JlY29tbWl0X3ZlcmlmaWVyAAAAAAAAAQAAAAAAAAAMbmV3X3ZlcmlmaWVyAAAAEwAAAAA=",
+        "AAAAAAAAAAAAAAAWYnVpbGRfY29uc2lzdGVuY3lfaGFzaAAAAAAABQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAIZGVmZW5kZXIAAAATAAAAAAAAAAVzaG90cwAAAAAAAAoAAAAAAAAABGhpdHMAAAAKAAAAAAAAABBib2FyZF9jb21taXRtZW50AAAD7gAAACAAAAABAAAD7gAAACA=",
+        "AAAAAAAAAFtHYW1lcyBzdGFydGVkIHdoaWxlIHRoaXMgaXMgc2V0IGRpc2Nsb3NlIHN1bmsgc2hpcCBwbGFjZW1lbnRzIChzZWUgYHJlc29sdmVfc2lua2luZ19zaG90YCkuAAAAABdnZXRfZGlzY2xvc3VyZV92ZXJpZmllcgAAAAAAAAAAAQAAA+gAAAAT",
+        "AAAAAAAAAAAAAAAXc2V0X2Rpc2Nsb3N1cmVfdmVyaWZpZXIAAAAAAQAAAAAAAAAMbmV3X3ZlcmlmaWVyAAAAEwAAAAA=",
+        "AAAAAAAAAK5QdWJsaWMgaW5wdXRzIGhhc2ggZm9yIGByZXNvbHZlX3Nob3RgIC8gYHJlc29sdmVfZmluYWxfc2hvdGAuIFRoZSBmbGF0IHBhcmFtZXRlciBsaXN0IGlzCnRoZSBjb250cmFjdCBpbnRlcmZhY2UgcHJvdmVycyBhbmQgdGhlIGZyb250ZW5kIGJpbmRpbmdzIGNhbGwsIHNvIGl0IHN0YXlzIHVuZ3JvdXBlZC4AAAAAABhidWlsZF9wdWJsaWNfaW5wdXRzX2hhc2gAAAAIAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAhkZWZlbmRlcgAAABMAAAAAAAAAB3Nob290ZXIAAAAAEwAAAAAAAAABeAAAAAAAAAQAAAAAAAAAAXkAAAAAAAAEAAAAAAAAAAZpc19oaXQAAAAAAAEAAAAAAAAACXN1bmtfc2hpcAAAAAAAAAQAAAAAAAAAEGJvYXJkX2NvbW1pdG1lbnQAAAPuAAAAIAAAAAEAAAPuAAAAIA==",
         "AAAAAAAAAKFOb3RpZnkgdGhlIEdhbWUgSHViIHRoYXQgdGhlIGdhbWUgaGFzIGVuZGVkLiBJZGVtcG90ZW50OyBzYWZlIHRvIGNhbGwgd2hlbiB0aGUgZ2FtZQppcyBhbHJlYWR5IGluIEVuZGVkIHN0YXRlIChlLmcuIGlmIGh1YiB3YXMgbm90IG5vdGlmaWVkIGR1cmluZyByZXNvbHZlX3Nob3QpLgAAAAAAABhub3RpZnlfZ2FtZV9lbmRlZF90b19odWIAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAACAAAAAw==",
-        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAFAAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAARR2FtZUFscmVhZHlFeGlzdHMAAAAAAAACAAAAAAAAAAlOb3RQbGF5ZXIAAAAAAAADAAAAAAAAABJTZWxmUGxheU5vdEFsbG93ZWQAAAAAAAQAAAAAAAAAEEdhbWVBbHJlYWR5RW5kZWQAAAAFAAAAAAAAAAxJbnZhbGlkUGhhc2UAAAAGAAAAAAAAABVCb2FyZEFscmVhZHlDb21taXR0ZWQAAAAAAAAHAAAAAAAAABFCb2FyZE5vdENvbW1pdHRlZAAAAAAAAAgAAAAAAAAAC05vdFlvdXJUdXJuAAAAAAkAAAAAAAAAEVBlbmRpbmdTaG90RXhpc3RzAAAAAAAACgAAAAAAAAANTm9QZW5kaW5nU2hvdAAAAAAAAAsAAAAAAAAAEUludmFsaWRDb29yZGluYXRlAAAAAAAADAAAAAAAAAATU2hvdEFscmVhZHlSZXNvbHZlZAAAAAANAAAAAAAAAA9JbnZhbGlkRGVmZW5kZXIAAAAADgAAAAAAAAAPSW52YWxpZFNoaXBUeXBlAAAAAA8AAAAAAAAAD0ludmFsaWRTdW5rU2hpcAAAAAAQAAAAAAAAAA9TaGlwQWxyZWFkeVN1bmsAAAAAEQAAAAAAAAAXSW52YWxpZFB1YmxpY0lucHV0c0hhc2gAAAAAEgAAAAAAAAAMSW52YWxpZFByb29mAAAAEwAAAAAAAAALVG9vTWFueUhpdHMAAAAAFA==",
-        "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAAZAAAAAAAAABNib2FyZF9jb21taXRtZW50X3AxAAAAA+gAAAPuAAAAIAAAAAAAAAATYm9hcmRfY29tbWl0bWVudF9wMgAAAAPoAAAD7gAAACAAAAAAAAAACmhpdHNfb25fcDEAAAAAAAQAAAAAAAAACmhpdHNfb25fcDIAAAAAAAQAAAAAAAAADWhpdHNfcDFfdG9fcDIAAAAAAAAKAAAAAAAAAA1oaXRzX3AyX3RvX3AxAAAAAAAACgAAAAAAAAAUbGFzdF9yZXNvbHZlZF9pc19oaXQAAAABAAAAAAAAABVsYXN0X3Jlc29sdmVkX3Nob290ZXIAAAAAAAPoAAAAEwAAAAAAAAAXbGFzdF9yZXNvbHZlZF9zdW5rX3NoaXAAAAAABAAAAAAAAAAPbGFzdF9yZXNvbHZlZF94AAAAAAQAAAAAAAAAD2xhc3RfcmVzb2x2ZWRfeQAAAAAEAAAAAAAAABRwZW5kaW5nX3Nob3Rfc2hvb3RlcgAAA+gAAAATAAAAAAAAAA5wZW5kaW5nX3Nob3RfeAAAAAAABAAAAAAAAAAOcGVuZGluZ19zaG90X3kAAAAAAAQAAAAAAAAABXBoYXNlAAAAAAAH0AAAAAlHYW1lUGhhc2UAAAAAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAAAAAAADnNob3RzX3AxX3RvX3AyAAAAAAAKAAAAAAAAAA5zaG90c19wMl90b19wMQAAAAAACgAAAAAAAAAQc3Vua19zaGlwc19vbl9wMQAAAAQAAAAAAAAAEHN1bmtfc2hpcHNfb25fcDIAAAAEAAAAAAAAAAR0dXJuAAAD6AAAABMAAAAAAAAABndpbm5lcgAAAAAD6AAAABM=",
-        "AAAAAgAAAAAAAAAAAAAACFNoaXBUeXBlAAAABQAAAAAAAAAAAAAAB0NhcnJpZXIAAAAAAAAAAAAAAAAKQmF0dGxlc2hpcAAAAAAAAAAAAAAAAAAHQ3J1aXNlcgAAAAAAAAAAAAAAAAlTdWJtYXJpbmUAAAAAAAAAAAAAAAAAAAlEZXN0cm95ZXIAAAA=",
+        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAANQAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAARR2FtZUFscmVhZHlFeGlzdHMAAAAAAAACAAAAAAAAAAlOb3RQbGF5ZXIAAAAAAAADAAAAAAAAABJTZWxmUGxheU5vdEFsbG93ZWQAAAAAAAQAAAAAAAAAEEdhbWVBbHJlYWR5RW5kZWQAAAAFAAAAAAAAAAxJbnZhbGlkUGhhc2UAAAAGAAAAAAAAABVCb2FyZEFscmVhZHlDb21taXR0ZWQAAAAAAAAHAAAAAAAAABFCb2FyZE5vdENvbW1pdHRlZAAAAAAAAAgAAAAAAAAAC05vdFlvdXJUdXJuAAAAAAkAAAAAAAAAEVBlbmRpbmdTaG90RXhpc3RzAAAAAAAACgAAAAAAAAANTm9QZW5kaW5nU2hvdAAAAAAAAAsAAAAAAAAAEUludmFsaWRDb29yZGluYXRlAAAAAAAADAAAAAAAAAATU2hvdEFscmVhZHlSZXNvbHZlZAAAAAANAAAAAAAAAA9JbnZhbGlkRGVmZW5kZXIAAAAADgAAAAAAAAAPSW52YWxpZFNoaXBUeXBlAAAAAA8AAAAAAAAAD0ludmFsaWRTdW5rU2hpcAAAAAAQAAAAAAAAAA9TaGlwQWxyZWFkeVN1bmsAAAAAEQAAAAAAAAAXSW52YWxpZFB1YmxpY0lucHV0c0hhc2gAAAAAEgAAAAAAAAAMSW52YWxpZFByb29mAAAAEwAAAAAAAAALVG9vTWFueUhpdHMAAAAAFAAAAAAAAAAVUmVjb21taXROb3RDb25maWd1cmVkAAAAAAAAFQAAAAAAAAATQ29tbWl0bWVudFVuY2hhbmdlZAAAAAAWAAAAAAAAABJUb29NYW55QWN0aXZlR2FtZXMAAAAAABcAAAAAAAAAEkZpbmFsUHJvb2ZSZXF1aXJlZAAAAAAAGAAAAAAAAAAMTm90RmluYWxTaG90AAAAGQAAAAAAAAAaRmluYWxWZXJpZmllck5vdENvbmZpZ3VyZWQAAAAAABoAAAAAAAAAE1JldmVhbE5vdENvbmZpZ3VyZWQAAAAAGwAAAAAAAAAUQm9hcmRBbHJlYWR5UmV2ZWFsZWQAAAAcAAAAAAAAABBCb2FyZE5vdFJldmVhbGVkAAAAHQAAAAAAAAANSW52YWxpZExheW91dAAAAAAAAB4AAAAAAAAAGlN1bmtTaGlwRGlzY2xvc3VyZVJlcXVpcmVkAAAAAAAfAAAAAAAAABJEaXNjbG9zdXJlRGlzYWJsZWQAAAAAACAAAAAAAAAAFEludmFsaWRTdW5rUGxhY2VtZW50AAAAIQAAAAAAAAARSW52YWxpZFNlc3Npb25LZXkAAAAAAAAiAAAAAAAAABNSZXRyYWN0V2luZG93Q2xvc2VkAAAAACMAAAAAAAAAEU5vUmV0cmFjdGlvbnNMZWZ0AAAAAAAAJAAAAAAAAAAZSHViTm90aWZpY2F0aW9uTm90UGVuZGluZwAAAAAAACUAAAAAAAAAD05vdFByYWN0aWNlR2FtZQAAAAAmAAAAAAAAABdQcmFjdGljZUdhbWVVbnN1cHBvcnRlZAAAAAAnAAAAAAAAABBTdGF0c05vdEFyY2hpdmVkAAAAKAAAAAAAAAAMSW52YWxpZFN0YWtlAAAAKQAAAAAAAAAPU2hvdE5vdFJldmVhbGVkAAAAACoAAAAAAAAAEUludmFsaWRTaG90UmV2ZWFsAAAAAAAAKwAAAAAAAAAUU2VlZEFscmVhZHlDb21taXR0ZWQAAAAsAAAAAAAAABBTZWVkTm90Q29tbWl0dGVkAAAALQAAAAAAAAARSW52YWxpZFNlZWRSZXZlYWwAAAAAAAAuAAAAAAAAABdSYW5kb21TdHJlYW1VbmF2YWlsYWJsZQAAAAAvAAAAAAAAAA9DaGF0UmF0ZUxpbWl0ZWQAAAAAMAAAAAAAAAAWQ2hhdEFuY2hvckxpbWl0UmVhY2hlZAAAAAAAMQAAAAAAAAARUmVzZXJ2ZWRTZXNzaW9uSWQAAAAAAAAyAAAAAAAAABdTZXNzaW9uQ2hhdExpbWl0UmVhY2hlZAAAAAAzAAAAAAAAABRTZWVkUmV2ZWFsV2luZG93T3BlbgAAADQAAAAAAAAADk5vU2VlZFdpdGhoZWxkAAAAAAA1",
+        "AAAABAAAAAAAAAAAAAAACkJvYXJkRXJyb3IAAAAAAAQAAAAAAAAAEEludmFsaWRCb2FyZFNpemUAAAABAAAAAAAAAAtJbnZhbGlkQ2VsbAAAAAACAAAAAAAAABFJbnZhbGlkU2hpcExlbmd0aAAAAAAAAAMAAAAAAAAADVNoaXBOb3RJbkxpbmUAAAAAAAAE",
         "AAAAAgAAAAAAAAAAAAAACUdhbWVQaGFzZQAAAAAAAAMAAAAAAAAAAAAAABBXYWl0aW5nRm9yQm9hcmRzAAAAAAAAAAAAAAAKSW5Qcm9ncmVzcwAAAAAAAAAAAAAAAAAFRW5kZWQAAAA=",
-        "AAAAAQAAAAAAAAAAAAAACUdhbWVSdWxlcwAAAAAAAAcAAAAAAAAADmJhdHRsZXNoaXBfbGVuAAAAAAAEAAAAAAAAAApib2FyZF9zaXplAAAAAAAEAAAAAAAAAAtjYXJyaWVyX2xlbgAAAAAEAAAAAAAAAAtjcnVpc2VyX2xlbgAAAAAEAAAAAAAAAA1kZXN0cm95ZXJfbGVuAAAAAAAABAAAAAAAAAANc3VibWFyaW5lX2xlbgAAAAAAAAQAAAAAAAAAEHRvdGFsX3NoaXBfY2VsbHMAAAAE",
-        "AAAAAQAAAAAAAAAAAAAAClNob3RSZXN1bHQAAAAAAAQAAAAAAAAABmlzX2hpdAAAAAAAAQAAAAAAAAAJbmV4dF90dXJuAAAAAAAD6AAAABMAAAAAAAAACXN1bmtfc2hpcAAAAAAAAAQAAAAAAAAABndpbm5lcgAAAAAD6AAAABM=",
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAABAAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAAAAAAAAAAADkdhbWVIdWJBZGRyZXNzAAAAAAAAAAAAAAAAAA9WZXJpZmllckFkZHJlc3MAAAAAAAAAAAAAAAAFQWRtaW4AAAA=" ]),
+        "AAAAAgAAAAAAAAAAAAAACkdhbWVTdGF0dXMAAAAAAAQAAAAAAAAAAAAAAAVTZXR1cAAAAAAAAAAAAAAAAAAACkluUHJvZ3Jlc3MAAAAAAAAAAAAAAAAABUVuZGVkAAAAAAAAAAAAAAAAAAAJQ2FuY2VsbGVkAAAA",
+        "AAAAAQAAAAAAAAAAAAAABFRlYW0AAAACAAAAAAAAAARsZWFkAAAAEwAAAAAAAAAHcGFydG5lcgAAAAAT",
+        "AAAAAQAAAAAAAAAAAAAACEh1YlNlYXRzAAAAAgAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAdwbGF5ZXIyAAAAABM=",
+        "AAAAAQAAAAAAAAAAAAAADFNlZWRFeGNoYW5nZQAAAAcAAAAAAAAADWNvbW1pdG1lbnRfcDEAAAAAAAPoAAAD7gAAACAAAAAAAAAADWNvbW1pdG1lbnRfcDIAAAAAAAPoAAAD7gAAACAAAAAAAAAABWRyYXdzAAAAAAAABAAAAAAAAAAPcmV2ZWFsX2RlYWRsaW5lAAAAAAQAAAAAAAAAB3NlZWRfcDEAAAAD6AAAA+4AAAAgAAAAAAAAAAdzZWVkX3AyAAAAA+gAAAPuAAAAIAAAAAAAAAAGc3RyZWFtAAAAAAPoAAAD7gAAACA=",
+        "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAAnAAAAAAAAAAthZGp1ZGljYXRlZAAAAAABAAAAAAAAABNib2FyZF9jb21taXRtZW50X3AxAAAAA+gAAAPuAAAAIAAAAAAAAAATYm9hcmRfY29tbWl0bWVudF9wMgAAAAPoAAAD7gAAACAAAAAAAAAAE2Rpc2Nsb3NlX3N1bmtfc2hpcHMAAAAAAQAAAAAAAAAKaGl0c19vbl9wMQAAAAAABAAAAAAAAAAKaGl0c19vbl9wMgAAAAAABAAAAAAAAAANaGl0c19wMV90b19wMgAAAAAAAAoAAAAAAAAADWhpdHNfcDJfdG9fcDEAAAAAAAAKAAAAAAAAAAlodWJfc2VhdHMAAAAAAAfQAAAACEh1YlNlYXRzAAAAAAAAABRsYXN0X3Jlc29sdmVkX2lzX2hpdAAAAAEAAAAAAAAAFWxhc3RfcmVzb2x2ZWRfc2hvb3RlcgAAAAAAA+gAAAATAAAAAAAAABdsYXN0X3Jlc29sdmVkX3N1bmtfc2hpcAAAAAAEAAAAAAAAAA9sYXN0X3Jlc29sdmVkX3gAAAAABAAAAAAAAAAPbGFzdF9yZXNvbHZlZF95AAAAAAQAAAAAAAAACW1heF9zaG90cwAAAAAAAAQAAAAAAAAACm1vdmVfY291bnQAAAAAAAQAAAAAAAAAGHBlbmRpbmdfaHViX25vdGlmaWNhdGlvbgAAAAEAAAAAAAAAF3BlbmRpbmdfc2hvdF9jb21taXRtZW50AAAAA+gAAAPuAAAAIAAAAAAAAAATcGVuZGluZ19zaG90X2xlZGdlcgAAAAAEAAAAAAAAABRwZW5kaW5nX3Nob3Rfc2hvb3RlcgAAA+gAAAATAAAAAAAAAA5wZW5kaW5nX3Nob3RfeAAAAAAABAAAAAAAAAAOcGVuZGluZ19zaG90X3kAAAAAAAQAAAAAAAAABXBoYXNlAAAAAAAH0AAAAAlHYW1lUGhhc2UAAAAAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAPcGxheWVyMV9wYXJ0bmVyAAAAA+gAAAATAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA9wbGF5ZXIyX3BhcnRuZXIAAAAD6AAAABMAAAAAAAAADnBsYXllcjJfcG9pbnRzAAAAAAALAAAAAAAAAAhwcmFjdGljZQAAAAEAAAAAAAAADnJldHJhY3Rpb25zX3AxAAAAAAAEAAAAAAAAAA5yZXRyYWN0aW9uc19wMgAAAAAABAAAAAAAAAAFc2VlZHMAAAAAAAfQAAAADFNlZWRFeGNoYW5nZQAAAAAAAAAOc2hvdHNfcDFfdG9fcDIAAAAAAAoAAAAAAAAADnNob3RzX3AyX3RvX3AxAAAAAAAKAAAAAAAAABBzdW5rX3NoaXBzX29uX3AxAAAABAAAAAAAAAAQc3Vua19zaGlwc19vbl9wMgAAAAQAAAAAAAAABHR1cm4AAAPoAAAAEwAAAAAAAAAGd2lubmVyAAAAAAPoAAAAEw==",
+        "AAAAAgAAAAAAAAAAAAAACFNoaXBUeXBlAAAABQAAAAAAAAAAAAAAB0NhcnJpZXIAAAAAAAAAAAAAAAAKQmF0dGxlc2hpcAAAAAAAAAAAAAAAAAAHQ3J1aXNlcgAAAAAAAAAAAAAAAAlTdWJtYXJpbmUAAAAAAAAAAAAAAAAAAAlEZXN0cm95ZXIAAAA=",
+        "AAAAAQAAAAAAAAAAAAAADVNoaXBQbGFjZW1lbnQAAAAAAAADAAAAAAAAAANkaXIAAAAABAAAAAAAAAABeAAAAAAAAAQAAAAAAAAAAXkAAAAAAAAE",
+        "AAAAAQAAAAAAAAAAAAAAC0JvYXJkTGF5b3V0AAAAAAMAAAAAAAAACHNoaXBfZGlyAAAD6gAAAAQAAAAAAAAABnNoaXBfeAAAAAAD6gAAAAQAAAAAAAAABnNoaXBfeQAAAAAD6gAAAAQ=",
+        "AAAAAgAAAAAAAAAAAAAADVN1bmtQbGFjZW1lbnQAAAAAAAACAAAAAAAAAAAAAAALVW5kaXNjbG9zZWQAAAAAAQAAAAAAAAAJRGlzY2xvc2VkAAAAAAAAAQAAB9AAAAANU2hpcFBsYWNlbWVudAAAAA==",
+        "AAAAAQAAAAAAAAAAAAAACk1vdmVSZWNvcmQAAAAAAAgAAAAAAAAABWluZGV4AAAAAAAABAAAAAAAAAAGaXNfaGl0AAAAAAABAAAAAAAAAAZsZWRnZXIAAAAAAAQAAAAAAAAAEnNob290ZXJfaXNfcGxheWVyMQAAAAAAAQAAAAAAAAAOc3Vua19wbGFjZW1lbnQAAAAAB9AAAAANU3Vua1BsYWNlbWVudAAAAAAAAAAAAAAJc3Vua19zaGlwAAAAAAAABAAAAAAAAAABeAAAAAAAAAQAAAAAAAAAAXkAAAAAAAAE",
+        "AAAAAQAAAAAAAAAAAAAAClNlc3Npb25LZXkAAAAAAAIAAAAAAAAABmV4cGlyeQAAAAAABAAAAAAAAAADa2V5AAAAABM=",
+        "AAAAAgAAAAAAAAAAAAAAC1N0b3JhZ2VUaWVyAAAAAAEAAAAAAAAAAAAAAAlUZW1wb3JhcnkAAAA=",
+        "AAAAAQAAAAAAAAAAAAAADVNlc3Npb25NYXJrZXIAAAAAAAADAAAAAAAAAAdwbGF5ZXJzAAAAA+oAAAATAAAAAAAAAAxzdGFydF9sZWRnZXIAAAAEAAAAAAAAAAR0aWVyAAAH0AAAAAtTdG9yYWdlVGllcgA=",
+        "AAAAAQAAAAAAAAAAAAAACkNoYXRBbmNob3IAAAAAAAIAAAAAAAAABGhhc2gAAAPuAAAAIAAAAAAAAAAGbGVkZ2VyAAAAAAAE",
+        "AAAAAQAAAAAAAAAAAAAADFNob290ZXJTdGF0cwAAAAQAAAAAAAAADGFjY3VyYWN5X2JwcwAAAAQAAAAAAAAABGhpdHMAAAAEAAAAAAAAABJsb25nZXN0X2hpdF9zdHJlYWsAAAAAAAQAAAAAAAAABXNob3RzAAAAAAAABA==",
+        "AAAAAQAAAAAAAAAAAAAADUFyY2hpdmVkU3RhdHMAAAAAAAAGAAAAAAAAAAxlbmRlZF9sZWRnZXIAAAAEAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAADXBsYXllcjFfc3RhdHMAAAAAAAfQAAAADFNob290ZXJTdGF0cwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA1wbGF5ZXIyX3N0YXRzAAAAAAAH0AAAAAxTaG9vdGVyU3RhdHMAAAAAAAAABndpbm5lcgAAAAAAEw==",
+        "AAAAAQAAAAAAAAAAAAAAB01ldHJpY3MAAAAABgAAAAAAAAARZ2FtZXNfYWRqdWRpY2F0ZWQAAAAAAAAEAAAAAAAAAA9nYW1lc19jYW5jZWxsZWQAAAAABAAAAAAAAAALZ2FtZXNfZW5kZWQAAAAABAAAAAAAAAANZ2FtZXNfc3RhcnRlZAAAAAAAAAQAAAAAAAAAFHByYWN0aWNlX2dhbWVzX2VuZGVkAAAABAAAAAAAAAAWcHJhY3RpY2VfZ2FtZXNfc3RhcnRlZAAAAAAABA==",
+        "AAAAAQAAAAAAAAAAAAAADUZpbmFsU2hvdEFyZ3MAAAAAAAAGAAAAAAAAABFjb25zaXN0ZW5jeV9wcm9vZgAAAAAAAA4AAAAAAAAACGRlZmVuZGVyAAAAEwAAAAAAAAAGaXNfaGl0AAAAAAABAAAAAAAAAA1wcm9vZl9wYXlsb2FkAAAAAAAADgAAAAAAAAAScHVibGljX2lucHV0c19oYXNoAAAAAAPuAAAAIAAAAAAAAAAJc3Vua19zaGlwAAAAAAAABA==",
+        "AAAAAQAAAAAAAAAAAAAAD1NpbmtpbmdTaG90QXJncwAAAAAGAAAAAAAAABFjb25zaXN0ZW5jeV9wcm9vZgAAAAAAA+gAAAAOAAAAAAAAAAhkZWZlbmRlcgAAABMAAAAAAAAADXByb29mX3BheWxvYWQAAAAAAAAOAAAAAAAAABJwdWJsaWNfaW5wdXRzX2hhc2gAAAAAA+4AAAAgAAAAAAAAAA5zdW5rX3BsYWNlbWVudAAAAAAH0AAAAA1TaGlwUGxhY2VtZW50AAAAAAAAAAAAAAlzdW5rX3NoaXAAAAAAAAAE",
+        "AAAAAQAAAAAAAAAAAAAAClNob3RSZXN1bHQAAAAAAAYAAAAAAAAABmlzX2hpdAAAAAAAAQAAAAAAAAAKbW92ZV9jb3VudAAAAAAABAAAAAAAAAAJbmV4dF90dXJuAAAAAAAD6AAAABMAAAAAAAAADnN1bmtfcGxhY2VtZW50AAAAAAfQAAAADVN1bmtQbGFjZW1lbnQAAAAAAAAAAAAACXN1bmtfc2hpcAAAAAAAAAQAAAAAAAAABndpbm5lcgAAAAAD6AAAABM=",
+        "AAAAAQAAAAAAAAAAAAAAD1Jlc29sdXRpb25DaGVjawAAAAAJAAAAAAAAABBib2FyZF9jb21taXRtZW50AAAD6AAAA+4AAAAgAAAAAAAAAAplcnJvcl9jb2RlAAAAAAAEAAAAAAAAAA1leHBlY3RlZF9oYXNoAAAAAAAD6AAAA+4AAAAgAAAAAAAAAAxoYXNoX21hdGNoZXMAAAABAAAAAAAAAAtwcm9vZl92YWxpZAAAAAABAAAAAAAAAAdzaG9vdGVyAAAAA+gAAAATAAAAAAAAABN2ZXJpZmllcl9lcnJvcl9jb2RlAAAAAAQAAAAAAAAAAXgAAAAAAAAEAAAAAAAAAAF5AAAAAAAABA==",
+        "AAAAAQAAAAAAAAAAAAAACUdhbWVSdWxlcwAAAAAAABEAAAAAAAAADmJhdHRsZXNoaXBfbGVuAAAAAAAEAAAAAAAAAApib2FyZF9zaXplAAAAAAAEAAAAAAAAAAtjYXJyaWVyX2xlbgAAAAAEAAAAAAAAABxjaGF0X2FuY2hvcl9pbnRlcnZhbF9sZWRnZXJzAAAABAAAAAAAAAALY3J1aXNlcl9sZW4AAAAABAAAAAAAAAANZGVzdHJveWVyX2xlbgAAAAAAAAQAAAAAAAAAE2Rpc2Nsb3NlX3N1bmtfc2hpcHMAAAAAAQAAAAAAAAAQbWF4X2NoYXRfYW5jaG9ycwAAAAQAAAAAAAAAGG1heF9zZXNzaW9uX2NoYXRfYW5jaG9ycwAAAAQAAAAAAAAAFG1heF9zaG90X3JldHJhY3Rpb25zAAAABAAAAAAAAAAJbWF4X3Nob3RzAAAAAAAABAAAAAAAAAAJbWF4X3N0YWtlAAAAAAAACwAAAAAAAAAJbWluX3N0YWtlAAAAAAAACwAAAAAAAAAac2VlZF9yZXZlYWxfd2luZG93X2xlZGdlcnMAAAAAAAQAAAAAAAAAG3Nob3RfcmV0cmFjdF93aW5kb3dfbGVkZ2VycwAAAAAEAAAAAAAAAA1zdWJtYXJpbmVfbGVuAAAAAAAABAAAAAAAAAAQdG90YWxfc2hpcF9jZWxscwAAAAQ=",
+        "AAAAAQAAAAAAAAAAAAAADENvbnRyYWN0SW5mbwAAAAQAAAAAAAAACmJ1aWxkX2hhc2gAAAAAABAAAAAAAAAACmludGVyZmFjZXMAAAAAA+oAAAAQAAAAAAAAAARuYW1lAAAAEAAAAAAAAAAHdmVyc2lvbgAAAAAQ",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAFwAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAEAAAAAAAAACUdhbWVTdGF0ZQAAAAAAAAEAAAAEAAAAAAAAAAAAAAAOR2FtZUh1YkFkZHJlc3MAAAAAAAAAAAAAAAAAD1ZlcmlmaWVyQWRkcmVzcwAAAAAAAAAAAAAAABdSZWNvbW1pdFZlcmlmaWVyQWRkcmVzcwAAAAAAAAAAAAAAABRGaW5hbFZlcmlmaWVyQWRkcmVzcwAAAAAAAAAAAAAAFVJldmVhbFZlcmlmaWVyQWRkcmVzcwAAAAAAAAAAAAAAAAAAGURpc2Nsb3N1cmVWZXJpZmllckFkZHJlc3MAAAAAAAABAAAAAAAAAA5QbGF5ZXJTZXNzaW9ucwAAAAAAAQAAABMAAAAAAAAAAAAAAA5NYXhBY3RpdmVHYW1lcwAAAAAAAAAAAAAAAAAITWF4U2hvdHMAAAAAAAAAAAAAAAhNaW5TdGFrZQAAAAAAAAAAAAAACE1heFN0YWtlAAAAAQAAAAAAAAAFTW92ZXMAAAAAAAACAAAABAAAAAQAAAABAAAAAAAAAAZSZXZlYWwAAAAAAAIAAAAEAAAAEwAAAAEAAAAAAAAAClNlc3Npb25LZXkAAAAAAAIAAAAEAAAAEwAAAAEAAAAAAAAACVBhaXJOb25jZQAAAAAAAAIAAAATAAAAEwAAAAEAAAAAAAAADUFyY2hpdmVkU3RhdHMAAAAAAAABAAAABAAAAAAAAAAAAAAAB01ldHJpY3MAAAAAAQAAAAAAAAANU2Vzc2lvbk1hcmtlcgAAAAAAAAEAAAAEAAAAAQAAAAAAAAALQ2hhdEFuY2hvcnMAAAAAAgAAAAQAAAATAAAAAQAAAAAAAAAPQ2hhdEFuY2hvckNvdW50AAAAAAEAAAAEAAAAAQAAAAAAAAAJTGFzdEVycm9yAAAAAAAAAQAAAAQ=",
+        "AAAAAQAAAAAAAAAAAAAACUdhbWVTZXR1cAAAAAAAAAwAAAAAAAAAE2JvYXJkX2NvbW1pdG1lbnRfcDEAAAAD6AAAA+4AAAAgAAAAAAAAABNib2FyZF9jb21taXRtZW50X3AyAAAAA+gAAAPuAAAAIAAAAAAAAAATZGlzY2xvc2Vfc3Vua19zaGlwcwAAAAABAAAAAAAAAAlodWJfc2VhdHMAAAAAAAfQAAAACEh1YlNlYXRzAAAAAAAAAAltYXhfc2hvdHMAAAAAAAAEAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAAD3BsYXllcjFfcGFydG5lcgAAAAPoAAAAEwAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAPcGxheWVyMl9wYXJ0bmVyAAAAA+gAAAATAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAAIcHJhY3RpY2UAAAAB",
+        "AAAAAQAAAAAAAAAAAAAACUdhbWVTdGF0ZQAAAAAAABsAAAAAAAAAC2FkanVkaWNhdGVkAAAAAAEAAAAAAAAACmhpdHNfb25fcDEAAAAAAAQAAAAAAAAACmhpdHNfb25fcDIAAAAAAAQAAAAAAAAADWhpdHNfcDFfdG9fcDIAAAAAAAAKAAAAAAAAAA1oaXRzX3AyX3RvX3AxAAAAAAAACgAAAAAAAAAUbGFzdF9yZXNvbHZlZF9pc19oaXQAAAABAAAAAAAAABVsYXN0X3Jlc29sdmVkX3Nob290ZXIAAAAAAAPoAAAAEwAAAAAAAAAXbGFzdF9yZXNvbHZlZF9zdW5rX3NoaXAAAAAABAAAAAAAAAAPbGFzdF9yZXNvbHZlZF94AAAAAAQAAAAAAAAAD2xhc3RfcmVzb2x2ZWRfeQAAAAAEAAAAAAAAAAptb3ZlX2NvdW50AAAAAAAEAAAAAAAAABhwZW5kaW5nX2h1Yl9ub3RpZmljYXRpb24AAAABAAAAAAAAABdwZW5kaW5nX3Nob3RfY29tbWl0bWVudAAAAAPoAAAD7gAAACAAAAAAAAAAE3BlbmRpbmdfc2hvdF9sZWRnZXIAAAAABAAAAAAAAAAUcGVuZGluZ19zaG90X3Nob290ZXIAAAPoAAAAEwAAAAAAAAAOcGVuZGluZ19zaG90X3gAAAAAAAQAAAAAAAAADnBlbmRpbmdfc2hvdF95AAAAAAAEAAAAAAAAAAVwaGFzZQAAAAAAB9AAAAAJR2FtZVBoYXNlAAAAAAAAAAAAAA5yZXRyYWN0aW9uc19wMQAAAAAABAAAAAAAAAAOcmV0cmFjdGlvbnNfcDIAAAAAAAQAAAAAAAAABXNlZWRzAAAAAAAH0AAAAAxTZWVkRXhjaGFuZ2UAAAAAAAAADnNob3RzX3AxX3RvX3AyAAAAAAAKAAAAAAAAAA5zaG90c19wMl90b19wMQAAAAAACgAAAAAAAAAQc3Vua19zaGlwc19vbl9wMQAAAAQAAAAAAAAAEHN1bmtfc2hpcHNfb25fcDIAAAAEAAAAAAAAAAR0dXJuAAAD6AAAABMAAAAAAAAABndpbm5lcgAAAAAD6AAAABM=",
+        "AAAABQAAAKBQdWJsaXNoZWQgYnkgZXZlcnkgYWN0aW9uIHRoYXQgY2hhbmdlcyBhIGdhbWUsIG51bWJlcmVkIGJ5IGBHYW1lOjptb3ZlX2NvdW50YC4gQ2xpZW50cyBvcmRlcgp1cGRhdGVzIGJ5IGBtb3ZlX2NvdW50YCBhbmQgcmVmZXRjaCBgZ2V0X2dhbWVgIHdoZW4gdGhleSBzZWUgYSBnYXAuAAAAAAAAAAhHYW1lTW92ZQAAAAEAAAAJZ2FtZV9tb3ZlAAAAAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAAAAAAACm1vdmVfY291bnQAAAAAAAQAAAAAAAAAAAAAAAZhY3Rpb24AAAAAABEAAAAAAAAAAAAAAAVhY3RvcgAAAAAAA+gAAAATAAAAAAAAAAI=",
+        "AAAABQAAAHlQdWJsaXNoZWQgYnkgYHBvc3RfY2hhdF9hbmNob3JgLCBzbyBjaGF0IGNsaWVudHMgY2FuIHNob3cgd2hpY2ggbWVzc2FnZXMgYXJlIGFuY2hvcmVkIHdpdGhvdXQKcG9sbGluZyBgZ2V0X2NoYXRfYW5jaG9yc2AuAAAAAAAAAAAAAAxDaGF0QW5jaG9yZWQAAAABAAAADWNoYXRfYW5jaG9yZWQAAAAAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAAAAAAAAGcG9zdGVyAAAAAAATAAAAAQAAAAAAAAAFaW5kZXgAAAAAAAAEAAAAAAAAAAAAAAAEaGFzaAAAA+4AAAAgAAAAAAAAAAI=",
+        "AAAABQAAAOlQdWJsaXNoZWQgcmlnaHQgYmVmb3JlIGFuIGVudHJ5cG9pbnQgcmV0dXJucyBhIGRvbWFpbiBlcnJvci4KCkV2ZW50cyBvZiBhIGZhaWxlZCBpbnZvY2F0aW9uIGFyZSBub3QgY29tbWl0dGVkLCBidXQgdGhleSBhcmUgcmV0dXJuZWQgYnkgdHJhbnNhY3Rpb24Kc2ltdWxhdGlvbiBhbmQgZmFpbGVkLXRyYW5zYWN0aW9uIG1ldGEsIHdoaWNoIGlzIHdoZXJlIHN1cHBvcnQgdG9vbGluZyBwaWNrcyB0aGVtIHVwLgAAAAAAAAAAAAAMRXJyb3JDb250ZXh0AAAAAQAAAA1lcnJvcl9jb250ZXh0AAAAAAAABwAAAAAAAAAJbmFtZXNwYWNlAAAAAAAAEQAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAAAAAAAAZhY3Rpb24AAAAAABEAAAAAAAAAAAAAAARjb2RlAAAABAAAAAAAAAAAAAAABmNhbGxlcgAAAAAD6AAAABMAAAAAAAAAAAAAAARhcmdzAAAD6gAAAAQAAAAAAAAAAAAAAAZsZWRnZXIAAAAAAAQAAAAAAAAAAg==",
+        "AAAAAQAAAShMYXN0IGZhaWx1cmUgc3RvcmVkIGZvciBhIHNlc3Npb24sIHJldHVybmVkIGJ5IGBnZXRfbGFzdF9lcnJvcl9kZXRhaWxgLgoKQSBmYWlsaW5nIGVudHJ5cG9pbnQgcm9sbHMgYmFjayBpdHMgc3RvcmFnZSBhbG9uZyB3aXRoIGV2ZXJ5dGhpbmcgZWxzZSwgc28gb25seSBmYWlsdXJlcyB0aGUKY29udHJhY3QgYWJzb3JicyB3aGlsZSB0aGUgY2FsbCBpdHNlbGYgc3VjY2VlZHMgYXJlIHN0b3JlZDogdGhlIEdhbWUgSHViIHJlamVjdGluZyBgZW5kX2dhbWVgCm9uIGEgd2lubmluZyBzaG90IG9yIGEgc2VlZCBmb3JmZWl0LgAAAAAAAAALRXJyb3JEZXRhaWwAAAAABgAAAAAAAAAGYWN0aW9uAAAAAAARAAAAAAAAAAdhZGRyZXNzAAAAA+gAAAATAAAAAAAAAARhcmdzAAAD6gAAAAQAAAAAAAAABGNvZGUAAAAEAAAAAAAAAAZsZWRnZXIAAAAAAAQAAAAAAAAACW5hbWVzcGFjZQAAAAAAABE=",
+        "AAAAAgAAAKZJbnN0YW5jZS1zdG9yYWdlIGtleXMuIGBBZG1pbmAgZW5jb2RlcyBleGFjdGx5IGxpa2UgdGhlIGBEYXRhS2V5OjpBZG1pbmAgdmFyaWFudCB0aGUKY29udHJhY3RzIHVzZWQgYmVmb3JlLCBzbyBkZXBsb3llZCBpbnN0YW5jZXMga2VlcCB0aGVpciBhZG1pbiBhY3Jvc3MgdGhlIHVwZ3JhZGUuAAAAAAAAAAAACk93bmFibGVLZXkAAAAAAAMAAAAAAAAAAAAAAAVBZG1pbgAAAAAAAAAAAAAAAAAADFBlbmRpbmdBZG1pbgAAAAEAAAAAAAAABFJvbGUAAAACAAAAEQAAABM=" ]),
       options
     )
   }
@@ -248,14 +1030,75 @@ export class Client extends ContractClient {
         set_hub: this.txFromJSON<null>,
         upgrade: this.txFromJSON<null>,
         get_game: this.txFromJSON<Result<Game>>,
+        get_info: this.txFromJSON<ContractInfo>,
         get_admin: this.txFromJSON<string>,
+        get_moves: this.txFromJSON<Result<Array<MoveRecord>>>,
         get_rules: this.txFromJSON<GameRules>,
         set_admin: this.txFromJSON<null>,
-        start_game: this.txFromJSON<Result<void>>,
+        start_game: this.txFromJSON<Result<u32>>,
+        cancel_game: this.txFromJSON<Result<void>>,
+        cancel_shot: this.txFromJSON<Result<void>>,
+        commit_seed: this.txFromJSON<Result<void>>,
+        commit_shot: this.txFromJSON<Result<void>>,
+        get_metrics: this.txFromJSON<Metrics>,
+        reveal_seed: this.txFromJSON<Result<void>>,
+        reveal_shot: this.txFromJSON<Result<void>>,
+        was_started: this.txFromJSON<boolean>,
+        accept_admin: this.txFromJSON<string>,
         commit_board: this.txFromJSON<Result<void>>,
         get_verifier: this.txFromJSON<string>,
         resolve_shot: this.txFromJSON<Result<ShotResult>>,
+        reveal_board: this.txFromJSON<Result<void>>,
         set_verifier: this.txFromJSON<null>,
+        evaluate_game: this.txFromJSON<Result<Option<string>>>,
+        get_max_shots: this.txFromJSON<u32>,
+        get_max_stake: this.txFromJSON<i128>,
+        get_min_stake: this.txFromJSON<i128>,
+        propose_admin: this.txFromJSON<null>,
+        set_max_shots: this.txFromJSON<null>,
+        set_max_stake: this.txFromJSON<Result<void>>,
+        set_min_stake: this.txFromJSON<Result<void>>,
+        get_move_count: this.txFromJSON<Result<u32>>,
+        get_session_id: this.txFromJSON<u32>,
+        recommit_board: this.txFromJSON<Result<void>>,
+        validate_board: this.txFromJSON<Result<void>>,
+        get_game_status: this.txFromJSON<Result<GameStatus>>,
+        get_session_key: this.txFromJSON<Option<SessionKey>>,
+        start_team_game: this.txFromJSON<Result<u32>>,
+        check_resolution: this.txFromJSON<ResolutionCheck>,
+        get_chat_anchors: this.txFromJSON<Array<ChatAnchor>>,
+        post_chat_anchor: this.txFromJSON<Result<u32>>,
+        retry_hub_notify: this.txFromJSON<Result<void>>,
+        build_reveal_hash: this.txFromJSON<Buffer>,
+        get_pending_admin: this.txFromJSON<Option<string>>,
+        get_session_nonce: this.txFromJSON<u32>,
+        get_archived_stats: this.txFromJSON<Result<ArchivedStats>>,
+        get_final_verifier: this.txFromJSON<Option<string>>,
+        get_revealed_board: this.txFromJSON<Result<Array<u32>>>,
+        get_session_marker: this.txFromJSON<Option<SessionMarker>>,
+        resolve_final_shot: this.txFromJSON<Result<ShotResult>>,
+        revoke_session_key: this.txFromJSON<null>,
+        set_final_verifier: this.txFromJSON<null>,
+        get_active_sessions: this.txFromJSON<Array<u32>>,
+        get_reveal_verifier: this.txFromJSON<Option<string>>,
+        set_reveal_verifier: this.txFromJSON<null>,
+        start_practice_game: this.txFromJSON<Result<u32>>,
+        get_max_active_games: this.txFromJSON<u32>,
+        resolve_sinking_shot: this.txFromJSON<Result<ShotResult>>,
+        set_max_active_games: this.txFromJSON<null>,
+        authorize_session_key: this.txFromJSON<Result<void>>,
+        build_disclosure_hash: this.txFromJSON<Buffer>,
+        build_seed_commitment: this.txFromJSON<Buffer>,
+        build_shot_commitment: this.txFromJSON<Buffer>,
+        commit_practice_board: this.txFromJSON<Result<void>>,
+        forfeit_withheld_seed: this.txFromJSON<Result<string>>,
+        get_last_error_detail: this.txFromJSON<Option<ErrorDetail>>,
+        get_recommit_verifier: this.txFromJSON<Option<string>>,
+        resolve_practice_shot: this.txFromJSON<Result<ShotResult>>,
+        set_recommit_verifier: this.txFromJSON<null>,
+        build_consistency_hash: this.txFromJSON<Buffer>,
+        get_disclosure_verifier: this.txFromJSON<Option<string>>,
+        set_disclosure_verifier: this.txFromJSON<null>,
         build_public_inputs_hash: this.txFromJSON<Buffer>,
         notify_game_ended_to_hub: this.txFromJSON<Result<void>>
   }
@@ -0,0 +1,76 @@
+pragma circom 2.1.9;
+
+include "./battleship_utils.circom";
+
+// Proves that every shot result reported against a board (including the
+// final, game-winning hit) matches the committed ship placement. Used by
+// `resolve_final_shot` as a whole-game check on top of the per-shot proofs.
+template BoardConsistency() {
+    signal input ship_x[5];
+    signal input ship_y[5];
+    signal input ship_dir[5];
+    signal input salt;
+
+    // Shot history against this board, indexed y * 10 + x (1 = fired / hit).
+    signal input shots[100];
+    signal input hits[100];
+
+    // Board commitment as hi/lo limbs (matches adapter split_u256_to_fr_limbs: hi = bytes 0..15, lo = bytes 16..31).
+    signal input board_commitment_hi;
+    signal input board_commitment_lo;
+
+    // Split u256 hash limbs for `build_consistency_hash` in the Soroban contract.
+    signal input public_inputs_hash_hi;
+    signal input public_inputs_hash_lo;
+
+    component board = BoardLayout();
+    for (var i = 0; i < 5; i++) {
+        board.ship_x[i] <== ship_x[i];
+        board.ship_y[i] <== ship_y[i];
+        board.ship_dir[i] <== ship_dir[i];
+    }
+    board.salt <== salt;
+    var TWO_128 = 340282366920938463463374607431768211456;
+    board.board_commitment === board_commitment_hi * TWO_128 + board_commitment_lo;
+
+    component shotBool[100];
+    component hitBool[100];
+    component onCell[100][17];
+    signal occupied[100][18];
+    signal hit_sum[101];
+    hit_sum[0] <== 0;
+
+    for (var c = 0; c < 100; c++) {
+        shotBool[c] = AssertBoolean();
+        shotBool[c].in <== shots[c];
+        hitBool[c] = AssertBoolean();
+        hitBool[c].in <== hits[c];
+
+        // Cells are distinct (BoardLayout), so this sum is 0 or 1.
+        occupied[c][0] <== 0;
+        for (var i = 0; i < 17; i++) {
+            onCell[c][i] = IsEqual();
+            onCell[c][i].a <== board.cell_idx[i];
+            onCell[c][i].b <== c;
+            occupied[c][i + 1] <== occupied[c][i] + onCell[c][i].out;
+        }
+
+        // A hit can only be reported on a fired cell, and every fired cell
+        // must report exactly what the board holds.
+        hits[c] * (1 - shots[c]) === 0;
+        shots[c] * (hits[c] - occupied[c][17]) === 0;
+
+        hit_sum[c + 1] <== hit_sum[c] + hits[c];
+    }
+
+    // The final resolution sinks the whole fleet.
+    hit_sum[100] === 17;
+
+    // TODO(security): add keccak gadget constraints so (session, defender,
+    // shots, hits, board_commitment) are constrained to `public_inputs_hash_hi/lo`
+    // exactly like `build_consistency_hash` in the Soroban contract.
+    signal hash_binding_witness;
+    hash_binding_witness <== public_inputs_hash_hi + public_inputs_hash_lo;
+}
+
+component main {public [board_commitment_hi, board_commitment_lo, public_inputs_hash_hi, public_inputs_hash_lo]} = BoardConsistency();
@@ -0,0 +1,40 @@
+pragma circom 2.1.9;
+
+include "./battleship_utils.circom";
+
+// Proves that two board commitments hide the same ship placement under
+// different salts, so a player can refresh their commitment mid-game without
+// revealing the board.
+template BoardRecommit() {
+    signal input ship_x[5];
+    signal input ship_y[5];
+    signal input ship_dir[5];
+    signal input old_salt;
+    signal input new_salt;
+
+    // Commitments as hi/lo limbs (matches adapter split_u256_to_fr_limbs: hi = bytes 0..15, lo = bytes 16..31).
+    // The adapter binds inputs [0..1] to the current on-chain commitment and [2..3] to the new one.
+    signal input old_commitment_hi;
+    signal input old_commitment_lo;
+    signal input new_commitment_hi;
+    signal input new_commitment_lo;
+
+    component oldBoard = BoardLayout();
+    component newBoard = BoardLayout();
+    for (var i = 0; i < 5; i++) {
+        oldBoard.ship_x[i] <== ship_x[i];
+        oldBoard.ship_y[i] <== ship_y[i];
+        oldBoard.ship_dir[i] <== ship_dir[i];
+        newBoard.ship_x[i] <== ship_x[i];
+        newBoard.ship_y[i] <== ship_y[i];
+        newBoard.ship_dir[i] <== ship_dir[i];
+    }
+    oldBoard.salt <== old_salt;
+    newBoard.salt <== new_salt;
+
+    var TWO_128 = 340282366920938463463374607431768211456;
+    oldBoard.board_commitment === old_commitment_hi * TWO_128 + old_commitment_lo;
+    newBoard.board_commitment === new_commitment_hi * TWO_128 + new_commitment_lo;
+}
+
+component main {public [old_commitment_hi, old_commitment_lo, new_commitment_hi, new_commitment_lo]} = BoardRecommit();
@@ -0,0 +1,38 @@
+pragma circom 2.1.9;
+
+include "./battleship_utils.circom";
+
+// Proves that a disclosed ship placement is the one behind a board commitment. Used by
+// `reveal_board` once a game has ended, so spectators and replays can render the true board.
+template BoardReveal() {
+    signal input ship_x[5];
+    signal input ship_y[5];
+    signal input ship_dir[5];
+    signal input salt;
+
+    // Board commitment as hi/lo limbs (matches adapter split_u256_to_fr_limbs: hi = bytes 0..15, lo = bytes 16..31).
+    signal input board_commitment_hi;
+    signal input board_commitment_lo;
+
+    // Split u256 hash limbs for `build_reveal_hash` in the Soroban contract.
+    signal input public_inputs_hash_hi;
+    signal input public_inputs_hash_lo;
+
+    component board = BoardLayout();
+    for (var i = 0; i < 5; i++) {
+        board.ship_x[i] <== ship_x[i];
+        board.ship_y[i] <== ship_y[i];
+        board.ship_dir[i] <== ship_dir[i];
+    }
+    board.salt <== salt;
+    var TWO_128 = 340282366920938463463374607431768211456;
+    board.board_commitment === board_commitment_hi * TWO_128 + board_commitment_lo;
+
+    // TODO(security): add keccak gadget constraints so (session, ship_x, ship_y, ship_dir,
+    // board_commitment, player) are constrained to `public_inputs_hash_hi/lo` exactly like
+    // `build_reveal_hash` in the Soroban contract.
+    signal hash_binding_witness;
+    hash_binding_witness <== public_inputs_hash_hi + public_inputs_hash_lo;
+}
+
+component main {public [board_commitment_hi, board_commitment_lo, public_inputs_hash_hi, public_inputs_hash_lo]} = BoardReveal();
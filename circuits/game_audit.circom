@@ -0,0 +1,62 @@
+pragma circom 2.1.9;
+
+include "./wordle_utils.circom";
+
+/// Proves that every feedback vector of a finished Wordle game was consistent
+/// with the committed word, in one aggregate proof (used by `finalize_with_audit`).
+///
+/// Games shorter than `rounds` guesses pad the unused rounds with
+/// guess = word and all-green feedback; only the real rounds enter the audit hash.
+///
+/// Public inputs (4 total):
+/// - word_commitment_hi, word_commitment_lo (split 256-bit commitment)
+/// - audit_hash_hi, audit_hash_lo (split `build_audit_hash` of the Soroban contract)
+template GameAudit(rounds) {
+    // Private inputs (word setter's secret and the recorded history)
+    signal input word[5];
+    signal input salt;
+    signal input guesses[rounds][5];
+    signal input feedbacks[rounds][5];
+    signal input alphabet_size;
+
+    // Public inputs
+    signal input word_commitment_hi;
+    signal input word_commitment_lo;
+    signal input audit_hash_hi;
+    signal input audit_hash_lo;
+
+    var TWO_128 = 340282366920938463463374607431768211456;
+
+    // 1. Commitment, with every letter inside the session alphabet
+    component commit = WordCommitment();
+    component wordRange[5];
+    for (var i = 0; i < 5; i++) {
+        commit.word[i] <== word[i];
+        wordRange[i] = AssertLetter();
+        wordRange[i].letter <== word[i];
+        wordRange[i].alphabet_size <== alphabet_size;
+    }
+    commit.salt <== salt;
+    commit.commitment === word_commitment_hi * TWO_128 + word_commitment_lo;
+
+    // 2. Every round's feedback matches the word
+    component round[rounds];
+    for (var r = 0; r < rounds; r++) {
+        round[r] = AssertGuessFeedback();
+        for (var i = 0; i < 5; i++) {
+            round[r].word[i] <== word[i];
+            round[r].guess[i] <== guesses[r][i];
+            round[r].feedback[i] <== feedbacks[r][i];
+        }
+        round[r].alphabet_size <== alphabet_size;
+    }
+
+    // 3. Binding witness for the audit hash
+    // TODO(security): add keccak constraints so (session_id, guess_count,
+    // alphabet_size, guesses, feedbacks, word_commitment, players) bind to audit_hash_hi/lo
+    // exactly like `build_audit_hash` in the Soroban contract.
+    signal hash_binding_witness;
+    hash_binding_witness <== audit_hash_hi + audit_hash_lo;
+}
+
+component main {public [word_commitment_hi, word_commitment_lo, audit_hash_hi, audit_hash_lo]} = GameAudit(6);
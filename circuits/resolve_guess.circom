@@ -12,13 +12,15 @@ include "./wordle_utils.circom";
 /// Public inputs (15 total):
 /// - word_commitment_hi, word_commitment_lo (split 256-bit commitment)
 /// - public_inputs_hash_hi, public_inputs_hash_lo (for binding to on-chain context)
-/// - guess[5] (the guessed letters, 0-25)
+/// - guess[5] (the guessed letters, 0 to alphabet_size - 1)
 /// - feedback[5] (the feedback, 0-2)
 /// - is_correct (1 if all letters match, 0 otherwise)
 template ResolveGuess() {
     // Private inputs (word setter's secret)
     signal input word[5];
     signal input salt;
+    // Session alphabet (26 = Latin A-Z), bound through the public inputs hash
+    signal input alphabet_size;
 
     // Public inputs
     signal input guess[5];
@@ -35,11 +37,13 @@ template ResolveGuess() {
     component feedbackRange[5];
 
     for (var i = 0; i < 5; i++) {
-        wordRange[i] = AssertInRange(26);
-        wordRange[i].in <== word[i];
+        wordRange[i] = AssertLetter();
+        wordRange[i].letter <== word[i];
+        wordRange[i].alphabet_size <== alphabet_size;
 
-        guessRange[i] = AssertInRange(26);
-        guessRange[i].in <== guess[i];
+        guessRange[i] = AssertLetter();
+        guessRange[i].letter <== guess[i];
+        guessRange[i].alphabet_size <== alphabet_size;
 
         feedbackRange[i] = AssertInRange(3); // 0, 1, or 2
         feedbackRange[i].in <== feedback[i];
@@ -186,7 +190,7 @@ template ResolveGuess() {
 
     // 6. Binding witness for public inputs hash
     // TODO(security): Add keccak constraints to bind public_inputs_hash to
-    // (session_id, word_setter, guesser, guess, feedback, is_correct, word_commitment)
+    // (session_id, word_setter, guesser, guess, feedback, is_correct, word_commitment, alphabet_size)
     signal hash_binding_witness;
     hash_binding_witness <== public_inputs_hash_hi + public_inputs_hash_lo;
 }
@@ -0,0 +1,80 @@
+pragma circom 2.1.9;
+
+include "./battleship_utils.circom";
+
+// ResolveShot for games with sunk-ship disclosure: when the shot sinks a
+// ship, also proves the disclosed start cell and direction are that ship's
+// committed placement. Used by `resolve_sinking_shot`.
+template ResolveShotDisclosed() {
+    signal input ship_x[5];
+    signal input ship_y[5];
+    signal input ship_dir[5];
+    signal input salt;
+
+    signal input prior_hits[17];
+
+    signal input shot_x;
+    signal input shot_y;
+    signal input is_hit;
+    signal input sunk_ship; // 0 = none, 1..5 = Carrier..Destroyer
+
+    // Disclosed placement of `sunk_ship` (all 0 when nothing is sunk).
+    signal input sunk_x;
+    signal input sunk_y;
+    signal input sunk_dir;
+
+    // Board commitment as hi/lo limbs (matches adapter split_u256_to_fr_limbs: hi = bytes 0..15, lo = bytes 16..31).
+    signal input board_commitment_hi;
+    signal input board_commitment_lo;
+
+    // Split u256 hash limbs for `build_disclosure_hash` in the Soroban contract.
+    signal input public_inputs_hash_hi;
+    signal input public_inputs_hash_lo;
+
+    component shot = ResolveShot();
+    for (var i = 0; i < 5; i++) {
+        shot.ship_x[i] <== ship_x[i];
+        shot.ship_y[i] <== ship_y[i];
+        shot.ship_dir[i] <== ship_dir[i];
+    }
+    shot.salt <== salt;
+    for (var i = 0; i < 17; i++) {
+        shot.prior_hits[i] <== prior_hits[i];
+    }
+    shot.shot_x <== shot_x;
+    shot.shot_y <== shot_y;
+    shot.is_hit <== is_hit;
+    shot.sunk_ship <== sunk_ship;
+    shot.board_commitment_hi <== board_commitment_hi;
+    shot.board_commitment_lo <== board_commitment_lo;
+    shot.public_inputs_hash_hi <== public_inputs_hash_hi;
+    shot.public_inputs_hash_lo <== public_inputs_hash_lo;
+
+    // Select the sunk ship's placement; the tags are one-hot or all zero.
+    component isShip[5];
+    signal sel_x[6];
+    signal sel_y[6];
+    signal sel_dir[6];
+    sel_x[0] <== 0;
+    sel_y[0] <== 0;
+    sel_dir[0] <== 0;
+    for (var s = 0; s < 5; s++) {
+        isShip[s] = IsEqual();
+        isShip[s].a <== sunk_ship;
+        isShip[s].b <== s + 1;
+        sel_x[s + 1] <== sel_x[s] + isShip[s].out * ship_x[s];
+        sel_y[s + 1] <== sel_y[s] + isShip[s].out * ship_y[s];
+        sel_dir[s + 1] <== sel_dir[s] + isShip[s].out * ship_dir[s];
+    }
+    sunk_x === sel_x[5];
+    sunk_y === sel_y[5];
+    sunk_dir === sel_dir[5];
+
+    // TODO(security): add keccak gadget constraints so (session, players, shot,
+    // outcome, board_commitment, sunk_x/y/dir) are constrained to
+    // `public_inputs_hash_hi/lo` exactly like `build_disclosure_hash`.
+    signal hash_binding_witness;
+    hash_binding_witness <== public_inputs_hash_hi + public_inputs_hash_lo;
+}
+
+component main {public [board_commitment_hi, board_commitment_lo, public_inputs_hash_hi, public_inputs_hash_lo]} = ResolveShotDisclosed();
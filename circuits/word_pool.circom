@@ -0,0 +1,77 @@
+pragma circom 2.1.9;
+
+include "./wordle_utils.circom";
+
+/// Proves that a word commitment hides the word at leaf `word_index` of the
+/// pool the setter committed before the word was drawn.
+///
+/// Same tree as `word_validity` (leaves Poseidon(word[0..4]), nodes
+/// Poseidon(left, right)), but the path bits are the bits of the public
+/// word index, so the setter cannot open any other leaf. The main component
+/// uses depth 14 (up to 16384 words).
+///
+/// Public inputs (5 total):
+/// - word_commitment_hi, word_commitment_lo (split 256-bit commitment)
+/// - pool_root_hi, pool_root_lo (split 256-bit root)
+/// - word_index (drawn by the contract from both players' seeds)
+template WordPool(depth) {
+    // Private inputs (word setter's secret)
+    signal input word[5];
+    signal input salt;
+    signal input path_elements[depth];
+    signal input path_indices[depth];
+
+    // Public inputs
+    signal input word_commitment_hi;
+    signal input word_commitment_lo;
+    signal input pool_root_hi;
+    signal input pool_root_lo;
+    signal input word_index;
+
+    var TWO_128 = 340282366920938463463374607431768211456;
+
+    // 1. Commitment (range-checks every letter to 0-25)
+    component commit = WordCommitment();
+    for (var i = 0; i < 5; i++) {
+        commit.word[i] <== word[i];
+    }
+    commit.salt <== salt;
+    commit.commitment === word_commitment_hi * TWO_128 + word_commitment_lo;
+
+    // 2. Path bits spell the drawn index (little-endian, leaf level first)
+    component indexBool[depth];
+    var index_sum = 0;
+    for (var i = 0; i < depth; i++) {
+        indexBool[i] = AssertBoolean();
+        indexBool[i].in <== path_indices[i];
+        index_sum += path_indices[i] * (1 << i);
+    }
+    index_sum === word_index;
+
+    // 3. Pool membership at that index
+    component leaf = Poseidon(5);
+    for (var i = 0; i < 5; i++) {
+        leaf.inputs[i] <== word[i];
+    }
+
+    component nodes[depth];
+    signal level[depth + 1];
+    signal left[depth];
+    signal right[depth];
+    level[0] <== leaf.out;
+
+    for (var i = 0; i < depth; i++) {
+        // path_indices[i] = 1 means the current node is the right child
+        left[i] <== level[i] + path_indices[i] * (path_elements[i] - level[i]);
+        right[i] <== path_elements[i] + path_indices[i] * (level[i] - path_elements[i]);
+
+        nodes[i] = Poseidon(2);
+        nodes[i].inputs[0] <== left[i];
+        nodes[i].inputs[1] <== right[i];
+        level[i + 1] <== nodes[i].out;
+    }
+
+    level[depth] === pool_root_hi * TWO_128 + pool_root_lo;
+}
+
+component main {public [word_commitment_hi, word_commitment_lo, pool_root_hi, pool_root_lo, word_index]} = WordPool(14);
@@ -0,0 +1,73 @@
+pragma circom 2.1.9;
+
+include "./wordle_utils.circom";
+
+/// Proves that a word commitment hides a well-formed word and, when a
+/// dictionary root is given, that the word is a leaf of that dictionary.
+///
+/// Leaves are Poseidon(word[0..4]); internal nodes are Poseidon(left, right).
+/// A dictionary root of 0 disables the membership check. The main component
+/// uses depth 14 (up to 16384 words).
+///
+/// Public inputs (4 total):
+/// - word_commitment_hi, word_commitment_lo (split 256-bit commitment)
+/// - dictionary_root_hi, dictionary_root_lo (split 256-bit root, 0 = no dictionary)
+template WordValidity(depth) {
+    // Private inputs (word setter's secret)
+    signal input word[5];
+    signal input salt;
+    signal input path_elements[depth];
+    signal input path_indices[depth];
+
+    // Public inputs
+    signal input word_commitment_hi;
+    signal input word_commitment_lo;
+    signal input dictionary_root_hi;
+    signal input dictionary_root_lo;
+
+    var TWO_128 = 340282366920938463463374607431768211456;
+
+    // 1. Commitment (range-checks every letter to 0-25)
+    component commit = WordCommitment();
+    for (var i = 0; i < 5; i++) {
+        commit.word[i] <== word[i];
+    }
+    commit.salt <== salt;
+    commit.commitment === word_commitment_hi * TWO_128 + word_commitment_lo;
+
+    // 2. Dictionary membership
+    component leaf = Poseidon(5);
+    for (var i = 0; i < 5; i++) {
+        leaf.inputs[i] <== word[i];
+    }
+
+    component indexBool[depth];
+    component nodes[depth];
+    signal level[depth + 1];
+    signal left[depth];
+    signal right[depth];
+    level[0] <== leaf.out;
+
+    for (var i = 0; i < depth; i++) {
+        indexBool[i] = AssertBoolean();
+        indexBool[i].in <== path_indices[i];
+
+        // path_indices[i] = 1 means the current node is the right child
+        left[i] <== level[i] + path_indices[i] * (path_elements[i] - level[i]);
+        right[i] <== path_elements[i] + path_indices[i] * (level[i] - path_elements[i]);
+
+        nodes[i] = Poseidon(2);
+        nodes[i].inputs[0] <== left[i];
+        nodes[i].inputs[1] <== right[i];
+        level[i + 1] <== nodes[i].out;
+    }
+
+    signal root;
+    root <== dictionary_root_hi * TWO_128 + dictionary_root_lo;
+
+    component noDictionary = IsZero();
+    noDictionary.in <== root;
+    (1 - noDictionary.out) * (level[depth] - root) === 0;
+}
+
+component main {public [word_commitment_hi, word_commitment_lo, dictionary_root_hi, dictionary_root_lo]} = WordValidity(14);
@@ -46,17 +46,30 @@ template AssertInRange(maxExclusive) {
     poly[maxExclusive] === 0;
 }
 
+/// Asserts that `letter` is in [0, alphabet_size), for per-session
+/// alphabets of up to 64 letters (contract `MAX_ALPHABET_SIZE`)
+template AssertLetter() {
+    signal input letter;
+    signal input alphabet_size;
+
+    component inMax = AssertInRange(64);
+    inMax.in <== letter;
+
+    component belowSize = AssertInRange(64);
+    belowSize.in <== alphabet_size - 1 - letter;
+}
+
 /// Computes word commitment: Poseidon(word[0..4], salt)
-/// Word letters are in range [0, 25] (A-Z)
+/// Word letters are in range [0, 63]; callers check the session alphabet
 template WordCommitment() {
     signal input word[5];
     signal input salt;
     signal output commitment;
 
-    // Validate word letters are in range [0, 25]
+    // Validate word letters are in range [0, 63]
     component letterRange[5];
     for (var i = 0; i < 5; i++) {
-        letterRange[i] = AssertInRange(26);
+        letterRange[i] = AssertInRange(64);
         letterRange[i].in <== word[i];
     }
 
@@ -119,3 +132,113 @@ template HasGreenMatch() {
 
     hasMatch <== partial[5];
 }
+
+/// Asserts that `feedback` is the Wordle feedback for `guess` against `word`.
+/// Same constraints as steps 3 and 5 of `resolve_guess.circom`; `word` must be
+/// range-checked by the caller (e.g. through WordCommitment).
+template AssertGuessFeedback() {
+    signal input word[5];
+    signal input guess[5];
+    signal input feedback[5];
+    signal input alphabet_size;
+
+    component guessRange[5];
+    component feedbackRange[5];
+    component exactMatch[5];
+    component isGreen[5];
+    component isYellow[5];
+    component isGray[5];
+
+    for (var i = 0; i < 5; i++) {
+        guessRange[i] = AssertLetter();
+        guessRange[i].letter <== guess[i];
+        guessRange[i].alphabet_size <== alphabet_size;
+
+        feedbackRange[i] = AssertInRange(3);
+        feedbackRange[i].in <== feedback[i];
+
+        exactMatch[i] = IsEqual();
+        exactMatch[i].a <== word[i];
+        exactMatch[i].b <== guess[i];
+
+        // GREEN if and only if exact match
+        isGreen[i] = IsEqual();
+        isGreen[i].a <== feedback[i];
+        isGreen[i].b <== 2;
+        isGreen[i].out * (1 - exactMatch[i].out) === 0;
+        exactMatch[i].out * (1 - isGreen[i].out) === 0;
+
+        isYellow[i] = IsEqual();
+        isYellow[i].a <== feedback[i];
+        isYellow[i].b <== 1;
+
+        isGray[i] = IsEqual();
+        isGray[i].a <== feedback[i];
+        isGray[i].b <== 0;
+    }
+
+    // Occurrences of guess[i] at non-green positions of the word
+    component letterInWord[5][5];
+    signal letterMatch[5][5];
+    signal availableCount[5];
+    signal partialAvailable[5][6];
+
+    for (var i = 0; i < 5; i++) {
+        partialAvailable[i][0] <== 0;
+        for (var j = 0; j < 5; j++) {
+            letterInWord[i][j] = IsEqual();
+            letterInWord[i][j].a <== guess[i];
+            letterInWord[i][j].b <== word[j];
+
+            letterMatch[i][j] <== letterInWord[i][j].out * (1 - exactMatch[j].out);
+            partialAvailable[i][j + 1] <== partialAvailable[i][j] + letterMatch[i][j];
+        }
+        availableCount[i] <== partialAvailable[i][5];
+    }
+
+    // Occurrences of guess[i] already coloured at earlier positions
+    component sameLetterBefore[5][5];
+    component feedbackGE1[5][5];
+    signal isEarlier[5][5];
+    signal hasColoredFeedback[5][5];
+    signal sameAndEarlier[5][5];
+    signal usedAtJ[5][5];
+    signal partialUsed[5][6];
+    signal usedBefore[5];
+
+    for (var i = 0; i < 5; i++) {
+        partialUsed[i][0] <== 0;
+        for (var j = 0; j < 5; j++) {
+            sameLetterBefore[i][j] = IsEqual();
+            sameLetterBefore[i][j].a <== guess[i];
+            sameLetterBefore[i][j].b <== guess[j];
+
+            isEarlier[i][j] <== (j < i) ? 1 : 0;
+
+            feedbackGE1[i][j] = IsZero();
+            feedbackGE1[i][j].in <== feedback[j];
+            hasColoredFeedback[i][j] <== 1 - feedbackGE1[i][j].out;
+
+            sameAndEarlier[i][j] <== sameLetterBefore[i][j].out * isEarlier[i][j];
+            usedAtJ[i][j] <== sameAndEarlier[i][j] * hasColoredFeedback[i][j];
+            partialUsed[i][j + 1] <== partialUsed[i][j] + usedAtJ[i][j];
+        }
+        usedBefore[i] <== partialUsed[i][5];
+    }
+
+    // YELLOW => availableCount > usedBefore, GRAY => availableCount <= usedBefore
+    signal inYellow[5];
+    signal inGray[5];
+    component yellowRange[5];
+    component grayRange[5];
+
+    for (var i = 0; i < 5; i++) {
+        inYellow[i] <== isYellow[i].out * (availableCount[i] - usedBefore[i] - 1);
+        yellowRange[i] = AssertInRange(5);
+        yellowRange[i].in <== inYellow[i];
+
+        inGray[i] <== isGray[i].out * (usedBefore[i] - availableCount[i]);
+        grayRange[i] = AssertInRange(6);
+        grayRange[i].in <== inGray[i];
+    }
+}
@@ -6,9 +6,11 @@ use soroban_sdk::{
         Bn254G1Affine as G1Affine, Bn254G2Affine as G2Affine, Fr, BN254_G1_SERIALIZED_SIZE,
         BN254_G2_SERIALIZED_SIZE,
     },
-    Address, Bytes, BytesN, Env, Vec,
+    panic_with_error, symbol_short, Address, Bytes, BytesN, Env, String, Symbol, Vec,
 };
 
+use ownable::{Ownable, Roles};
+
 #[contracttype]
 #[derive(Clone)]
 pub struct Groth16Proof {
@@ -25,6 +27,8 @@ pub enum Groth16Error {
     MalformedPublicInputs = 2,
     InvalidProof = 3,
     MalformedProof = 4,
+    PayloadTooLarge = 5,
+    Frozen = 6,
 }
 
 #[contractclient(name = "CircomGroth16VerifierClient")]
@@ -35,10 +39,87 @@ pub trait CircomGroth16Verifier {
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
-    Admin,
     Verifier,
+    ExpectedInputs(Symbol),
+    MaxPayloadLen,
+    MaxPublicInputs,
+    ProofCacheEnabled,
+    /// Set by `freeze`: every proof is rejected until `unfreeze`.
+    Frozen,
+    /// Bumped to orphan every cached verification at once.
+    ProofCacheEpoch,
+    /// keccak256(epoch || board_commitment || public_inputs_hash || proof_payload) of a proof
+    /// the Groth16 verifier accepted.
+    VerifiedProof(BytesN<32>),
+    /// `VerificationMetrics` tallied by `verify`.
+    Metrics,
+}
+
+/// Returned by `get_info` so the hub, SDK and tooling can negotiate capabilities.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractInfo {
+    pub name: String,
+    pub version: String,
+    /// Interface ids implemented by the contract, e.g. "verifier-adapter-v1".
+    pub interfaces: Vec<String>,
+    /// Source revision baked in at build time from ZKARCADE_BUILD_HASH ("dev" when unset).
+    pub build_hash: String,
+}
+
+/// Returned by `diagnose`. Checks run in `verify` order and stop at the
+/// first failure, so fields past it keep their zero values.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayloadDiagnosis {
+    /// `Groth16Error` code of the first failed check, 0 when the payload is well formed.
+    pub error_code: u32,
+    pub payload_len: u32,
+    pub max_payload_len: u32,
+    /// Public input count declared in the header.
+    pub declared_inputs: u32,
+    pub max_public_inputs: u32,
+    /// Input count `verify` enforces for `resolve_shot`.
+    pub expected_inputs: u32,
+    /// Payload length implied by the declared input count.
+    pub expected_len: u32,
+    /// Public inputs 0..3 as sent.
+    pub binding_limbs: Vec<BytesN<32>>,
+    /// Board commitment and public inputs hash reassembled from the limbs, to compare with the
+    /// values the game binds (e.g. battleship's `check_resolution`).
+    pub board_commitment: Option<BytesN<32>>,
+    pub public_inputs_hash: Option<BytesN<32>>,
+}
+
+/// Returned by `get_metrics`: `verify` calls by outcome. Counters only persist when the
+/// enclosing transaction commits, so a game call that aborts on a rejected proof leaves no
+/// trace; rejections show up from direct calls (e.g. client pre-checks) and from callers that
+/// do not abort on `false`. Payloads over the size caps abort `verify` and are never counted.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct VerificationMetrics {
+    // Accepted proofs, proof cache hits included.
+    pub verified: u32,
+    // Payloads that failed to parse or carried the wrong public input count.
+    pub malformed: u32,
+    // Well-formed payloads whose binding limbs do not match the game context.
+    pub binding_mismatch: u32,
+    // Payloads the Groth16 verifier rejected.
+    pub proof_invalid: u32,
+    // Payloads rejected while the adapter was frozen.
+    pub frozen: u32,
 }
 
+/// Interface ids reported by `get_info`
+const INTERFACES: [&str; 1] = ["verifier-adapter-v1"];
+
+/// Circuit id whose input count `verify` enforces
+const RESOLVE_SHOT_CIRCUIT: &str = "resolve_shot";
+/// Public input count of `resolve_shot` (and `board_recommit`) when not overridden
+const DEFAULT_EXPECTED_INPUTS: u32 = 4;
+/// Binding limbs every circuit must expose
+const MIN_EXPECTED_INPUTS: u32 = 4;
+
 const PAYLOAD_HEADER_BYTES: u32 = 4;
 const FR_BYTES: u32 = 32;
 const PROOF_BYTES: u32 =
@@ -49,13 +130,28 @@ const B_OFFSET: u32 = A_OFFSET + BN254_G1_SERIALIZED_SIZE as u32;
 const C_OFFSET: u32 = B_OFFSET + BN254_G2_SERIALIZED_SIZE as u32;
 const INPUTS_OFFSET: u32 = PROOF_OFFSET + PROOF_BYTES;
 
+/// Declared public input count accepted before parsing, when not overridden
+const DEFAULT_MAX_PUBLIC_INPUTS: u32 = 64;
+/// Payload length accepted before parsing, when not overridden
+const DEFAULT_MAX_PAYLOAD_LEN: u32 = INPUTS_OFFSET + DEFAULT_MAX_PUBLIC_INPUTS * FR_BYTES;
+/// Smallest payload cap that still fits the binding limbs
+const MIN_MAX_PAYLOAD_LEN: u32 = INPUTS_OFFSET + MIN_EXPECTED_INPUTS * FR_BYTES;
+
+/// Ledgers a cached verification stays valid (~10 minutes), long enough for a retried transaction
+const PROOF_CACHE_TTL_LEDGERS: u32 = 120;
+
+/// Role that may `freeze` the adapter besides the admin; only the admin can `unfreeze` it
+pub const GUARDIAN_ROLE: Symbol = symbol_short!("guardian");
+/// Role that may switch and clear the proof cache besides the admin
+pub const CACHE_ROLE: Symbol = symbol_short!("cache");
+
 #[contract]
 pub struct BattleshipVerifierAdapter;
 
 #[contractimpl]
 impl BattleshipVerifierAdapter {
     pub fn __constructor(env: Env, admin: Address, verifier: Address) {
-        env.storage().instance().set(&DataKey::Admin, &admin);
+        Ownable::init(&env, &admin);
         env.storage().instance().set(&DataKey::Verifier, &verifier);
     }
 
@@ -73,88 +169,386 @@ impl BattleshipVerifierAdapter {
     /// - [1]: board_commitment low 16 bytes, right-aligned in 32 bytes
     /// - [2]: public_inputs_hash high 16 bytes, right-aligned in 32 bytes
     /// - [3]: public_inputs_hash low 16 bytes, right-aligned in 32 bytes
+    ///
+    /// Payloads longer than `get_max_payload_len` or declaring more than
+    /// `get_max_public_inputs` inputs abort with `PayloadTooLarge` instead of
+    /// returning false.
+    ///
+    /// With the proof cache on (`set_proof_cache`), an accepted proof is remembered for
+    /// `PROOF_CACHE_TTL_LEDGERS`, and an identical call within that window skips the pairing.
+    ///
+    /// While the adapter is frozen (`freeze`), every payload is rejected.
     pub fn verify(
         env: Env,
         board_commitment: BytesN<32>,
         public_inputs_hash: BytesN<32>,
         proof_payload: Bytes,
     ) -> bool {
-        let parsed = match Self::parse_payload(&env, &proof_payload) {
+        let outcome =
+            Self::verify_outcome(&env, &board_commitment, &public_inputs_hash, &proof_payload);
+        Self::record_outcome(&env, outcome);
+        outcome == Outcome::Verified
+    }
+
+    /// Reports how `verify` would read `proof_payload` and which check it fails, without
+    /// aborting or calling the Groth16 verifier. Binding limbs that are not right-aligned
+    /// 16-byte halves fail with `MalformedPublicInputs`. While the adapter is frozen, a payload
+    /// that passes every check reports `Frozen`, since `verify` would still reject it.
+    pub fn diagnose(env: Env, proof_payload: Bytes) -> PayloadDiagnosis {
+        let mut diagnosis = PayloadDiagnosis {
+            error_code: 0,
+            payload_len: proof_payload.len(),
+            max_payload_len: Self::max_payload_len(&env),
+            declared_inputs: 0,
+            max_public_inputs: Self::max_public_inputs(&env),
+            expected_inputs: Self::expected_inputs(&env, Symbol::new(&env, RESOLVE_SHOT_CIRCUIT)),
+            expected_len: 0,
+            binding_limbs: Vec::new(&env),
+            board_commitment: None,
+            public_inputs_hash: None,
+        };
+        if let Err(err) = Self::diagnose_payload(&env, &proof_payload, &mut diagnosis) {
+            diagnosis.error_code = err as u32;
+        } else if Self::is_frozen(env.clone()) {
+            diagnosis.error_code = Groth16Error::Frozen as u32;
+        }
+        diagnosis
+    }
+
+    pub fn get_info(env: Env) -> ContractInfo {
+        let mut interfaces = Vec::new(&env);
+        for id in INTERFACES {
+            interfaces.push_back(String::from_str(&env, id));
+        }
+        ContractInfo {
+            name: String::from_str(&env, env!("CARGO_PKG_NAME")),
+            version: String::from_str(&env, env!("CARGO_PKG_VERSION")),
+            interfaces,
+            build_hash: String::from_str(&env, option_env!("ZKARCADE_BUILD_HASH").unwrap_or("dev")),
+        }
+    }
+
+    /// Verification counts by outcome since deployment.
+    pub fn get_metrics(env: Env) -> VerificationMetrics {
+        env.storage()
+            .instance()
+            .get(&DataKey::Metrics)
+            .unwrap_or_default()
+    }
+
+    pub fn get_admin(env: Env) -> Address {
+        Ownable::admin(&env)
+    }
+
+    pub fn set_admin(env: Env, new_admin: Address) {
+        Ownable::set_admin(&env, &new_admin);
+    }
+
+    /// First step of an admin transfer; `new_admin` takes over once they call `accept_admin`.
+    pub fn propose_admin(env: Env, new_admin: Address) {
+        Ownable::propose_admin(&env, &new_admin);
+    }
+
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        Ownable::pending_admin(&env)
+    }
+
+    /// Completes a transfer started with `propose_admin`; signed by the nominee.
+    pub fn accept_admin(env: Env) -> Address {
+        Ownable::accept_admin(&env)
+    }
+
+    /// Grants `role` (`GUARDIAN_ROLE` or `CACHE_ROLE`) to `account`. Admin only.
+    pub fn grant_role(env: Env, role: Symbol, account: Address) {
+        Roles::grant(&env, &role, &account);
+    }
+
+    /// Admin only.
+    pub fn revoke_role(env: Env, role: Symbol, account: Address) {
+        Roles::revoke(&env, &role, &account);
+    }
+
+    pub fn has_role(env: Env, role: Symbol, account: Address) -> bool {
+        Roles::has(&env, &role, &account)
+    }
+
+    pub fn get_verifier(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Verifier)
+            .expect("Verifier not set")
+    }
+
+    pub fn set_verifier(env: Env, new_verifier: Address) {
+        Ownable::require_admin(&env);
+        env.storage()
+            .instance()
+            .set(&DataKey::Verifier, &new_verifier);
+        Self::bump_proof_cache_epoch(&env);
+    }
+
+    /// Public input count enforced for `circuit_id`
+    pub fn get_expected_inputs(env: Env, circuit_id: Symbol) -> u32 {
+        Self::expected_inputs(&env, circuit_id)
+    }
+
+    /// Overrides the public input count for `circuit_id` after a circuit
+    /// upgrade. A count of 0 restores the built-in default.
+    pub fn set_expected_inputs(
+        env: Env,
+        circuit_id: Symbol,
+        count: u32,
+    ) -> Result<(), Groth16Error> {
+        Ownable::require_admin(&env);
+        Self::bump_proof_cache_epoch(&env);
+        let key = DataKey::ExpectedInputs(circuit_id);
+        if count == 0 {
+            env.storage().instance().remove(&key);
+            return Ok(());
+        }
+        if count < MIN_EXPECTED_INPUTS {
+            return Err(Groth16Error::MalformedPublicInputs);
+        }
+        env.storage().instance().set(&key, &count);
+        Ok(())
+    }
+
+    /// Longest proof payload accepted by `verify`
+    pub fn get_max_payload_len(env: Env) -> u32 {
+        Self::max_payload_len(&env)
+    }
+
+    /// Caps the payload length checked before parsing. A length of 0
+    /// restores the built-in default.
+    pub fn set_max_payload_len(env: Env, max_len: u32) -> Result<(), Groth16Error> {
+        Ownable::require_admin(&env);
+        if max_len == 0 {
+            env.storage().instance().remove(&DataKey::MaxPayloadLen);
+            return Ok(());
+        }
+        if max_len < MIN_MAX_PAYLOAD_LEN {
+            return Err(Groth16Error::MalformedProof);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxPayloadLen, &max_len);
+        Ok(())
+    }
+
+    /// Largest public input count a payload may declare
+    pub fn get_max_public_inputs(env: Env) -> u32 {
+        Self::max_public_inputs(&env)
+    }
+
+    /// Caps the declared public input count checked before parsing. A count
+    /// of 0 restores the built-in default.
+    pub fn set_max_public_inputs(env: Env, max_count: u32) -> Result<(), Groth16Error> {
+        Ownable::require_admin(&env);
+        if max_count == 0 {
+            env.storage().instance().remove(&DataKey::MaxPublicInputs);
+            return Ok(());
+        }
+        if max_count < MIN_EXPECTED_INPUTS {
+            return Err(Groth16Error::MalformedPublicInputs);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxPublicInputs, &max_count);
+        Ok(())
+    }
+
+    /// Whether `verify` caches accepted proofs (off by default)
+    pub fn get_proof_cache(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::ProofCacheEnabled)
+            .unwrap_or(false)
+    }
+
+    /// Turns the proof cache on or off; `caller` is the admin or holds `CACHE_ROLE`. While off,
+    /// cached entries are neither read nor written and keep expiring on their own.
+    pub fn set_proof_cache(env: Env, caller: Address, enabled: bool) {
+        Roles::require_admin_or(&env, &CACHE_ROLE, &caller);
+        env.storage()
+            .instance()
+            .set(&DataKey::ProofCacheEnabled, &enabled);
+    }
+
+    /// Evicts every cached verification, e.g. after a verifying key issue; `caller` is the admin
+    /// or holds `CACHE_ROLE`. `set_verifier` and `set_expected_inputs` do the same.
+    pub fn clear_proof_cache(env: Env, caller: Address) {
+        Roles::require_admin_or(&env, &CACHE_ROLE, &caller);
+        Self::bump_proof_cache_epoch(&env);
+    }
+
+    /// Whether verification is frozen
+    pub fn is_frozen(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::Frozen)
+            .unwrap_or(false)
+    }
+
+    /// Rejects every proof until `unfreeze`, so games cannot settle on proofs while a circuit
+    /// soundness issue is investigated. Also evicts the proof cache, so proofs accepted before
+    /// the freeze are verified afresh once it is lifted. `caller` is the admin or holds
+    /// `GUARDIAN_ROLE`.
+    pub fn freeze(env: Env, caller: Address) {
+        Roles::require_admin_or(&env, &GUARDIAN_ROLE, &caller);
+        env.storage().instance().set(&DataKey::Frozen, &true);
+        Self::bump_proof_cache_epoch(&env);
+    }
+
+    /// Lifts a `freeze`. Admin only, so a guardian cannot undo another's freeze.
+    pub fn unfreeze(env: Env) {
+        Ownable::require_admin(&env);
+        env.storage().instance().remove(&DataKey::Frozen);
+    }
+
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        Ownable::upgrade(&env, new_wasm_hash);
+    }
+
+    fn verify_outcome(
+        env: &Env,
+        board_commitment: &BytesN<32>,
+        public_inputs_hash: &BytesN<32>,
+        proof_payload: &Bytes,
+    ) -> Outcome {
+        if Self::is_frozen(env.clone()) {
+            return Outcome::Frozen;
+        }
+
+        let parsed = match Self::parse_payload(env, proof_payload) {
             Some(v) => v,
-            None => return false,
+            None => return Outcome::Malformed,
         };
 
+        let circuit_id = Symbol::new(env, RESOLVE_SHOT_CIRCUIT);
+        if parsed.public_inputs.len() != Self::expected_inputs(env, circuit_id) {
+            return Outcome::Malformed;
+        }
+
         if !Self::binding_inputs_match(
-            &env,
+            env,
             &parsed.public_inputs,
-            &board_commitment,
-            &public_inputs_hash,
+            board_commitment,
+            public_inputs_hash,
         ) {
-            return false;
+            return Outcome::BindingMismatch;
         }
 
+        let cache_key = if Self::get_proof_cache(env.clone()) {
+            let key =
+                Self::proof_cache_key(env, board_commitment, public_inputs_hash, proof_payload);
+            if env.storage().temporary().has(&key) {
+                return Outcome::Verified;
+            }
+            Some(key)
+        } else {
+            None
+        };
+
         let verifier_addr: Address = env
             .storage()
             .instance()
             .get(&DataKey::Verifier)
             .expect("Verifier not set");
-        let verifier = CircomGroth16VerifierClient::new(&env, &verifier_addr);
+        let verifier = CircomGroth16VerifierClient::new(env, &verifier_addr);
+
+        let valid = verifier.verify(&parsed.proof, &parsed.public_inputs);
+        if let (true, Some(key)) = (valid, cache_key) {
+            // Not extended on hits: an entry lives for one window after the first verification.
+            env.storage().temporary().set(&key, &true);
+            env.storage().temporary().extend_ttl(
+                &key,
+                PROOF_CACHE_TTL_LEDGERS,
+                PROOF_CACHE_TTL_LEDGERS,
+            );
+        }
+        if valid {
+            Outcome::Verified
+        } else {
+            Outcome::ProofInvalid
+        }
+    }
 
-        verifier.verify(&parsed.proof, &parsed.public_inputs)
+    fn record_outcome(env: &Env, outcome: Outcome) {
+        let mut metrics = Self::get_metrics(env.clone());
+        let counter = match outcome {
+            Outcome::Verified => &mut metrics.verified,
+            Outcome::Malformed => &mut metrics.malformed,
+            Outcome::BindingMismatch => &mut metrics.binding_mismatch,
+            Outcome::ProofInvalid => &mut metrics.proof_invalid,
+            Outcome::Frozen => &mut metrics.frozen,
+        };
+        *counter = counter.saturating_add(1);
+        env.storage().instance().set(&DataKey::Metrics, &metrics);
     }
 
-    pub fn get_admin(env: Env) -> Address {
-        env.storage()
+    fn proof_cache_key(
+        env: &Env,
+        board_commitment: &BytesN<32>,
+        public_inputs_hash: &BytesN<32>,
+        proof_payload: &Bytes,
+    ) -> DataKey {
+        let epoch: u32 = env
+            .storage()
             .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set")
+            .get(&DataKey::ProofCacheEpoch)
+            .unwrap_or(0);
+        let mut preimage = Bytes::from_array(env, &epoch.to_be_bytes());
+        preimage.append(board_commitment.as_ref());
+        preimage.append(public_inputs_hash.as_ref());
+        preimage.append(proof_payload);
+        DataKey::VerifiedProof(env.crypto().keccak256(&preimage).into())
     }
 
-    pub fn set_admin(env: Env, new_admin: Address) {
-        let admin: Address = env
+    /// Entries keyed under an older epoch are never looked up again and expire with their TTL.
+    fn bump_proof_cache_epoch(env: &Env) {
+        let epoch: u32 = env
             .storage()
             .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
+            .get(&DataKey::ProofCacheEpoch)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::ProofCacheEpoch, &epoch.wrapping_add(1));
     }
 
-    pub fn get_verifier(env: Env) -> Address {
+    fn expected_inputs(env: &Env, circuit_id: Symbol) -> u32 {
         env.storage()
             .instance()
-            .get(&DataKey::Verifier)
-            .expect("Verifier not set")
+            .get(&DataKey::ExpectedInputs(circuit_id))
+            .unwrap_or(DEFAULT_EXPECTED_INPUTS)
     }
 
-    pub fn set_verifier(env: Env, new_verifier: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+    fn max_payload_len(env: &Env) -> u32 {
         env.storage()
             .instance()
-            .set(&DataKey::Verifier, &new_verifier);
+            .get(&DataKey::MaxPayloadLen)
+            .unwrap_or(DEFAULT_MAX_PAYLOAD_LEN)
     }
 
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let admin: Address = env
-            .storage()
+    fn max_public_inputs(env: &Env) -> u32 {
+        env.storage()
             .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+            .get(&DataKey::MaxPublicInputs)
+            .unwrap_or(DEFAULT_MAX_PUBLIC_INPUTS)
     }
 
     fn parse_payload(env: &Env, payload: &Bytes) -> Option<ParsedPayload> {
+        if payload.len() > Self::max_payload_len(env) {
+            panic_with_error!(env, Groth16Error::PayloadTooLarge);
+        }
         if payload.len() < INPUTS_OFFSET {
             return None;
         }
 
         let public_inputs_count = Self::read_u32_be(payload, 0)?;
+        if public_inputs_count > Self::max_public_inputs(env) {
+            panic_with_error!(env, Groth16Error::PayloadTooLarge);
+        }
         let expected_len = INPUTS_OFFSET.checked_add(public_inputs_count.checked_mul(FR_BYTES)?)?;
         if payload.len() != expected_len {
             return None;
@@ -184,6 +578,55 @@ impl BattleshipVerifierAdapter {
         })
     }
 
+    fn diagnose_payload(
+        env: &Env,
+        payload: &Bytes,
+        diagnosis: &mut PayloadDiagnosis,
+    ) -> Result<(), Groth16Error> {
+        if diagnosis.payload_len > diagnosis.max_payload_len {
+            return Err(Groth16Error::PayloadTooLarge);
+        }
+        if diagnosis.payload_len < INPUTS_OFFSET {
+            return Err(Groth16Error::MalformedProof);
+        }
+
+        diagnosis.declared_inputs =
+            Self::read_u32_be(payload, 0).ok_or(Groth16Error::MalformedProof)?;
+        if diagnosis.declared_inputs > diagnosis.max_public_inputs {
+            return Err(Groth16Error::PayloadTooLarge);
+        }
+        diagnosis.expected_len = INPUTS_OFFSET + diagnosis.declared_inputs * FR_BYTES;
+        if diagnosis.payload_len != diagnosis.expected_len {
+            return Err(Groth16Error::MalformedProof);
+        }
+        if diagnosis.declared_inputs != diagnosis.expected_inputs {
+            return Err(Groth16Error::MalformedPublicInputs);
+        }
+
+        let mut halves = [[0u8; 16]; 4];
+        for (i, half) in halves.iter_mut().enumerate() {
+            let limb = Self::read_array::<32>(payload, INPUTS_OFFSET + i as u32 * FR_BYTES)
+                .ok_or(Groth16Error::MalformedPublicInputs)?;
+            diagnosis
+                .binding_limbs
+                .push_back(BytesN::from_array(env, &limb));
+            if limb[0..16] != [0u8; 16] {
+                return Err(Groth16Error::MalformedPublicInputs);
+            }
+            half.copy_from_slice(&limb[16..32]);
+        }
+
+        let mut board_commitment = [0u8; 32];
+        board_commitment[0..16].copy_from_slice(&halves[0]);
+        board_commitment[16..32].copy_from_slice(&halves[1]);
+        let mut public_inputs_hash = [0u8; 32];
+        public_inputs_hash[0..16].copy_from_slice(&halves[2]);
+        public_inputs_hash[16..32].copy_from_slice(&halves[3]);
+        diagnosis.board_commitment = Some(BytesN::from_array(env, &board_commitment));
+        diagnosis.public_inputs_hash = Some(BytesN::from_array(env, &public_inputs_hash));
+        Ok(())
+    }
+
     fn binding_inputs_match(
         env: &Env,
         public_inputs: &Vec<Fr>,
@@ -270,5 +713,15 @@ struct ParsedPayload {
     public_inputs: Vec<Fr>,
 }
 
+/// How a `verify` call ended, tallied into `VerificationMetrics`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    Verified,
+    Malformed,
+    BindingMismatch,
+    ProofInvalid,
+    Frozen,
+}
+
 #[cfg(test)]
 mod test;
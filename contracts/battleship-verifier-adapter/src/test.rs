@@ -2,14 +2,16 @@
 
 use crate::{
     BattleshipVerifierAdapter, BattleshipVerifierAdapterClient, CircomGroth16VerifierClient,
-    Groth16Error, Groth16Proof,
+    Groth16Error, Groth16Proof, VerificationMetrics, CACHE_ROLE, GUARDIAN_ROLE,
 };
 use soroban_sdk::crypto::bn254::{
     Bn254G1Affine as G1Affine, Bn254G2Affine as G2Affine, Fr, BN254_G1_SERIALIZED_SIZE,
     BN254_G2_SERIALIZED_SIZE,
 };
-use soroban_sdk::testutils::Address as _;
-use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, Vec};
+use soroban_sdk::testutils::{Address as _, MockAuth, MockAuthInvoke};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, Address, Bytes, BytesN, Env, IntoVal, String, Symbol, Vec,
+};
 
 #[contract]
 pub struct MockCircomVerifier;
@@ -17,15 +19,26 @@ pub struct MockCircomVerifier;
 #[contractimpl]
 impl MockCircomVerifier {
     pub fn verify(
-        _env: Env,
+        env: Env,
         _proof: Groth16Proof,
         public_inputs: Vec<Fr>,
     ) -> Result<bool, Groth16Error> {
+        let calls = Self::calls(env.clone());
+        env.storage()
+            .instance()
+            .set(&symbol_short!("calls"), &(calls + 1));
         if public_inputs.len() < 4 {
             return Err(Groth16Error::MalformedPublicInputs);
         }
         Ok(true)
     }
+
+    pub fn calls(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("calls"))
+            .unwrap_or(0)
+    }
 }
 
 fn split_to_limbs(v: &BytesN<32>) -> ([u8; 32], [u8; 32]) {
@@ -136,3 +149,302 @@ fn test_verify_rejects_malformed_payload() {
     let ok = adapter.verify(&board, &hash, &malformed);
     assert!(!ok);
 }
+
+#[test]
+fn test_verify_enforces_expected_input_count() {
+    let (env, adapter, board, hash) = setup();
+
+    let proof = Groth16Proof {
+        a: G1Affine::from_array(&env, &[0u8; BN254_G1_SERIALIZED_SIZE]),
+        b: G2Affine::from_array(&env, &[0u8; BN254_G2_SERIALIZED_SIZE]),
+        c: G1Affine::from_array(&env, &[0u8; BN254_G1_SERIALIZED_SIZE]),
+    };
+
+    let mut inputs = make_inputs(&env, &board, &hash);
+    inputs.push_back(Fr::from_bytes(BytesN::from_array(&env, &[0u8; 32])));
+    let payload = encode_payload(&env, &proof, &inputs);
+
+    // Default count for resolve_shot is 4
+    let circuit = Symbol::new(&env, "resolve_shot");
+    assert_eq!(adapter.get_expected_inputs(&circuit), 4);
+    assert!(!adapter.verify(&board, &hash, &payload));
+
+    // Circuit upgrade adds an input
+    adapter.set_expected_inputs(&circuit, &5);
+    assert_eq!(adapter.get_expected_inputs(&circuit), 5);
+    assert!(adapter.verify(&board, &hash, &payload));
+
+    // Fewer than the binding limbs is rejected; 0 restores the default
+    let result = adapter.try_set_expected_inputs(&circuit, &3);
+    assert_eq!(result, Err(Ok(Groth16Error::MalformedPublicInputs)));
+    adapter.set_expected_inputs(&circuit, &0);
+    assert_eq!(adapter.get_expected_inputs(&circuit), 4);
+}
+
+#[test]
+fn test_verify_rejects_oversized_payload() {
+    let (env, adapter, board, hash) = setup();
+    let too_large = Err(Ok(soroban_sdk::Error::from_contract_error(
+        Groth16Error::PayloadTooLarge as u32,
+    )));
+
+    assert_eq!(adapter.get_max_public_inputs(), 64);
+    assert_eq!(adapter.get_max_payload_len(), 260 + 64 * 32);
+
+    // A header declaring ~4 billion inputs is rejected before parsing
+    let mut payload = Bytes::from_array(&env, &[0xffu8; 4]);
+    payload.append(&Bytes::from_array(&env, &[0u8; 256]));
+    assert_eq!(adapter.try_verify(&board, &hash, &payload), too_large);
+
+    // So is a payload longer than the configured cap
+    let proof = Groth16Proof {
+        a: G1Affine::from_array(&env, &[0u8; BN254_G1_SERIALIZED_SIZE]),
+        b: G2Affine::from_array(&env, &[0u8; BN254_G2_SERIALIZED_SIZE]),
+        c: G1Affine::from_array(&env, &[0u8; BN254_G1_SERIALIZED_SIZE]),
+    };
+    let mut inputs = make_inputs(&env, &board, &hash);
+    let payload = encode_payload(&env, &proof, &inputs);
+    adapter.set_max_payload_len(&payload.len());
+    assert_eq!(adapter.get_max_payload_len(), 260 + 4 * 32);
+    assert!(adapter.verify(&board, &hash, &payload));
+    inputs.push_back(Fr::from_bytes(BytesN::from_array(&env, &[0u8; 32])));
+    let longer = encode_payload(&env, &proof, &inputs);
+    assert_eq!(adapter.try_verify(&board, &hash, &longer), too_large);
+
+    // Caps below the binding limbs are refused; 0 restores the defaults
+    let result = adapter.try_set_max_payload_len(&(260 + 3 * 32));
+    assert_eq!(result, Err(Ok(Groth16Error::MalformedProof)));
+    let result = adapter.try_set_max_public_inputs(&3);
+    assert_eq!(result, Err(Ok(Groth16Error::MalformedPublicInputs)));
+    adapter.set_max_payload_len(&0);
+    assert!(!adapter.verify(&board, &hash, &longer));
+}
+
+#[test]
+fn test_diagnose_reports_failed_check_and_binding_values() {
+    let (env, adapter, board, hash) = setup();
+
+    let proof = Groth16Proof {
+        a: G1Affine::from_array(&env, &[0u8; BN254_G1_SERIALIZED_SIZE]),
+        b: G2Affine::from_array(&env, &[0u8; BN254_G2_SERIALIZED_SIZE]),
+        c: G1Affine::from_array(&env, &[0u8; BN254_G1_SERIALIZED_SIZE]),
+    };
+    let mut inputs = make_inputs(&env, &board, &hash);
+    let payload = encode_payload(&env, &proof, &inputs);
+
+    let ok = adapter.diagnose(&payload);
+    assert_eq!(ok.error_code, 0);
+    assert_eq!(ok.payload_len, 260 + 4 * 32);
+    assert_eq!(ok.expected_len, ok.payload_len);
+    assert_eq!((ok.declared_inputs, ok.expected_inputs), (4, 4));
+    assert_eq!(ok.binding_limbs.len(), 4);
+    assert_eq!(ok.board_commitment, Some(board.clone()));
+    assert_eq!(ok.public_inputs_hash, Some(hash.clone()));
+
+    let short = adapter.diagnose(&Bytes::from_array(&env, &[1u8, 2u8, 3u8]));
+    assert_eq!(short.error_code, Groth16Error::MalformedProof as u32);
+    assert_eq!(short.payload_len, 3);
+
+    // Oversized headers are reported instead of aborting like `verify`
+    let mut huge = Bytes::from_array(&env, &[0xffu8; 4]);
+    huge.append(&Bytes::from_array(&env, &[0u8; 256]));
+    let huge = adapter.diagnose(&huge);
+    assert_eq!(huge.error_code, Groth16Error::PayloadTooLarge as u32);
+    assert_eq!(huge.declared_inputs, u32::MAX);
+
+    let mut truncated = payload.clone();
+    truncated.pop_back();
+    let truncated = adapter.diagnose(&truncated);
+    assert_eq!(truncated.error_code, Groth16Error::MalformedProof as u32);
+    assert_eq!(truncated.expected_len, 260 + 4 * 32);
+
+    inputs.push_back(Fr::from_bytes(BytesN::from_array(&env, &[0u8; 32])));
+    let extra = adapter.diagnose(&encode_payload(&env, &proof, &inputs));
+    assert_eq!(extra.error_code, Groth16Error::MalformedPublicInputs as u32);
+    assert_eq!(extra.declared_inputs, 5);
+
+    let mut unaligned = make_inputs(&env, &board, &hash);
+    unaligned.set(2, Fr::from_bytes(BytesN::from_array(&env, &[9u8; 32])));
+    let unaligned = adapter.diagnose(&encode_payload(&env, &proof, &unaligned));
+    assert_eq!(
+        unaligned.error_code,
+        Groth16Error::MalformedPublicInputs as u32
+    );
+    assert_eq!(unaligned.binding_limbs.len(), 3);
+    assert_eq!(unaligned.public_inputs_hash, None);
+}
+
+#[test]
+fn test_proof_cache_skips_repeat_verification() {
+    let (env, adapter, board, hash) = setup();
+    let circom = MockCircomVerifierClient::new(&env, &adapter.get_verifier());
+    let admin = adapter.get_admin();
+
+    let proof = Groth16Proof {
+        a: G1Affine::from_array(&env, &[0u8; BN254_G1_SERIALIZED_SIZE]),
+        b: G2Affine::from_array(&env, &[0u8; BN254_G2_SERIALIZED_SIZE]),
+        c: G1Affine::from_array(&env, &[0u8; BN254_G1_SERIALIZED_SIZE]),
+    };
+    let payload = encode_payload(&env, &proof, &make_inputs(&env, &board, &hash));
+
+    // Off by default: every call reaches the Groth16 verifier.
+    assert!(!adapter.get_proof_cache());
+    assert!(adapter.verify(&board, &hash, &payload));
+    assert!(adapter.verify(&board, &hash, &payload));
+    assert_eq!(circom.calls(), 2);
+
+    adapter.set_proof_cache(&admin, &true);
+    assert!(adapter.verify(&board, &hash, &payload));
+    assert!(adapter.verify(&board, &hash, &payload));
+    assert_eq!(circom.calls(), 3);
+
+    // The cache is keyed on the binding too, so a different hash is verified afresh.
+    let other_hash = BytesN::from_array(&env, &[10u8; 32]);
+    let other_payload = encode_payload(&env, &proof, &make_inputs(&env, &board, &other_hash));
+    assert!(adapter.verify(&board, &other_hash, &other_payload));
+    assert_eq!(circom.calls(), 4);
+
+    adapter.clear_proof_cache(&admin);
+    assert!(adapter.verify(&board, &hash, &payload));
+    assert_eq!(circom.calls(), 5);
+    assert!(adapter.verify(&board, &hash, &payload));
+    assert_eq!(circom.calls(), 5);
+}
+
+#[test]
+fn test_freeze_rejects_every_proof_until_unfrozen() {
+    let (env, adapter, board, hash) = setup();
+    let circom = MockCircomVerifierClient::new(&env, &adapter.get_verifier());
+    let admin = adapter.get_admin();
+
+    let proof = Groth16Proof {
+        a: G1Affine::from_array(&env, &[0u8; BN254_G1_SERIALIZED_SIZE]),
+        b: G2Affine::from_array(&env, &[0u8; BN254_G2_SERIALIZED_SIZE]),
+        c: G1Affine::from_array(&env, &[0u8; BN254_G1_SERIALIZED_SIZE]),
+    };
+    let payload = encode_payload(&env, &proof, &make_inputs(&env, &board, &hash));
+
+    adapter.set_proof_cache(&admin, &true);
+    assert!(adapter.verify(&board, &hash, &payload));
+    assert_eq!(circom.calls(), 1);
+
+    assert!(!adapter.is_frozen());
+    adapter.freeze(&admin);
+    assert!(adapter.is_frozen());
+
+    // Cached proofs are rejected too, and the Groth16 verifier is never reached.
+    assert!(!adapter.verify(&board, &hash, &payload));
+    assert_eq!(circom.calls(), 1);
+    assert_eq!(
+        adapter.diagnose(&payload).error_code,
+        Groth16Error::Frozen as u32
+    );
+    // Malformed payloads still report their own failure.
+    assert_eq!(
+        adapter
+            .diagnose(&Bytes::from_array(&env, &[1u8, 2u8, 3u8]))
+            .error_code,
+        Groth16Error::MalformedProof as u32
+    );
+
+    adapter.unfreeze();
+    assert!(!adapter.is_frozen());
+    assert_eq!(adapter.diagnose(&payload).error_code, 0);
+    // The freeze evicted the cache, so the proof is verified afresh.
+    assert!(adapter.verify(&board, &hash, &payload));
+    assert_eq!(circom.calls(), 2);
+}
+
+#[test]
+fn test_roles_delegate_freeze_and_proof_cache() {
+    let (env, adapter, _board, _hash) = setup();
+    let guardian = Address::generate(&env);
+    let cache_operator = Address::generate(&env);
+
+    // Without a role, even a signed call is refused
+    assert!(adapter.try_freeze(&guardian).is_err());
+    assert!(adapter.try_clear_proof_cache(&cache_operator).is_err());
+
+    adapter.grant_role(&GUARDIAN_ROLE, &guardian);
+    adapter.grant_role(&CACHE_ROLE, &cache_operator);
+    assert!(adapter.has_role(&GUARDIAN_ROLE, &guardian));
+    assert!(!adapter.has_role(&CACHE_ROLE, &guardian));
+
+    // Each role only opens its own switches
+    assert!(adapter.try_set_proof_cache(&guardian, &true).is_err());
+    assert!(adapter.try_freeze(&cache_operator).is_err());
+    adapter.set_proof_cache(&cache_operator, &true);
+    adapter.clear_proof_cache(&cache_operator);
+    assert!(adapter.get_proof_cache());
+    adapter.freeze(&guardian);
+    assert!(adapter.is_frozen());
+
+    // A guardian can freeze but only the admin lifts it
+    let result = adapter
+        .mock_auths(&[MockAuth {
+            address: &guardian,
+            invoke: &MockAuthInvoke {
+                contract: &adapter.address,
+                fn_name: "unfreeze",
+                args: ().into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_unfreeze();
+    assert!(result.is_err());
+    env.mock_all_auths();
+    adapter.unfreeze();
+    assert!(!adapter.is_frozen());
+
+    adapter.revoke_role(&GUARDIAN_ROLE, &guardian);
+    assert!(adapter.try_freeze(&guardian).is_err());
+}
+
+#[test]
+fn test_metrics_count_verifications_by_outcome() {
+    let (env, adapter, board, hash) = setup();
+    assert_eq!(adapter.get_metrics(), VerificationMetrics::default());
+
+    let proof = Groth16Proof {
+        a: G1Affine::from_array(&env, &[0u8; BN254_G1_SERIALIZED_SIZE]),
+        b: G2Affine::from_array(&env, &[0u8; BN254_G2_SERIALIZED_SIZE]),
+        c: G1Affine::from_array(&env, &[0u8; BN254_G1_SERIALIZED_SIZE]),
+    };
+    let payload = encode_payload(&env, &proof, &make_inputs(&env, &board, &hash));
+    let wrong_hash = BytesN::from_array(&env, &[11u8; 32]);
+    let mismatched = encode_payload(&env, &proof, &make_inputs(&env, &board, &wrong_hash));
+
+    assert!(adapter.verify(&board, &hash, &payload));
+    assert!(adapter.verify(&board, &hash, &payload));
+    assert!(!adapter.verify(&board, &hash, &Bytes::from_array(&env, &[1u8, 2u8, 3u8])));
+    assert!(!adapter.verify(&board, &hash, &mismatched));
+    adapter.freeze(&adapter.get_admin());
+    assert!(!adapter.verify(&board, &hash, &payload));
+
+    assert_eq!(
+        adapter.get_metrics(),
+        VerificationMetrics {
+            verified: 2,
+            malformed: 1,
+            binding_mismatch: 1,
+            proof_invalid: 0,
+            frozen: 1,
+        }
+    );
+}
+
+#[test]
+fn test_get_info_reports_adapter_interface() {
+    let (env, adapter, _board, _hash) = setup();
+
+    let info = adapter.get_info();
+    assert_eq!(
+        info.name,
+        String::from_str(&env, "battleship-verifier-adapter")
+    );
+    assert_eq!(info.interfaces.len(), 1);
+    assert_eq!(
+        info.interfaces.get(0),
+        Some(String::from_str(&env, "verifier-adapter-v1"))
+    );
+}
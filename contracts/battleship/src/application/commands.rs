@@ -0,0 +1,916 @@
+use binding_hash::BindingHashBuilder;
+use soroban_sdk::{symbol_short, vec, Address, Bytes, BytesN, Env, IntoVal, Symbol};
+
+use crate::domain::{
+    append_chat_anchor, layout_cells, matches_shots, ArchivedStats, BoardLayout, Error, Game,
+    GamePhase, MoveRecord, SessionKey, SessionMarker,
+};
+use crate::infrastructure::storage::{
+    append_move, game_exists, load_active_sessions, load_address, load_chat_anchor_count,
+    load_chat_anchors, load_game, load_max_active_games, load_max_shots, load_max_stake,
+    load_metrics, load_min_stake, load_moves, load_pair_nonce, load_reveal, load_session_key,
+    remove_active_session, remove_session_key, save_active_sessions, save_archived_stats,
+    save_chat_anchor_count, save_chat_anchors, save_game, save_metrics, save_pair_nonce,
+    save_reveal, save_session_key, save_session_marker, session_started, DataKey, MAX_MOVES,
+};
+use crate::infrastructure::{
+    record_hub_failure, record_move, ChatAnchored, GameHubGateway, VerifierGateway,
+};
+
+use super::dto::{ResolutionArgs, ShotHashArgs, ShotResult};
+
+// Kind of session opened by `StartGameCommand`.
+pub enum StartMode {
+    Standard,
+    // Open board, no hub, no proofs.
+    Practice,
+    // 2v2: partners of player1 and player2.
+    Team(Address, Address),
+}
+
+/// Command: start a game in any mode and return its session id.
+pub struct StartGameCommand;
+
+impl StartGameCommand {
+    /// `requested_id = 0` derives the id from the players and their next pair nonce.
+    pub fn execute(
+        env: &Env,
+        requested_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+        mode: StartMode,
+    ) -> Result<u32, Error> {
+        let (practice, partners) = match mode {
+            StartMode::Standard => (false, None),
+            StartMode::Practice => (true, None),
+            StartMode::Team(partner1, partner2) => (false, Some((partner1, partner2))),
+        };
+
+        let mut members = vec![env, player1.clone(), player2.clone()];
+        if let Some((partner1, partner2)) = &partners {
+            members.push_back(partner1.clone());
+            members.push_back(partner2.clone());
+        }
+        for (i, member) in members.iter().enumerate() {
+            if members.iter().skip(i + 1).any(|other| other == member) {
+                return Err(Error::SelfPlayNotAllowed);
+            }
+        }
+
+        // Practice games stake nothing and never reach the hub.
+        if !practice {
+            let min_stake = load_min_stake(env);
+            let max_stake = load_max_stake(env);
+            for points in [player1_points, player2_points] {
+                if points < min_stake || (max_stake > 0 && points > max_stake) {
+                    return Err(Error::InvalidStake);
+                }
+            }
+        }
+
+        let derived_nonce = if requested_id == 0 {
+            Some(load_pair_nonce(env, &player1, &player2))
+        } else {
+            None
+        };
+        let session_id = match derived_nonce {
+            Some(nonce) => derive_session_id(env, &player1, &player2, nonce),
+            // Derived ids are known in advance, so callers cannot take them.
+            None if requested_id & DERIVED_SESSION_ID_BIT != 0 => {
+                return Err(Error::ReservedSessionId)
+            }
+            None => requested_id,
+        };
+
+        // The marker outlives the game, so an expired session cannot be restarted over it.
+        if game_exists(env, session_id) || session_started(env, session_id) {
+            return Err(Error::GameAlreadyExists);
+        }
+
+        let max_active = load_max_active_games(env);
+        if max_active > 0
+            && members
+                .iter()
+                .any(|member| load_active_sessions(env, &member).len() >= max_active)
+        {
+            return Err(Error::TooManyActiveGames);
+        }
+
+        player1.require_auth_for_args(vec![
+            env,
+            requested_id.into_val(env),
+            player1_points.into_val(env),
+        ]);
+        player2.require_auth_for_args(vec![
+            env,
+            requested_id.into_val(env),
+            player2_points.into_val(env),
+        ]);
+        // Partners stake nothing; they only agree to join this session.
+        if let Some((partner1, partner2)) = &partners {
+            partner1.require_auth_for_args(vec![env, requested_id.into_val(env)]);
+            partner2.require_auth_for_args(vec![env, requested_id.into_val(env)]);
+        }
+
+        let disclose_sunk_ships = load_address(env, &DataKey::DisclosureVerifierAddress).is_some();
+        let mut game = Game::new(
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+            partners,
+            practice,
+            disclose_sunk_ships,
+        );
+        game.max_shots = load_max_shots(env);
+
+        // Required ordering: notify hub first. Practice games never reach the hub.
+        if !practice {
+            GameHubGateway::notify_game_started(
+                env,
+                session_id,
+                &game.hub_seats.player1,
+                &game.hub_seats.player2,
+                player1_points,
+                player2_points,
+            );
+        }
+
+        for member in members.iter() {
+            let mut sessions = load_active_sessions(env, &member);
+            sessions.push_back(session_id);
+            save_active_sessions(env, &member, &sessions);
+        }
+
+        save_game(env, session_id, &game);
+        save_session_marker(env, session_id, &SessionMarker::new(env, &game));
+        let mut metrics = load_metrics(env);
+        metrics.record_start(&game);
+        save_metrics(env, &metrics);
+        if let Some(nonce) = derived_nonce {
+            save_pair_nonce(env, &game.player1, &game.player2, nonce + 1);
+        }
+        Ok(session_id)
+    }
+}
+
+// Set on every derived session id and refused on explicit ones, so no caller can squat the
+// id a pair's next `start_game(0, ..)` will derive.
+const DERIVED_SESSION_ID_BIT: u32 = 1 << 31;
+
+/// First 4 bytes of keccak256(player1 || player2 || nonce) with `DERIVED_SESSION_ID_BIT` set.
+pub fn derive_session_id(env: &Env, player1: &Address, player2: &Address, nonce: u32) -> u32 {
+    let mut payload = player1.to_string().to_bytes();
+    payload.append(&player2.to_string().to_bytes());
+    payload.append(&Bytes::from_array(env, &nonce.to_be_bytes()));
+    let digest: BytesN<32> = env.crypto().keccak256(&payload).into();
+    let head = digest.to_array();
+    u32::from_be_bytes([head[0], head[1], head[2], head[3]]) | DERIVED_SESSION_ID_BIT
+}
+
+/// Moves of a player may be signed by their session key instead of the main wallet.
+fn require_move_auth(env: &Env, session_id: u32, player: &Address) {
+    match load_session_key(env, session_id, player) {
+        Some(session_key) => session_key.key.require_auth(),
+        None => player.require_auth(),
+    }
+}
+
+/// Drop a finished game from every member's active sessions and count how it ended.
+fn release_sessions(env: &Env, session_id: u32, game: &Game) {
+    for member in game.members(env).iter() {
+        remove_active_session(env, &member, session_id);
+    }
+    let mut metrics = load_metrics(env);
+    metrics.record_end(game);
+    save_metrics(env, &metrics);
+}
+
+/// Command: commit a side's board.
+pub struct CommitBoardCommand;
+
+impl CommitBoardCommand {
+    pub fn execute(
+        env: &Env,
+        session_id: u32,
+        player: Address,
+        board_commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        Self::commit(env, session_id, player, board_commitment, None)
+    }
+
+    /// Practice games place the board in clear; its reveal hash stands in for the commitment and
+    /// the layout is published at once.
+    pub fn execute_practice(
+        env: &Env,
+        session_id: u32,
+        player: Address,
+        layout: BoardLayout,
+    ) -> Result<(), Error> {
+        let commitment = RevealBoardCommand::build_reveal_hash(
+            env,
+            session_id,
+            player.clone(),
+            &layout,
+            BytesN::from_array(env, &[0u8; 32]),
+        );
+        Self::commit(env, session_id, player, commitment, Some(layout))
+    }
+
+    fn commit(
+        env: &Env,
+        session_id: u32,
+        player: Address,
+        board_commitment: BytesN<32>,
+        layout: Option<BoardLayout>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut game = load_game(env, session_id)?;
+        let side = game.commit_board(&player, board_commitment, layout.as_ref())?;
+
+        record_move(
+            env,
+            session_id,
+            &mut game,
+            symbol_short!("commit"),
+            Some(player),
+        );
+        save_game(env, session_id, &game);
+        if let Some(layout) = layout {
+            save_reveal(env, session_id, &side, &layout);
+        }
+        Ok(())
+    }
+}
+
+/// Command: commit a side's RNG seed at game start.
+pub struct CommitSeedCommand;
+
+impl CommitSeedCommand {
+    pub fn execute(
+        env: &Env,
+        session_id: u32,
+        player: Address,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut game = load_game(env, session_id)?;
+        game.commit_seed(&player, commitment, env.ledger().sequence())?;
+
+        record_move(
+            env,
+            session_id,
+            &mut game,
+            symbol_short!("seedcmt"),
+            Some(player),
+        );
+        save_game(env, session_id, &game);
+        Ok(())
+    }
+}
+
+/// Command: reveal a committed RNG seed; the second reveal starts the game's random stream.
+pub struct RevealSeedCommand;
+
+impl RevealSeedCommand {
+    pub fn execute(
+        env: &Env,
+        session_id: u32,
+        player: Address,
+        seed: BytesN<32>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut game = load_game(env, session_id)?;
+        let commitment = Self::build_seed_commitment(env, session_id, player.clone(), &seed);
+        game.reveal_seed(env, &player, seed, &commitment)?;
+
+        record_move(
+            env,
+            session_id,
+            &mut game,
+            symbol_short!("seedrev"),
+            Some(player),
+        );
+        save_game(env, session_id, &game);
+        Ok(())
+    }
+
+    /// Hash a player commits to with `commit_seed`; bound to the session and player so a
+    /// commitment cannot be replayed elsewhere.
+    pub fn build_seed_commitment(
+        env: &Env,
+        session_id: u32,
+        player: Address,
+        seed: &BytesN<32>,
+    ) -> BytesN<32> {
+        // session_id || seed || player
+        BindingHashBuilder::new(env, session_id)
+            .commitment(seed)
+            .address(&player)
+            .finish()
+    }
+}
+
+/// Report an ended game to the hub without letting a failing hub revert `action`. A rejection is
+/// stored for `get_last_error_detail` and leaves the result pending for `retry_hub_notify`.
+fn notify_hub_isolated(env: &Env, session_id: u32, game: &mut Game, action: Symbol) {
+    let result = GameHubGateway::try_notify_game_ended(env, session_id, game.hub_player1_won());
+    game.pending_hub_notification = result.is_err();
+    if let Err(code) = result {
+        record_hub_failure(env, session_id, action, GameHubGateway::address(env), code);
+    }
+}
+
+/// Command: end a game whose seed exchange one side abandoned after the other revealed.
+pub struct ForfeitWithheldSeedCommand;
+
+impl ForfeitWithheldSeedCommand {
+    /// Callable by anyone once the reveal window has passed; returns the winner.
+    pub fn execute(env: &Env, session_id: u32) -> Result<Address, Error> {
+        let mut game = load_game(env, session_id)?;
+        let winner = game.forfeit_withheld_seed(env.ledger().sequence())?;
+
+        if !game.practice {
+            notify_hub_isolated(env, session_id, &mut game, symbol_short!("seedlapse"));
+        }
+        release_sessions(env, session_id, &game);
+
+        record_move(env, session_id, &mut game, symbol_short!("seedlapse"), None);
+        save_game(env, session_id, &game);
+
+        let moves = load_moves(env, session_id, 0, MAX_MOVES, game.resolved_moves());
+        let stats = ArchivedStats::from_moves(&game, &winner, env.ledger().sequence(), &moves);
+        save_archived_stats(env, session_id, &stats);
+        Ok(winner)
+    }
+}
+
+/// Command: fire at a cell of the opponent's board.
+pub struct FireCommand;
+
+impl FireCommand {
+    pub fn execute(
+        env: &Env,
+        session_id: u32,
+        shooter: Address,
+        x: u32,
+        y: u32,
+    ) -> Result<(), Error> {
+        require_move_auth(env, session_id, &shooter);
+
+        let mut game = load_game(env, session_id)?;
+        game.fire(&shooter, x, y, env.ledger().sequence())?;
+
+        record_move(
+            env,
+            session_id,
+            &mut game,
+            symbol_short!("fire"),
+            Some(shooter),
+        );
+        save_game(env, session_id, &game);
+        Ok(())
+    }
+}
+
+/// Command: fire a hidden shot, recording only the hash of its cell.
+pub struct CommitShotCommand;
+
+impl CommitShotCommand {
+    pub fn execute(
+        env: &Env,
+        session_id: u32,
+        shooter: Address,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        require_move_auth(env, session_id, &shooter);
+
+        let mut game = load_game(env, session_id)?;
+        game.commit_shot(&shooter, commitment, env.ledger().sequence())?;
+
+        record_move(
+            env,
+            session_id,
+            &mut game,
+            symbol_short!("shotcmt"),
+            Some(shooter),
+        );
+        save_game(env, session_id, &game);
+        Ok(())
+    }
+}
+
+/// Command: open a committed shot so the defender can resolve it.
+pub struct RevealShotCommand;
+
+impl RevealShotCommand {
+    pub fn execute(
+        env: &Env,
+        session_id: u32,
+        shooter: Address,
+        x: u32,
+        y: u32,
+        salt: BytesN<32>,
+    ) -> Result<(), Error> {
+        require_move_auth(env, session_id, &shooter);
+
+        let mut game = load_game(env, session_id)?;
+        let commitment = Self::build_shot_commitment(env, session_id, shooter.clone(), x, y, &salt);
+        game.reveal_shot(&shooter, x, y, &commitment)?;
+
+        record_move(
+            env,
+            session_id,
+            &mut game,
+            symbol_short!("shotrev"),
+            Some(shooter),
+        );
+        save_game(env, session_id, &game);
+        Ok(())
+    }
+
+    /// Hash a shooter commits to with `commit_shot`; bound to the session and shooter so a
+    /// commitment cannot be replayed elsewhere.
+    pub fn build_shot_commitment(
+        env: &Env,
+        session_id: u32,
+        shooter: Address,
+        x: u32,
+        y: u32,
+        salt: &BytesN<32>,
+    ) -> BytesN<32> {
+        // session_id || x || y || salt || shooter
+        BindingHashBuilder::new(env, session_id)
+            .coord(x, y)
+            .commitment(salt)
+            .address(&shooter)
+            .finish()
+    }
+}
+
+/// Command: retract the shooter's unresolved shot.
+pub struct CancelShotCommand;
+
+impl CancelShotCommand {
+    pub fn execute(env: &Env, session_id: u32, shooter: Address) -> Result<(), Error> {
+        require_move_auth(env, session_id, &shooter);
+
+        let mut game = load_game(env, session_id)?;
+        game.cancel_shot(&shooter, env.ledger().sequence())?;
+
+        record_move(
+            env,
+            session_id,
+            &mut game,
+            symbol_short!("retract"),
+            Some(shooter),
+        );
+        save_game(env, session_id, &game);
+        Ok(())
+    }
+}
+
+/// Command: resolve the pending shot, proven or (in practice games) derived on-chain.
+pub struct ResolveShotCommand;
+
+impl ResolveShotCommand {
+    /// See `ResolutionArgs` for which proofs each kind of resolution carries.
+    pub fn execute(env: &Env, session_id: u32, args: ResolutionArgs) -> Result<ShotResult, Error> {
+        let ResolutionArgs {
+            defender,
+            is_hit,
+            sunk_ship,
+            proof,
+            sunk_placement,
+            consistency_proof,
+        } = args;
+        let mut game = load_game(env, session_id)?;
+        let resolution = game.validate_resolution(&defender, is_hit, sunk_ship, sunk_placement)?;
+
+        match (proof, game.practice) {
+            (Some((proof_payload, public_inputs_hash)), false) => {
+                let expected_hash = Self::build_public_inputs_hash(
+                    env,
+                    session_id,
+                    &ShotHashArgs {
+                        defender: resolution.defender.clone(),
+                        shooter: resolution.shooter.clone(),
+                        x: resolution.x,
+                        y: resolution.y,
+                        is_hit,
+                        sunk_ship,
+                        board_commitment: resolution.board_commitment.clone(),
+                        sunk_placement: resolution.sunk_placement.clone(),
+                    },
+                );
+                if expected_hash != public_inputs_hash {
+                    return Err(Error::InvalidPublicInputsHash);
+                }
+
+                // Sinking shots with a disclosed placement are proven by `resolve_shot_disclosed`.
+                let verifier_key = if resolution.sunk_placement.is_some() {
+                    DataKey::DisclosureVerifierAddress
+                } else {
+                    DataKey::VerifierAddress
+                };
+                let verifier = load_address(env, &verifier_key).expect("Verifier address not set");
+                if !VerifierGateway::verify(
+                    env,
+                    &verifier,
+                    &resolution.board_commitment,
+                    &public_inputs_hash,
+                    &proof_payload,
+                ) {
+                    return Err(Error::InvalidProof);
+                }
+            }
+            // The contract derived the result from the clear layout itself.
+            (None, true) => {}
+            (Some(_), true) => return Err(Error::PracticeGameUnsupported),
+            (None, false) => return Err(Error::NotPracticeGame),
+        }
+
+        let move_index = game.resolved_moves();
+        let is_final = game.apply_resolution(&resolution)?;
+
+        let final_verifier = if game.practice {
+            None
+        } else {
+            load_address(env, &DataKey::FinalVerifierAddress)
+        };
+        match (consistency_proof, final_verifier) {
+            (None, Some(_)) if is_final => return Err(Error::FinalProofRequired),
+            (None, _) => {}
+            (Some(_), None) => return Err(Error::FinalVerifierNotConfigured),
+            (Some(_), Some(_)) if !is_final => return Err(Error::NotFinalShot),
+            (Some(proof), Some(verifier)) => {
+                let (shots, hits) = game.side_bitmaps(resolution.shooter_is_player1);
+                let consistency_hash = Self::build_consistency_hash(
+                    env,
+                    session_id,
+                    defender.clone(),
+                    shots,
+                    hits,
+                    resolution.board_commitment.clone(),
+                );
+                if !VerifierGateway::verify(
+                    env,
+                    &verifier,
+                    &resolution.board_commitment,
+                    &consistency_hash,
+                    &proof,
+                ) {
+                    return Err(Error::InvalidProof);
+                }
+            }
+        }
+
+        let (winner, next_turn) = game.finish_resolution(env, &resolution, is_final);
+        // A failing hub must not block the win, so the call is isolated and retried later through
+        // `retry_hub_notify`.
+        if winner.is_some() && !game.practice {
+            notify_hub_isolated(env, session_id, &mut game, symbol_short!("resolve"));
+        }
+        if game.phase == GamePhase::Ended {
+            release_sessions(env, session_id, &game);
+        }
+
+        record_move(
+            env,
+            session_id,
+            &mut game,
+            symbol_short!("resolve"),
+            Some(defender),
+        );
+        save_game(env, session_id, &game);
+
+        let recorded_placement = resolution.recorded_placement();
+        append_move(
+            env,
+            session_id,
+            &MoveRecord {
+                index: move_index,
+                shooter_is_player1: resolution.shooter_is_player1,
+                x: resolution.x,
+                y: resolution.y,
+                is_hit,
+                sunk_ship,
+                sunk_placement: recorded_placement.clone(),
+                ledger: env.ledger().sequence(),
+            },
+        );
+
+        if let Some(winner) = &winner {
+            let moves = load_moves(env, session_id, 0, MAX_MOVES, move_index + 1);
+            let stats = ArchivedStats::from_moves(&game, winner, env.ledger().sequence(), &moves);
+            save_archived_stats(env, session_id, &stats);
+        }
+
+        Ok(ShotResult {
+            is_hit,
+            sunk_ship,
+            sunk_placement: recorded_placement,
+            winner,
+            next_turn,
+            move_count: game.move_count,
+        })
+    }
+
+    /// Hash binding a shot resolution; `sunk_placement` appends the disclosed ship's x, y and dir
+    /// bytes for `resolve_sinking_shot`.
+    pub fn build_public_inputs_hash(env: &Env, session_id: u32, args: &ShotHashArgs) -> BytesN<32> {
+        let mut hash = BindingHashBuilder::new(env, session_id)
+            .coord(args.x, args.y)
+            .flag(args.is_hit)
+            .u32(args.sunk_ship)
+            .commitment(&args.board_commitment)
+            .address(&args.defender)
+            .address(&args.shooter);
+        if let Some(placement) = &args.sunk_placement {
+            hash = hash.bytes(&[placement.x as u8, placement.y as u8, placement.dir as u8]);
+        }
+        hash.finish()
+    }
+
+    /// Hash binding the full shot history against a board for `board_consistency` proofs.
+    pub fn build_consistency_hash(
+        env: &Env,
+        session_id: u32,
+        defender: Address,
+        shots: u128,
+        hits: u128,
+        board_commitment: BytesN<32>,
+    ) -> BytesN<32> {
+        BindingHashBuilder::new(env, session_id)
+            .u128(shots)
+            .u128(hits)
+            .commitment(&board_commitment)
+            .address(&defender)
+            .finish()
+    }
+}
+
+/// Command: resolve a practice game's pending shot from the defender's clear layout.
+pub struct ResolvePracticeShotCommand;
+
+impl ResolvePracticeShotCommand {
+    pub fn execute(env: &Env, session_id: u32) -> Result<ShotResult, Error> {
+        let game = load_game(env, session_id)?;
+        let defender = game.practice_defender()?;
+        let layout = load_reveal(env, session_id, &defender).ok_or(Error::BoardNotCommitted)?;
+        let (is_hit, sunk_ship) = game.practice_outcome(&defender, &layout_cells(&layout)?)?;
+
+        ResolveShotCommand::execute(
+            env,
+            session_id,
+            ResolutionArgs {
+                defender,
+                is_hit,
+                sunk_ship,
+                proof: None,
+                sunk_placement: None,
+                consistency_proof: None,
+            },
+        )
+    }
+}
+
+/// Command: swap a committed board for a proven-equivalent commitment under a new salt.
+pub struct RecommitBoardCommand;
+
+impl RecommitBoardCommand {
+    pub fn execute(
+        env: &Env,
+        session_id: u32,
+        player: Address,
+        new_commitment: BytesN<32>,
+        equivalence_proof: Bytes,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let mut game = load_game(env, session_id)?;
+        let old_commitment = game.recommit_target(&player, &new_commitment)?;
+
+        let verifier = load_address(env, &DataKey::RecommitVerifierAddress)
+            .ok_or(Error::RecommitNotConfigured)?;
+        if !VerifierGateway::verify(
+            env,
+            &verifier,
+            &old_commitment,
+            &new_commitment,
+            &equivalence_proof,
+        ) {
+            return Err(Error::InvalidProof);
+        }
+
+        game.replace_commitment(&player, new_commitment);
+        record_move(
+            env,
+            session_id,
+            &mut game,
+            symbol_short!("recommit"),
+            Some(player),
+        );
+        save_game(env, session_id, &game);
+        Ok(())
+    }
+}
+
+/// Command: disclose a player's board once the game has ended.
+pub struct RevealBoardCommand;
+
+impl RevealBoardCommand {
+    pub fn execute(
+        env: &Env,
+        session_id: u32,
+        player: Address,
+        layout: BoardLayout,
+        reveal_proof: Bytes,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let game = load_game(env, session_id)?;
+        // Shots and hits the opponent recorded against this player's board.
+        let (commitment, shots, hits) = game.reveal_target(&player)?;
+
+        if load_reveal(env, session_id, &player).is_some() {
+            return Err(Error::BoardAlreadyRevealed);
+        }
+
+        if !matches_shots(&layout_cells(&layout)?, shots, hits) {
+            return Err(Error::InvalidLayout);
+        }
+
+        let verifier =
+            load_address(env, &DataKey::RevealVerifierAddress).ok_or(Error::RevealNotConfigured)?;
+        let reveal_hash =
+            Self::build_reveal_hash(env, session_id, player.clone(), &layout, commitment.clone());
+        if !VerifierGateway::verify(env, &verifier, &commitment, &reveal_hash, &reveal_proof) {
+            return Err(Error::InvalidProof);
+        }
+
+        save_reveal(env, session_id, &player, &layout);
+        Ok(())
+    }
+
+    /// Hash binding a disclosed layout to the board commitment for `board_reveal` proofs.
+    pub fn build_reveal_hash(
+        env: &Env,
+        session_id: u32,
+        player: Address,
+        layout: &BoardLayout,
+        board_commitment: BytesN<32>,
+    ) -> BytesN<32> {
+        // session_id || ship_x[5] || ship_y[5] || ship_dir[5], one byte per coordinate.
+        let mut cells = [0u8; 15];
+        for i in 0..5u32 {
+            let at = i as usize;
+            cells[at] = layout.ship_x.get(i).unwrap_or(0) as u8;
+            cells[at + 5] = layout.ship_y.get(i).unwrap_or(0) as u8;
+            cells[at + 10] = layout.ship_dir.get(i).unwrap_or(0) as u8;
+        }
+
+        BindingHashBuilder::new(env, session_id)
+            .bytes(&cells)
+            .commitment(&board_commitment)
+            .address(&player)
+            .finish()
+    }
+}
+
+/// Command: the Game Hub aborts a running game; it ends with no winner.
+pub struct CancelGameCommand;
+
+impl CancelGameCommand {
+    pub fn execute(env: &Env, session_id: u32) -> Result<(), Error> {
+        let mut game = load_game(env, session_id)?;
+        if game.phase == GamePhase::Ended {
+            return Err(Error::GameAlreadyEnded);
+        }
+        GameHubGateway::address(env).require_auth();
+
+        game.cancel()?;
+        record_move(env, session_id, &mut game, symbol_short!("cancel"), None);
+        save_game(env, session_id, &game);
+        release_sessions(env, session_id, &game);
+        Ok(())
+    }
+}
+
+/// Command: re-send the hub `end_game` that failed on the winning shot.
+pub struct RetryHubNotifyCommand;
+
+impl RetryHubNotifyCommand {
+    pub fn execute(env: &Env, session_id: u32) -> Result<(), Error> {
+        let mut game = load_game(env, session_id)?;
+
+        if !game.pending_hub_notification {
+            return Err(Error::HubNotificationNotPending);
+        }
+        if game.winner.is_none() {
+            return Err(Error::InvalidPhase);
+        }
+
+        // Not isolated: if the hub still fails, the whole call reverts and the flag stays set.
+        GameHubGateway::notify_game_ended(env, session_id, game.hub_player1_won());
+
+        game.pending_hub_notification = false;
+        save_game(env, session_id, &game);
+        Ok(())
+    }
+}
+
+/// Command: notify the hub of an ended game again; idempotent.
+pub struct NotifyGameEndedCommand;
+
+impl NotifyGameEndedCommand {
+    pub fn execute(env: &Env, session_id: u32) -> Result<(), Error> {
+        let game = load_game(env, session_id)?;
+        if game.phase != GamePhase::Ended {
+            return Err(Error::InvalidPhase);
+        }
+        if game.winner.is_none() {
+            return Err(Error::InvalidPhase);
+        }
+        GameHubGateway::notify_game_ended(env, session_id, game.hub_player1_won());
+        Ok(())
+    }
+}
+
+/// Command: let a session key sign `fire` for a player.
+pub struct AuthorizeSessionKeyCommand;
+
+impl AuthorizeSessionKeyCommand {
+    pub fn execute(
+        env: &Env,
+        session_id: u32,
+        player: Address,
+        key: Address,
+        expiry: u32,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        let game = load_game(env, session_id)?;
+        game.ensure_active_member(&player)?;
+
+        let session_key = SessionKey::new(&player, key, expiry, env.ledger().sequence())?;
+        save_session_key(env, session_id, &player, &session_key);
+        Ok(())
+    }
+}
+
+/// Command: anchor the hash of an off-chain chat message.
+pub struct PostChatAnchorCommand;
+
+impl PostChatAnchorCommand {
+    /// Only game members may anchor, so fresh addresses cannot dodge the per-poster rate limit;
+    /// the per-session cap bounds the room as a whole. Returns the anchor's index among the
+    /// poster's.
+    pub fn execute(
+        env: &Env,
+        session_id: u32,
+        poster: Address,
+        hash: BytesN<32>,
+    ) -> Result<u32, Error> {
+        poster.require_auth();
+
+        let game = load_game(env, session_id)?;
+        if !game.members(env).contains(&poster) {
+            return Err(Error::NotPlayer);
+        }
+
+        let mut anchors = load_chat_anchors(env, session_id, &poster);
+        let session_anchors = load_chat_anchor_count(env, session_id);
+        let index = append_chat_anchor(
+            &mut anchors,
+            session_anchors,
+            hash.clone(),
+            env.ledger().sequence(),
+        )?;
+        save_chat_anchors(env, session_id, &poster, &anchors);
+        save_chat_anchor_count(env, session_id, session_anchors + 1);
+        ChatAnchored {
+            session_id,
+            poster,
+            index,
+            hash,
+        }
+        .publish(env);
+        Ok(index)
+    }
+}
+
+/// Command: drop a player's session key.
+pub struct RevokeSessionKeyCommand;
+
+impl RevokeSessionKeyCommand {
+    pub fn execute(env: &Env, session_id: u32, player: Address) {
+        player.require_auth();
+        remove_session_key(env, session_id, &player);
+    }
+}
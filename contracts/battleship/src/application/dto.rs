@@ -0,0 +1,156 @@
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, String, Vec};
+
+use crate::domain::{ShipPlacement, SunkPlacement};
+
+// A defender's resolution of the pending shot, as `ResolveShotCommand::execute` takes it.
+pub struct ResolutionArgs {
+    pub defender: Address,
+    pub is_hit: bool,
+    // 0 = none, 1..5 = Carrier..Destroyer
+    pub sunk_ship: u32,
+    // (proof_payload, public_inputs_hash); None only for practice games.
+    pub proof: Option<(Bytes, BytesN<32>)>,
+    // Disclosed placement of the sunk ship, for `resolve_sinking_shot`.
+    pub sunk_placement: Option<ShipPlacement>,
+    // `board_consistency` proof, required on the winning shot once a final verifier is set.
+    pub consistency_proof: Option<Bytes>,
+}
+
+// Arguments of `resolve_final_shot`: a regular resolution plus the `board_consistency` proof.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FinalShotArgs {
+    pub defender: Address,
+    pub is_hit: bool,
+    // 0 = none, 1..5 = Carrier..Destroyer
+    pub sunk_ship: u32,
+    pub proof_payload: Bytes,
+    pub public_inputs_hash: BytesN<32>,
+    pub consistency_proof: Bytes,
+}
+
+impl From<FinalShotArgs> for ResolutionArgs {
+    fn from(args: FinalShotArgs) -> Self {
+        ResolutionArgs {
+            defender: args.defender,
+            is_hit: args.is_hit,
+            sunk_ship: args.sunk_ship,
+            proof: Some((args.proof_payload, args.public_inputs_hash)),
+            sunk_placement: None,
+            consistency_proof: Some(args.consistency_proof),
+        }
+    }
+}
+
+// Arguments of `resolve_sinking_shot`: a sinking hit with the sunk ship's disclosed placement, and
+// the `board_consistency` proof when the shot also wins the game.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SinkingShotArgs {
+    pub defender: Address,
+    // 1..5 = Carrier..Destroyer
+    pub sunk_ship: u32,
+    pub sunk_placement: ShipPlacement,
+    pub proof_payload: Bytes,
+    pub public_inputs_hash: BytesN<32>,
+    pub consistency_proof: Option<Bytes>,
+}
+
+impl From<SinkingShotArgs> for ResolutionArgs {
+    fn from(args: SinkingShotArgs) -> Self {
+        ResolutionArgs {
+            defender: args.defender,
+            is_hit: true,
+            sunk_ship: args.sunk_ship,
+            proof: Some((args.proof_payload, args.public_inputs_hash)),
+            sunk_placement: Some(args.sunk_placement),
+            consistency_proof: args.consistency_proof,
+        }
+    }
+}
+
+// Everything a shot resolution's public inputs hash binds.
+pub struct ShotHashArgs {
+    pub defender: Address,
+    pub shooter: Address,
+    pub x: u32,
+    pub y: u32,
+    pub is_hit: bool,
+    pub sunk_ship: u32,
+    pub board_commitment: BytesN<32>,
+    // Appended as x, y and dir bytes for `resolve_sinking_shot`.
+    pub sunk_placement: Option<ShipPlacement>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShotResult {
+    pub is_hit: bool,
+    // 0 = none, 1..5 = Carrier..Destroyer
+    pub sunk_ship: u32,
+    pub sunk_placement: SunkPlacement,
+    pub winner: Option<Address>,
+    pub next_turn: Option<Address>,
+    // The game's `move_count` once this resolution is applied.
+    pub move_count: u32,
+}
+
+// Dry-run diagnosis returned by `check_resolution`. Checks run in `resolve_shot` order and stop at
+// the first failure, so fields past it keep their zero values.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResolutionCheck {
+    // `Error` code `resolve_shot` would fail with, 0 when the resolution would be accepted.
+    pub error_code: u32,
+    pub shooter: Option<Address>,
+    pub x: u32,
+    pub y: u32,
+    pub board_commitment: Option<BytesN<32>>,
+    // Hash the contract binds the proof to, for comparison with the prover's.
+    pub expected_hash: Option<BytesN<32>>,
+    pub hash_matches: bool,
+    pub proof_valid: bool,
+    // Contract error the verifier aborted with (e.g. an adapter payload error), 0 if none.
+    pub verifier_error_code: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameRules {
+    pub board_size: u32,
+    pub carrier_len: u32,
+    pub battleship_len: u32,
+    pub cruiser_len: u32,
+    pub submarine_len: u32,
+    pub destroyer_len: u32,
+    pub total_ship_cells: u32,
+    // Whether new games require sinking shots to disclose the sunk ship's placement.
+    pub disclose_sunk_ships: bool,
+    // `cancel_shot` limits: ledgers after `fire`, and retractions per player per game.
+    pub shot_retract_window_ledgers: u32,
+    pub max_shot_retractions: u32,
+    // Stake bounds `start_game` enforces on each player's points; max 0 = no cap.
+    pub min_stake: i128,
+    pub max_stake: i128,
+    // Resolved shots after which new games are adjudicated on hits; 0 = no cap.
+    pub max_shots: u32,
+    // `post_chat_anchor` limits: ledgers between two anchors, anchors per poster per game, and
+    // anchors per game across its members.
+    pub chat_anchor_interval_ledgers: u32,
+    pub max_chat_anchors: u32,
+    pub max_session_chat_anchors: u32,
+    // Ledgers after the second seed commitment to reveal both seeds before a withholder forfeits.
+    pub seed_reveal_window_ledgers: u32,
+}
+
+// Returned by `get_info` so the hub, SDK and tooling can negotiate capabilities.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractInfo {
+    pub name: String,
+    pub version: String,
+    // Interface ids implemented by the contract, e.g. "game-v1".
+    pub interfaces: Vec<String>,
+    // Source revision baked in at build time from ZKARCADE_BUILD_HASH ("dev" when unset).
+    pub build_hash: String,
+}
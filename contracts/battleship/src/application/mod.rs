@@ -0,0 +1,20 @@
+mod commands;
+mod dto;
+mod queries;
+
+pub use commands::{
+    derive_session_id, AuthorizeSessionKeyCommand, CancelGameCommand, CancelShotCommand,
+    CommitBoardCommand, CommitSeedCommand, CommitShotCommand, FireCommand,
+    ForfeitWithheldSeedCommand, NotifyGameEndedCommand, PostChatAnchorCommand,
+    RecommitBoardCommand, ResolvePracticeShotCommand, ResolveShotCommand, RetryHubNotifyCommand,
+    RevealBoardCommand, RevealSeedCommand, RevealShotCommand, RevokeSessionKeyCommand,
+    StartGameCommand, StartMode,
+};
+pub use dto::{
+    ContractInfo, FinalShotArgs, GameRules, ResolutionArgs, ResolutionCheck, ShotHashArgs,
+    ShotResult, SinkingShotArgs,
+};
+pub use queries::{
+    CheckResolutionQuery, GetArchivedStatsQuery, GetGameQuery, GetGameStatusQuery, GetInfoQuery,
+    GetMoveCountQuery, GetMovesQuery, GetRevealedBoardQuery, GetRulesQuery,
+};
@@ -0,0 +1,225 @@
+use soroban_sdk::{Address, Bytes, BytesN, Env, String, Vec};
+
+use crate::domain::{
+    layout_cells, ArchivedStats, Error, Game, GameStatus, MoveRecord, BOARD_SIZE,
+    CHAT_ANCHOR_INTERVAL_LEDGERS, MAX_CHAT_ANCHORS, MAX_SESSION_CHAT_ANCHORS, MAX_SHOT_RETRACTIONS,
+    SEED_REVEAL_WINDOW_LEDGERS, SHIP_BATTLESHIP_LEN, SHIP_CARRIER_LEN, SHIP_CRUISER_LEN,
+    SHIP_DESTROYER_LEN, SHIP_SUBMARINE_LEN, SHOT_RETRACT_WINDOW_LEDGERS, TOTAL_SHIP_CELLS,
+};
+use crate::infrastructure::storage::{
+    load_address, load_archived_stats, load_game, load_max_shots, load_max_stake, load_min_stake,
+    load_moves, load_reveal, DataKey, INTERFACES, MAX_MOVES,
+};
+use crate::infrastructure::VerifierGateway;
+
+use super::commands::ResolveShotCommand;
+use super::dto::{ContractInfo, GameRules, ResolutionCheck, ShotHashArgs};
+
+/// Query: full game state.
+pub struct GetGameQuery;
+
+impl GetGameQuery {
+    pub fn execute(env: &Env, session_id: u32) -> Result<Game, Error> {
+        load_game(env, session_id)
+    }
+}
+
+/// Query: lifecycle status for `GameInterface`.
+pub struct GetGameStatusQuery;
+
+impl GetGameStatusQuery {
+    pub fn execute(env: &Env, session_id: u32) -> Result<GameStatus, Error> {
+        Ok(load_game(env, session_id)?.status())
+    }
+}
+
+/// Query: number of actions applied to a game.
+pub struct GetMoveCountQuery;
+
+impl GetMoveCountQuery {
+    pub fn execute(env: &Env, session_id: u32) -> Result<u32, Error> {
+        Ok(load_game(env, session_id)?.move_count)
+    }
+}
+
+/// Query: a page of the move log.
+pub struct GetMovesQuery;
+
+impl GetMovesQuery {
+    pub fn execute(
+        env: &Env,
+        session_id: u32,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<MoveRecord>, Error> {
+        let game = load_game(env, session_id)?;
+        Ok(load_moves(
+            env,
+            session_id,
+            start,
+            limit.min(MAX_MOVES),
+            game.resolved_moves(),
+        ))
+    }
+}
+
+/// Query: archived statistics of a won game.
+pub struct GetArchivedStatsQuery;
+
+impl GetArchivedStatsQuery {
+    pub fn execute(env: &Env, session_id: u32) -> Result<ArchivedStats, Error> {
+        load_archived_stats(env, session_id).ok_or(Error::StatsNotArchived)
+    }
+}
+
+/// Query: a revealed board as 100 cells.
+pub struct GetRevealedBoardQuery;
+
+impl GetRevealedBoardQuery {
+    pub fn execute(env: &Env, session_id: u32, player: &Address) -> Result<Vec<u32>, Error> {
+        let layout = load_reveal(env, session_id, player).ok_or(Error::BoardNotRevealed)?;
+        Ok(Vec::from_array(env, layout_cells(&layout)?))
+    }
+}
+
+/// Query: board rules and the contract's current limits.
+pub struct GetRulesQuery;
+
+impl GetRulesQuery {
+    pub fn execute(env: &Env) -> GameRules {
+        GameRules {
+            board_size: BOARD_SIZE,
+            carrier_len: SHIP_CARRIER_LEN,
+            battleship_len: SHIP_BATTLESHIP_LEN,
+            cruiser_len: SHIP_CRUISER_LEN,
+            submarine_len: SHIP_SUBMARINE_LEN,
+            destroyer_len: SHIP_DESTROYER_LEN,
+            total_ship_cells: TOTAL_SHIP_CELLS,
+            disclose_sunk_ships: load_address(env, &DataKey::DisclosureVerifierAddress).is_some(),
+            shot_retract_window_ledgers: SHOT_RETRACT_WINDOW_LEDGERS,
+            max_shot_retractions: MAX_SHOT_RETRACTIONS,
+            min_stake: load_min_stake(env),
+            max_stake: load_max_stake(env),
+            max_shots: load_max_shots(env),
+            chat_anchor_interval_ledgers: CHAT_ANCHOR_INTERVAL_LEDGERS,
+            max_chat_anchors: MAX_CHAT_ANCHORS,
+            max_session_chat_anchors: MAX_SESSION_CHAT_ANCHORS,
+            seed_reveal_window_ledgers: SEED_REVEAL_WINDOW_LEDGERS,
+        }
+    }
+}
+
+/// Query: contract name, version, interface ids and build hash.
+pub struct GetInfoQuery;
+
+impl GetInfoQuery {
+    pub fn execute(env: &Env) -> ContractInfo {
+        let mut interfaces = Vec::new(env);
+        for id in INTERFACES {
+            interfaces.push_back(String::from_str(env, id));
+        }
+        ContractInfo {
+            name: String::from_str(env, env!("CARGO_PKG_NAME")),
+            version: String::from_str(env, env!("CARGO_PKG_VERSION")),
+            interfaces,
+            build_hash: String::from_str(env, option_env!("ZKARCADE_BUILD_HASH").unwrap_or("dev")),
+        }
+    }
+}
+
+/// Query: dry-run a `resolve_shot` and report the first check that would fail.
+pub struct CheckResolutionQuery;
+
+impl CheckResolutionQuery {
+    pub fn execute(
+        env: &Env,
+        session_id: u32,
+        defender: &Address,
+        is_hit: bool,
+        sunk_ship: u32,
+        proof_payload: &Bytes,
+        public_inputs_hash: &BytesN<32>,
+    ) -> ResolutionCheck {
+        let mut check = ResolutionCheck {
+            error_code: 0,
+            shooter: None,
+            x: 0,
+            y: 0,
+            board_commitment: None,
+            expected_hash: None,
+            hash_matches: false,
+            proof_valid: false,
+            verifier_error_code: 0,
+        };
+        let board_commitment = match Self::check_claim(
+            env,
+            session_id,
+            defender,
+            is_hit,
+            sunk_ship,
+            public_inputs_hash,
+            &mut check,
+        ) {
+            Ok(board_commitment) => board_commitment,
+            Err(err) => {
+                check.error_code = err as u32;
+                return check;
+            }
+        };
+
+        let verifier =
+            load_address(env, &DataKey::VerifierAddress).expect("Verifier address not set");
+        (check.proof_valid, check.verifier_error_code) = VerifierGateway::diagnose(
+            env,
+            &verifier,
+            &board_commitment,
+            public_inputs_hash,
+            proof_payload,
+        );
+        if !check.proof_valid {
+            check.error_code = Error::InvalidProof as u32;
+        }
+        check
+    }
+
+    /// Game checks and hash comparison, filling `check` as far as they get.
+    fn check_claim(
+        env: &Env,
+        session_id: u32,
+        defender: &Address,
+        is_hit: bool,
+        sunk_ship: u32,
+        public_inputs_hash: &BytesN<32>,
+        check: &mut ResolutionCheck,
+    ) -> Result<BytesN<32>, Error> {
+        let game = load_game(env, session_id)?;
+        let (shooter, x, y) = game.pending_shot()?;
+        check.shooter = Some(shooter);
+        check.x = x;
+        check.y = y;
+
+        let resolution = game.validate_resolution(defender, is_hit, sunk_ship, None)?;
+        check.board_commitment = Some(resolution.board_commitment.clone());
+
+        let expected_hash = ResolveShotCommand::build_public_inputs_hash(
+            env,
+            session_id,
+            &ShotHashArgs {
+                defender: resolution.defender,
+                shooter: resolution.shooter,
+                x,
+                y,
+                is_hit,
+                sunk_ship,
+                board_commitment: resolution.board_commitment.clone(),
+                sunk_placement: None,
+            },
+        );
+        check.hash_matches = expected_hash == *public_inputs_hash;
+        check.expected_hash = Some(expected_hash);
+        if !check.hash_matches {
+            return Err(Error::InvalidPublicInputsHash);
+        }
+        Ok(resolution.board_commitment)
+    }
+}
@@ -0,0 +1,259 @@
+use soroban_sdk::{contracttype, Bytes, Vec};
+
+use super::errors::{BoardError, Error};
+
+pub const BOARD_SIZE: u32 = 10;
+pub const TOTAL_SHIP_CELLS: u32 = 17;
+pub const SHIP_CARRIER_LEN: u32 = 5;
+pub const SHIP_BATTLESHIP_LEN: u32 = 4;
+pub const SHIP_CRUISER_LEN: u32 = 3;
+pub const SHIP_SUBMARINE_LEN: u32 = 3;
+pub const SHIP_DESTROYER_LEN: u32 = 2;
+
+// Ship lengths in id order (1..5 = Carrier..Destroyer).
+const SHIP_LENS: [u32; 5] = [
+    SHIP_CARRIER_LEN,
+    SHIP_BATTLESHIP_LEN,
+    SHIP_CRUISER_LEN,
+    SHIP_SUBMARINE_LEN,
+    SHIP_DESTROYER_LEN,
+];
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ShipType {
+    Carrier,
+    Battleship,
+    Cruiser,
+    Submarine,
+    Destroyer,
+}
+
+impl ShipType {
+    /// Ship named by a resolution's `sunk_ship`: 0 = none, 1..5 = Carrier..Destroyer.
+    pub fn from_id(id: u32) -> Result<Option<Self>, Error> {
+        match id {
+            0 => Ok(None),
+            1 => Ok(Some(ShipType::Carrier)),
+            2 => Ok(Some(ShipType::Battleship)),
+            3 => Ok(Some(ShipType::Cruiser)),
+            4 => Ok(Some(ShipType::Submarine)),
+            5 => Ok(Some(ShipType::Destroyer)),
+            _ => Err(Error::InvalidShipType),
+        }
+    }
+
+    pub fn length(&self) -> u32 {
+        match self {
+            ShipType::Carrier => SHIP_CARRIER_LEN,
+            ShipType::Battleship => SHIP_BATTLESHIP_LEN,
+            ShipType::Cruiser => SHIP_CRUISER_LEN,
+            ShipType::Submarine => SHIP_SUBMARINE_LEN,
+            ShipType::Destroyer => SHIP_DESTROYER_LEN,
+        }
+    }
+
+    /// Bit of this ship in the `sunk_ships_on_*` masks.
+    pub fn bit(&self) -> u32 {
+        match self {
+            ShipType::Carrier => 1 << 0,
+            ShipType::Battleship => 1 << 1,
+            ShipType::Cruiser => 1 << 2,
+            ShipType::Submarine => 1 << 3,
+            ShipType::Destroyer => 1 << 4,
+        }
+    }
+}
+
+// Start cell and direction (1 = horizontal) of a single ship.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShipPlacement {
+    pub x: u32,
+    pub y: u32,
+    pub dir: u32,
+}
+
+// Ship placement disclosed after the game, ships in Carrier..Destroyer order (dir 1 = horizontal).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BoardLayout {
+    pub ship_x: Vec<u32>,
+    pub ship_y: Vec<u32>,
+    pub ship_dir: Vec<u32>,
+}
+
+/// Bit of cell (x, y) in the 100-cell bitmaps; index = y * 10 + x.
+pub fn coord_to_bit(x: u32, y: u32) -> Result<u128, Error> {
+    if x >= BOARD_SIZE || y >= BOARD_SIZE {
+        return Err(Error::InvalidCoordinate);
+    }
+
+    let index = y * BOARD_SIZE + x;
+    Ok(1u128 << index)
+}
+
+/// Cell bitmap covered by `ship` at `placement`; fails if it leaves the board.
+pub fn placement_cells(ship: &ShipType, placement: &ShipPlacement) -> Result<u128, Error> {
+    if placement.dir > 1 {
+        return Err(Error::InvalidSunkPlacement);
+    }
+    let mut cells = 0u128;
+    for k in 0..ship.length() {
+        let (x, y) = if placement.dir == 1 {
+            (placement.x + k, placement.y)
+        } else {
+            (placement.x, placement.y + k)
+        };
+        cells |= coord_to_bit(x, y).map_err(|_| Error::InvalidSunkPlacement)?;
+    }
+    Ok(cells)
+}
+
+/// Expand a layout into 100 cells (0 = water, 1..5 = ship id), rejecting layouts that leave the
+/// board or overlap.
+pub fn layout_cells(layout: &BoardLayout) -> Result<[u32; 100], Error> {
+    if layout.ship_x.len() != 5 || layout.ship_y.len() != 5 || layout.ship_dir.len() != 5 {
+        return Err(Error::InvalidLayout);
+    }
+
+    let mut cells = [0u32; 100];
+    for (i, len) in SHIP_LENS.iter().enumerate() {
+        let x = layout.ship_x.get_unchecked(i as u32);
+        let y = layout.ship_y.get_unchecked(i as u32);
+        let dir = layout.ship_dir.get_unchecked(i as u32);
+        if dir > 1 {
+            return Err(Error::InvalidLayout);
+        }
+        for k in 0..*len {
+            let (cx, cy) = if dir == 1 { (x + k, y) } else { (x, y + k) };
+            if cx >= BOARD_SIZE || cy >= BOARD_SIZE {
+                return Err(Error::InvalidLayout);
+            }
+            let idx = (cy * BOARD_SIZE + cx) as usize;
+            if cells[idx] != 0 {
+                return Err(Error::InvalidLayout);
+            }
+            cells[idx] = i as u32 + 1;
+        }
+    }
+    Ok(cells)
+}
+
+/// Whether a board agrees with the opponent's record against it: of the cells in `shots`, exactly
+/// those in `hits` hold a ship.
+pub fn matches_shots(cells: &[u32; 100], shots: u128, hits: u128) -> bool {
+    let mut occupied = 0u128;
+    for (idx, ship) in cells.iter().enumerate() {
+        if *ship != 0 {
+            occupied |= 1u128 << idx;
+        }
+    }
+    shots & occupied == hits
+}
+
+/// Result of a shot at (x, y) on a clear board, given the shooter's earlier hits on it: whether
+/// it hits, and the ship id it sinks (0 if none). A hit sinks its ship once every other cell of
+/// the ship has already been hit.
+pub fn shot_outcome(cells: &[u32; 100], x: u32, y: u32, hits: u128) -> Result<(bool, u32), Error> {
+    let shot = coord_to_bit(x, y)?;
+    let ship_id = cells[(y * BOARD_SIZE + x) as usize];
+    let hits = hits | shot;
+    let sunk = ship_id != 0
+        && cells
+            .iter()
+            .enumerate()
+            .all(|(idx, id)| *id != ship_id || hits & (1u128 << idx) != 0);
+    Ok((ship_id != 0, if sunk { ship_id } else { 0 }))
+}
+
+/// Check a 100-cell board against the placement circuit's rules; see `validate_board`.
+pub fn validate_board_cells(cells: &Bytes) -> Result<(), BoardError> {
+    if cells.len() != BOARD_SIZE * BOARD_SIZE {
+        return Err(BoardError::InvalidBoardSize);
+    }
+    if cells.iter().any(|cell| cell > 5) {
+        return Err(BoardError::InvalidCell);
+    }
+
+    for (i, len) in SHIP_LENS.iter().enumerate() {
+        let id = i as u8 + 1;
+        let count = cells.iter().filter(|cell| *cell == id).count() as u32;
+        if count != *len {
+            return Err(BoardError::InvalidShipLength);
+        }
+
+        // With the count right, the ship is valid if its cells run from the first one.
+        let first = cells.iter().position(|cell| cell == id).unwrap_or(0) as u32;
+        let (x, y) = (first % BOARD_SIZE, first / BOARD_SIZE);
+        let run = |step: u32| (0..*len).all(|k| cells.get(first + k * step) == Some(id));
+        let horizontal = x + len <= BOARD_SIZE && run(1);
+        let vertical = y + len <= BOARD_SIZE && run(BOARD_SIZE);
+        if !horizontal && !vertical {
+            return Err(BoardError::ShipNotInLine);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{vec, Env};
+
+    fn standard_cells(env: &Env) -> [u32; 100] {
+        // Ships stacked horizontally in rows 0..4, all starting at x = 0.
+        layout_cells(&BoardLayout {
+            ship_x: vec![env, 0, 0, 0, 0, 0],
+            ship_y: vec![env, 0, 1, 2, 3, 4],
+            ship_dir: vec![env, 1, 1, 1, 1, 1],
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_layout_cells_rejects_overlap_and_overflow() {
+        let env = Env::default();
+        let overlapping = BoardLayout {
+            ship_x: vec![&env, 0, 0, 0, 0, 0],
+            ship_y: vec![&env, 0, 0, 2, 3, 4],
+            ship_dir: vec![&env, 1, 1, 1, 1, 1],
+        };
+        assert_eq!(layout_cells(&overlapping), Err(Error::InvalidLayout));
+
+        let off_board = BoardLayout {
+            ship_x: vec![&env, 6, 0, 0, 0, 0],
+            ship_y: vec![&env, 0, 1, 2, 3, 4],
+            ship_dir: vec![&env, 1, 1, 1, 1, 1],
+        };
+        assert_eq!(layout_cells(&off_board), Err(Error::InvalidLayout));
+    }
+
+    #[test]
+    fn test_shot_outcome_sinks_on_last_cell() {
+        let env = Env::default();
+        let cells = standard_cells(&env);
+
+        // Destroyer occupies (0, 4) and (1, 4).
+        assert_eq!(shot_outcome(&cells, 0, 4, 0), Ok((true, 0)));
+        let first_hit = coord_to_bit(0, 4).unwrap();
+        assert_eq!(shot_outcome(&cells, 1, 4, first_hit), Ok((true, 5)));
+        assert_eq!(shot_outcome(&cells, 9, 9, first_hit), Ok((false, 0)));
+        assert_eq!(
+            shot_outcome(&cells, 10, 0, 0),
+            Err(Error::InvalidCoordinate)
+        );
+    }
+
+    #[test]
+    fn test_matches_shots_checks_hits_and_misses() {
+        let env = Env::default();
+        let cells = standard_cells(&env);
+        let hit = coord_to_bit(0, 0).unwrap();
+        let miss = coord_to_bit(9, 9).unwrap();
+
+        assert!(matches_shots(&cells, hit | miss, hit));
+        assert!(!matches_shots(&cells, hit | miss, miss));
+        assert!(!matches_shots(&cells, hit, 0));
+    }
+}
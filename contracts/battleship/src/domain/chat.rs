@@ -0,0 +1,42 @@
+use soroban_sdk::{contracttype, BytesN, Vec};
+
+use super::errors::Error;
+
+// Ledgers a poster waits between two anchors in a session (~1 minute).
+pub const CHAT_ANCHOR_INTERVAL_LEDGERS: u32 = 12;
+// Anchors one poster can keep per session, bounding the size of their entry.
+pub const MAX_CHAT_ANCHORS: u32 = 64;
+// Anchors all members together can keep per session, so a 2v2 room cannot grow without bound.
+pub const MAX_SESSION_CHAT_ANCHORS: u32 = 96;
+
+// Hash of an off-chain chat message, fixed on-chain so a transcript produced in a dispute can be
+// checked against it without publishing the message itself.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChatAnchor {
+    pub hash: BytesN<32>,
+    pub ledger: u32,
+}
+
+/// Append `hash` to a poster's anchors at `ledger` and return its index, enforcing the rate limit,
+/// the per-poster cap and the per-session cap on `session_anchors` already posted.
+pub fn append_chat_anchor(
+    anchors: &mut Vec<ChatAnchor>,
+    session_anchors: u32,
+    hash: BytesN<32>,
+    ledger: u32,
+) -> Result<u32, Error> {
+    if anchors.len() >= MAX_CHAT_ANCHORS {
+        return Err(Error::ChatAnchorLimitReached);
+    }
+    if session_anchors >= MAX_SESSION_CHAT_ANCHORS {
+        return Err(Error::SessionChatLimitReached);
+    }
+    if let Some(last) = anchors.last() {
+        if ledger < last.ledger.saturating_add(CHAT_ANCHOR_INTERVAL_LEDGERS) {
+            return Err(Error::ChatRateLimited);
+        }
+    }
+    anchors.push_back(ChatAnchor { hash, ledger });
+    Ok(anchors.len() - 1)
+}
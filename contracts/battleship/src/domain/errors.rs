@@ -0,0 +1,75 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    GameNotFound = 1,
+    GameAlreadyExists = 2,
+    NotPlayer = 3,
+    SelfPlayNotAllowed = 4,
+    GameAlreadyEnded = 5,
+    InvalidPhase = 6,
+    BoardAlreadyCommitted = 7,
+    BoardNotCommitted = 8,
+    NotYourTurn = 9,
+    PendingShotExists = 10,
+    NoPendingShot = 11,
+    InvalidCoordinate = 12,
+    ShotAlreadyResolved = 13,
+    InvalidDefender = 14,
+    InvalidShipType = 15,
+    InvalidSunkShip = 16,
+    ShipAlreadySunk = 17,
+    InvalidPublicInputsHash = 18,
+    InvalidProof = 19,
+    TooManyHits = 20,
+    RecommitNotConfigured = 21,
+    CommitmentUnchanged = 22,
+    TooManyActiveGames = 23,
+    FinalProofRequired = 24,
+    NotFinalShot = 25,
+    FinalVerifierNotConfigured = 26,
+    RevealNotConfigured = 27,
+    BoardAlreadyRevealed = 28,
+    BoardNotRevealed = 29,
+    InvalidLayout = 30,
+    SunkShipDisclosureRequired = 31,
+    DisclosureDisabled = 32,
+    InvalidSunkPlacement = 33,
+    InvalidSessionKey = 34,
+    RetractWindowClosed = 35,
+    NoRetractionsLeft = 36,
+    HubNotificationNotPending = 37,
+    NotPracticeGame = 38,
+    PracticeGameUnsupported = 39,
+    StatsNotArchived = 40,
+    InvalidStake = 41,
+    ShotNotRevealed = 42,
+    InvalidShotReveal = 43,
+    SeedAlreadyCommitted = 44,
+    SeedNotCommitted = 45,
+    InvalidSeedReveal = 46,
+    RandomStreamUnavailable = 47,
+    ChatRateLimited = 48,
+    ChatAnchorLimitReached = 49,
+    ReservedSessionId = 50,
+    SessionChatLimitReached = 51,
+    SeedRevealWindowOpen = 52,
+    NoSeedWithheld = 53,
+}
+
+// Returned by `validate_board`, which checks a board on its own rather than a game action.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum BoardError {
+    // The board is not 100 cells.
+    InvalidBoardSize = 1,
+    // A cell is not 0 (water) or 1..5 (Carrier..Destroyer).
+    InvalidCell = 2,
+    // A ship is missing or covers a number of cells other than its length.
+    InvalidShipLength = 3,
+    // A ship's cells do not form one horizontal or vertical run.
+    ShipNotInLine = 4,
+}
\ No newline at end of file
@@ -0,0 +1,864 @@
+use core::cmp::Ordering;
+
+use soroban_sdk::{contracttype, vec, Address, Bytes, BytesN, Env, Vec};
+
+use super::board::{
+    coord_to_bit, layout_cells, placement_cells, shot_outcome, BoardLayout, ShipPlacement,
+    ShipType, TOTAL_SHIP_CELLS,
+};
+use super::errors::Error;
+use super::shot::Resolution;
+
+// Ledgers after `fire` during which the shooter may still retract the shot (~15 s).
+pub const SHOT_RETRACT_WINDOW_LEDGERS: u32 = 3;
+// Shots each player may retract per game.
+pub const MAX_SHOT_RETRACTIONS: u32 = 2;
+// Ledgers after the second seed commitment within which both seeds must be revealed (~1 hour).
+pub const SEED_REVEAL_WINDOW_LEDGERS: u32 = 720;
+// A hit earns its shooter a bonus shot when the first byte of the next random draw is below this
+// (1 in 4).
+pub const BONUS_SHOT_THRESHOLD: u8 = 64;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GamePhase {
+    WaitingForBoards,
+    InProgress,
+    Ended,
+}
+
+// Lifecycle status reported through `GameInterface`; every game uses the same variants.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GameStatus {
+    Setup,
+    InProgress,
+    Ended,
+    Cancelled,
+}
+
+// One side of a 2v2 game. The lead stakes the points and stands for the team at the Game Hub; the
+// partner alternates firing with them and defends the same board.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Team {
+    pub lead: Address,
+    pub partner: Address,
+}
+
+// Players in the Game Hub's player1 and player2 seats, fixed at start. `end_game` reports whether
+// the winner is `player1`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HubSeats {
+    pub player1: Address,
+    pub player2: Address,
+}
+
+// Commit-reveal exchange of both sides' RNG seeds and the random stream derived from them, for
+// modes with random events. Left empty when the players skip the exchange.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SeedExchange {
+    pub commitment_p1: Option<BytesN<32>>,
+    pub commitment_p2: Option<BytesN<32>>,
+    pub seed_p1: Option<BytesN<32>>,
+    pub seed_p2: Option<BytesN<32>>,
+    // Head of the hash chain: keccak256(seed_p1 || seed_p2) once both seeds are revealed, then the
+    // keccak256 of the previous head after every draw.
+    pub stream: Option<BytesN<32>>,
+    // Values drawn from the stream so far.
+    pub draws: u32,
+    // Last ledger to reveal a seed, set by the second commitment; past it, a side still
+    // withholding its seed while the other revealed forfeits.
+    pub reveal_deadline: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Game {
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+    // 2v2 partners of player1 and player2 (`start_team_game`); None in 1v1 games.
+    pub player1_partner: Option<Address>,
+    pub player2_partner: Option<Address>,
+    pub phase: GamePhase,
+    pub turn: Option<Address>,
+    pub board_commitment_p1: Option<BytesN<32>>,
+    pub board_commitment_p2: Option<BytesN<32>>,
+    pub pending_shot_shooter: Option<Address>,
+    pub pending_shot_x: u32,
+    pub pending_shot_y: u32,
+    // Hash of a `commit_shot` cell until `reveal_shot` opens it; the pending cell is hidden and
+    // cannot be resolved while this is set.
+    pub pending_shot_commitment: Option<BytesN<32>>,
+    // Ledger the pending shot was fired at, for the `cancel_shot` window.
+    pub pending_shot_ledger: u32,
+    // Shots each player has retracted with `cancel_shot`.
+    pub retractions_p1: u32,
+    pub retractions_p2: u32,
+    // Bitmaps over 100 cells. Index = y * 10 + x.
+    pub shots_p1_to_p2: u128,
+    pub shots_p2_to_p1: u128,
+    // Per-cell hit bitmaps so any client can reconstruct hit/miss for every shot.
+    pub hits_p1_to_p2: u128,
+    pub hits_p2_to_p1: u128,
+    pub hits_on_p1: u32,
+    pub hits_on_p2: u32,
+    // Bit mask for sunk ships for each player board.
+    pub sunk_ships_on_p1: u32,
+    pub sunk_ships_on_p2: u32,
+    pub winner: Option<Address>,
+    // Last resolved shot so shooter can learn hit/miss from get_game().
+    pub last_resolved_shooter: Option<Address>,
+    pub last_resolved_x: u32,
+    pub last_resolved_y: u32,
+    pub last_resolved_is_hit: bool,
+    pub last_resolved_sunk_ship: u32,
+    // Rules flag snapshotted at start: sinking shots must go through `resolve_sinking_shot`.
+    pub disclose_sunk_ships: bool,
+    // Set when the hub's `end_game` failed on the winning shot; cleared by `retry_hub_notify`.
+    pub pending_hub_notification: bool,
+    // Side leads as seated at the Game Hub when the session was opened.
+    pub hub_seats: HubSeats,
+    // Open-board game from `start_practice_game`: no hub, no proofs, shots resolved on-chain.
+    pub practice: bool,
+    // Length cap snapshotted at start: once both sides have resolved this many shots in total the
+    // game is adjudicated on hits. 0 = no cap.
+    pub max_shots: u32,
+    // Set when the length cap ended the game.
+    pub adjudicated: bool,
+    // Actions applied so far (board commits, shots, retractions, resolutions, cancels). Each one
+    // publishes a `GameMove` event carrying the new value, so a gap means a missed event.
+    pub move_count: u32,
+    // Optional RNG seed exchange (`commit_seed` / `reveal_seed`).
+    pub seeds: SeedExchange,
+}
+
+impl Game {
+    /// A game waiting for both boards. `partners` are the 2v2 partners of player1 and player2.
+    pub fn new(
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+        partners: Option<(Address, Address)>,
+        practice: bool,
+        disclose_sunk_ships: bool,
+    ) -> Self {
+        let (player1_partner, player2_partner) = match partners {
+            Some((partner1, partner2)) => (Some(partner1), Some(partner2)),
+            None => (None, None),
+        };
+        Self {
+            hub_seats: HubSeats {
+                player1: player1.clone(),
+                player2: player2.clone(),
+            },
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+            player1_partner,
+            player2_partner,
+            phase: GamePhase::WaitingForBoards,
+            turn: None,
+            board_commitment_p1: None,
+            board_commitment_p2: None,
+            pending_shot_shooter: None,
+            pending_shot_x: 0,
+            pending_shot_y: 0,
+            pending_shot_commitment: None,
+            pending_shot_ledger: 0,
+            retractions_p1: 0,
+            retractions_p2: 0,
+            shots_p1_to_p2: 0,
+            shots_p2_to_p1: 0,
+            hits_p1_to_p2: 0,
+            hits_p2_to_p1: 0,
+            hits_on_p1: 0,
+            hits_on_p2: 0,
+            sunk_ships_on_p1: 0,
+            sunk_ships_on_p2: 0,
+            winner: None,
+            last_resolved_shooter: None,
+            last_resolved_x: 0,
+            last_resolved_y: 0,
+            last_resolved_is_hit: false,
+            last_resolved_sunk_ship: 0,
+            // Practice boards are public, so there is nothing to disclose.
+            disclose_sunk_ships: !practice && disclose_sunk_ships,
+            pending_hub_notification: false,
+            practice,
+            max_shots: 0,
+            adjudicated: false,
+            move_count: 0,
+            seeds: SeedExchange::default(),
+        }
+    }
+
+    /// Status as reported through `GameInterface`.
+    pub fn status(&self) -> GameStatus {
+        match self.phase {
+            GamePhase::WaitingForBoards => GameStatus::Setup,
+            GamePhase::InProgress => GameStatus::InProgress,
+            GamePhase::Ended if self.winner.is_some() => GameStatus::Ended,
+            GamePhase::Ended => GameStatus::Cancelled,
+        }
+    }
+
+    /// Commit `player`'s side board and return that side's lead. Practice games take the clear
+    /// `layout` (validated here) instead of a commitment, and only they do.
+    pub fn commit_board(
+        &mut self,
+        player: &Address,
+        board_commitment: BytesN<32>,
+        layout: Option<&BoardLayout>,
+    ) -> Result<Address, Error> {
+        self.ensure_not_ended()?;
+        self.ensure_phase(GamePhase::WaitingForBoards)?;
+
+        match (layout, self.practice) {
+            (Some(layout), true) => {
+                layout_cells(layout)?;
+            }
+            (None, false) => {}
+            (Some(_), false) => return Err(Error::NotPracticeGame),
+            (None, true) => return Err(Error::PracticeGameUnsupported),
+        }
+
+        // Either member of a 2v2 team commits the side's shared board.
+        let side = self.side_lead(player)?;
+        let commitment = if side == self.player1 {
+            &mut self.board_commitment_p1
+        } else {
+            &mut self.board_commitment_p2
+        };
+        if commitment.is_some() {
+            return Err(Error::BoardAlreadyCommitted);
+        }
+        *commitment = Some(board_commitment);
+
+        if self.board_commitment_p1.is_some() && self.board_commitment_p2.is_some() {
+            self.phase = GamePhase::InProgress;
+            // Deterministic first turn.
+            self.turn = Some(self.player1.clone());
+        }
+        Ok(side)
+    }
+
+    /// Commit `player`'s side to an RNG seed at `ledger`, before the boards are in. Both sides
+    /// must commit before either reveals, so neither can pick a seed knowing the other; the second
+    /// commitment opens the reveal window.
+    pub fn commit_seed(
+        &mut self,
+        player: &Address,
+        commitment: BytesN<32>,
+        ledger: u32,
+    ) -> Result<(), Error> {
+        self.ensure_not_ended()?;
+        self.ensure_phase(GamePhase::WaitingForBoards)?;
+
+        let slot = if self.side_lead(player)? == self.player1 {
+            &mut self.seeds.commitment_p1
+        } else {
+            &mut self.seeds.commitment_p2
+        };
+        if slot.is_some() {
+            return Err(Error::SeedAlreadyCommitted);
+        }
+        *slot = Some(commitment);
+        if self.seeds.commitment_p1.is_some() && self.seeds.commitment_p2.is_some() {
+            self.seeds.reveal_deadline = ledger.saturating_add(SEED_REVEAL_WINDOW_LEDGERS);
+        }
+        Ok(())
+    }
+
+    /// Reveal `player`'s side seed. `commitment` is the seed hash rebuilt from `seed`; it must
+    /// match the committed one. The second reveal starts the random stream. Reveals close once the
+    /// boards are in, so the stream is fixed before the first shot and the second revealer cannot
+    /// hold their seed back until the upcoming draws suit them.
+    pub fn reveal_seed(
+        &mut self,
+        env: &Env,
+        player: &Address,
+        seed: BytesN<32>,
+        commitment: &BytesN<32>,
+    ) -> Result<(), Error> {
+        self.ensure_not_ended()?;
+        self.ensure_phase(GamePhase::WaitingForBoards)?;
+
+        if self.seeds.commitment_p1.is_none() || self.seeds.commitment_p2.is_none() {
+            return Err(Error::SeedNotCommitted);
+        }
+        let (committed, revealed) = if self.side_lead(player)? == self.player1 {
+            (&self.seeds.commitment_p1, &mut self.seeds.seed_p1)
+        } else {
+            (&self.seeds.commitment_p2, &mut self.seeds.seed_p2)
+        };
+        if revealed.is_some() || committed.as_ref() != Some(commitment) {
+            return Err(Error::InvalidSeedReveal);
+        }
+        *revealed = Some(seed);
+
+        if let (Some(seed_p1), Some(seed_p2)) = (&self.seeds.seed_p1, &self.seeds.seed_p2) {
+            let mut seeds = Bytes::from(seed_p1);
+            seeds.append(&Bytes::from(seed_p2));
+            self.seeds.stream = Some(env.crypto().keccak256(&seeds).into());
+        }
+        Ok(())
+    }
+
+    /// Next value of the game's random stream, for random-event logic. Draws are deterministic:
+    /// anyone can replay them from the revealed seeds.
+    pub fn draw_random(&mut self, env: &Env) -> Result<BytesN<32>, Error> {
+        let value = self
+            .seeds
+            .stream
+            .clone()
+            .ok_or(Error::RandomStreamUnavailable)?;
+        self.seeds.stream = Some(env.crypto().keccak256(&Bytes::from(&value)).into());
+        self.seeds.draws += 1;
+        Ok(value)
+    }
+
+    /// End the game at `ledger` against the side that withheld its seed past
+    /// `seeds.reveal_deadline` after the other revealed, so nobody can back out of a random
+    /// stream they dislike. Returns the winner, the revealing side's lead.
+    pub fn forfeit_withheld_seed(&mut self, ledger: u32) -> Result<Address, Error> {
+        self.ensure_not_ended()?;
+
+        if self.seeds.commitment_p1.is_none() || self.seeds.commitment_p2.is_none() {
+            return Err(Error::SeedNotCommitted);
+        }
+        if ledger <= self.seeds.reveal_deadline {
+            return Err(Error::SeedRevealWindowOpen);
+        }
+        let winner = match (&self.seeds.seed_p1, &self.seeds.seed_p2) {
+            (Some(_), None) => self.player1.clone(),
+            (None, Some(_)) => self.player2.clone(),
+            _ => return Err(Error::NoSeedWithheld),
+        };
+
+        self.phase = GamePhase::Ended;
+        self.winner = Some(winner.clone());
+        self.turn = None;
+        self.pending_shot_shooter = None;
+        self.pending_shot_commitment = None;
+        Ok(winner)
+    }
+
+    /// Whether a hit earns its shooter another shot, decided by the next random draw; never
+    /// without a random stream.
+    fn draw_bonus_shot(&mut self, env: &Env) -> bool {
+        match self.draw_random(env) {
+            Ok(value) => value.to_array()[0] < BONUS_SHOT_THRESHOLD,
+            Err(_) => false,
+        }
+    }
+
+    /// Fire at (x, y) at `ledger`; the shot stays pending until the defender resolves it.
+    pub fn fire(&mut self, shooter: &Address, x: u32, y: u32, ledger: u32) -> Result<(), Error> {
+        self.ensure_can_fire(shooter)?;
+        self.ensure_new_cell(shooter, x, y)?;
+
+        self.take_shot(shooter, ledger);
+        self.pending_shot_x = x;
+        self.pending_shot_y = y;
+        Ok(())
+    }
+
+    /// Fire a hidden shot at `ledger`: only the hash of its cell is recorded until `reveal_shot`.
+    pub fn commit_shot(
+        &mut self,
+        shooter: &Address,
+        commitment: BytesN<32>,
+        ledger: u32,
+    ) -> Result<(), Error> {
+        self.ensure_can_fire(shooter)?;
+
+        self.take_shot(shooter, ledger);
+        self.pending_shot_commitment = Some(commitment);
+        Ok(())
+    }
+
+    /// Open the shooter's committed shot at (x, y). `commitment` is the shot hash rebuilt from the
+    /// revealed cell and salt; it must match the one recorded by `commit_shot`.
+    pub fn reveal_shot(
+        &mut self,
+        shooter: &Address,
+        x: u32,
+        y: u32,
+        commitment: &BytesN<32>,
+    ) -> Result<(), Error> {
+        self.ensure_not_ended()?;
+
+        if self.pending_shot_shooter.as_ref() != Some(shooter) {
+            return Err(Error::NoPendingShot);
+        }
+        if self.pending_shot_commitment.as_ref() != Some(commitment) {
+            return Err(Error::InvalidShotReveal);
+        }
+        self.ensure_new_cell(shooter, x, y)?;
+
+        self.pending_shot_commitment = None;
+        self.pending_shot_x = x;
+        self.pending_shot_y = y;
+        Ok(())
+    }
+
+    /// Withdraw the shooter's unresolved shot at `ledger`, within `SHOT_RETRACT_WINDOW_LEDGERS`
+    /// of firing and at most `MAX_SHOT_RETRACTIONS` times per side.
+    pub fn cancel_shot(&mut self, shooter: &Address, ledger: u32) -> Result<(), Error> {
+        self.ensure_not_ended()?;
+
+        if self.pending_shot_shooter.as_ref() != Some(shooter) {
+            return Err(Error::NoPendingShot);
+        }
+
+        let closes_at = self
+            .pending_shot_ledger
+            .saturating_add(SHOT_RETRACT_WINDOW_LEDGERS);
+        if ledger > closes_at {
+            return Err(Error::RetractWindowClosed);
+        }
+
+        let retractions = if self.side_lead(shooter)? == self.player1 {
+            &mut self.retractions_p1
+        } else {
+            &mut self.retractions_p2
+        };
+        if *retractions >= MAX_SHOT_RETRACTIONS {
+            return Err(Error::NoRetractionsLeft);
+        }
+        *retractions += 1;
+
+        // The turn stays with the shooter so they can fire again.
+        self.pending_shot_shooter = None;
+        self.pending_shot_x = 0;
+        self.pending_shot_y = 0;
+        self.pending_shot_commitment = None;
+        self.pending_shot_ledger = 0;
+        Ok(())
+    }
+
+    /// Shooter and cell of the shot awaiting resolution.
+    pub fn pending_shot(&self) -> Result<(Address, u32, u32), Error> {
+        self.ensure_not_ended()?;
+        self.ensure_phase(GamePhase::InProgress)?;
+
+        let shooter = self
+            .pending_shot_shooter
+            .clone()
+            .ok_or(Error::NoPendingShot)?;
+        if self.pending_shot_commitment.is_some() {
+            return Err(Error::ShotNotRevealed);
+        }
+        Ok((shooter, self.pending_shot_x, self.pending_shot_y))
+    }
+
+    /// Check `defender`'s claimed result for the pending shot against the game, before any proof
+    /// is verified. A sinking shot carries the sunk ship's placement exactly when the game
+    /// discloses sunk ships.
+    pub fn validate_resolution(
+        &self,
+        defender: &Address,
+        is_hit: bool,
+        sunk_ship: u32,
+        sunk_placement: Option<ShipPlacement>,
+    ) -> Result<Resolution, Error> {
+        let (shooter, x, y) = self.pending_shot()?;
+
+        if *defender != self.opponent(&shooter)? {
+            return Err(Error::InvalidDefender);
+        }
+        // In 2v2 games the shooter may be a partner; sides are keyed by their lead.
+        let shooter_is_player1 = *defender == self.player2;
+
+        let ship = ShipType::from_id(sunk_ship)?;
+        if ship.is_some() && !is_hit {
+            return Err(Error::InvalidSunkShip);
+        }
+        match (&ship, &sunk_placement) {
+            (Some(_), None) if self.disclose_sunk_ships => {
+                return Err(Error::SunkShipDisclosureRequired)
+            }
+            (None, Some(_)) => return Err(Error::InvalidSunkShip),
+            (Some(_), Some(_)) if !self.disclose_sunk_ships => {
+                return Err(Error::DisclosureDisabled)
+            }
+            _ => {}
+        }
+
+        let (shots, _) = self.side_bitmaps(shooter_is_player1);
+        if shots & coord_to_bit(x, y)? != 0 {
+            return Err(Error::ShotAlreadyResolved);
+        }
+
+        let board_commitment = if *defender == self.player1 {
+            self.board_commitment_p1.clone()
+        } else {
+            self.board_commitment_p2.clone()
+        }
+        .ok_or(Error::BoardNotCommitted)?;
+
+        Ok(Resolution {
+            shooter,
+            defender: defender.clone(),
+            shooter_is_player1,
+            x,
+            y,
+            is_hit,
+            sunk_ship,
+            ship,
+            sunk_placement,
+            board_commitment,
+        })
+    }
+
+    /// Record a verified resolution in the shot, hit and sunk-ship bitmaps. Returns whether it
+    /// was the defender's last ship cell, i.e. the winning shot.
+    pub fn apply_resolution(&mut self, resolution: &Resolution) -> Result<bool, Error> {
+        let bit = coord_to_bit(resolution.x, resolution.y)?;
+        let defender_is_player1 = resolution.defender == self.player1;
+
+        if resolution.shooter_is_player1 {
+            self.shots_p1_to_p2 |= bit;
+        } else {
+            self.shots_p2_to_p1 |= bit;
+        }
+
+        if resolution.is_hit {
+            if resolution.shooter_is_player1 {
+                self.hits_p1_to_p2 |= bit;
+            } else {
+                self.hits_p2_to_p1 |= bit;
+            }
+
+            let hits_on = if defender_is_player1 {
+                &mut self.hits_on_p1
+            } else {
+                &mut self.hits_on_p2
+            };
+            *hits_on += 1;
+            if *hits_on > TOTAL_SHIP_CELLS {
+                return Err(Error::TooManyHits);
+            }
+        }
+
+        if let Some(ship) = &resolution.ship {
+            let sunk_ships = if defender_is_player1 {
+                &mut self.sunk_ships_on_p1
+            } else {
+                &mut self.sunk_ships_on_p2
+            };
+            if *sunk_ships & ship.bit() != 0 {
+                return Err(Error::ShipAlreadySunk);
+            }
+            *sunk_ships |= ship.bit();
+        }
+
+        if let (Some(ship), Some(placement)) = (&resolution.ship, &resolution.sunk_placement) {
+            let (_, hits) = self.side_bitmaps(resolution.shooter_is_player1);
+            let cells = placement_cells(ship, placement)?;
+            if cells & bit == 0 || cells & hits != cells {
+                return Err(Error::InvalidSunkPlacement);
+            }
+        }
+
+        let defender_hits = if defender_is_player1 {
+            self.hits_on_p1
+        } else {
+            self.hits_on_p2
+        };
+        Ok(defender_hits >= TOTAL_SHIP_CELLS)
+    }
+
+    /// Close out an applied resolution: the shooter's side wins on the final shot, the game is
+    /// adjudicated once it reaches `max_shots`, a hit may earn the shooter a bonus shot from the
+    /// random stream, otherwise the turn passes to the defending side. Returns (winner, next turn).
+    pub fn finish_resolution(
+        &mut self,
+        env: &Env,
+        resolution: &Resolution,
+        is_final: bool,
+    ) -> (Option<Address>, Option<Address>) {
+        let mut winner = None;
+        let mut next_turn = None;
+        if is_final {
+            let side = self.opponent_lead(&resolution.defender);
+            self.phase = GamePhase::Ended;
+            self.winner = Some(side.clone());
+            self.turn = None;
+            winner = Some(side);
+        } else if self.max_shots > 0 && self.resolved_moves() >= self.max_shots {
+            winner = Some(self.adjudicate(&resolution.defender));
+        } else if resolution.is_hit && self.draw_bonus_shot(env) {
+            self.turn = Some(resolution.shooter.clone());
+            next_turn = Some(resolution.shooter.clone());
+        } else {
+            let shooter = self.next_shooter(&resolution.defender);
+            self.turn = Some(shooter.clone());
+            next_turn = Some(shooter);
+        }
+
+        // Expose last resolved shot so shooter can learn result from get_game().
+        self.last_resolved_shooter = Some(resolution.shooter.clone());
+        self.last_resolved_x = resolution.x;
+        self.last_resolved_y = resolution.y;
+        self.last_resolved_is_hit = resolution.is_hit;
+        self.last_resolved_sunk_ship = resolution.sunk_ship;
+
+        self.pending_shot_shooter = None;
+        (winner, next_turn)
+    }
+
+    /// End the game on the hit differential: the side that landed more hits wins. Equal hits go
+    /// to `defender`, the side that took the capping shot without getting to answer it, so the
+    /// Game Hub always has a winner to settle the stakes with.
+    fn adjudicate(&mut self, defender: &Address) -> Address {
+        let winner = match self.hits_on_p2.cmp(&self.hits_on_p1) {
+            Ordering::Greater => self.player1.clone(),
+            Ordering::Less => self.player2.clone(),
+            Ordering::Equal => defender.clone(),
+        };
+        self.winner = Some(winner.clone());
+        self.phase = GamePhase::Ended;
+        self.adjudicated = true;
+        self.turn = None;
+        winner
+    }
+
+    /// `player1_won` as reported to the Game Hub for the recorded winner.
+    pub fn hub_player1_won(&self) -> bool {
+        self.winner.as_ref() == Some(&self.hub_seats.player1)
+    }
+
+    /// Defender of a practice game's pending shot, whose clear board resolves it.
+    pub fn practice_defender(&self) -> Result<Address, Error> {
+        if !self.practice {
+            return Err(Error::NotPracticeGame);
+        }
+        self.ensure_not_ended()?;
+
+        let shooter = self
+            .pending_shot_shooter
+            .clone()
+            .ok_or(Error::NoPendingShot)?;
+        if self.pending_shot_commitment.is_some() {
+            return Err(Error::ShotNotRevealed);
+        }
+        self.opponent(&shooter)
+    }
+
+    /// (is_hit, sunk ship id) of the pending shot against `defender`'s clear board `cells`.
+    pub fn practice_outcome(
+        &self,
+        defender: &Address,
+        cells: &[u32; 100],
+    ) -> Result<(bool, u32), Error> {
+        let (_, hits) = self.side_bitmaps(*defender == self.player2);
+        shot_outcome(cells, self.pending_shot_x, self.pending_shot_y, hits)
+    }
+
+    /// Current commitment of `player`'s board, which `new_commitment` is about to replace.
+    pub fn recommit_target(
+        &self,
+        player: &Address,
+        new_commitment: &BytesN<32>,
+    ) -> Result<BytesN<32>, Error> {
+        self.ensure_not_ended()?;
+        if self.practice {
+            return Err(Error::PracticeGameUnsupported);
+        }
+
+        let old_commitment = if *player == self.player1 {
+            self.board_commitment_p1.clone()
+        } else if *player == self.player2 {
+            self.board_commitment_p2.clone()
+        } else {
+            return Err(Error::NotPlayer);
+        }
+        .ok_or(Error::BoardNotCommitted)?;
+
+        if old_commitment == *new_commitment {
+            return Err(Error::CommitmentUnchanged);
+        }
+        Ok(old_commitment)
+    }
+
+    /// Swap in a proven-equivalent commitment for `player`'s board.
+    pub fn replace_commitment(&mut self, player: &Address, new_commitment: BytesN<32>) {
+        if *player == self.player1 {
+            self.board_commitment_p1 = Some(new_commitment);
+        } else {
+            self.board_commitment_p2 = Some(new_commitment);
+        }
+    }
+
+    /// Commitment of `player`'s board with the shots and hits the opponent recorded against it,
+    /// once the game has ended.
+    pub fn reveal_target(&self, player: &Address) -> Result<(BytesN<32>, u128, u128), Error> {
+        if self.phase != GamePhase::Ended {
+            return Err(Error::InvalidPhase);
+        }
+
+        let (commitment, shots, hits) = if *player == self.player1 {
+            (
+                self.board_commitment_p1.clone(),
+                self.shots_p2_to_p1,
+                self.hits_p2_to_p1,
+            )
+        } else if *player == self.player2 {
+            (
+                self.board_commitment_p2.clone(),
+                self.shots_p1_to_p2,
+                self.hits_p1_to_p2,
+            )
+        } else {
+            return Err(Error::NotPlayer);
+        };
+        Ok((commitment.ok_or(Error::BoardNotCommitted)?, shots, hits))
+    }
+
+    /// Fails unless `player` plays in this game and it has not ended.
+    pub fn ensure_active_member(&self, player: &Address) -> Result<(), Error> {
+        self.ensure_not_ended()?;
+        self.side_lead(player)?;
+        Ok(())
+    }
+
+    /// End a running game without a winner.
+    pub fn cancel(&mut self) -> Result<(), Error> {
+        self.ensure_not_ended()?;
+
+        self.phase = GamePhase::Ended;
+        self.turn = None;
+        self.pending_shot_shooter = None;
+        self.pending_shot_commitment = None;
+        Ok(())
+    }
+
+    /// Count one more action on the game and return its sequence number.
+    pub fn next_move(&mut self) -> u32 {
+        self.move_count += 1;
+        self.move_count
+    }
+
+    /// Shots resolved so far by both sides, i.e. the length of the move log.
+    pub fn resolved_moves(&self) -> u32 {
+        self.shots_p1_to_p2.count_ones() + self.shots_p2_to_p1.count_ones()
+    }
+
+    /// Shot and hit bitmaps of one side's fire at the other board.
+    pub fn side_bitmaps(&self, shooter_is_player1: bool) -> (u128, u128) {
+        if shooter_is_player1 {
+            (self.shots_p1_to_p2, self.hits_p1_to_p2)
+        } else {
+            (self.shots_p2_to_p1, self.hits_p2_to_p1)
+        }
+    }
+
+    /// Everyone playing in the game: both players, then any 2v2 partners.
+    pub fn members(&self, env: &Env) -> Vec<Address> {
+        let mut members = vec![env, self.player1.clone(), self.player2.clone()];
+        for partner in [&self.player1_partner, &self.player2_partner]
+            .into_iter()
+            .flatten()
+        {
+            members.push_back(partner.clone());
+        }
+        members
+    }
+
+    /// Lead of the opposing side, for a lead or a 2v2 partner.
+    pub fn opponent(&self, player: &Address) -> Result<Address, Error> {
+        Ok(self.opponent_lead(&self.side_lead(player)?))
+    }
+
+    /// player1 or player2, whichever side `player` plays on.
+    pub fn side_lead(&self, player: &Address) -> Result<Address, Error> {
+        if *player == self.player1 || self.player1_partner.as_ref() == Some(player) {
+            Ok(self.player1.clone())
+        } else if *player == self.player2 || self.player2_partner.as_ref() == Some(player) {
+            Ok(self.player2.clone())
+        } else {
+            Err(Error::NotPlayer)
+        }
+    }
+
+    /// Member of `lead`'s side who fires next: 2v2 partners alternate with their lead, who opens.
+    pub fn next_shooter(&self, lead: &Address) -> Address {
+        let (partner, shots) = if *lead == self.player1 {
+            (&self.player1_partner, self.shots_p1_to_p2)
+        } else {
+            (&self.player2_partner, self.shots_p2_to_p1)
+        };
+        match partner {
+            Some(partner) if shots.count_ones() % 2 == 1 => partner.clone(),
+            _ => lead.clone(),
+        }
+    }
+
+    fn opponent_lead(&self, lead: &Address) -> Address {
+        if *lead == self.player1 {
+            self.player2.clone()
+        } else {
+            self.player1.clone()
+        }
+    }
+
+    fn ensure_can_fire(&self, shooter: &Address) -> Result<(), Error> {
+        self.ensure_not_ended()?;
+        self.ensure_phase(GamePhase::InProgress)?;
+
+        if self.pending_shot_shooter.is_some() {
+            return Err(Error::PendingShotExists);
+        }
+
+        let turn = self.turn.clone().ok_or(Error::InvalidPhase)?;
+        if *shooter != turn {
+            return Err(Error::NotYourTurn);
+        }
+        Ok(())
+    }
+
+    fn ensure_new_cell(&self, shooter: &Address, x: u32, y: u32) -> Result<(), Error> {
+        let bit = coord_to_bit(x, y)?;
+
+        // Duplicate shot check against already resolved shots of the shooter's side.
+        let (shots, _) = self.side_bitmaps(self.side_lead(shooter)? == self.player1);
+        if shots & bit != 0 {
+            return Err(Error::ShotAlreadyResolved);
+        }
+        Ok(())
+    }
+
+    fn take_shot(&mut self, shooter: &Address, ledger: u32) {
+        // Clear last resolved so only the most recent resolve is visible to shooter.
+        self.last_resolved_shooter = None;
+        self.last_resolved_x = 0;
+        self.last_resolved_y = 0;
+        self.last_resolved_is_hit = false;
+        self.last_resolved_sunk_ship = 0;
+
+        self.pending_shot_shooter = Some(shooter.clone());
+        self.pending_shot_ledger = ledger;
+    }
+
+    fn ensure_not_ended(&self) -> Result<(), Error> {
+        if self.phase == GamePhase::Ended {
+            return Err(Error::GameAlreadyEnded);
+        }
+        Ok(())
+    }
+
+    fn ensure_phase(&self, expected: GamePhase) -> Result<(), Error> {
+        if self.phase != expected {
+            return Err(Error::InvalidPhase);
+        }
+        Ok(())
+    }
+}
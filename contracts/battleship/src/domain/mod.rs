@@ -0,0 +1,27 @@
+mod board;
+mod chat;
+mod errors;
+mod game;
+mod session_key;
+mod session_marker;
+mod shot;
+mod stats;
+
+pub use board::{
+    layout_cells, matches_shots, validate_board_cells, BoardLayout, ShipPlacement, ShipType,
+    BOARD_SIZE, SHIP_BATTLESHIP_LEN, SHIP_CARRIER_LEN, SHIP_CRUISER_LEN, SHIP_DESTROYER_LEN,
+    SHIP_SUBMARINE_LEN, TOTAL_SHIP_CELLS,
+};
+pub use chat::{
+    append_chat_anchor, ChatAnchor, CHAT_ANCHOR_INTERVAL_LEDGERS, MAX_CHAT_ANCHORS,
+    MAX_SESSION_CHAT_ANCHORS,
+};
+pub use errors::{BoardError, Error};
+pub use game::{
+    Game, GamePhase, GameStatus, HubSeats, SeedExchange, Team, MAX_SHOT_RETRACTIONS,
+    SEED_REVEAL_WINDOW_LEDGERS, SHOT_RETRACT_WINDOW_LEDGERS,
+};
+pub use session_key::SessionKey;
+pub use session_marker::{SessionMarker, StorageTier};
+pub use shot::{MoveRecord, SunkPlacement};
+pub use stats::{ArchivedStats, Metrics, ShooterStats};
@@ -0,0 +1,25 @@
+use soroban_sdk::{contracttype, Address};
+
+use super::errors::Error;
+
+// Key allowed to sign `fire` for a player until ledger `expiry` (exclusive).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SessionKey {
+    pub key: Address,
+    pub expiry: u32,
+}
+
+impl SessionKey {
+    /// Key for `player`, authorized at `ledger`; it must differ from the player and expire later.
+    pub fn new(player: &Address, key: Address, expiry: u32, ledger: u32) -> Result<Self, Error> {
+        if key == *player || expiry <= ledger {
+            return Err(Error::InvalidSessionKey);
+        }
+        Ok(Self { key, expiry })
+    }
+
+    pub fn is_active(&self, ledger: u32) -> bool {
+        ledger < self.expiry
+    }
+}
@@ -0,0 +1,33 @@
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use super::game::Game;
+
+// Storage tier a game's entries were written to.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StorageTier {
+    // Expires with its TTL unless a write extends it; every game is stored here.
+    Temporary,
+}
+
+// Persistent record of a started session. It outlives the game entries, so the hub can refund
+// stakes stranded by an expired game and auditors can still see who played and when.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SessionMarker {
+    // `Game::members` at start: both players, then any 2v2 partners.
+    pub players: Vec<Address>,
+    pub start_ledger: u32,
+    pub tier: StorageTier,
+}
+
+impl SessionMarker {
+    /// Marker for `game`, started at the current ledger.
+    pub fn new(env: &Env, game: &Game) -> Self {
+        Self {
+            players: game.members(env),
+            start_ledger: env.ledger().sequence(),
+            tier: StorageTier::Temporary,
+        }
+    }
+}
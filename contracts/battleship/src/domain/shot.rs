@@ -0,0 +1,54 @@
+use soroban_sdk::{contracttype, Address, BytesN};
+
+use super::board::{ShipPlacement, ShipType};
+
+// Sunk ship placement as recorded on a resolution.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SunkPlacement {
+    // No ship sunk, or the game does not disclose sunk ships.
+    Undisclosed,
+    Disclosed(ShipPlacement),
+}
+
+// One resolved shot, in resolution order. Bitmaps in `Game` stay the source of truth for
+// validation; the move log only serves history queries and replays.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MoveRecord {
+    pub index: u32,
+    pub shooter_is_player1: bool,
+    pub x: u32,
+    pub y: u32,
+    pub is_hit: bool,
+    pub sunk_ship: u32,
+    pub sunk_placement: SunkPlacement,
+    pub ledger: u32,
+}
+
+// The defender's claimed result for the pending shot, accepted by `Game::validate_resolution`
+// against the game state. Proofs are checked against it before `Game::apply_resolution` runs.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Resolution {
+    pub shooter: Address,
+    // Lead of the defending side; in 2v2 games the shooter may be a partner.
+    pub defender: Address,
+    pub shooter_is_player1: bool,
+    pub x: u32,
+    pub y: u32,
+    pub is_hit: bool,
+    pub sunk_ship: u32,
+    pub ship: Option<ShipType>,
+    pub sunk_placement: Option<ShipPlacement>,
+    pub board_commitment: BytesN<32>,
+}
+
+impl Resolution {
+    /// Placement as written to the move log and returned in `ShotResult`.
+    pub fn recorded_placement(&self) -> SunkPlacement {
+        match &self.sunk_placement {
+            Some(placement) => SunkPlacement::Disclosed(placement.clone()),
+            None => SunkPlacement::Undisclosed,
+        }
+    }
+}
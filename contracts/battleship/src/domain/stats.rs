@@ -0,0 +1,120 @@
+use soroban_sdk::{contracttype, Address, Vec};
+
+use super::game::{Game, GameStatus};
+use super::shot::MoveRecord;
+
+// One player's shooting record over a finished game.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShooterStats {
+    pub shots: u32,
+    pub hits: u32,
+    // hits * 10_000 / shots, 0 when no shot was taken.
+    pub accuracy_bps: u32,
+    // Most consecutive hits among this player's own shots.
+    pub longest_hit_streak: u32,
+}
+
+// Summary written to persistent storage when a game is won, so profile pages can read it after
+// the temporary game state and move log expire.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArchivedStats {
+    pub player1: Address,
+    pub player2: Address,
+    pub winner: Address,
+    pub ended_ledger: u32,
+    pub player1_stats: ShooterStats,
+    pub player2_stats: ShooterStats,
+}
+
+impl ArchivedStats {
+    /// Fold the move log of a game won by `winner` at `ended_ledger` into per-player statistics.
+    pub fn from_moves(
+        game: &Game,
+        winner: &Address,
+        ended_ledger: u32,
+        moves: &Vec<MoveRecord>,
+    ) -> Self {
+        let mut player1_stats = ShooterStats {
+            shots: 0,
+            hits: 0,
+            accuracy_bps: 0,
+            longest_hit_streak: 0,
+        };
+        let mut player2_stats = player1_stats.clone();
+        let (mut streak_p1, mut streak_p2) = (0u32, 0u32);
+        for record in moves.iter() {
+            let (stats, streak) = if record.shooter_is_player1 {
+                (&mut player1_stats, &mut streak_p1)
+            } else {
+                (&mut player2_stats, &mut streak_p2)
+            };
+            stats.shots += 1;
+            if record.is_hit {
+                stats.hits += 1;
+                *streak += 1;
+                stats.longest_hit_streak = stats.longest_hit_streak.max(*streak);
+            } else {
+                *streak = 0;
+            }
+        }
+        for stats in [&mut player1_stats, &mut player2_stats] {
+            stats.accuracy_bps = (stats.hits * 10_000).checked_div(stats.shots).unwrap_or(0);
+        }
+
+        ArchivedStats {
+            player1: game.player1.clone(),
+            player2: game.player2.clone(),
+            winner: winner.clone(),
+            ended_ledger,
+            player1_stats,
+            player2_stats,
+        }
+    }
+}
+
+// Operator counters returned by `get_metrics`. Rejected proofs are not counted: a failing call
+// rolls back every write, so they only show up as `ErrorContext` events of failed calls.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Metrics {
+    pub games_started: u32,
+    pub practice_games_started: u32,
+    // Games ended with a winner.
+    pub games_ended: u32,
+    // Of `games_ended`, those decided by the `max_shots` cap.
+    pub games_adjudicated: u32,
+    // Games the hub aborted through `cancel_game`.
+    pub games_cancelled: u32,
+    // Practice games ended, however they ended; kept apart from `games_ended` like the starts.
+    pub practice_games_ended: u32,
+}
+
+impl Metrics {
+    /// Count a game that has just been started.
+    pub fn record_start(&mut self, game: &Game) {
+        if game.practice {
+            self.practice_games_started += 1;
+        } else {
+            self.games_started += 1;
+        }
+    }
+
+    /// Count a game that has just ended, by how it ended.
+    pub fn record_end(&mut self, game: &Game) {
+        if game.practice {
+            self.practice_games_ended += 1;
+            return;
+        }
+        match game.status() {
+            GameStatus::Cancelled => self.games_cancelled += 1,
+            _ => {
+                self.games_ended += 1;
+                if game.adjudicated {
+                    self.games_adjudicated += 1;
+                }
+            }
+        }
+    }
+}
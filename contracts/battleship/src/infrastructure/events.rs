@@ -0,0 +1,127 @@
+use soroban_sdk::{contractevent, contracttype, symbol_short, Address, BytesN, Env, Symbol, Vec};
+
+use super::storage::save_last_error;
+use crate::domain::{Error, Game};
+
+/// Namespace shared by every error code this contract returns.
+pub const ERROR_NAMESPACE: Symbol = symbol_short!("bship");
+/// Namespace of error codes the Game Hub rejected a call with.
+pub const HUB_ERROR_NAMESPACE: Symbol = symbol_short!("hub");
+
+/// Published by every action that changes a game, numbered by `Game::move_count`. Clients order
+/// updates by `move_count` and refetch `get_game` when they see a gap.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameMove {
+    #[topic]
+    pub session_id: u32,
+    pub move_count: u32,
+    pub action: Symbol,
+    pub actor: Option<Address>,
+}
+
+/// Published by `post_chat_anchor`, so chat clients can show which messages are anchored without
+/// polling `get_chat_anchors`.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChatAnchored {
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub poster: Address,
+    pub index: u32,
+    pub hash: BytesN<32>,
+}
+
+/// Published right before an entrypoint returns a domain error.
+///
+/// Events of a failed invocation are not committed, but they are returned by transaction
+/// simulation and failed-transaction meta, which is where support tooling picks them up.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ErrorContext {
+    #[topic]
+    pub namespace: Symbol,
+    #[topic]
+    pub session_id: u32,
+    pub action: Symbol,
+    pub code: u32,
+    pub caller: Option<Address>,
+    // Numeric summary of the offending call (coordinates, flags, ship ids).
+    pub args: Vec<u32>,
+    // Ledger the call failed at, to match the error with a player's report.
+    pub ledger: u32,
+}
+
+pub fn error_context(
+    env: &Env,
+    session_id: u32,
+    action: Symbol,
+    caller: Option<Address>,
+    args: Vec<u32>,
+    error: Error,
+) -> Error {
+    ErrorContext {
+        namespace: ERROR_NAMESPACE,
+        session_id,
+        action,
+        code: error as u32,
+        caller,
+        args,
+        ledger: env.ledger().sequence(),
+    }
+    .publish(env);
+    error
+}
+
+/// Last failure stored for a session, returned by `get_last_error_detail`.
+///
+/// A failing entrypoint rolls back its storage along with everything else, so only failures the
+/// contract absorbs while the call itself succeeds are stored: the Game Hub rejecting `end_game`
+/// on a winning shot or a seed forfeit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ErrorDetail {
+    // Namespace of `code`: `bship` for this contract's errors, `hub` for the Game Hub's.
+    pub namespace: Symbol,
+    pub action: Symbol,
+    pub code: u32,
+    // Offending address: the caller, or the contract that failed.
+    pub address: Option<Address>,
+    // Numeric summary of the offending call (coordinates, flags, ship ids).
+    pub args: Vec<u32>,
+    pub ledger: u32,
+}
+
+/// Store that the Game Hub rejected `end_game` with `code` while `action` still succeeded.
+pub fn record_hub_failure(env: &Env, session_id: u32, action: Symbol, hub: Address, code: u32) {
+    save_last_error(
+        env,
+        session_id,
+        &ErrorDetail {
+            namespace: HUB_ERROR_NAMESPACE,
+            action,
+            code,
+            address: Some(hub),
+            args: Vec::new(env),
+            ledger: env.ledger().sequence(),
+        },
+    );
+}
+
+/// Number the action just applied to `game` and publish it as a `GameMove`.
+pub fn record_move(
+    env: &Env,
+    session_id: u32,
+    game: &mut Game,
+    action: Symbol,
+    actor: Option<Address>,
+) {
+    GameMove {
+        session_id,
+        move_count: game.next_move(),
+        action,
+        actor,
+    }
+    .publish(env);
+}
@@ -0,0 +1,131 @@
+use soroban_sdk::xdr::ScErrorType;
+use soroban_sdk::{contractclient, Address, Bytes, BytesN, Env};
+
+use super::storage::{load_address, DataKey};
+use crate::domain::{Error, GameStatus};
+
+#[allow(dead_code)] // Trait is used by contractclient macro
+#[contractclient(name = "GameHubClient")]
+pub trait GameHub {
+    fn start_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    );
+
+    fn end_game(env: Env, session_id: u32, player1_won: bool);
+}
+
+/// Adapter verifier interface for Battleship proofs.
+/// A verifier contract can internally call a Groth16 verifier and return `true` only for valid proofs.
+#[allow(dead_code)] // Trait is used by contractclient macro
+#[contractclient(name = "BattleshipVerifierClient")]
+pub trait BattleshipVerifier {
+    fn verify(
+        env: Env,
+        board_commitment: BytesN<32>,
+        public_inputs_hash: BytesN<32>,
+        proof_payload: Bytes,
+    ) -> bool;
+}
+
+/// Lifecycle surface shared by every arcade game, so the hub's tournament module can drive any
+/// registered game through one client instead of hard-coding each game's function names.
+#[contractclient(name = "GameInterfaceClient")]
+pub trait GameInterface {
+    fn start_game(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<u32, Error>;
+
+    fn evaluate_game(env: Env, session_id: u32) -> Result<Option<Address>, Error>;
+
+    fn cancel_game(env: Env, session_id: u32) -> Result<(), Error>;
+
+    fn get_game_status(env: Env, session_id: u32) -> Result<GameStatus, Error>;
+}
+
+/// Calls into the Game Hub configured at construction.
+pub struct GameHubGateway;
+
+impl GameHubGateway {
+    pub fn address(env: &Env) -> Address {
+        load_address(env, &DataKey::GameHubAddress).expect("GameHub address not set")
+    }
+
+    pub fn notify_game_started(
+        env: &Env,
+        session_id: u32,
+        player1: &Address,
+        player2: &Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) {
+        GameHubClient::new(env, &Self::address(env)).start_game(
+            &env.current_contract_address(),
+            &session_id,
+            player1,
+            player2,
+            &player1_points,
+            &player2_points,
+        );
+    }
+
+    pub fn notify_game_ended(env: &Env, session_id: u32, player1_won: bool) {
+        GameHubClient::new(env, &Self::address(env)).end_game(&session_id, &player1_won);
+    }
+
+    /// Like `notify_game_ended`, but a failing hub does not revert the caller. Returns the
+    /// contract error code the hub rejected the result with (0 if it aborted without one).
+    pub fn try_notify_game_ended(env: &Env, session_id: u32, player1_won: bool) -> Result<(), u32> {
+        match GameHubClient::new(env, &Self::address(env)).try_end_game(&session_id, &player1_won) {
+            Ok(_) => Ok(()),
+            Err(Ok(err)) if err.is_type(ScErrorType::Contract) => Err(err.get_code()),
+            Err(_) => Err(0),
+        }
+    }
+}
+
+/// Calls into the proof adapters; every adapter exposes the same `verify` signature.
+pub struct VerifierGateway;
+
+impl VerifierGateway {
+    pub fn verify(
+        env: &Env,
+        verifier: &Address,
+        commitment: &BytesN<32>,
+        public_inputs_hash: &BytesN<32>,
+        proof_payload: &Bytes,
+    ) -> bool {
+        BattleshipVerifierClient::new(env, verifier).verify(
+            commitment,
+            public_inputs_hash,
+            proof_payload,
+        )
+    }
+
+    /// Like `verify`, but never aborts: returns whether the proof verified and the contract error
+    /// code the verifier aborted with (0 if it returned normally).
+    pub fn diagnose(
+        env: &Env,
+        verifier: &Address,
+        commitment: &BytesN<32>,
+        public_inputs_hash: &BytesN<32>,
+        proof_payload: &Bytes,
+    ) -> (bool, u32) {
+        let client = BattleshipVerifierClient::new(env, verifier);
+        match client.try_verify(commitment, public_inputs_hash, proof_payload) {
+            Ok(Ok(valid)) => (valid, 0),
+            Err(Ok(err)) if err.is_type(ScErrorType::Contract) => (false, err.get_code()),
+            _ => (false, 0),
+        }
+    }
+}
@@ -0,0 +1,9 @@
+pub mod events;
+mod external;
+pub mod storage;
+
+pub use events::{
+    error_context, record_hub_failure, record_move, ChatAnchored, ErrorContext, ErrorDetail,
+    GameMove,
+};
+pub use external::{GameHubGateway, GameInterface, GameInterfaceClient, VerifierGateway};
@@ -0,0 +1,523 @@
+use soroban_sdk::{contracttype, Address, BytesN, Env, Vec};
+
+use super::events::ErrorDetail;
+use crate::domain::{
+    ArchivedStats, BoardLayout, ChatAnchor, Error, Game, GamePhase, HubSeats, Metrics, MoveRecord,
+    SeedExchange, SessionKey, SessionMarker, BOARD_SIZE,
+};
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    // A game is stored in two entries (see `load_game`); the setup entry also marks the session
+    // id as taken.
+    Game(u32),
+    GameState(u32),
+    GameHubAddress,
+    VerifierAddress,
+    RecommitVerifierAddress,
+    // Adapter for board_consistency proofs; when set, winning resolutions need one.
+    FinalVerifierAddress,
+    // Adapter for board_reveal proofs used by `reveal_board`.
+    RevealVerifierAddress,
+    // Verifier for `resolve_shot_disclosed`; new games disclose sunk ships while it is set.
+    DisclosureVerifierAddress,
+    // Session ids a player is currently engaged in.
+    PlayerSessions(Address),
+    MaxActiveGames,
+    // Resolved shots after which new games are adjudicated; 0 or unset means no cap.
+    MaxShots,
+    // Stake bounds checked by `start_game`; no max stored means no cap.
+    MinStake,
+    MaxStake,
+    // Move log chunk: (session_id, chunk index).
+    Moves(u32, u32),
+    // Board layout a player disclosed after the game: (session_id, player).
+    Reveal(u32, Address),
+    // Session key a player authorized for `fire`: (session_id, player).
+    SessionKey(u32, Address),
+    // Games started between (player1, player2) with a derived session id.
+    PairNonce(Address, Address),
+    // Per-player statistics of a won game, kept after the game itself expires.
+    ArchivedStats(u32),
+    // Operator counters read by `get_metrics`.
+    Metrics,
+    // Who started a session and when, kept after the game entries expire.
+    SessionMarker(u32),
+    // Chat message hashes a poster anchored in a session: (session_id, poster).
+    ChatAnchors(u32, Address),
+    // Chat anchors posted in a session by all members together.
+    ChatAnchorCount(u32),
+    // Last failure the contract absorbed in a session, read by `get_last_error_detail`.
+    LastError(u32),
+}
+
+pub const GAME_TTL_LEDGERS: u32 = 518_400;
+// Every cell can be shot once by each player.
+pub const MAX_MOVES: u32 = BOARD_SIZE * BOARD_SIZE * 2;
+pub const MOVES_PER_CHUNK: u32 = 25;
+// Smallest stake accepted until the admin sets one.
+pub const DEFAULT_MIN_STAKE: i128 = 1;
+// Interface ids reported by `get_info`.
+pub const INTERFACES: [&str; 3] = ["game-v1", "game-interface-v1", "battleship-v1"];
+
+// Cold half of `Game`: fixed at start except for the board commitments.
+#[contracttype]
+#[derive(Clone, Eq, PartialEq)]
+pub struct GameSetup {
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+    pub player1_partner: Option<Address>,
+    pub player2_partner: Option<Address>,
+    pub board_commitment_p1: Option<BytesN<32>>,
+    pub board_commitment_p2: Option<BytesN<32>>,
+    pub disclose_sunk_ships: bool,
+    pub practice: bool,
+    pub hub_seats: HubSeats,
+    pub max_shots: u32,
+}
+
+// Hot half of `Game`: everything `fire`, `cancel_shot` and resolutions change.
+#[contracttype]
+#[derive(Clone)]
+pub struct GameState {
+    pub phase: GamePhase,
+    pub turn: Option<Address>,
+    pub pending_shot_shooter: Option<Address>,
+    pub pending_shot_x: u32,
+    pub pending_shot_y: u32,
+    pub pending_shot_commitment: Option<BytesN<32>>,
+    pub pending_shot_ledger: u32,
+    pub retractions_p1: u32,
+    pub retractions_p2: u32,
+    pub shots_p1_to_p2: u128,
+    pub shots_p2_to_p1: u128,
+    pub hits_p1_to_p2: u128,
+    pub hits_p2_to_p1: u128,
+    pub hits_on_p1: u32,
+    pub hits_on_p2: u32,
+    pub sunk_ships_on_p1: u32,
+    pub sunk_ships_on_p2: u32,
+    pub winner: Option<Address>,
+    pub last_resolved_shooter: Option<Address>,
+    pub last_resolved_x: u32,
+    pub last_resolved_y: u32,
+    pub last_resolved_is_hit: bool,
+    pub last_resolved_sunk_ship: u32,
+    pub pending_hub_notification: bool,
+    pub adjudicated: bool,
+    pub move_count: u32,
+    pub seeds: SeedExchange,
+}
+
+/// Contract address configured under `key` (hub, verifiers), if set.
+pub fn load_address(env: &Env, key: &DataKey) -> Option<Address> {
+    env.storage().instance().get(key)
+}
+
+pub fn save_address(env: &Env, key: &DataKey, address: &Address) {
+    env.storage().instance().set(key, address);
+}
+
+/// Maximum concurrent sessions per player; 0 means unlimited.
+pub fn load_max_active_games(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MaxActiveGames)
+        .unwrap_or(0)
+}
+
+pub fn save_max_active_games(env: &Env, max_active_games: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::MaxActiveGames, &max_active_games);
+}
+
+/// Resolved shots after which a game is adjudicated; 0 means no cap.
+pub fn load_max_shots(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MaxShots)
+        .unwrap_or(0)
+}
+
+pub fn save_max_shots(env: &Env, max_shots: u32) {
+    env.storage().instance().set(&DataKey::MaxShots, &max_shots);
+}
+
+pub fn load_min_stake(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MinStake)
+        .unwrap_or(DEFAULT_MIN_STAKE)
+}
+
+pub fn save_min_stake(env: &Env, min_stake: i128) {
+    env.storage().instance().set(&DataKey::MinStake, &min_stake);
+}
+
+pub fn load_max_stake(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MaxStake)
+        .unwrap_or(0)
+}
+
+pub fn save_max_stake(env: &Env, max_stake: i128) {
+    env.storage().instance().set(&DataKey::MaxStake, &max_stake);
+}
+
+/// Whether `session_id` is taken by a stored game (the setup entry marks it).
+pub fn game_exists(env: &Env, session_id: u32) -> bool {
+    env.storage().temporary().has(&DataKey::Game(session_id))
+}
+
+/// Read a game back from its setup and state entries.
+pub fn load_game(env: &Env, session_id: u32) -> Result<Game, Error> {
+    let setup: GameSetup = env
+        .storage()
+        .temporary()
+        .get(&DataKey::Game(session_id))
+        .ok_or(Error::GameNotFound)?;
+    let state: GameState = env
+        .storage()
+        .temporary()
+        .get(&DataKey::GameState(session_id))
+        .ok_or(Error::GameNotFound)?;
+
+    Ok(Game {
+        player1: setup.player1,
+        player2: setup.player2,
+        player1_points: setup.player1_points,
+        player2_points: setup.player2_points,
+        player1_partner: setup.player1_partner,
+        player2_partner: setup.player2_partner,
+        phase: state.phase,
+        turn: state.turn,
+        board_commitment_p1: setup.board_commitment_p1,
+        board_commitment_p2: setup.board_commitment_p2,
+        pending_shot_shooter: state.pending_shot_shooter,
+        pending_shot_x: state.pending_shot_x,
+        pending_shot_y: state.pending_shot_y,
+        pending_shot_commitment: state.pending_shot_commitment,
+        pending_shot_ledger: state.pending_shot_ledger,
+        retractions_p1: state.retractions_p1,
+        retractions_p2: state.retractions_p2,
+        shots_p1_to_p2: state.shots_p1_to_p2,
+        shots_p2_to_p1: state.shots_p2_to_p1,
+        hits_p1_to_p2: state.hits_p1_to_p2,
+        hits_p2_to_p1: state.hits_p2_to_p1,
+        hits_on_p1: state.hits_on_p1,
+        hits_on_p2: state.hits_on_p2,
+        sunk_ships_on_p1: state.sunk_ships_on_p1,
+        sunk_ships_on_p2: state.sunk_ships_on_p2,
+        winner: state.winner,
+        last_resolved_shooter: state.last_resolved_shooter,
+        last_resolved_x: state.last_resolved_x,
+        last_resolved_y: state.last_resolved_y,
+        last_resolved_is_hit: state.last_resolved_is_hit,
+        last_resolved_sunk_ship: state.last_resolved_sunk_ship,
+        disclose_sunk_ships: setup.disclose_sunk_ships,
+        pending_hub_notification: state.pending_hub_notification,
+        practice: setup.practice,
+        hub_seats: setup.hub_seats,
+        max_shots: setup.max_shots,
+        adjudicated: state.adjudicated,
+        move_count: state.move_count,
+        seeds: state.seeds,
+    })
+}
+
+/// Write `game` back. The state entry is rewritten every time; the setup entry only when it
+/// changed (start and board commits), so shots and resolutions write the small half only.
+pub fn save_game(env: &Env, session_id: u32, game: &Game) {
+    let setup = GameSetup {
+        player1: game.player1.clone(),
+        player2: game.player2.clone(),
+        player1_points: game.player1_points,
+        player2_points: game.player2_points,
+        player1_partner: game.player1_partner.clone(),
+        player2_partner: game.player2_partner.clone(),
+        board_commitment_p1: game.board_commitment_p1.clone(),
+        board_commitment_p2: game.board_commitment_p2.clone(),
+        disclose_sunk_ships: game.disclose_sunk_ships,
+        practice: game.practice,
+        hub_seats: game.hub_seats.clone(),
+        max_shots: game.max_shots,
+    };
+    let state = GameState {
+        phase: game.phase.clone(),
+        turn: game.turn.clone(),
+        pending_shot_shooter: game.pending_shot_shooter.clone(),
+        pending_shot_x: game.pending_shot_x,
+        pending_shot_y: game.pending_shot_y,
+        pending_shot_commitment: game.pending_shot_commitment.clone(),
+        pending_shot_ledger: game.pending_shot_ledger,
+        retractions_p1: game.retractions_p1,
+        retractions_p2: game.retractions_p2,
+        shots_p1_to_p2: game.shots_p1_to_p2,
+        shots_p2_to_p1: game.shots_p2_to_p1,
+        hits_p1_to_p2: game.hits_p1_to_p2,
+        hits_p2_to_p1: game.hits_p2_to_p1,
+        hits_on_p1: game.hits_on_p1,
+        hits_on_p2: game.hits_on_p2,
+        sunk_ships_on_p1: game.sunk_ships_on_p1,
+        sunk_ships_on_p2: game.sunk_ships_on_p2,
+        winner: game.winner.clone(),
+        last_resolved_shooter: game.last_resolved_shooter.clone(),
+        last_resolved_x: game.last_resolved_x,
+        last_resolved_y: game.last_resolved_y,
+        last_resolved_is_hit: game.last_resolved_is_hit,
+        last_resolved_sunk_ship: game.last_resolved_sunk_ship,
+        pending_hub_notification: game.pending_hub_notification,
+        adjudicated: game.adjudicated,
+        move_count: game.move_count,
+        seeds: game.seeds.clone(),
+    };
+
+    let storage = env.storage().temporary();
+    let setup_key = DataKey::Game(session_id);
+    let state_key = DataKey::GameState(session_id);
+    if storage.get::<_, GameSetup>(&setup_key).as_ref() != Some(&setup) {
+        storage.set(&setup_key, &setup);
+    }
+    storage.set(&state_key, &state);
+    storage.extend_ttl(&setup_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    storage.extend_ttl(&state_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+/// Active sessions for `player`, skipping entries whose game has expired or ended.
+pub fn load_active_sessions(env: &Env, player: &Address) -> Vec<u32> {
+    let stored: Vec<u32> = env
+        .storage()
+        .temporary()
+        .get(&DataKey::PlayerSessions(player.clone()))
+        .unwrap_or(Vec::new(env));
+
+    let mut active = Vec::new(env);
+    for session_id in stored.iter() {
+        if let Ok(game) = load_game(env, session_id) {
+            if game.phase != GamePhase::Ended {
+                active.push_back(session_id);
+            }
+        }
+    }
+    active
+}
+
+pub fn save_active_sessions(env: &Env, player: &Address, sessions: &Vec<u32>) {
+    let key = DataKey::PlayerSessions(player.clone());
+    if sessions.is_empty() {
+        env.storage().temporary().remove(&key);
+        return;
+    }
+    env.storage().temporary().set(&key, sessions);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+pub fn remove_active_session(env: &Env, player: &Address, session_id: u32) {
+    let mut sessions = load_active_sessions(env, player);
+    if let Some(index) = sessions.first_index_of(session_id) {
+        sessions.remove(index);
+    }
+    save_active_sessions(env, player, &sessions);
+}
+
+/// Append `record` at position `record.index`, keeping every chunk alive as long as the game.
+pub fn append_move(env: &Env, session_id: u32, record: &MoveRecord) {
+    let chunk = record.index / MOVES_PER_CHUNK;
+    let key = DataKey::Moves(session_id, chunk);
+    let mut moves: Vec<MoveRecord> = env.storage().temporary().get(&key).unwrap_or(Vec::new(env));
+    moves.push_back(record.clone());
+    env.storage().temporary().set(&key, &moves);
+
+    for i in 0..=chunk {
+        env.storage().temporary().extend_ttl(
+            &DataKey::Moves(session_id, i),
+            GAME_TTL_LEDGERS,
+            GAME_TTL_LEDGERS,
+        );
+    }
+}
+
+/// Read up to `limit` records starting at `start`, bounded by `total` recorded moves.
+pub fn load_moves(
+    env: &Env,
+    session_id: u32,
+    start: u32,
+    limit: u32,
+    total: u32,
+) -> Vec<MoveRecord> {
+    let mut out = Vec::new(env);
+    let end = total.min(start.saturating_add(limit));
+    let mut index = start;
+    while index < end {
+        let key = DataKey::Moves(session_id, index / MOVES_PER_CHUNK);
+        let moves: Vec<MoveRecord> = match env.storage().temporary().get(&key) {
+            Some(moves) => moves,
+            None => break,
+        };
+        let offset = index % MOVES_PER_CHUNK;
+        if offset >= moves.len() {
+            break;
+        }
+        let take = (moves.len() - offset).min(end - index);
+        for record in moves.slice(offset..offset + take).iter() {
+            out.push_back(record);
+        }
+        index += take;
+    }
+    out
+}
+
+pub fn load_reveal(env: &Env, session_id: u32, player: &Address) -> Option<BoardLayout> {
+    env.storage()
+        .temporary()
+        .get(&DataKey::Reveal(session_id, player.clone()))
+}
+
+pub fn save_reveal(env: &Env, session_id: u32, player: &Address, layout: &BoardLayout) {
+    let key = DataKey::Reveal(session_id, player.clone());
+    env.storage().temporary().set(&key, layout);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+/// Session key for `player`, ignoring one whose expiry ledger has passed.
+pub fn load_session_key(env: &Env, session_id: u32, player: &Address) -> Option<SessionKey> {
+    let stored: Option<SessionKey> = env
+        .storage()
+        .temporary()
+        .get(&DataKey::SessionKey(session_id, player.clone()));
+    stored.filter(|session_key| session_key.is_active(env.ledger().sequence()))
+}
+
+pub fn save_session_key(env: &Env, session_id: u32, player: &Address, session_key: &SessionKey) {
+    let key = DataKey::SessionKey(session_id, player.clone());
+    env.storage().temporary().set(&key, session_key);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+pub fn remove_session_key(env: &Env, session_id: u32, player: &Address) {
+    env.storage()
+        .temporary()
+        .remove(&DataKey::SessionKey(session_id, player.clone()));
+}
+
+/// Chat anchors `poster` posted in the session, oldest first.
+pub fn load_chat_anchors(env: &Env, session_id: u32, poster: &Address) -> Vec<ChatAnchor> {
+    env.storage()
+        .temporary()
+        .get(&DataKey::ChatAnchors(session_id, poster.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn save_chat_anchors(env: &Env, session_id: u32, poster: &Address, anchors: &Vec<ChatAnchor>) {
+    let key = DataKey::ChatAnchors(session_id, poster.clone());
+    env.storage().temporary().set(&key, anchors);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+/// Chat anchors all members posted in the session.
+pub fn load_chat_anchor_count(env: &Env, session_id: u32) -> u32 {
+    env.storage()
+        .temporary()
+        .get(&DataKey::ChatAnchorCount(session_id))
+        .unwrap_or(0)
+}
+
+pub fn save_chat_anchor_count(env: &Env, session_id: u32, count: u32) {
+    let key = DataKey::ChatAnchorCount(session_id);
+    env.storage().temporary().set(&key, &count);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+pub fn load_last_error(env: &Env, session_id: u32) -> Option<ErrorDetail> {
+    env.storage()
+        .temporary()
+        .get(&DataKey::LastError(session_id))
+}
+
+pub fn save_last_error(env: &Env, session_id: u32, detail: &ErrorDetail) {
+    let key = DataKey::LastError(session_id);
+    env.storage().temporary().set(&key, detail);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+/// Nonce the next derived session id for (player1, player2) will use. Persistent, so an
+/// archived entry must be restored rather than silently restarting at 0.
+pub fn load_pair_nonce(env: &Env, player1: &Address, player2: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PairNonce(player1.clone(), player2.clone()))
+        .unwrap_or(0)
+}
+
+pub fn save_pair_nonce(env: &Env, player1: &Address, player2: &Address, nonce: u32) {
+    let key = DataKey::PairNonce(player1.clone(), player2.clone());
+    env.storage().persistent().set(&key, &nonce);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+pub fn load_archived_stats(env: &Env, session_id: u32) -> Option<ArchivedStats> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ArchivedStats(session_id))
+}
+
+pub fn save_archived_stats(env: &Env, session_id: u32, stats: &ArchivedStats) {
+    let key = DataKey::ArchivedStats(session_id);
+    env.storage().persistent().set(&key, stats);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+/// Whether `session_id` was ever started here, even if its game entries have since expired.
+pub fn session_started(env: &Env, session_id: u32) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::SessionMarker(session_id))
+}
+
+pub fn load_session_marker(env: &Env, session_id: u32) -> Option<SessionMarker> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SessionMarker(session_id))
+}
+
+/// Persistent, so an archived marker is restored rather than lost with the temporary game.
+pub fn save_session_marker(env: &Env, session_id: u32, marker: &SessionMarker) {
+    let key = DataKey::SessionMarker(session_id);
+    env.storage().persistent().set(&key, marker);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+/// Operator counters; all zero until the first game starts.
+pub fn load_metrics(env: &Env) -> Metrics {
+    env.storage()
+        .instance()
+        .get(&DataKey::Metrics)
+        .unwrap_or_default()
+}
+
+pub fn save_metrics(env: &Env, metrics: &Metrics) {
+    env.storage().instance().set(&DataKey::Metrics, metrics);
+}
@@ -1,109 +1,111 @@
 #![no_std]
 
-mod error;
-mod interfaces;
-mod storage;
-mod types;
-
-pub use error::Error;
-pub use types::{Game, GamePhase, GameRules, ShotResult, ShipType};
-
-use soroban_sdk::{contract, contractimpl, vec, Address, Bytes, BytesN, Env, IntoVal};
-
-use interfaces::{BattleshipVerifierClient, GameHubClient};
-use storage::{load_game, save_game, DataKey, BOARD_SIZE, SHIP_BATTLESHIP_LEN, SHIP_CARRIER_LEN,
-              SHIP_CRUISER_LEN, SHIP_DESTROYER_LEN, SHIP_SUBMARINE_LEN, TOTAL_SHIP_CELLS};
+mod application;
+mod domain;
+mod infrastructure;
+
+pub use application::{
+    ContractInfo, FinalShotArgs, GameRules, ResolutionCheck, ShotResult, SinkingShotArgs,
+};
+pub use domain::{
+    ArchivedStats, BoardError, BoardLayout, ChatAnchor, Error, Game, GamePhase, GameStatus,
+    HubSeats, Metrics, MoveRecord, SeedExchange, SessionKey, SessionMarker, ShipPlacement,
+    ShipType, ShooterStats, StorageTier, SunkPlacement, Team,
+};
+pub use infrastructure::{
+    ChatAnchored, ErrorContext, ErrorDetail, GameInterface, GameInterfaceClient, GameMove,
+};
+
+use soroban_sdk::{contract, contractimpl, symbol_short, vec, Address, Bytes, BytesN, Env, Vec};
+
+use application::{
+    derive_session_id, AuthorizeSessionKeyCommand, CancelGameCommand, CancelShotCommand,
+    CheckResolutionQuery, CommitBoardCommand, CommitSeedCommand, CommitShotCommand, FireCommand,
+    ForfeitWithheldSeedCommand, GetArchivedStatsQuery, GetGameQuery, GetGameStatusQuery,
+    GetInfoQuery, GetMoveCountQuery, GetMovesQuery, GetRevealedBoardQuery, GetRulesQuery,
+    NotifyGameEndedCommand, PostChatAnchorCommand, RecommitBoardCommand, ResolutionArgs,
+    ResolvePracticeShotCommand, ResolveShotCommand, RetryHubNotifyCommand, RevealBoardCommand,
+    RevealSeedCommand, RevealShotCommand, RevokeSessionKeyCommand, ShotHashArgs, StartGameCommand,
+    StartMode,
+};
+use domain::validate_board_cells;
+use infrastructure::error_context;
+use infrastructure::storage::{
+    load_active_sessions, load_address, load_chat_anchors, load_last_error, load_max_active_games,
+    load_max_shots, load_max_stake, load_metrics, load_min_stake, load_pair_nonce,
+    load_session_key, load_session_marker, save_address, save_max_active_games, save_max_shots,
+    save_max_stake, save_min_stake, session_started, DataKey,
+};
+use ownable::Ownable;
 
 #[contract]
 pub struct BattleshipContract;
 
 #[contractimpl]
-impl BattleshipContract {
-    pub fn __constructor(env: Env, admin: Address, game_hub: Address, verifier: Address) {
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &game_hub);
-        env.storage()
-            .instance()
-            .set(&DataKey::VerifierAddress, &verifier);
-    }
-
-    pub fn start_game(
+impl GameInterface for BattleshipContract {
+    /// Start a game and return its session id. Passing `session_id = 0` derives the id from the
+    /// players and their next pair nonce (see `get_session_id`), so clients never pick colliding ids.
+    /// Derived ids have the high bit set; explicit ids with it fail with `ReservedSessionId`.
+    fn start_game(
         env: Env,
         session_id: u32,
         player1: Address,
         player2: Address,
         player1_points: i128,
         player2_points: i128,
-    ) -> Result<(), Error> {
-        if player1 == player2 {
-            return Err(Error::SelfPlayNotAllowed);
-        }
-
-        let key = DataKey::Game(session_id);
-        if env.storage().temporary().has(&key) {
-            return Err(Error::GameAlreadyExists);
-        }
-
-        player1.require_auth_for_args(vec![
-            &env,
-            session_id.into_val(&env),
-            player1_points.into_val(&env),
-        ]);
-        player2.require_auth_for_args(vec![
+    ) -> Result<u32, Error> {
+        StartGameCommand::execute(
             &env,
-            session_id.into_val(&env),
-            player2_points.into_val(&env),
-        ]);
-
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
-
-        // Required ordering: notify hub first.
-        game_hub.start_game(
-            &env.current_contract_address(),
-            &session_id,
-            &player1,
-            &player2,
-            &player1_points,
-            &player2_points,
-        );
-
-        let game = Game {
-            player1,
+            session_id,
+            player1.clone(),
             player2,
             player1_points,
             player2_points,
-            phase: GamePhase::WaitingForBoards,
-            turn: None,
-            board_commitment_p1: None,
-            board_commitment_p2: None,
-            pending_shot_shooter: None,
-            pending_shot_x: 0,
-            pending_shot_y: 0,
-            shots_p1_to_p2: 0,
-            shots_p2_to_p1: 0,
-            hits_p1_to_p2: 0,
-            hits_p2_to_p1: 0,
-            hits_on_p1: 0,
-            hits_on_p2: 0,
-            sunk_ships_on_p1: 0,
-            sunk_ships_on_p2: 0,
-            winner: None,
-            last_resolved_shooter: None,
-            last_resolved_x: 0,
-            last_resolved_y: 0,
-            last_resolved_is_hit: false,
-            last_resolved_sunk_ship: 0,
-        };
-
-        save_game(&env, &key, &game);
-        Ok(())
+            StartMode::Standard,
+        )
+        .map_err(|err| {
+            error_context(
+                &env,
+                session_id,
+                symbol_short!("start"),
+                Some(player1),
+                Vec::new(&env),
+                err,
+            )
+        })
+    }
+
+    /// Winner of an ended game; None while the game is running or after `cancel_game`.
+    fn evaluate_game(env: Env, session_id: u32) -> Result<Option<Address>, Error> {
+        Ok(GetGameQuery::execute(&env, session_id)?.winner)
+    }
+
+    /// Abort a game that has not ended (e.g. a tournament no-show). Only the Game Hub can cancel,
+    /// and since it initiated the cancel it is not notified back. The game ends with no winner.
+    fn cancel_game(env: Env, session_id: u32) -> Result<(), Error> {
+        CancelGameCommand::execute(&env, session_id).map_err(|err| {
+            error_context(
+                &env,
+                session_id,
+                symbol_short!("cancel"),
+                None,
+                Vec::new(&env),
+                err,
+            )
+        })
+    }
+
+    fn get_game_status(env: Env, session_id: u32) -> Result<GameStatus, Error> {
+        GetGameStatusQuery::execute(&env, session_id)
+    }
+}
+
+#[contractimpl]
+impl BattleshipContract {
+    pub fn __constructor(env: Env, admin: Address, game_hub: Address, verifier: Address) {
+        Ownable::init(&env, &admin);
+        save_address(&env, &DataKey::GameHubAddress, &game_hub);
+        save_address(&env, &DataKey::VerifierAddress, &verifier);
     }
 
     pub fn commit_board(
@@ -112,94 +114,148 @@ impl BattleshipContract {
         player: Address,
         board_commitment: BytesN<32>,
     ) -> Result<(), Error> {
-        player.require_auth();
-
-        let key = DataKey::Game(session_id);
-        let mut game = load_game(&env, &key)?;
-
-        if game.phase == GamePhase::Ended {
-            return Err(Error::GameAlreadyEnded);
-        }
-
-        if game.phase != GamePhase::WaitingForBoards {
-            return Err(Error::InvalidPhase);
-        }
+        CommitBoardCommand::execute(&env, session_id, player.clone(), board_commitment).map_err(
+            |err| {
+                error_context(
+                    &env,
+                    session_id,
+                    symbol_short!("commit"),
+                    Some(player),
+                    Vec::new(&env),
+                    err,
+                )
+            },
+        )
+    }
 
-        if player == game.player1 {
-            if game.board_commitment_p1.is_some() {
-                return Err(Error::BoardAlreadyCommitted);
-            }
-            game.board_commitment_p1 = Some(board_commitment);
-        } else if player == game.player2 {
-            if game.board_commitment_p2.is_some() {
-                return Err(Error::BoardAlreadyCommitted);
-            }
-            game.board_commitment_p2 = Some(board_commitment);
-        } else {
-            return Err(Error::NotPlayer);
-        }
+    /// Commit the caller's side to an RNG seed (see `build_seed_commitment`) while the boards are
+    /// being placed. Optional; modes with random events need both sides' seeds.
+    pub fn commit_seed(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        CommitSeedCommand::execute(&env, session_id, player.clone(), commitment).map_err(|err| {
+            error_context(
+                &env,
+                session_id,
+                symbol_short!("seedcmt"),
+                Some(player),
+                Vec::new(&env),
+                err,
+            )
+        })
+    }
 
-        if game.board_commitment_p1.is_some() && game.board_commitment_p2.is_some() {
-            game.phase = GamePhase::InProgress;
-            // Deterministic first turn.
-            game.turn = Some(game.player1.clone());
-        }
+    /// Reveal the caller's side seed once both sides have committed and before the boards are
+    /// in. Fails with `InvalidSeedReveal` unless it hashes to the committed value, and with
+    /// `InvalidPhase` once the game is in progress. The second reveal sets
+    /// `seeds.stream` to keccak256(seed_p1 || seed_p2), the head of the game's random hash chain.
+    pub fn reveal_seed(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        seed: BytesN<32>,
+    ) -> Result<(), Error> {
+        RevealSeedCommand::execute(&env, session_id, player.clone(), seed).map_err(|err| {
+            error_context(
+                &env,
+                session_id,
+                symbol_short!("seedrev"),
+                Some(player),
+                Vec::new(&env),
+                err,
+            )
+        })
+    }
 
-        save_game(&env, &key, &game);
-        Ok(())
+    /// End the game once `seed_reveal_window_ledgers` have passed since the second seed
+    /// commitment with only one seed revealed: the side withholding its seed forfeits. Callable
+    /// by anyone; returns the winner. Fails with `SeedRevealWindowOpen` while the window runs and
+    /// `NoSeedWithheld` unless exactly one side revealed.
+    pub fn forfeit_withheld_seed(env: Env, session_id: u32) -> Result<Address, Error> {
+        ForfeitWithheldSeedCommand::execute(&env, session_id).map_err(|err| {
+            error_context(
+                &env,
+                session_id,
+                symbol_short!("seedlapse"),
+                None,
+                Vec::new(&env),
+                err,
+            )
+        })
     }
 
     pub fn fire(env: Env, session_id: u32, shooter: Address, x: u32, y: u32) -> Result<(), Error> {
-        shooter.require_auth();
-
-        let key = DataKey::Game(session_id);
-        let mut game = load_game(&env, &key)?;
-
-        if game.phase == GamePhase::Ended {
-            return Err(Error::GameAlreadyEnded);
-        }
-
-        if game.phase != GamePhase::InProgress {
-            return Err(Error::InvalidPhase);
-        }
-
-        if game.pending_shot_shooter.is_some() {
-            return Err(Error::PendingShotExists);
-        }
-
-        let turn = game.turn.clone().ok_or(Error::InvalidPhase)?;
-        if shooter != turn {
-            return Err(Error::NotYourTurn);
-        }
-
-        let bit = Self::coord_to_bit(x, y)?;
-
-        // Duplicate shot check against already resolved shots.
-        if shooter == game.player1 {
-            if game.shots_p1_to_p2 & bit != 0 {
-                return Err(Error::ShotAlreadyResolved);
-            }
-        } else if shooter == game.player2 {
-            if game.shots_p2_to_p1 & bit != 0 {
-                return Err(Error::ShotAlreadyResolved);
-            }
-        } else {
-            return Err(Error::NotPlayer);
-        }
+        FireCommand::execute(&env, session_id, shooter.clone(), x, y).map_err(|err| {
+            error_context(
+                &env,
+                session_id,
+                symbol_short!("fire"),
+                Some(shooter),
+                vec![&env, x, y],
+                err,
+            )
+        })
+    }
 
-        // Clear last resolved so only the most recent resolve is visible to shooter.
-        game.last_resolved_shooter = None;
-        game.last_resolved_x = 0;
-        game.last_resolved_y = 0;
-        game.last_resolved_is_hit = false;
-        game.last_resolved_sunk_ship = 0;
+    /// Fire without exposing the cell: record `commitment` (see `build_shot_commitment`) as the
+    /// pending shot. It takes the turn like `fire` and is opened with `reveal_shot` once the
+    /// defender is ready to resolve, so the coordinate is not public while their proof is pending.
+    pub fn commit_shot(
+        env: Env,
+        session_id: u32,
+        shooter: Address,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        CommitShotCommand::execute(&env, session_id, shooter.clone(), commitment).map_err(|err| {
+            error_context(
+                &env,
+                session_id,
+                symbol_short!("shotcmt"),
+                Some(shooter),
+                Vec::new(&env),
+                err,
+            )
+        })
+    }
 
-        game.pending_shot_shooter = Some(shooter);
-        game.pending_shot_x = x;
-        game.pending_shot_y = y;
-        save_game(&env, &key, &game);
+    /// Open the caller's committed shot at (x, y). Fails with `InvalidShotReveal` unless the cell
+    /// and `salt` hash to the committed value; the shot then resolves like a `fire` shot.
+    pub fn reveal_shot(
+        env: Env,
+        session_id: u32,
+        shooter: Address,
+        x: u32,
+        y: u32,
+        salt: BytesN<32>,
+    ) -> Result<(), Error> {
+        RevealShotCommand::execute(&env, session_id, shooter.clone(), x, y, salt).map_err(|err| {
+            error_context(
+                &env,
+                session_id,
+                symbol_short!("shotrev"),
+                Some(shooter),
+                vec![&env, x, y],
+                err,
+            )
+        })
+    }
 
-        Ok(())
+    /// Retract the caller's unresolved shot (e.g. a misclick) within `SHOT_RETRACT_WINDOW_LEDGERS`
+    /// of firing, before the defender resolves it. Each player gets `MAX_SHOT_RETRACTIONS` per game.
+    pub fn cancel_shot(env: Env, session_id: u32, shooter: Address) -> Result<(), Error> {
+        CancelShotCommand::execute(&env, session_id, shooter.clone()).map_err(|err| {
+            error_context(
+                &env,
+                session_id,
+                symbol_short!("retract"),
+                Some(shooter),
+                Vec::new(&env),
+                err,
+            )
+        })
     }
 
     pub fn resolve_shot(
@@ -211,364 +267,695 @@ impl BattleshipContract {
         proof_payload: Bytes,
         public_inputs_hash: BytesN<32>,
     ) -> Result<ShotResult, Error> {
-        let key = DataKey::Game(session_id);
-        let mut game = load_game(&env, &key)?;
-
-        if game.phase == GamePhase::Ended {
-            return Err(Error::GameAlreadyEnded);
-        }
-
-        if game.phase != GamePhase::InProgress {
-            return Err(Error::InvalidPhase);
-        }
-
-        let shooter = game
-            .pending_shot_shooter
-            .clone()
-            .ok_or(Error::NoPendingShot)?;
-        let shot_x = game.pending_shot_x;
-        let shot_y = game.pending_shot_y;
+        ResolveShotCommand::execute(
+            &env,
+            session_id,
+            ResolutionArgs {
+                defender: defender.clone(),
+                is_hit,
+                sunk_ship,
+                proof: Some((proof_payload, public_inputs_hash)),
+                sunk_placement: None,
+                consistency_proof: None,
+            },
+        )
+        .map_err(|err| {
+            error_context(
+                &env,
+                session_id,
+                symbol_short!("resolve"),
+                Some(defender),
+                vec![&env, is_hit as u32, sunk_ship],
+                err,
+            )
+        })
+    }
 
-        let expected_defender = Self::opponent(&game, &shooter)?;
-        if defender != expected_defender {
-            return Err(Error::InvalidDefender);
-        }
+    /// Resolve the game-winning (17th hit) shot with an additional `board_consistency` proof that
+    /// every result reported against the defender's board, including this one, matches the
+    /// committed placement. Required instead of `resolve_shot` once a final verifier is set.
+    ///
+    /// The final adapter is called as `verify(board_commitment, consistency_hash, payload)`,
+    /// where `consistency_hash` is `build_consistency_hash` over the shot history after this shot.
+    pub fn resolve_final_shot(
+        env: Env,
+        session_id: u32,
+        args: FinalShotArgs,
+    ) -> Result<ShotResult, Error> {
+        let defender = args.defender.clone();
+        let context = vec![&env, args.is_hit as u32, args.sunk_ship];
+        ResolveShotCommand::execute(&env, session_id, args.into()).map_err(|err| {
+            error_context(
+                &env,
+                session_id,
+                symbol_short!("final"),
+                Some(defender),
+                context,
+                err,
+            )
+        })
+    }
 
-        let ship = Self::parse_ship_type(sunk_ship)?;
-        if ship.is_some() && !is_hit {
-            return Err(Error::InvalidSunkShip);
-        }
+    /// Resolve a shot that sinks `sunk_ship` and disclose that ship's placement. Required instead
+    /// of `resolve_shot` / `resolve_final_shot` for sinking shots in games started with
+    /// `disclose_sunk_ships`; pass `consistency_proof` when the shot also wins the game.
+    ///
+    /// The placement is bound into `build_disclosure_hash`, proven by the disclosure verifier
+    /// (`resolve_shot_disclosed` circuit) and must cover only cells the shooter has hit.
+    pub fn resolve_sinking_shot(
+        env: Env,
+        session_id: u32,
+        args: SinkingShotArgs,
+    ) -> Result<ShotResult, Error> {
+        let defender = args.defender.clone();
+        let placement = &args.sunk_placement;
+        let context = vec![
+            &env,
+            args.sunk_ship,
+            placement.x,
+            placement.y,
+            placement.dir,
+        ];
+        ResolveShotCommand::execute(&env, session_id, args.into()).map_err(|err| {
+            error_context(
+                &env,
+                session_id,
+                symbol_short!("sink"),
+                Some(defender),
+                context,
+                err,
+            )
+        })
+    }
 
-        let bit = Self::coord_to_bit(shot_x, shot_y)?;
-        if shooter == game.player1 {
-            if game.shots_p1_to_p2 & bit != 0 {
-                return Err(Error::ShotAlreadyResolved);
-            }
-        } else {
-            if game.shots_p2_to_p1 & bit != 0 {
-                return Err(Error::ShotAlreadyResolved);
-            }
-        }
+    /// Start a 2v2 game. Each team's lead stakes its points and stands for the team at the Game
+    /// Hub; lead and partner take turns firing for their side (lead first) and defend one shared
+    /// board, which either of them commits. The winner is reported as the winning team's lead.
+    pub fn start_team_game(
+        env: Env,
+        session_id: u32,
+        team1: Team,
+        team2: Team,
+        team1_points: i128,
+        team2_points: i128,
+    ) -> Result<u32, Error> {
+        StartGameCommand::execute(
+            &env,
+            session_id,
+            team1.lead.clone(),
+            team2.lead,
+            team1_points,
+            team2_points,
+            StartMode::Team(team1.partner, team2.partner),
+        )
+        .map_err(|err| {
+            error_context(
+                &env,
+                session_id,
+                symbol_short!("team"),
+                Some(team1.lead),
+                Vec::new(&env),
+                err,
+            )
+        })
+    }
 
-        let board_commitment = if defender == game.player1 {
-            game.board_commitment_p1
-                .clone()
-                .ok_or(Error::BoardNotCommitted)?
-        } else {
-            game.board_commitment_p2
-                .clone()
-                .ok_or(Error::BoardNotCommitted)?
-        };
-
-        let expected_hash = Self::build_public_inputs_hash_internal(
+    /// Start an open-board practice game: no points, no Game Hub, no proofs. Both players submit
+    /// their layout in clear with `commit_practice_board` and `resolve_practice_shot` resolves
+    /// each shot against it, for tutorials, UI work and integration tests without a prover.
+    pub fn start_practice_game(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+    ) -> Result<u32, Error> {
+        StartGameCommand::execute(
             &env,
             session_id,
-            defender.clone(),
-            shooter.clone(),
-            shot_x,
-            shot_y,
-            is_hit,
-            sunk_ship,
-            board_commitment.clone(),
-        );
+            player1.clone(),
+            player2,
+            0,
+            0,
+            StartMode::Practice,
+        )
+        .map_err(|err| {
+            error_context(
+                &env,
+                session_id,
+                symbol_short!("practice"),
+                Some(player1),
+                Vec::new(&env),
+                err,
+            )
+        })
+    }
 
-        if expected_hash != public_inputs_hash {
-            return Err(Error::InvalidPublicInputsHash);
-        }
+    /// Place a practice board in clear. The layout is published at once (`get_revealed_board`)
+    /// and its reveal hash stands in for the board commitment.
+    pub fn commit_practice_board(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        layout: BoardLayout,
+    ) -> Result<(), Error> {
+        CommitBoardCommand::execute_practice(&env, session_id, player.clone(), layout).map_err(
+            |err| {
+                error_context(
+                    &env,
+                    session_id,
+                    symbol_short!("pcommit"),
+                    Some(player),
+                    Vec::new(&env),
+                    err,
+                )
+            },
+        )
+    }
 
-        let verifier_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::VerifierAddress)
-            .expect("Verifier address not set");
-        let verifier = BattleshipVerifierClient::new(&env, &verifier_addr);
-        if !verifier.verify(&board_commitment, &public_inputs_hash, &proof_payload) {
-            return Err(Error::InvalidProof);
-        }
+    /// Resolve the pending shot of a practice game from the defender's clear layout. Callable by
+    /// anyone; hit, sunk ship and the win are derived the same way a proof would attest them.
+    pub fn resolve_practice_shot(env: Env, session_id: u32) -> Result<ShotResult, Error> {
+        ResolvePracticeShotCommand::execute(&env, session_id).map_err(|err| {
+            error_context(
+                &env,
+                session_id,
+                symbol_short!("presolve"),
+                None,
+                Vec::new(&env),
+                err,
+            )
+        })
+    }
 
-        // Mark shot as resolved.
-        if shooter == game.player1 {
-            game.shots_p1_to_p2 |= bit;
-        } else {
-            game.shots_p2_to_p1 |= bit;
-        }
+    /// Replace a committed board with a fresh commitment to the same placement under a new salt.
+    ///
+    /// `equivalence_proof` is a `board_recommit` proof payload verified by the recommit adapter
+    /// as `verify(old_commitment, new_commitment, payload)`, i.e. public inputs 0..1 bind the
+    /// current commitment and 2..3 bind `new_commitment`.
+    pub fn recommit_board(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        new_commitment: BytesN<32>,
+        equivalence_proof: Bytes,
+    ) -> Result<(), Error> {
+        RecommitBoardCommand::execute(
+            &env,
+            session_id,
+            player.clone(),
+            new_commitment,
+            equivalence_proof,
+        )
+        .map_err(|err| {
+            error_context(
+                &env,
+                session_id,
+                symbol_short!("recommit"),
+                Some(player),
+                Vec::new(&env),
+                err,
+            )
+        })
+    }
 
-        if is_hit {
-            if shooter == game.player1 {
-                game.hits_p1_to_p2 |= bit;
-            } else {
-                game.hits_p2_to_p1 |= bit;
-            }
-
-            if defender == game.player1 {
-                game.hits_on_p1 += 1;
-                if game.hits_on_p1 > TOTAL_SHIP_CELLS {
-                    return Err(Error::TooManyHits);
-                }
-            } else {
-                game.hits_on_p2 += 1;
-                if game.hits_on_p2 > TOTAL_SHIP_CELLS {
-                    return Err(Error::TooManyHits);
-                }
-            }
-        }
+    /// Disclose a player's board once the game has ended, so spectators and replays can render it.
+    ///
+    /// `reveal_proof` is a `board_reveal` payload verified by the reveal adapter as
+    /// `verify(board_commitment, build_reveal_hash(...), payload)`. The layout must also agree with
+    /// every hit and miss the opponent recorded against this board.
+    pub fn reveal_board(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        layout: BoardLayout,
+        reveal_proof: Bytes,
+    ) -> Result<(), Error> {
+        RevealBoardCommand::execute(&env, session_id, player.clone(), layout, reveal_proof).map_err(
+            |err| {
+                error_context(
+                    &env,
+                    session_id,
+                    symbol_short!("reveal"),
+                    Some(player),
+                    Vec::new(&env),
+                    err,
+                )
+            },
+        )
+    }
 
-        if let Some(ship_kind) = ship.clone() {
-            let bit = Self::ship_bit(ship_kind);
-            if defender == game.player1 {
-                if game.sunk_ships_on_p1 & bit != 0 {
-                    return Err(Error::ShipAlreadySunk);
-                }
-                game.sunk_ships_on_p1 |= bit;
-            } else {
-                if game.sunk_ships_on_p2 & bit != 0 {
-                    return Err(Error::ShipAlreadySunk);
-                }
-                game.sunk_ships_on_p2 |= bit;
-            }
-        }
+    /// Let `key` sign `fire` for `player` in this session until ledger `expiry`, so fast play
+    /// doesn't prompt the main wallet on every shot. Replaces any previous key.
+    pub fn authorize_session_key(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        key: Address,
+        expiry: u32,
+    ) -> Result<(), Error> {
+        AuthorizeSessionKeyCommand::execute(&env, session_id, player.clone(), key, expiry).map_err(
+            |err| {
+                error_context(
+                    &env,
+                    session_id,
+                    symbol_short!("sesskey"),
+                    Some(player),
+                    vec![&env, expiry],
+                    err,
+                )
+            },
+        )
+    }
 
-        let defender_hits = if defender == game.player1 {
-            game.hits_on_p1
-        } else {
-            game.hits_on_p2
-        };
-
-        let mut winner: Option<Address> = None;
-        let mut next_turn: Option<Address> = None;
-
-        if defender_hits >= TOTAL_SHIP_CELLS {
-            // Required ordering: end in hub before final winner state.
-            let game_hub_addr: Address = env
-                .storage()
-                .instance()
-                .get(&DataKey::GameHubAddress)
-                .expect("GameHub address not set");
-            let game_hub = GameHubClient::new(&env, &game_hub_addr);
-            let player1_won = shooter == game.player1;
-            game_hub.end_game(&session_id, &player1_won);
-
-            game.phase = GamePhase::Ended;
-            game.winner = Some(shooter.clone());
-            game.turn = None;
-            winner = Some(shooter.clone());
-        } else {
-            game.turn = Some(defender.clone());
-            next_turn = Some(defender);
-        }
+    /// Anchor the hash of an off-chain chat message (game members only), so a transcript
+    /// produced in a dispute can be proven authentic without putting message bodies on-chain.
+    /// Rate-limited per poster to one anchor every `chat_anchor_interval_ledgers`, up to
+    /// `max_chat_anchors` per poster and `max_session_chat_anchors` per game (see `get_rules`).
+    /// Returns the anchor's index.
+    pub fn post_chat_anchor(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        hash: BytesN<32>,
+    ) -> Result<u32, Error> {
+        PostChatAnchorCommand::execute(&env, session_id, player.clone(), hash).map_err(|err| {
+            error_context(
+                &env,
+                session_id,
+                symbol_short!("chat"),
+                Some(player),
+                Vec::new(&env),
+                err,
+            )
+        })
+    }
 
-        // Expose last resolved shot so shooter can learn result from get_game().
-        game.last_resolved_shooter = Some(shooter.clone());
-        game.last_resolved_x = shot_x;
-        game.last_resolved_y = shot_y;
-        game.last_resolved_is_hit = is_hit;
-        game.last_resolved_sunk_ship = sunk_ship;
+    /// Drop the player's session key; `fire` needs the main wallet again.
+    pub fn revoke_session_key(env: Env, session_id: u32, player: Address) {
+        RevokeSessionKeyCommand::execute(&env, session_id, player);
+    }
 
-        game.pending_shot_shooter = None;
-        save_game(&env, &key, &game);
+    /// Public inputs hash for `resolve_shot` / `resolve_final_shot`. The flat parameter list is
+    /// the contract interface provers and the frontend bindings call, so it stays ungrouped.
+    #[allow(clippy::too_many_arguments)]
+    pub fn build_public_inputs_hash(
+        env: Env,
+        session_id: u32,
+        defender: Address,
+        shooter: Address,
+        x: u32,
+        y: u32,
+        is_hit: bool,
+        sunk_ship: u32,
+        board_commitment: BytesN<32>,
+    ) -> BytesN<32> {
+        ResolveShotCommand::build_public_inputs_hash(
+            &env,
+            session_id,
+            &ShotHashArgs {
+                defender,
+                shooter,
+                x,
+                y,
+                is_hit,
+                sunk_ship,
+                board_commitment,
+                sunk_placement: None,
+            },
+        )
+    }
 
-        Ok(ShotResult {
+    /// Simulate `resolve_shot` without changing state and report which check would fail: the
+    /// expected public inputs hash, whether it matches, and the verifier's answer or abort code.
+    /// Meant for prover debugging through transaction simulation before paying fees.
+    pub fn check_resolution(
+        env: Env,
+        session_id: u32,
+        defender: Address,
+        is_hit: bool,
+        sunk_ship: u32,
+        proof_payload: Bytes,
+        public_inputs_hash: BytesN<32>,
+    ) -> ResolutionCheck {
+        CheckResolutionQuery::execute(
+            &env,
+            session_id,
+            &defender,
             is_hit,
             sunk_ship,
-            winner,
-            next_turn,
-        })
+            &proof_payload,
+            &public_inputs_hash,
+        )
     }
 
-    pub fn build_public_inputs_hash(
+    /// Public inputs hash for `resolve_sinking_shot`: the regular shot hash payload (with
+    /// `is_hit = true`) followed by the sunk ship's x, y and dir bytes.
+    #[allow(clippy::too_many_arguments)]
+    pub fn build_disclosure_hash(
         env: Env,
         session_id: u32,
         defender: Address,
         shooter: Address,
         x: u32,
         y: u32,
-        is_hit: bool,
         sunk_ship: u32,
+        sunk_placement: ShipPlacement,
+        board_commitment: BytesN<32>,
+    ) -> BytesN<32> {
+        ResolveShotCommand::build_public_inputs_hash(
+            &env,
+            session_id,
+            &ShotHashArgs {
+                defender,
+                shooter,
+                x,
+                y,
+                is_hit: true,
+                sunk_ship,
+                board_commitment,
+                sunk_placement: Some(sunk_placement),
+            },
+        )
+    }
+
+    pub fn build_consistency_hash(
+        env: Env,
+        session_id: u32,
+        defender: Address,
+        shots: u128,
+        hits: u128,
         board_commitment: BytesN<32>,
     ) -> BytesN<32> {
-        Self::build_public_inputs_hash_internal(
+        ResolveShotCommand::build_consistency_hash(
             &env,
             session_id,
             defender,
-            shooter,
-            x,
-            y,
-            is_hit,
-            sunk_ship,
+            shots,
+            hits,
             board_commitment,
         )
     }
 
+    pub fn build_reveal_hash(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        layout: BoardLayout,
+        board_commitment: BytesN<32>,
+    ) -> BytesN<32> {
+        RevealBoardCommand::build_reveal_hash(&env, session_id, player, &layout, board_commitment)
+    }
+
+    pub fn build_shot_commitment(
+        env: Env,
+        session_id: u32,
+        shooter: Address,
+        x: u32,
+        y: u32,
+        salt: BytesN<32>,
+    ) -> BytesN<32> {
+        RevealShotCommand::build_shot_commitment(&env, session_id, shooter, x, y, &salt)
+    }
+
+    pub fn build_seed_commitment(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        seed: BytesN<32>,
+    ) -> BytesN<32> {
+        RevealSeedCommand::build_seed_commitment(&env, session_id, player, &seed)
+    }
+
     pub fn get_game(env: Env, session_id: u32) -> Result<Game, Error> {
-        let key = DataKey::Game(session_id);
-        load_game(&env, &key)
+        GetGameQuery::execute(&env, session_id)
+    }
+
+    /// Per-player shots, hits, accuracy and longest hit streak of a won game. Written when the
+    /// game ends and kept in persistent storage, so it outlives `get_game` and `get_moves`.
+    pub fn get_archived_stats(env: Env, session_id: u32) -> Result<ArchivedStats, Error> {
+        GetArchivedStatsQuery::execute(&env, session_id)
+    }
+
+    /// Whether `session_id` was ever started here. Kept in persistent storage, so once `get_game`
+    /// fails with `GameNotFound` for a started session, the hub knows the game expired mid-match
+    /// and can refund the stakes.
+    pub fn was_started(env: Env, session_id: u32) -> bool {
+        session_started(&env, session_id)
+    }
+
+    /// Members, start ledger and storage tier of a started session, for post-mortem audits of
+    /// games whose state has expired. `None` if the session was never started.
+    pub fn get_session_marker(env: Env, session_id: u32) -> Option<SessionMarker> {
+        load_session_marker(&env, session_id)
+    }
+
+    /// Latest failure the contract absorbed in a session instead of reverting: the error
+    /// namespace and code, the offending address and the ledger it happened in. Failed calls
+    /// roll back their own storage, so their cause is only reported in the transaction result.
+    pub fn get_last_error_detail(env: Env, session_id: u32) -> Option<ErrorDetail> {
+        load_last_error(&env, session_id)
+    }
+
+    /// Resolved moves in order, starting at `start`. Clients can poll with `start` set to the
+    /// number of moves they already have to fetch only new ones.
+    pub fn get_moves(
+        env: Env,
+        session_id: u32,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<MoveRecord>, Error> {
+        GetMovesQuery::execute(&env, session_id, start, limit)
+    }
+
+    /// Actions applied to the game so far; the `move_count` of the latest `GameMove` event.
+    pub fn get_move_count(env: Env, session_id: u32) -> Result<u32, Error> {
+        GetMoveCountQuery::execute(&env, session_id)
+    }
+
+    /// Revealed board as 100 cells indexed `y * 10 + x`: 0 = water, 1..5 = Carrier..Destroyer.
+    /// Only available after `reveal_board`.
+    pub fn get_revealed_board(
+        env: Env,
+        session_id: u32,
+        player: Address,
+    ) -> Result<Vec<u32>, Error> {
+        GetRevealedBoardQuery::execute(&env, session_id, &player)
+    }
+
+    /// Check a board in the `get_revealed_board` format (100 cells, row-major, `0` = water,
+    /// `1..5` = Carrier..Destroyer) against the placement circuit's rules: every ship present
+    /// with its length, on one straight run inside the board, and no overlap. Like the circuit,
+    /// ships may touch.
+    pub fn validate_board(_env: Env, cells: Bytes) -> Result<(), BoardError> {
+        validate_board_cells(&cells)
+    }
+
+    /// Session id `start_game(0, player1, player2, ..)` derives for the given pair nonce.
+    pub fn get_session_id(env: Env, player1: Address, player2: Address, nonce: u32) -> u32 {
+        derive_session_id(&env, &player1, &player2, nonce)
+    }
+
+    /// Pair nonce the next derived session id for (player1, player2) uses.
+    pub fn get_session_nonce(env: Env, player1: Address, player2: Address) -> u32 {
+        load_pair_nonce(&env, &player1, &player2)
+    }
+
+    /// Chat anchors `player` posted in the session, oldest first.
+    pub fn get_chat_anchors(env: Env, session_id: u32, player: Address) -> Vec<ChatAnchor> {
+        load_chat_anchors(&env, session_id, &player)
+    }
+
+    /// Session key currently authorized to `fire` for `player`, if not expired.
+    pub fn get_session_key(env: Env, session_id: u32, player: Address) -> Option<SessionKey> {
+        load_session_key(&env, session_id, &player)
     }
 
     /// Notify the Game Hub that the game has ended. Idempotent; safe to call when the game
     /// is already in Ended state (e.g. if hub was not notified during resolve_shot).
     pub fn notify_game_ended_to_hub(env: Env, session_id: u32) -> Result<(), Error> {
-        let key = DataKey::Game(session_id);
-        let game = load_game(&env, &key)?;
-        if game.phase != GamePhase::Ended {
-            return Err(Error::InvalidPhase);
-        }
-        let winner = game.winner.ok_or(Error::InvalidPhase)?;
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
-        let player1_won = winner == game.player1;
-        game_hub.end_game(&session_id, &player1_won);
-        Ok(())
+        NotifyGameEndedCommand::execute(&env, session_id)
     }
 
-    pub fn get_rules(_env: Env) -> GameRules {
-        GameRules {
-            board_size: BOARD_SIZE,
-            carrier_len: SHIP_CARRIER_LEN,
-            battleship_len: SHIP_BATTLESHIP_LEN,
-            cruiser_len: SHIP_CRUISER_LEN,
-            submarine_len: SHIP_SUBMARINE_LEN,
-            destroyer_len: SHIP_DESTROYER_LEN,
-            total_ship_cells: TOTAL_SHIP_CELLS,
-        }
+    /// Re-sends the hub `end_game` that failed when the winning shot was resolved. Callable by
+    /// anyone; fails with `HubNotificationNotPending` once the hub has been notified.
+    pub fn retry_hub_notify(env: Env, session_id: u32) -> Result<(), Error> {
+        RetryHubNotifyCommand::execute(&env, session_id).map_err(|err| {
+            error_context(
+                &env,
+                session_id,
+                symbol_short!("retryhub"),
+                None,
+                Vec::new(&env),
+                err,
+            )
+        })
+    }
+
+    pub fn get_rules(env: Env) -> GameRules {
+        GetRulesQuery::execute(&env)
+    }
+
+    pub fn get_info(env: Env) -> ContractInfo {
+        GetInfoQuery::execute(&env)
+    }
+
+    /// Games started and how they ended since deployment, for operator health checks without an
+    /// indexer.
+    pub fn get_metrics(env: Env) -> Metrics {
+        load_metrics(&env)
     }
 
     pub fn get_admin(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set")
+        Ownable::admin(&env)
     }
 
     pub fn set_admin(env: Env, new_admin: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+        Ownable::set_admin(&env, &new_admin);
+    }
 
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
+    /// First step of an admin transfer; `new_admin` takes over once they call `accept_admin`.
+    pub fn propose_admin(env: Env, new_admin: Address) {
+        Ownable::propose_admin(&env, &new_admin);
+    }
+
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        Ownable::pending_admin(&env)
+    }
+
+    /// Completes a transfer started with `propose_admin`; signed by the nominee.
+    pub fn accept_admin(env: Env) -> Address {
+        Ownable::accept_admin(&env)
     }
 
     pub fn get_hub(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set")
+        load_address(&env, &DataKey::GameHubAddress).expect("GameHub address not set")
     }
 
     pub fn set_hub(env: Env, new_hub: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &new_hub);
+        Ownable::require_admin(&env);
+        save_address(&env, &DataKey::GameHubAddress, &new_hub);
     }
 
     pub fn get_verifier(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::VerifierAddress)
-            .expect("Verifier address not set")
+        load_address(&env, &DataKey::VerifierAddress).expect("Verifier address not set")
     }
 
     pub fn set_verifier(env: Env, new_verifier: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+        Ownable::require_admin(&env);
+        save_address(&env, &DataKey::VerifierAddress, &new_verifier);
+    }
 
-        env.storage()
-            .instance()
-            .set(&DataKey::VerifierAddress, &new_verifier);
+    pub fn get_recommit_verifier(env: Env) -> Option<Address> {
+        load_address(&env, &DataKey::RecommitVerifierAddress)
     }
 
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+    pub fn set_recommit_verifier(env: Env, new_verifier: Address) {
+        Ownable::require_admin(&env);
+        save_address(&env, &DataKey::RecommitVerifierAddress, &new_verifier);
+    }
 
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    pub fn get_final_verifier(env: Env) -> Option<Address> {
+        load_address(&env, &DataKey::FinalVerifierAddress)
     }
 
-    fn build_public_inputs_hash_internal(
-        env: &Env,
-        session_id: u32,
-        defender: Address,
-        shooter: Address,
-        x: u32,
-        y: u32,
-        is_hit: bool,
-        sunk_ship: u32,
-        board_commitment: BytesN<32>,
-    ) -> BytesN<32> {
-        let mut fixed = [0u8; 17];
-        fixed[0..4].copy_from_slice(&session_id.to_be_bytes());
-        fixed[4..8].copy_from_slice(&x.to_be_bytes());
-        fixed[8..12].copy_from_slice(&y.to_be_bytes());
-        fixed[12] = if is_hit { 1 } else { 0 };
-        fixed[13..17].copy_from_slice(&sunk_ship.to_be_bytes());
-
-        let mut payload = Bytes::from_array(env, &fixed);
-        payload.append(&Bytes::from_array(env, &board_commitment.to_array()));
-        payload.append(&defender.to_string().to_bytes());
-        payload.append(&shooter.to_string().to_bytes());
-        env.crypto().keccak256(&payload).into()
-    }
-
-    fn opponent(game: &Game, player: &Address) -> Result<Address, Error> {
-        if *player == game.player1 {
-            Ok(game.player2.clone())
-        } else if *player == game.player2 {
-            Ok(game.player1.clone())
-        } else {
-            Err(Error::NotPlayer)
-        }
+    pub fn set_final_verifier(env: Env, new_verifier: Address) {
+        Ownable::require_admin(&env);
+        save_address(&env, &DataKey::FinalVerifierAddress, &new_verifier);
     }
 
-    fn coord_to_bit(x: u32, y: u32) -> Result<u128, Error> {
-        if x >= BOARD_SIZE || y >= BOARD_SIZE {
-            return Err(Error::InvalidCoordinate);
-        }
+    pub fn get_reveal_verifier(env: Env) -> Option<Address> {
+        load_address(&env, &DataKey::RevealVerifierAddress)
+    }
+
+    pub fn set_reveal_verifier(env: Env, new_verifier: Address) {
+        Ownable::require_admin(&env);
+        save_address(&env, &DataKey::RevealVerifierAddress, &new_verifier);
+    }
+
+    /// Games started while this is set disclose sunk ship placements (see `resolve_sinking_shot`).
+    pub fn get_disclosure_verifier(env: Env) -> Option<Address> {
+        load_address(&env, &DataKey::DisclosureVerifierAddress)
+    }
 
-        let index = y * BOARD_SIZE + x;
-        Ok(1u128 << index)
+    pub fn set_disclosure_verifier(env: Env, new_verifier: Address) {
+        Ownable::require_admin(&env);
+        save_address(&env, &DataKey::DisclosureVerifierAddress, &new_verifier);
     }
 
-    fn parse_ship_type(raw: u32) -> Result<Option<ShipType>, Error> {
-        match raw {
-            0 => Ok(None),
-            1 => Ok(Some(ShipType::Carrier)),
-            2 => Ok(Some(ShipType::Battleship)),
-            3 => Ok(Some(ShipType::Cruiser)),
-            4 => Ok(Some(ShipType::Submarine)),
-            5 => Ok(Some(ShipType::Destroyer)),
-            _ => Err(Error::InvalidShipType),
+    /// Sessions the player has started that have not ended or expired yet.
+    pub fn get_active_sessions(env: Env, player: Address) -> Vec<u32> {
+        load_active_sessions(&env, &player)
+    }
+
+    /// Maximum concurrent sessions per player; 0 means unlimited.
+    pub fn get_max_active_games(env: Env) -> u32 {
+        load_max_active_games(&env)
+    }
+
+    pub fn set_max_active_games(env: Env, max_active_games: u32) {
+        Ownable::require_admin(&env);
+        save_max_active_games(&env, max_active_games);
+    }
+
+    /// Resolved shots (both sides together) after which new games end on the hit differential,
+    /// with equal hits going to the defender of the capping shot; 0 means no cap.
+    pub fn get_max_shots(env: Env) -> u32 {
+        load_max_shots(&env)
+    }
+
+    /// Applies to games started afterwards; running games keep the cap they started with.
+    pub fn set_max_shots(env: Env, max_shots: u32) {
+        Ownable::require_admin(&env);
+        save_max_shots(&env, max_shots);
+    }
+
+    /// Smallest stake `start_game` accepts from each player (1 unless set).
+    pub fn get_min_stake(env: Env) -> i128 {
+        load_min_stake(&env)
+    }
+
+    /// Must be positive and, when a max is set, not above it.
+    pub fn set_min_stake(env: Env, min_stake: i128) -> Result<(), Error> {
+        Ownable::require_admin(&env);
+        let max_stake = load_max_stake(&env);
+        if min_stake <= 0 || (max_stake > 0 && min_stake > max_stake) {
+            return Err(Error::InvalidStake);
         }
+        save_min_stake(&env, min_stake);
+        Ok(())
+    }
+
+    /// Largest stake `start_game` accepts from each player; 0 means no cap.
+    pub fn get_max_stake(env: Env) -> i128 {
+        load_max_stake(&env)
     }
 
-    fn ship_bit(ship: ShipType) -> u32 {
-        match ship {
-            ShipType::Carrier => 1 << 0,
-            ShipType::Battleship => 1 << 1,
-            ShipType::Cruiser => 1 << 2,
-            ShipType::Submarine => 1 << 3,
-            ShipType::Destroyer => 1 << 4,
+    /// 0 removes the cap; otherwise it must not be below the min stake.
+    pub fn set_max_stake(env: Env, max_stake: i128) -> Result<(), Error> {
+        Ownable::require_admin(&env);
+        if max_stake < 0 || (max_stake > 0 && max_stake < load_min_stake(&env)) {
+            return Err(Error::InvalidStake);
         }
+        save_max_stake(&env, max_stake);
+        Ok(())
+    }
+
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        Ownable::upgrade(&env, new_wasm_hash);
+    }
+}
+
+/// Debugging entrypoints, only compiled with the `game-import` feature (and in unit tests). Never
+/// enable the feature in a deployed build.
+#[cfg(any(test, feature = "game-import"))]
+#[contractimpl]
+impl BattleshipContract {
+    /// Store `game`, as exported by `get_game`, under `session_id`, replacing any game there, so a
+    /// reported session can be replayed locally against the real contract code. The state is taken
+    /// as is: the move log and the players' active sessions are not restored.
+    pub fn import_game(env: Env, session_id: u32, game: Game) {
+        Ownable::require_admin(&env);
+        infrastructure::storage::save_game(&env, session_id, &game);
     }
 }
 
@@ -1,14 +1,23 @@
 #![cfg(test)]
 
-use crate::{BattleshipContract, BattleshipContractClient, Error, GamePhase};
-use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Bytes, BytesN, Env};
+use crate::{
+    BattleshipContract, BattleshipContractClient, BoardError, BoardLayout, ChatAnchor, Error,
+    FinalShotArgs, GameMove, GamePhase, GameStatus, Metrics, SessionMarker, ShipPlacement,
+    ShooterStats, SinkingShotArgs, StorageTier, SunkPlacement, Team,
+};
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger as _};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, panic_with_error, symbol_short, vec, Address, Bytes,
+    BytesN, Env, Event as _, String,
+};
 
 #[contracttype]
 #[derive(Clone)]
 enum HubDataKey {
     Started(u32),
     Ended(u32),
+    Player1Won(u32),
+    Failing,
 }
 
 #[contract]
@@ -30,10 +39,25 @@ impl MockGameHub {
             .set(&HubDataKey::Started(session_id), &true);
     }
 
-    pub fn end_game(env: Env, session_id: u32, _player1_won: bool) {
+    pub fn end_game(env: Env, session_id: u32, player1_won: bool) {
+        if env
+            .storage()
+            .instance()
+            .get(&HubDataKey::Failing)
+            .unwrap_or(false)
+        {
+            panic!("hub unavailable");
+        }
         env.storage()
             .persistent()
             .set(&HubDataKey::Ended(session_id), &true);
+        env.storage()
+            .persistent()
+            .set(&HubDataKey::Player1Won(session_id), &player1_won);
+    }
+
+    pub fn set_failing(env: Env, failing: bool) {
+        env.storage().instance().set(&HubDataKey::Failing, &failing);
     }
 
     pub fn was_started(env: Env, session_id: u32) -> bool {
@@ -43,6 +67,12 @@ impl MockGameHub {
             .unwrap_or(false)
     }
 
+    pub fn player1_won(env: Env, session_id: u32) -> Option<bool> {
+        env.storage()
+            .persistent()
+            .get(&HubDataKey::Player1Won(session_id))
+    }
+
     pub fn was_ended(env: Env, session_id: u32) -> bool {
         env.storage()
             .persistent()
@@ -57,7 +87,7 @@ pub struct MockVerifier;
 #[contractimpl]
 impl MockVerifier {
     pub fn verify(
-        _env: Env,
+        env: Env,
         _board_commitment: BytesN<32>,
         _public_inputs_hash: BytesN<32>,
         proof_payload: Bytes,
@@ -66,8 +96,11 @@ impl MockVerifier {
             return false;
         }
 
-        // Convention for tests: first byte 1 => valid proof
-        proof_payload.get(0).unwrap() == 1
+        // Convention for tests: first byte 1 => valid proof, 2 => abort like a malformed payload
+        match proof_payload.get(0).unwrap() {
+            2 => panic_with_error!(&env, soroban_sdk::Error::from_contract_error(4)),
+            first => first == 1,
+        }
     }
 }
 
@@ -121,6 +154,37 @@ fn assert_battleship_error<T, E>(
     }
 }
 
+fn failed_error_context(env: &Env) -> Option<(u32, u32, u32)> {
+    use soroban_sdk::xdr::{ContractEventBody, ScVal};
+
+    // Events of failed calls are dropped by `events().all()`, so read the host buffer directly.
+    let events = env.host().get_events().unwrap().0;
+    events.iter().rev().find_map(|e| {
+        if !e.failed_call {
+            return None;
+        }
+        let ContractEventBody::V0(body) = &e.event.body;
+        match body.topics.first() {
+            Some(ScVal::Symbol(name)) if name.to_utf8_string_lossy() == "error_context" => {}
+            _ => return None,
+        }
+        let session_id = match body.topics.get(2) {
+            Some(ScVal::U32(v)) => *v,
+            _ => return None,
+        };
+        let ScVal::Map(Some(map)) = &body.data else {
+            return None;
+        };
+        let field = |name: &str| {
+            map.iter().find_map(|entry| match (&entry.key, &entry.val) {
+                (ScVal::Symbol(k), ScVal::U32(v)) if k.to_utf8_string_lossy() == name => Some(*v),
+                _ => None,
+            })
+        };
+        Some((session_id, field("code")?, field("ledger")?))
+    })
+}
+
 fn valid_proof(env: &Env) -> Bytes {
     Bytes::from_array(env, &[1u8])
 }
@@ -442,13 +506,16 @@ fn test_win_at_17_hits_ends_in_game_hub() {
 
     let game = client.get_game(&session_id);
     assert_eq!(game.phase, GamePhase::Ended);
-    assert_eq!(game.winner, Some(player1));
+    assert_eq!(game.winner, Some(player1.clone()));
+    assert_eq!(game.hub_seats.player1, player1);
+    assert_eq!(game.hub_seats.player2, player2);
     assert_eq!(game.hits_on_p2, 17);
     // All 17 hits should have corresponding bits set in the hits bitmap
     assert_eq!(game.hits_p1_to_p2.count_ones(), 17);
     // Player2 never hit anything
     assert_eq!(game.hits_p2_to_p1, 0);
     assert!(hub.was_ended(&session_id));
+    assert_eq!(hub.player1_won(&session_id), Some(true));
 }
 
 #[test]
@@ -464,3 +531,1981 @@ fn test_rules_expose_standard_ship_sizes() {
     assert_eq!(rules.destroyer_len, 2);
     assert_eq!(rules.total_ship_cells, 17);
 }
+
+#[test]
+fn test_recommit_board_replaces_commitment() {
+    let (env, client, _hub, player1, player2, board1, board2) = setup_test();
+
+    let session_id = 8u32;
+    client.start_game(&session_id, &player1, &player2, &1, &1);
+    client.commit_board(&session_id, &player1, &board1);
+    client.commit_board(&session_id, &player2, &board2);
+
+    let new_board2 = BytesN::from_array(&env, &[33u8; 32]);
+
+    let result = client.try_recommit_board(&session_id, &player2, &new_board2, &valid_proof(&env));
+    assert_battleship_error(&result, Error::RecommitNotConfigured);
+
+    let recommit_verifier = env.register(MockVerifier, ());
+    client.set_recommit_verifier(&recommit_verifier);
+    assert_eq!(client.get_recommit_verifier(), Some(recommit_verifier));
+
+    let result = client.try_recommit_board(&session_id, &player2, &board2, &valid_proof(&env));
+    assert_battleship_error(&result, Error::CommitmentUnchanged);
+
+    let result =
+        client.try_recommit_board(&session_id, &player2, &new_board2, &invalid_proof(&env));
+    assert_battleship_error(&result, Error::InvalidProof);
+
+    client.recommit_board(&session_id, &player2, &new_board2, &valid_proof(&env));
+    let game = client.get_game(&session_id);
+    assert_eq!(game.board_commitment_p2, Some(new_board2.clone()));
+    assert_eq!(game.board_commitment_p1, Some(board1));
+
+    // Subsequent resolutions bind to the refreshed commitment.
+    client.fire(&session_id, &player1, &4, &4);
+    resolve_pending(
+        &client,
+        session_id,
+        &player2,
+        &player1,
+        4,
+        4,
+        false,
+        0,
+        &new_board2,
+        &valid_proof(&env),
+    );
+    assert_eq!(client.get_game(&session_id).turn, Some(player2));
+}
+
+#[test]
+fn test_recommit_board_requires_committed_board() {
+    let (env, client, _hub, player1, player2, board1, _board2) = setup_test();
+
+    let session_id = 9u32;
+    client.start_game(&session_id, &player1, &player2, &1, &1);
+    client.commit_board(&session_id, &player1, &board1);
+
+    let recommit_verifier = env.register(MockVerifier, ());
+    client.set_recommit_verifier(&recommit_verifier);
+
+    let new_board = BytesN::from_array(&env, &[44u8; 32]);
+    let result = client.try_recommit_board(&session_id, &player2, &new_board, &valid_proof(&env));
+    assert_battleship_error(&result, Error::BoardNotCommitted);
+
+    let outsider = Address::generate(&env);
+    let result = client.try_recommit_board(&session_id, &outsider, &new_board, &valid_proof(&env));
+    assert_battleship_error(&result, Error::NotPlayer);
+}
+
+#[test]
+fn test_failed_call_publishes_error_context() {
+    let (env, client, _hub, player1, player2, board1, board2) = setup_test();
+
+    let session_id = 10u32;
+    client.start_game(&session_id, &player1, &player2, &1, &1);
+    client.commit_board(&session_id, &player1, &board1);
+    client.commit_board(&session_id, &player2, &board2);
+
+    let result = client.try_fire(&session_id, &player2, &0, &0);
+    assert_battleship_error(&result, Error::NotYourTurn);
+    assert_eq!(
+        failed_error_context(&env),
+        Some((
+            session_id,
+            Error::NotYourTurn as u32,
+            env.ledger().sequence()
+        ))
+    );
+}
+
+#[test]
+fn test_max_active_games_per_player() {
+    let (env, client, _hub, player1, player2, board1, board2) = setup_test();
+    let player3 = Address::generate(&env);
+
+    assert_eq!(client.get_max_active_games(), 0);
+    client.set_max_active_games(&2);
+
+    client.start_game(&11, &player1, &player2, &1, &1);
+    client.start_game(&12, &player1, &player3, &1, &1);
+    assert_eq!(client.get_active_sessions(&player1).len(), 2);
+    assert_eq!(client.get_active_sessions(&player2).len(), 1);
+
+    let result = client.try_start_game(&13, &player2, &player1, &1, &1);
+    assert_battleship_error(&result, Error::TooManyActiveGames);
+
+    // Player2 still has room with another opponent.
+    client.start_game(&14, &player2, &player3, &1, &1);
+
+    // Finishing a game frees a slot.
+    client.commit_board(&11, &player1, &board1);
+    client.commit_board(&11, &player2, &board2);
+    for i in 0..17u32 {
+        client.fire(&11, &player1, &(i % 10), &(i / 10));
+        resolve_pending(
+            &client,
+            11,
+            &player2,
+            &player1,
+            i % 10,
+            i / 10,
+            true,
+            0,
+            &board2,
+            &valid_proof(&env),
+        );
+        if i == 16 {
+            break;
+        }
+        client.fire(&11, &player2, &(9 - i % 10), &(9 - i / 10));
+        resolve_pending(
+            &client,
+            11,
+            &player1,
+            &player2,
+            9 - i % 10,
+            9 - i / 10,
+            false,
+            0,
+            &board1,
+            &valid_proof(&env),
+        );
+    }
+    assert_eq!(client.get_game(&11).phase, GamePhase::Ended);
+    assert_eq!(client.get_active_sessions(&player1).len(), 1);
+
+    client.start_game(&13, &player2, &player1, &1, &1);
+}
+
+#[test]
+fn test_start_game_enforces_stake_bounds() {
+    let (env, client, _hub, player1, player2, _board1, _board2) = setup_test();
+
+    assert_eq!(client.get_min_stake(), 1);
+    assert_eq!(client.get_max_stake(), 0);
+    let result = client.try_start_game(&1, &player1, &player2, &0, &1);
+    assert_battleship_error(&result, Error::InvalidStake);
+    let result = client.try_start_game(&1, &player1, &player2, &1, &-5);
+    assert_battleship_error(&result, Error::InvalidStake);
+
+    client.set_min_stake(&10);
+    client.set_max_stake(&100);
+    let rules = client.get_rules();
+    assert_eq!((rules.min_stake, rules.max_stake), (10, 100));
+    let result = client.try_start_game(&1, &player1, &player2, &9, &50);
+    assert_battleship_error(&result, Error::InvalidStake);
+    let result = client.try_start_game(&1, &player1, &player2, &50, &101);
+    assert_battleship_error(&result, Error::InvalidStake);
+    client.start_game(&1, &player1, &player2, &10, &100);
+
+    // Bounds must stay positive and ordered; a max of 0 lifts the cap.
+    assert_battleship_error(&client.try_set_min_stake(&0), Error::InvalidStake);
+    assert_battleship_error(&client.try_set_min_stake(&101), Error::InvalidStake);
+    assert_battleship_error(&client.try_set_max_stake(&9), Error::InvalidStake);
+    client.set_max_stake(&0);
+    client.start_game(&2, &player1, &player2, &10, &1_000_000);
+
+    // Practice games stake nothing and skip the check.
+    let player3 = Address::generate(&env);
+    client.start_practice_game(&3, &player1, &player3);
+}
+
+#[test]
+fn test_move_count_numbers_every_action() {
+    let (env, client, _hub, player1, player2, board1, board2) = setup_test();
+    let session_id = 8u32;
+
+    client.start_game(&session_id, &player1, &player2, &1, &1);
+    assert_eq!(client.get_move_count(&session_id), 0);
+    client.commit_board(&session_id, &player1, &board1);
+    client.commit_board(&session_id, &player2, &board2);
+    client.fire(&session_id, &player1, &0, &0);
+    client.cancel_shot(&session_id, &player1);
+    client.fire(&session_id, &player1, &1, &0);
+    assert_eq!(
+        env.events().all().events().last().unwrap(),
+        &GameMove {
+            session_id,
+            move_count: 5,
+            action: symbol_short!("fire"),
+            actor: Some(player1.clone()),
+        }
+        .to_xdr(&env, &client.address)
+    );
+    assert_eq!(client.get_move_count(&session_id), 5);
+
+    let hash = client.build_public_inputs_hash(
+        &session_id,
+        &player2,
+        &player1,
+        &1,
+        &0,
+        &true,
+        &0,
+        &board2,
+    );
+    let result = client.resolve_shot(&session_id, &player2, &true, &0, &valid_proof(&env), &hash);
+    assert_eq!(result.move_count, 6);
+    assert_eq!(client.get_game(&session_id).move_count, 6);
+
+    // Rejected actions are not numbered.
+    let result = client.try_fire(&session_id, &player1, &2, &0);
+    assert_battleship_error(&result, Error::NotYourTurn);
+    assert_eq!(client.get_move_count(&session_id), 6);
+}
+
+#[test]
+fn test_move_log_records_every_resolution_across_chunks() {
+    let (env, client, _hub, player1, player2, board1, board2) = setup_test();
+
+    let session_id = 15u32;
+    client.start_game(&session_id, &player1, &player2, &1, &1);
+    client.commit_board(&session_id, &player1, &board1);
+    client.commit_board(&session_id, &player2, &board2);
+
+    // 30 resolutions spans two 25-move chunks.
+    for i in 0..15u32 {
+        client.fire(&session_id, &player1, &(i % 10), &(i / 10));
+        resolve_pending(
+            &client,
+            session_id,
+            &player2,
+            &player1,
+            i % 10,
+            i / 10,
+            i % 2 == 0,
+            0,
+            &board2,
+            &valid_proof(&env),
+        );
+        client.fire(&session_id, &player2, &(9 - i % 10), &(9 - i / 10));
+        resolve_pending(
+            &client,
+            session_id,
+            &player1,
+            &player2,
+            9 - i % 10,
+            9 - i / 10,
+            false,
+            0,
+            &board1,
+            &valid_proof(&env),
+        );
+    }
+
+    let all = client.get_moves(&session_id, &0, &100);
+    assert_eq!(all.len(), 30);
+    for (i, record) in all.iter().enumerate() {
+        assert_eq!(record.index, i as u32);
+        assert_eq!(record.shooter_is_player1, i % 2 == 0);
+    }
+    let first = all.get(0).unwrap();
+    assert_eq!((first.x, first.y, first.is_hit), (0, 0, true));
+    let second = all.get(1).unwrap();
+    assert_eq!((second.x, second.y, second.is_hit), (9, 9, false));
+
+    // Incremental read across the chunk boundary.
+    let tail = client.get_moves(&session_id, &24, &3);
+    assert_eq!(tail.len(), 3);
+    assert_eq!(tail.get(0).unwrap().index, 24);
+    assert_eq!(tail.get(2).unwrap().index, 26);
+
+    assert_eq!(client.get_moves(&session_id, &30, &10).len(), 0);
+}
+
+#[test]
+fn test_final_shot_requires_consistency_proof_when_configured() {
+    let (env, client, hub, player1, player2, board1, board2) = setup_test();
+
+    let final_verifier = env.register(MockVerifier, ());
+    client.set_final_verifier(&final_verifier);
+    assert_eq!(client.get_final_verifier(), Some(final_verifier));
+
+    let session_id = 30u32;
+    client.start_game(&session_id, &player1, &player2, &1, &1);
+    client.commit_board(&session_id, &player1, &board1);
+    client.commit_board(&session_id, &player2, &board2);
+
+    for i in 0..17u32 {
+        client.fire(&session_id, &player1, &(i % 10), &(i / 10));
+        if i < 16 {
+            resolve_pending(
+                &client,
+                session_id,
+                &player2,
+                &player1,
+                i % 10,
+                i / 10,
+                true,
+                0,
+                &board2,
+                &valid_proof(&env),
+            );
+            client.fire(&session_id, &player2, &(i % 10), &(9 - i / 10));
+            resolve_pending(
+                &client,
+                session_id,
+                &player1,
+                &player2,
+                i % 10,
+                9 - i / 10,
+                false,
+                0,
+                &board1,
+                &valid_proof(&env),
+            );
+        }
+    }
+
+    let hash = client.build_public_inputs_hash(
+        &session_id,
+        &player2,
+        &player1,
+        &6,
+        &1,
+        &true,
+        &0,
+        &board2,
+    );
+
+    // Plain resolution of the winning hit is refused
+    let plain =
+        client.try_resolve_shot(&session_id, &player2, &true, &0, &valid_proof(&env), &hash);
+    assert_battleship_error(&plain, Error::FinalProofRequired);
+
+    let bad = client.try_resolve_final_shot(
+        &session_id,
+        &FinalShotArgs {
+            defender: player2.clone(),
+            is_hit: true,
+            sunk_ship: 0,
+            proof_payload: valid_proof(&env),
+            public_inputs_hash: hash.clone(),
+            consistency_proof: invalid_proof(&env),
+        },
+    );
+    assert_battleship_error(&bad, Error::InvalidProof);
+
+    let result = client.resolve_final_shot(
+        &session_id,
+        &FinalShotArgs {
+            defender: player2.clone(),
+            is_hit: true,
+            sunk_ship: 0,
+            proof_payload: valid_proof(&env),
+            public_inputs_hash: hash.clone(),
+            consistency_proof: valid_proof(&env),
+        },
+    );
+    assert_eq!(result.winner, Some(player1));
+    assert!(hub.was_ended(&session_id));
+}
+
+#[test]
+fn test_final_shot_rejected_before_winning_hit() {
+    let (env, client, _hub, player1, player2, board1, board2) = setup_test();
+
+    let session_id = 31u32;
+    client.start_game(&session_id, &player1, &player2, &1, &1);
+    client.commit_board(&session_id, &player1, &board1);
+    client.commit_board(&session_id, &player2, &board2);
+    client.fire(&session_id, &player1, &0, &0);
+
+    let hash = client.build_public_inputs_hash(
+        &session_id,
+        &player2,
+        &player1,
+        &0,
+        &0,
+        &true,
+        &0,
+        &board2,
+    );
+    let unconfigured = client.try_resolve_final_shot(
+        &session_id,
+        &FinalShotArgs {
+            defender: player2.clone(),
+            is_hit: true,
+            sunk_ship: 0,
+            proof_payload: valid_proof(&env),
+            public_inputs_hash: hash.clone(),
+            consistency_proof: valid_proof(&env),
+        },
+    );
+    assert_battleship_error(&unconfigured, Error::FinalVerifierNotConfigured);
+
+    client.set_final_verifier(&env.register(MockVerifier, ()));
+    let early = client.try_resolve_final_shot(
+        &session_id,
+        &FinalShotArgs {
+            defender: player2.clone(),
+            is_hit: true,
+            sunk_ship: 0,
+            proof_payload: valid_proof(&env),
+            public_inputs_hash: hash.clone(),
+            consistency_proof: valid_proof(&env),
+        },
+    );
+    assert_battleship_error(&early, Error::NotFinalShot);
+
+    // Non-final shots still resolve normally
+    client.resolve_shot(&session_id, &player2, &true, &0, &valid_proof(&env), &hash);
+}
+
+#[test]
+fn test_reveal_board_after_game_end() {
+    let (env, client, _hub, player1, player2, board1, board2) = setup_test();
+
+    let session_id = 32u32;
+    client.start_game(&session_id, &player1, &player2, &1, &1);
+    client.commit_board(&session_id, &player1, &board1);
+    client.commit_board(&session_id, &player2, &board2);
+
+    // Player2's fleet fills row 0 and the first 7 cells of row 1
+    let layout = BoardLayout {
+        ship_x: vec![&env, 0, 0, 5, 4, 8],
+        ship_y: vec![&env, 0, 1, 0, 1, 0],
+        ship_dir: vec![&env, 1, 1, 1, 1, 1],
+    };
+
+    let early = client.try_reveal_board(&session_id, &player2, &layout, &valid_proof(&env));
+    assert_battleship_error(&early, Error::InvalidPhase);
+
+    for i in 0..17u32 {
+        client.fire(&session_id, &player1, &(i % 10), &(i / 10));
+        resolve_pending(
+            &client,
+            session_id,
+            &player2,
+            &player1,
+            i % 10,
+            i / 10,
+            true,
+            0,
+            &board2,
+            &valid_proof(&env),
+        );
+        if i == 16 {
+            break;
+        }
+
+        let (x2, y2) = (9 - i % 10, 9 - i / 10);
+        client.fire(&session_id, &player2, &x2, &y2);
+        resolve_pending(
+            &client,
+            session_id,
+            &player1,
+            &player2,
+            x2,
+            y2,
+            false,
+            0,
+            &board1,
+            &valid_proof(&env),
+        );
+    }
+    assert_eq!(client.get_game(&session_id).phase, GamePhase::Ended);
+
+    let unconfigured = client.try_reveal_board(&session_id, &player2, &layout, &valid_proof(&env));
+    assert_battleship_error(&unconfigured, Error::RevealNotConfigured);
+    client.set_reveal_verifier(&env.register(MockVerifier, ()));
+
+    let missing = client.try_get_revealed_board(&session_id, &player2);
+    assert_battleship_error(&missing, Error::BoardNotRevealed);
+
+    // A layout that contradicts the recorded hits is rejected
+    let shifted = BoardLayout {
+        ship_x: vec![&env, 0, 0, 5, 4, 8],
+        ship_y: vec![&env, 2, 1, 0, 1, 0],
+        ship_dir: vec![&env, 1, 1, 1, 1, 1],
+    };
+    let contradicting =
+        client.try_reveal_board(&session_id, &player2, &shifted, &valid_proof(&env));
+    assert_battleship_error(&contradicting, Error::InvalidLayout);
+
+    let bad_proof = client.try_reveal_board(&session_id, &player2, &layout, &invalid_proof(&env));
+    assert_battleship_error(&bad_proof, Error::InvalidProof);
+
+    client.reveal_board(&session_id, &player2, &layout, &valid_proof(&env));
+    let cells = client.get_revealed_board(&session_id, &player2);
+    assert_eq!(cells.len(), 100);
+    assert_eq!(cells.get(0), Some(1));
+    assert_eq!(cells.get(7), Some(3));
+    assert_eq!(cells.get(9), Some(5));
+    assert_eq!(cells.get(13), Some(2));
+    assert_eq!(cells.get(16), Some(4));
+    assert_eq!(cells.get(17), Some(0));
+
+    let again = client.try_reveal_board(&session_id, &player2, &layout, &valid_proof(&env));
+    assert_battleship_error(&again, Error::BoardAlreadyRevealed);
+}
+
+#[test]
+fn test_sinking_shot_discloses_ship_placement() {
+    let (env, client, _hub, player1, player2, board1, board2) = setup_test();
+
+    // Games started before a disclosure verifier is registered keep plain sinking shots
+    let legacy_session = 33u32;
+    client.start_game(&legacy_session, &player1, &player2, &1, &1);
+    assert!(!client.get_game(&legacy_session).disclose_sunk_ships);
+
+    client.set_disclosure_verifier(&env.register(MockVerifier, ()));
+    assert!(client.get_rules().disclose_sunk_ships);
+
+    let session_id = 34u32;
+    client.start_game(&session_id, &player1, &player2, &1, &1);
+    client.commit_board(&session_id, &player1, &board1);
+    client.commit_board(&session_id, &player2, &board2);
+
+    // Destroyer (ship 5) sits horizontally at (8, 0)
+    client.fire(&session_id, &player1, &8, &0);
+    resolve_pending(
+        &client,
+        session_id,
+        &player2,
+        &player1,
+        8,
+        0,
+        true,
+        0,
+        &board2,
+        &valid_proof(&env),
+    );
+    client.fire(&session_id, &player2, &0, &9);
+    resolve_pending(
+        &client,
+        session_id,
+        &player1,
+        &player2,
+        0,
+        9,
+        false,
+        0,
+        &board1,
+        &valid_proof(&env),
+    );
+    client.fire(&session_id, &player1, &9, &0);
+
+    let hash = client.build_public_inputs_hash(
+        &session_id,
+        &player2,
+        &player1,
+        &9,
+        &0,
+        &true,
+        &5,
+        &board2,
+    );
+    let plain =
+        client.try_resolve_shot(&session_id, &player2, &true, &5, &valid_proof(&env), &hash);
+    assert_battleship_error(&plain, Error::SunkShipDisclosureRequired);
+
+    // A placement over cells the shooter has not hit is rejected
+    let wrong = ShipPlacement { x: 8, y: 1, dir: 1 };
+    let wrong_hash =
+        client.build_disclosure_hash(&session_id, &player2, &player1, &9, &0, &5, &wrong, &board2);
+    let rejected = client.try_resolve_sinking_shot(
+        &session_id,
+        &SinkingShotArgs {
+            defender: player2.clone(),
+            sunk_ship: 5,
+            sunk_placement: wrong,
+            proof_payload: valid_proof(&env),
+            public_inputs_hash: wrong_hash,
+            consistency_proof: None,
+        },
+    );
+    assert_battleship_error(&rejected, Error::InvalidSunkPlacement);
+
+    let placement = ShipPlacement { x: 8, y: 0, dir: 1 };
+    let disclosure_hash = client.build_disclosure_hash(
+        &session_id,
+        &player2,
+        &player1,
+        &9,
+        &0,
+        &5,
+        &placement,
+        &board2,
+    );
+    assert_ne!(disclosure_hash, hash);
+
+    let result = client.resolve_sinking_shot(
+        &session_id,
+        &SinkingShotArgs {
+            defender: player2.clone(),
+            sunk_ship: 5,
+            sunk_placement: placement.clone(),
+            proof_payload: valid_proof(&env),
+            public_inputs_hash: disclosure_hash.clone(),
+            consistency_proof: None,
+        },
+    );
+    assert_eq!(result.sunk_ship, 5);
+    assert_eq!(
+        result.sunk_placement,
+        SunkPlacement::Disclosed(placement.clone())
+    );
+
+    let moves = client.get_moves(&session_id, &0, &10);
+    assert_eq!(moves.len(), 3);
+    assert_eq!(
+        moves.get(0).unwrap().sunk_placement,
+        SunkPlacement::Undisclosed
+    );
+    assert_eq!(
+        moves.get(2).unwrap().sunk_placement,
+        SunkPlacement::Disclosed(placement.clone())
+    );
+
+    // Disclosure is refused in games started without it
+    client.commit_board(&legacy_session, &player1, &board1);
+    client.commit_board(&legacy_session, &player2, &board2);
+    client.fire(&legacy_session, &player1, &9, &0);
+    let legacy = client.try_resolve_sinking_shot(
+        &legacy_session,
+        &SinkingShotArgs {
+            defender: player2.clone(),
+            sunk_ship: 5,
+            sunk_placement: placement.clone(),
+            proof_payload: valid_proof(&env),
+            public_inputs_hash: disclosure_hash.clone(),
+            consistency_proof: None,
+        },
+    );
+    assert_battleship_error(&legacy, Error::DisclosureDisabled);
+}
+
+#[test]
+fn test_session_key_signs_fire_until_expiry() {
+    let (env, client, _hub, player1, player2, board1, board2) = setup_test();
+
+    let session_id = 35u32;
+    client.start_game(&session_id, &player1, &player2, &1, &1);
+    client.commit_board(&session_id, &player1, &board1);
+    client.commit_board(&session_id, &player2, &board2);
+
+    let hot_key = Address::generate(&env);
+    let expired = client.try_authorize_session_key(&session_id, &player1, &hot_key, &100);
+    assert_battleship_error(&expired, Error::InvalidSessionKey);
+    let outsider = client.try_authorize_session_key(&session_id, &hot_key, &hot_key, &200);
+    assert_battleship_error(&outsider, Error::NotPlayer);
+
+    client.authorize_session_key(&session_id, &player1, &hot_key, &200);
+    assert_eq!(
+        client.get_session_key(&session_id, &player1).unwrap().key,
+        hot_key
+    );
+
+    client.fire(&session_id, &player1, &0, &0);
+    assert_eq!(env.auths().first().unwrap().0, hot_key);
+    resolve_pending(
+        &client,
+        session_id,
+        &player2,
+        &player1,
+        0,
+        0,
+        true,
+        0,
+        &board2,
+        &valid_proof(&env),
+    );
+
+    // Player2 has no session key and still signs with the wallet
+    client.fire(&session_id, &player2, &0, &9);
+    assert_eq!(env.auths().first().unwrap().0, player2);
+    resolve_pending(
+        &client,
+        session_id,
+        &player1,
+        &player2,
+        0,
+        9,
+        false,
+        0,
+        &board1,
+        &valid_proof(&env),
+    );
+
+    // Past the expiry ledger the main wallet signs again
+    env.ledger().set_sequence_number(200);
+    assert_eq!(client.get_session_key(&session_id, &player1), None);
+    client.fire(&session_id, &player1, &1, &0);
+    assert_eq!(env.auths().first().unwrap().0, player1);
+
+    client.authorize_session_key(&session_id, &player1, &hot_key, &300);
+    client.revoke_session_key(&session_id, &player1);
+    assert_eq!(client.get_session_key(&session_id, &player1), None);
+}
+
+#[test]
+fn test_start_game_derives_session_id_when_zero() {
+    let (_env, client, hub, player1, player2, _board1, _board2) = setup_test();
+
+    assert_eq!(client.start_game(&36u32, &player1, &player2, &1, &1), 36);
+    assert_eq!(client.get_session_nonce(&player1, &player2), 0);
+
+    // Derived ids live in their own half of the id space: nobody can take the next one first
+    let first = client.get_session_id(&player1, &player2, &0);
+    assert_ne!(first & 0x8000_0000, 0);
+    let squat = client.try_start_game(&first, &player2, &player1, &1, &1);
+    assert_battleship_error(&squat, Error::ReservedSessionId);
+    assert_eq!(client.start_game(&0u32, &player1, &player2, &1, &1), first);
+    assert!(hub.was_started(&first));
+    assert_eq!(client.get_game(&first).player1, player1);
+    assert_eq!(client.get_session_nonce(&player1, &player2), 1);
+
+    let second = client.start_game(&0u32, &player1, &player2, &1, &1);
+    assert_eq!(second, client.get_session_id(&player1, &player2, &1));
+    assert_ne!(first, second);
+
+    // The nonce chain is per ordered pair
+    assert_eq!(client.get_session_nonce(&player2, &player1), 0);
+    assert_ne!(client.get_session_id(&player2, &player1, &0), first);
+}
+
+#[test]
+fn test_game_interface_status_and_hub_cancel() {
+    let (env, client, hub, player1, player2, board1, _board2) = setup_test();
+
+    let session_id = 37u32;
+    client.start_game(&session_id, &player1, &player2, &1, &1);
+    assert_eq!(client.get_game_status(&session_id), GameStatus::Setup);
+    assert_eq!(client.evaluate_game(&session_id), None);
+    client.commit_board(&session_id, &player1, &board1);
+
+    // Only the hub can cancel, and it is not notified back
+    client.cancel_game(&session_id);
+    assert_eq!(env.auths().first().unwrap().0, hub.address);
+    assert!(!hub.was_ended(&session_id));
+    assert_eq!(client.get_game_status(&session_id), GameStatus::Cancelled);
+    assert_eq!(client.evaluate_game(&session_id), None);
+    assert_eq!(client.get_game(&session_id).phase, GamePhase::Ended);
+    assert!(client.get_active_sessions(&player1).is_empty());
+
+    let again = client.try_cancel_game(&session_id);
+    assert_battleship_error(&again, Error::GameAlreadyEnded);
+    let missing = client.try_get_game_status(&(session_id + 1));
+    assert_battleship_error(&missing, Error::GameNotFound);
+}
+
+#[test]
+fn test_shooter_can_retract_shot_within_window() {
+    let (env, client, _hub, player1, player2, board1, board2) = setup_test();
+
+    let session_id = 38u32;
+    client.start_game(&session_id, &player1, &player2, &1, &1);
+    client.commit_board(&session_id, &player1, &board1);
+    client.commit_board(&session_id, &player2, &board2);
+
+    let no_shot = client.try_cancel_shot(&session_id, &player1);
+    assert_battleship_error(&no_shot, Error::NoPendingShot);
+
+    let rules = client.get_rules();
+    for retraction in 1..=rules.max_shot_retractions {
+        client.fire(&session_id, &player1, &0, &0);
+        let not_shooter = client.try_cancel_shot(&session_id, &player2);
+        assert_battleship_error(&not_shooter, Error::NoPendingShot);
+
+        env.ledger()
+            .set_sequence_number(env.ledger().sequence() + rules.shot_retract_window_ledgers);
+        client.cancel_shot(&session_id, &player1);
+
+        let game = client.get_game(&session_id);
+        assert_eq!(game.pending_shot_shooter, None);
+        assert_eq!(game.turn, Some(player1.clone()));
+        assert_eq!(game.retractions_p1, retraction);
+    }
+
+    // Out of retractions, and the window closes after a few ledgers
+    client.fire(&session_id, &player1, &1, &1);
+    let exhausted = client.try_cancel_shot(&session_id, &player1);
+    assert_battleship_error(&exhausted, Error::NoRetractionsLeft);
+    resolve_pending(
+        &client,
+        session_id,
+        &player2,
+        &player1,
+        1,
+        1,
+        false,
+        0,
+        &board2,
+        &valid_proof(&env),
+    );
+
+    client.fire(&session_id, &player2, &2, &2);
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + rules.shot_retract_window_ledgers + 1);
+    let late = client.try_cancel_shot(&session_id, &player2);
+    assert_battleship_error(&late, Error::RetractWindowClosed);
+    assert_eq!(client.get_game(&session_id).retractions_p2, 0);
+}
+
+#[test]
+fn test_check_resolution_diagnoses_without_resolving() {
+    let (env, client, _hub, player1, player2, board1, board2) = setup_test();
+
+    let session_id = 31u32;
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+    client.commit_board(&session_id, &player1, &board1);
+    client.commit_board(&session_id, &player2, &board2);
+
+    let zero_hash = BytesN::from_array(&env, &[0u8; 32]);
+    let idle = client.check_resolution(
+        &session_id,
+        &player2,
+        &false,
+        &0,
+        &valid_proof(&env),
+        &zero_hash,
+    );
+    assert_eq!(idle.error_code, Error::NoPendingShot as u32);
+    assert_eq!(idle.shooter, None);
+
+    client.fire(&session_id, &player1, &3, &4);
+    let hash = client.build_public_inputs_hash(
+        &session_id,
+        &player2,
+        &player1,
+        &3,
+        &4,
+        &true,
+        &0,
+        &board2,
+    );
+
+    // A hash built for the wrong outcome is reported with the one the contract expects
+    let mismatch =
+        client.check_resolution(&session_id, &player2, &false, &0, &valid_proof(&env), &hash);
+    assert_eq!(mismatch.error_code, Error::InvalidPublicInputsHash as u32);
+    assert_eq!(mismatch.shooter, Some(player1.clone()));
+    assert_eq!((mismatch.x, mismatch.y), (3, 4));
+    assert_eq!(mismatch.board_commitment, Some(board2.clone()));
+    assert!(!mismatch.hash_matches);
+    assert_ne!(mismatch.expected_hash, Some(hash.clone()));
+
+    let rejected = client.check_resolution(
+        &session_id,
+        &player2,
+        &true,
+        &0,
+        &invalid_proof(&env),
+        &hash,
+    );
+    assert_eq!(rejected.error_code, Error::InvalidProof as u32);
+    assert!(rejected.hash_matches);
+    assert!(!rejected.proof_valid);
+    assert_eq!(rejected.verifier_error_code, 0);
+
+    let malformed = Bytes::from_array(&env, &[2u8]);
+    let aborted = client.check_resolution(&session_id, &player2, &true, &0, &malformed, &hash);
+    assert_eq!(aborted.error_code, Error::InvalidProof as u32);
+    assert_eq!(aborted.verifier_error_code, 4);
+
+    let ok = client.check_resolution(&session_id, &player2, &true, &0, &valid_proof(&env), &hash);
+    assert_eq!(ok.error_code, 0);
+    assert_eq!(ok.expected_hash, Some(hash.clone()));
+    assert!(ok.proof_valid);
+
+    // Nothing was resolved by the dry runs
+    let game = client.get_game(&session_id);
+    assert_eq!(game.pending_shot_shooter, Some(player1.clone()));
+    assert_eq!(game.hits_on_p2, 0);
+    client.resolve_shot(&session_id, &player2, &true, &0, &valid_proof(&env), &hash);
+}
+
+#[test]
+fn test_failed_hub_notification_is_retried() {
+    let (env, client, hub, player1, player2, board1, board2) = setup_test();
+
+    let session_id = 9u32;
+    client.start_game(&session_id, &player1, &player2, &1, &1);
+    client.commit_board(&session_id, &player1, &board1);
+    client.commit_board(&session_id, &player2, &board2);
+    hub.set_failing(&true);
+    assert_eq!(client.get_last_error_detail(&session_id), None);
+
+    for i in 0..17u32 {
+        client.fire(&session_id, &player1, &(i % 10), &(i / 10));
+        resolve_pending(
+            &client,
+            session_id,
+            &player2,
+            &player1,
+            i % 10,
+            i / 10,
+            true,
+            0,
+            &board2,
+            &valid_proof(&env),
+        );
+        if i == 16 {
+            break;
+        }
+        let (x2, y2) = (9 - i % 10, 9 - i / 10);
+        client.fire(&session_id, &player2, &x2, &y2);
+        resolve_pending(
+            &client,
+            session_id,
+            &player1,
+            &player2,
+            x2,
+            y2,
+            false,
+            0,
+            &board1,
+            &valid_proof(&env),
+        );
+    }
+
+    // The win lands even though the hub rejected end_game.
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, GamePhase::Ended);
+    assert_eq!(game.winner, Some(player1.clone()));
+    assert!(game.pending_hub_notification);
+    assert!(!hub.was_ended(&session_id));
+
+    // The rejection is kept for diagnosis; a panicking hub carries no contract error code.
+    let detail = client.get_last_error_detail(&session_id).unwrap();
+    assert_eq!(detail.namespace, symbol_short!("hub"));
+    assert_eq!(detail.action, symbol_short!("resolve"));
+    assert_eq!(detail.code, 0);
+    assert_eq!(detail.address, Some(hub.address.clone()));
+    assert_eq!(detail.ledger, env.ledger().sequence());
+
+    // Still failing: the retry reverts and the flag stays set.
+    assert!(client.try_retry_hub_notify(&session_id).is_err());
+    assert!(client.get_game(&session_id).pending_hub_notification);
+
+    hub.set_failing(&false);
+    client.retry_hub_notify(&session_id);
+    assert!(hub.was_ended(&session_id));
+    assert!(!client.get_game(&session_id).pending_hub_notification);
+
+    let result = client.try_retry_hub_notify(&session_id);
+    assert_battleship_error(&result, Error::HubNotificationNotPending);
+}
+
+#[test]
+fn test_practice_game_resolves_shots_from_clear_boards() {
+    let (env, client, hub, player1, player2, board1, board2) = setup_test();
+    // Ships stacked along the left edge, one row each in Carrier..Destroyer order.
+    let layout = BoardLayout {
+        ship_x: vec![&env, 0, 0, 0, 0, 0],
+        ship_y: vec![&env, 0, 1, 2, 3, 4],
+        ship_dir: vec![&env, 1, 1, 1, 1, 1],
+    };
+
+    let session_id = 11u32;
+    client.start_practice_game(&session_id, &player1, &player2);
+    assert!(!hub.was_started(&session_id));
+    let proven = client.try_commit_board(&session_id, &player1, &board1);
+    assert_battleship_error(&proven, Error::PracticeGameUnsupported);
+    client.commit_practice_board(&session_id, &player1, &layout);
+    client.commit_practice_board(&session_id, &player2, &layout);
+    assert_eq!(client.get_game(&session_id).phase, GamePhase::InProgress);
+    assert_eq!(
+        client.get_revealed_board(&session_id, &player2).get(10),
+        Some(2)
+    );
+
+    // Proofs are not accepted in practice games.
+    client.fire(&session_id, &player1, &9, &9);
+    let proof_path = client.try_resolve_shot(
+        &session_id,
+        &player2,
+        &false,
+        &0,
+        &valid_proof(&env),
+        &BytesN::from_array(&env, &[0u8; 32]),
+    );
+    assert_battleship_error(&proof_path, Error::PracticeGameUnsupported);
+    let miss = client.resolve_practice_shot(&session_id);
+    assert!(!miss.is_hit);
+
+    let ship_lens = [5u32, 4, 3, 3, 2];
+    let mut p2_shots = 0u32;
+    for (ship, len) in ship_lens.iter().enumerate() {
+        for k in 0..*len {
+            client.fire(
+                &session_id,
+                &player2,
+                &(p2_shots % 10),
+                &(9 - p2_shots / 10),
+            );
+            p2_shots += 1;
+            assert!(!client.resolve_practice_shot(&session_id).is_hit);
+
+            client.fire(&session_id, &player1, &k, &(ship as u32));
+            let result = client.resolve_practice_shot(&session_id);
+            assert!(result.is_hit);
+            let sunk = if k == len - 1 { ship as u32 + 1 } else { 0 };
+            assert_eq!(result.sunk_ship, sunk);
+        }
+    }
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, GamePhase::Ended);
+    assert_eq!(game.winner, Some(player1.clone()));
+    assert_eq!(game.sunk_ships_on_p2, 0b11111);
+    assert!(!game.pending_hub_notification);
+    assert!(!hub.was_ended(&session_id));
+
+    // Practice entrypoints do not apply to proven games.
+    let ranked = 12u32;
+    client.start_game(&ranked, &player1, &player2, &1, &1);
+    let clear = client.try_commit_practice_board(&ranked, &player1, &layout);
+    assert_battleship_error(&clear, Error::NotPracticeGame);
+    client.commit_board(&ranked, &player1, &board1);
+    client.commit_board(&ranked, &player2, &board2);
+    client.fire(&ranked, &player1, &0, &0);
+    let unproven = client.try_resolve_practice_shot(&ranked);
+    assert_battleship_error(&unproven, Error::NotPracticeGame);
+}
+
+#[test]
+fn test_validate_board_matches_placement_rules() {
+    let (env, client, _hub, _player1, _player2, _board1, _board2) = setup_test();
+
+    // Carrier..Destroyer stacked on rows 0..4; touching ships are allowed, as in the circuit.
+    let mut cells = [0u8; 100];
+    for (row, len) in [5usize, 4, 3, 3, 2].iter().enumerate() {
+        for k in 0..*len {
+            cells[row * 10 + k] = row as u8 + 1;
+        }
+    }
+    assert_eq!(
+        client.try_validate_board(&Bytes::from_array(&env, &cells)),
+        Ok(Ok(()))
+    );
+
+    // The Destroyer turned vertical in column 9.
+    let mut vertical = cells;
+    vertical[40] = 0;
+    vertical[41] = 0;
+    vertical[89] = 5;
+    vertical[99] = 5;
+    assert_eq!(
+        client.try_validate_board(&Bytes::from_array(&env, &vertical)),
+        Ok(Ok(()))
+    );
+
+    let mut bent = cells;
+    bent[41] = 0;
+    bent[51] = 5;
+    assert_eq!(
+        client.try_validate_board(&Bytes::from_array(&env, &bent)),
+        Err(Ok(BoardError::ShipNotInLine))
+    );
+
+    // A run that wraps from the end of one row to the start of the next is not straight.
+    let mut wrapped = cells;
+    wrapped[40] = 0;
+    wrapped[41] = 0;
+    wrapped[59] = 5;
+    wrapped[60] = 5;
+    assert_eq!(
+        client.try_validate_board(&Bytes::from_array(&env, &wrapped)),
+        Err(Ok(BoardError::ShipNotInLine))
+    );
+
+    let mut short = cells;
+    short[4] = 0;
+    assert_eq!(
+        client.try_validate_board(&Bytes::from_array(&env, &short)),
+        Err(Ok(BoardError::InvalidShipLength))
+    );
+
+    let mut unknown = cells;
+    unknown[99] = 6;
+    assert_eq!(
+        client.try_validate_board(&Bytes::from_array(&env, &unknown)),
+        Err(Ok(BoardError::InvalidCell))
+    );
+
+    assert_eq!(
+        client.try_validate_board(&Bytes::from_array(&env, &[0u8; 99])),
+        Err(Ok(BoardError::InvalidBoardSize))
+    );
+}
+
+#[test]
+fn test_won_game_archives_shooter_stats() {
+    let (env, client, _hub, player1, player2, board1, board2) = setup_test();
+
+    let session_id = 47u32;
+    client.start_game(&session_id, &player1, &player2, &1, &1);
+    client.commit_board(&session_id, &player1, &board1);
+    client.commit_board(&session_id, &player2, &board2);
+
+    // Player 1 hits cells 0..16 with one miss after the fifth hit; player 2 opens with 3 hits.
+    for turn in 0..18u32 {
+        let (x1, y1, is_hit) = match turn {
+            5 => (9, 9, false),
+            t if t < 5 => (t % 10, t / 10, true),
+            t => ((t - 1) % 10, (t - 1) / 10, true),
+        };
+        client.fire(&session_id, &player1, &x1, &y1);
+        resolve_pending(
+            &client,
+            session_id,
+            &player2,
+            &player1,
+            x1,
+            y1,
+            is_hit,
+            0,
+            &board2,
+            &valid_proof(&env),
+        );
+        if turn == 17 {
+            break;
+        }
+        assert_battleship_error(
+            &client.try_get_archived_stats(&session_id),
+            Error::StatsNotArchived,
+        );
+
+        let (x2, y2) = (9 - turn % 10, 9 - turn / 10);
+        client.fire(&session_id, &player2, &x2, &y2);
+        resolve_pending(
+            &client,
+            session_id,
+            &player1,
+            &player2,
+            x2,
+            y2,
+            turn < 3,
+            0,
+            &board1,
+            &valid_proof(&env),
+        );
+    }
+
+    let stats = client.get_archived_stats(&session_id);
+    assert_eq!(stats.winner, player1);
+    assert_eq!(stats.player1, player1);
+    assert_eq!(stats.player2, player2);
+    assert_eq!(
+        stats.player1_stats,
+        ShooterStats {
+            shots: 18,
+            hits: 17,
+            accuracy_bps: 9_444,
+            longest_hit_streak: 12,
+        }
+    );
+    assert_eq!(
+        stats.player2_stats,
+        ShooterStats {
+            shots: 17,
+            hits: 3,
+            accuracy_bps: 1_764,
+            longest_hit_streak: 3,
+        }
+    );
+}
+
+#[test]
+fn test_team_game_alternates_shooters_on_shared_boards() {
+    let (env, client, hub, player1, player2, board1, board2) = setup_test();
+    let partner1 = Address::generate(&env);
+    let partner2 = Address::generate(&env);
+    let team1 = Team {
+        lead: player1.clone(),
+        partner: partner1.clone(),
+    };
+    let team2 = Team {
+        lead: player2.clone(),
+        partner: partner2.clone(),
+    };
+
+    let clash = Team {
+        lead: player2.clone(),
+        partner: partner1.clone(),
+    };
+    let result = client.try_start_team_game(&0, &team1, &clash, &1, &1);
+    assert_battleship_error(&result, Error::SelfPlayNotAllowed);
+
+    let session_id = 48u32;
+    client.start_team_game(&session_id, &team1, &team2, &1, &1);
+    assert!(hub.was_started(&session_id));
+    assert_eq!(
+        client.get_active_sessions(&partner2),
+        vec![&env, session_id]
+    );
+
+    // Either member commits the team's board, once.
+    client.commit_board(&session_id, &partner1, &board1);
+    let result = client.try_commit_board(&session_id, &player1, &board1);
+    assert_battleship_error(&result, Error::BoardAlreadyCommitted);
+    client.commit_board(&session_id, &player2, &board2);
+
+    // Team 1 opens with a miss, then hits cells 0..16; team 2 always misses.
+    let expected_order = [&player1, &player2, &partner1, &partner2, &player1];
+    let mut team1_shot = 0u32;
+    let mut team2_shot = 0u32;
+    let mut turns = 0usize;
+    loop {
+        let game = client.get_game(&session_id);
+        if game.phase == GamePhase::Ended {
+            break;
+        }
+        let shooter = game.turn.unwrap();
+        if let Some(expected) = expected_order.get(turns) {
+            assert_eq!(&&shooter, expected);
+        }
+        turns += 1;
+
+        if shooter == player1 || shooter == partner1 {
+            let (x, y, is_hit) = match team1_shot {
+                0 => (9, 9, false),
+                n => ((n - 1) % 10, (n - 1) / 10, true),
+            };
+            team1_shot += 1;
+            client.fire(&session_id, &shooter, &x, &y);
+            resolve_pending(
+                &client,
+                session_id,
+                &player2,
+                &shooter,
+                x,
+                y,
+                is_hit,
+                0,
+                &board2,
+                &valid_proof(&env),
+            );
+        } else {
+            let (x, y) = (9 - team2_shot % 10, 9 - team2_shot / 10);
+            team2_shot += 1;
+            if shooter == partner2 {
+                // Out of turn: the lead cannot fire for the partner.
+                let result = client.try_fire(&session_id, &player2, &x, &y);
+                assert_battleship_error(&result, Error::NotYourTurn);
+            }
+            client.fire(&session_id, &shooter, &x, &y);
+            resolve_pending(
+                &client,
+                session_id,
+                &player1,
+                &shooter,
+                x,
+                y,
+                false,
+                0,
+                &board1,
+                &valid_proof(&env),
+            );
+        }
+    }
+
+    // The partner landed the 18th and final shot; the team is reported through its lead.
+    let game = client.get_game(&session_id);
+    assert_eq!(team1_shot, 18);
+    assert_eq!(game.last_resolved_shooter, Some(partner1.clone()));
+    assert_eq!(game.winner, Some(player1.clone()));
+    assert_eq!(game.hits_on_p2, 17);
+    assert!(hub.was_ended(&session_id));
+    for member in [&player1, &partner1, &player2, &partner2] {
+        assert_eq!(client.get_active_sessions(member).len(), 0);
+    }
+}
+
+#[test]
+fn test_max_shots_adjudicates_on_hit_differential() {
+    let (env, client, hub, player1, player2, board1, board2) = setup_test();
+    let proof = valid_proof(&env);
+
+    // Games started before the cap is set stay uncapped.
+    let uncapped = 60u32;
+    client.start_game(&uncapped, &player1, &player2, &1, &1);
+    client.set_max_shots(&4);
+    assert_eq!(client.get_max_shots(), 4);
+    assert_eq!(client.get_rules().max_shots, 4);
+
+    // Commit both boards and play four shots; only player1's first may hit.
+    let play = |session_id: u32, p1_hits: bool| {
+        client.commit_board(&session_id, &player1, &board1);
+        client.commit_board(&session_id, &player2, &board2);
+        // (shooter, defender, defender board, x, is_hit)
+        for (shooter, defender, board, x, is_hit) in [
+            (&player1, &player2, &board2, 0, p1_hits),
+            (&player2, &player1, &board1, 0, false),
+            (&player1, &player2, &board2, 1, false),
+            (&player2, &player1, &board1, 1, false),
+        ] {
+            client.fire(&session_id, shooter, &x, &0);
+            resolve_pending(
+                &client, session_id, defender, shooter, x, 0, is_hit, 0, board, &proof,
+            );
+        }
+    };
+
+    let won = 61u32;
+    client.start_game(&won, &player1, &player2, &1, &1);
+    play(won, true);
+    let game = client.get_game(&won);
+    assert_eq!(game.phase, GamePhase::Ended);
+    assert!(game.adjudicated);
+    assert_eq!(game.winner, Some(player1.clone()));
+    assert_eq!(client.get_game_status(&won), GameStatus::Ended);
+    assert_eq!(hub.player1_won(&won), Some(true));
+    assert_eq!(client.get_archived_stats(&won).winner, player1);
+
+    // Equal hits go to the defender of the capping shot: player2 fired it, so player1 wins and
+    // the hub settles the stakes.
+    let tied = 62u32;
+    client.start_game(&tied, &player1, &player2, &1, &1);
+    play(tied, false);
+    let game = client.get_game(&tied);
+    assert_eq!(game.phase, GamePhase::Ended);
+    assert!(game.adjudicated);
+    assert_eq!(game.winner, Some(player1.clone()));
+    assert!(!game.pending_hub_notification);
+    assert_eq!(client.get_game_status(&tied), GameStatus::Ended);
+    assert_eq!(client.evaluate_game(&tied), Some(player1.clone()));
+    assert!(hub.was_ended(&tied));
+    assert_eq!(hub.player1_won(&tied), Some(true));
+    let more = client.try_fire(&tied, &player1, &2, &0);
+    assert_battleship_error(&more, Error::GameAlreadyEnded);
+    assert_eq!(client.get_active_sessions(&player1), vec![&env, uncapped]);
+
+    play(uncapped, true);
+    assert_eq!(client.get_game(&uncapped).phase, GamePhase::InProgress);
+}
+
+#[test]
+fn test_committed_shot_hides_cell_until_revealed() {
+    let (env, client, _hub, player1, player2, board1, board2) = setup_test();
+
+    let session_id = 62u32;
+    client.start_game(&session_id, &player1, &player2, &1, &1);
+    client.commit_board(&session_id, &player1, &board1);
+    client.commit_board(&session_id, &player2, &board2);
+
+    let salt = BytesN::from_array(&env, &[7u8; 32]);
+    let commitment = client.build_shot_commitment(&session_id, &player1, &3, &7, &salt);
+    let not_turn = client.try_commit_shot(&session_id, &player2, &commitment);
+    assert_battleship_error(&not_turn, Error::NotYourTurn);
+    client.commit_shot(&session_id, &player1, &commitment);
+
+    // The cell is hidden and the shot cannot be resolved or replaced until it is revealed
+    let game = client.get_game(&session_id);
+    assert_eq!(game.pending_shot_shooter, Some(player1.clone()));
+    assert_eq!(game.pending_shot_commitment, Some(commitment));
+    assert_eq!((game.pending_shot_x, game.pending_shot_y), (0, 0));
+    let second = client.try_fire(&session_id, &player1, &0, &0);
+    assert_battleship_error(&second, Error::PendingShotExists);
+    let hash = client.build_public_inputs_hash(
+        &session_id,
+        &player2,
+        &player1,
+        &3,
+        &7,
+        &true,
+        &0,
+        &board2,
+    );
+    let early =
+        client.try_resolve_shot(&session_id, &player2, &true, &0, &valid_proof(&env), &hash);
+    assert_battleship_error(&early, Error::ShotNotRevealed);
+
+    // Only the committed cell and salt open it
+    let other_cell = client.try_reveal_shot(&session_id, &player1, &4, &7, &salt);
+    assert_battleship_error(&other_cell, Error::InvalidShotReveal);
+    let wrong_salt = BytesN::from_array(&env, &[8u8; 32]);
+    let bad_salt = client.try_reveal_shot(&session_id, &player1, &3, &7, &wrong_salt);
+    assert_battleship_error(&bad_salt, Error::InvalidShotReveal);
+    let not_shooter = client.try_reveal_shot(&session_id, &player2, &3, &7, &salt);
+    assert_battleship_error(&not_shooter, Error::NoPendingShot);
+    client.reveal_shot(&session_id, &player1, &3, &7, &salt);
+
+    let revealed = client.get_game(&session_id);
+    assert_eq!(revealed.pending_shot_commitment, None);
+    assert_eq!((revealed.pending_shot_x, revealed.pending_shot_y), (3, 7));
+    resolve_pending(
+        &client,
+        session_id,
+        &player2,
+        &player1,
+        3,
+        7,
+        true,
+        0,
+        &board2,
+        &valid_proof(&env),
+    );
+
+    let after = client.get_game(&session_id);
+    assert_eq!(after.hits_on_p2, 1);
+    assert_eq!(after.turn, Some(player2));
+    // Two board commits, then commit, reveal and resolve of the shot
+    assert_eq!(after.move_count, 5);
+}
+
+#[test]
+fn test_seed_exchange_derives_random_stream() {
+    let (env, client, _hub, player1, player2, board1, board2) = setup_test();
+
+    let session_id = 66u32;
+    client.start_game(&session_id, &player1, &player2, &1, &1);
+    let seed1 = BytesN::from_array(&env, &[3u8; 32]);
+    let seed2 = BytesN::from_array(&env, &[4u8; 32]);
+    let commitment1 = client.build_seed_commitment(&session_id, &player1, &seed1);
+    let commitment2 = client.build_seed_commitment(&session_id, &player2, &seed2);
+
+    client.commit_seed(&session_id, &player1, &commitment1);
+    let early = client.try_reveal_seed(&session_id, &player1, &seed1);
+    assert_battleship_error(&early, Error::SeedNotCommitted);
+    let twice = client.try_commit_seed(&session_id, &player1, &commitment1);
+    assert_battleship_error(&twice, Error::SeedAlreadyCommitted);
+    client.commit_seed(&session_id, &player2, &commitment2);
+
+    let wrong = client.try_reveal_seed(&session_id, &player2, &seed1);
+    assert_battleship_error(&wrong, Error::InvalidSeedReveal);
+    client.reveal_seed(&session_id, &player2, &seed2);
+    assert_eq!(client.get_game(&session_id).seeds.stream, None);
+    client.reveal_seed(&session_id, &player1, &seed1);
+
+    // Seeds are committed and revealed before play
+    client.commit_board(&session_id, &player1, &board1);
+    client.commit_board(&session_id, &player2, &board2);
+    let late = client.try_commit_seed(&session_id, &player1, &commitment1);
+    assert_battleship_error(&late, Error::InvalidPhase);
+
+    // Anyone can replay the stream from the revealed seeds
+    let mut seeds = Bytes::from(&seed1);
+    seeds.append(&Bytes::from(&seed2));
+    let head: BytesN<32> = env.crypto().keccak256(&seeds).into();
+    let game = client.get_game(&session_id);
+    assert_eq!(game.seeds.stream, Some(head.clone()));
+    assert_eq!(
+        game.seeds.reveal_deadline,
+        100 + client.get_rules().seed_reveal_window_ledgers
+    );
+
+    // A hit draws the head; a first byte below 64 keeps the turn with the shooter
+    client.fire(&session_id, &player1, &0, &0);
+    resolve_pending(
+        &client,
+        session_id,
+        &player2,
+        &player1,
+        0,
+        0,
+        true,
+        0,
+        &board2,
+        &valid_proof(&env),
+    );
+    let bonus = head.to_array()[0] < 64;
+    let mut game = client.get_game(&session_id);
+    assert_eq!(
+        game.turn,
+        Some(if bonus {
+            player1.clone()
+        } else {
+            player2.clone()
+        })
+    );
+    assert_eq!(game.seeds.draws, 1);
+    let next: BytesN<32> = env.crypto().keccak256(&Bytes::from(&head)).into();
+    assert_eq!(game.draw_random(&env), Ok(next));
+    assert_eq!(game.seeds.draws, 2);
+}
+
+#[test]
+fn test_seed_reveal_rejected_once_game_in_progress() {
+    let (env, client, _hub, player1, player2, board1, board2) = setup_test();
+
+    let session_id = 77u32;
+    client.start_game(&session_id, &player1, &player2, &1, &1);
+    let seed1 = BytesN::from_array(&env, &[6u8; 32]);
+    let seed2 = BytesN::from_array(&env, &[7u8; 32]);
+    let commitment1 = client.build_seed_commitment(&session_id, &player1, &seed1);
+    let commitment2 = client.build_seed_commitment(&session_id, &player2, &seed2);
+    client.commit_seed(&session_id, &player1, &commitment1);
+    client.commit_seed(&session_id, &player2, &commitment2);
+    client.reveal_seed(&session_id, &player1, &seed1);
+
+    // player2 knows the stream player1's seed implies but can no longer pick when to start it
+    client.commit_board(&session_id, &player1, &board1);
+    client.commit_board(&session_id, &player2, &board2);
+    let late = client.try_reveal_seed(&session_id, &player2, &seed2);
+    assert_battleship_error(&late, Error::InvalidPhase);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, GamePhase::InProgress);
+    assert_eq!(game.seeds.seed_p2, None);
+    assert_eq!(game.seeds.stream, None);
+}
+
+#[test]
+fn test_withheld_seed_forfeits_after_reveal_window() {
+    let (env, client, hub, player1, player2, _board1, _board2) = setup_test();
+    let window = client.get_rules().seed_reveal_window_ledgers;
+    let seed = BytesN::from_array(&env, &[5u8; 32]);
+
+    let (withheld, silent) = (75u32, 76u32);
+    for session_id in [withheld, silent] {
+        client.start_game(&session_id, &player1, &player2, &1, &1);
+        for player in [&player1, &player2] {
+            let commitment = client.build_seed_commitment(&session_id, player, &seed);
+            client.commit_seed(&session_id, player, &commitment);
+        }
+    }
+    client.reveal_seed(&withheld, &player2, &seed);
+
+    // The window includes its last ledger
+    env.ledger().set_sequence_number(100 + window);
+    let open = client.try_forfeit_withheld_seed(&withheld);
+    assert_battleship_error(&open, Error::SeedRevealWindowOpen);
+
+    // player1 never revealed: they forfeit, and the hub settles the stakes
+    env.ledger().set_sequence_number(100 + window + 1);
+    assert_eq!(client.forfeit_withheld_seed(&withheld), player2);
+    let game = client.get_game(&withheld);
+    assert_eq!(game.phase, GamePhase::Ended);
+    assert_eq!(game.winner, Some(player2.clone()));
+    assert_eq!(hub.player1_won(&withheld), Some(false));
+    let again = client.try_forfeit_withheld_seed(&withheld);
+    assert_battleship_error(&again, Error::GameAlreadyEnded);
+
+    // Nobody revealed: there is no one to forfeit
+    let neither = client.try_forfeit_withheld_seed(&silent);
+    assert_battleship_error(&neither, Error::NoSeedWithheld);
+}
+
+#[test]
+fn test_imported_game_replays_on_a_fresh_env() {
+    use soroban_sdk::xdr::{FromXdr, ToXdr};
+
+    let (env, client, _hub, player1, player2, board1, board2) = setup_test();
+    let session_id = 65u32;
+    client.start_game(&session_id, &player1, &player2, &1, &1);
+    client.commit_board(&session_id, &player1, &board1);
+    client.commit_board(&session_id, &player2, &board2);
+    client.fire(&session_id, &player1, &3, &7);
+
+    // Export the stuck game as a bug report would, then load it into another env
+    let exported = client.get_game(&session_id).to_xdr(&env);
+    let mut buf = [0u8; 4096];
+    let len = exported.len() as usize;
+    exported.copy_into_slice(&mut buf[..len]);
+
+    let (local_env, local, _hub, _, _, _, _) = setup_test();
+    let game =
+        crate::Game::from_xdr(&local_env, &Bytes::from_slice(&local_env, &buf[..len])).unwrap();
+    local.import_game(&session_id, &game);
+    assert_eq!(local_env.auths().first().unwrap().0, local.get_admin());
+    assert_eq!(local.get_game(&session_id), game);
+
+    resolve_pending(
+        &local,
+        session_id,
+        &game.player2,
+        &game.player1,
+        3,
+        7,
+        true,
+        0,
+        game.board_commitment_p2.as_ref().unwrap(),
+        &valid_proof(&local_env),
+    );
+    let after = local.get_game(&session_id);
+    assert_eq!(after.hits_on_p2, 1);
+    assert_eq!(after.turn, Some(game.player2));
+    assert_eq!(after.move_count, game.move_count + 1);
+}
+
+#[test]
+fn test_metrics_count_started_and_ended_games() {
+    let (env, client, _hub, player1, player2, board1, board2) = setup_test();
+    let proof = valid_proof(&env);
+    assert_eq!(client.get_metrics(), Metrics::default());
+
+    // Two missed shots end a capped game on the tiebreak.
+    client.set_max_shots(&2);
+    let capped = 67u32;
+    client.start_game(&capped, &player1, &player2, &1, &1);
+    client.commit_board(&capped, &player1, &board1);
+    client.commit_board(&capped, &player2, &board2);
+    client.fire(&capped, &player1, &0, &0);
+    let rejected = client.try_resolve_shot(
+        &capped,
+        &player2,
+        &false,
+        &0,
+        &invalid_proof(&env),
+        &client.build_public_inputs_hash(&capped, &player2, &player1, &0, &0, &false, &0, &board2),
+    );
+    assert_battleship_error(&rejected, Error::InvalidProof);
+    resolve_pending(
+        &client, capped, &player2, &player1, 0, 0, false, 0, &board2, &proof,
+    );
+    client.fire(&capped, &player2, &0, &0);
+    resolve_pending(
+        &client, capped, &player1, &player2, 0, 0, false, 0, &board1, &proof,
+    );
+
+    let cancelled = 68u32;
+    client.start_game(&cancelled, &player1, &player2, &1, &1);
+    client.cancel_game(&cancelled);
+    // A finished practice game is counted apart from ranked ones.
+    let practice = 69u32;
+    let layout = BoardLayout {
+        ship_x: vec![&env, 0, 0, 0, 0, 0],
+        ship_y: vec![&env, 0, 1, 2, 3, 4],
+        ship_dir: vec![&env, 1, 1, 1, 1, 1],
+    };
+    client.start_practice_game(&practice, &player1, &player2);
+    client.commit_practice_board(&practice, &player1, &layout);
+    client.commit_practice_board(&practice, &player2, &layout);
+    client.fire(&practice, &player1, &9, &9);
+    client.resolve_practice_shot(&practice);
+    client.fire(&practice, &player2, &9, &9);
+    client.resolve_practice_shot(&practice);
+    assert_eq!(client.get_game(&practice).phase, GamePhase::Ended);
+
+    assert_eq!(
+        client.get_metrics(),
+        Metrics {
+            games_started: 2,
+            practice_games_started: 1,
+            games_ended: 1,
+            games_adjudicated: 1,
+            games_cancelled: 1,
+            practice_games_ended: 1,
+        }
+    );
+}
+
+#[test]
+fn test_session_marker_outlives_expired_game() {
+    use crate::infrastructure::storage::DataKey;
+
+    let (env, client, _hub, player1, player2, _board1, _board2) = setup_test();
+
+    let session_id = 70u32;
+    assert!(!client.was_started(&session_id));
+    client.start_game(&session_id, &player1, &player2, &1, &1);
+    assert!(client.was_started(&session_id));
+
+    // Expire the temporary game entries as if their TTL had run out mid-match.
+    env.as_contract(&client.address, || {
+        env.storage().temporary().remove(&DataKey::Game(session_id));
+        env.storage()
+            .temporary()
+            .remove(&DataKey::GameState(session_id));
+    });
+    assert_battleship_error(&client.try_get_game(&session_id), Error::GameNotFound);
+    assert!(client.was_started(&session_id));
+    assert_eq!(
+        client.get_session_marker(&session_id),
+        Some(SessionMarker {
+            players: vec![&env, player1.clone(), player2.clone()],
+            start_ledger: 100,
+            tier: StorageTier::Temporary,
+        })
+    );
+    assert!(!client.was_started(&71));
+    assert_eq!(client.get_session_marker(&71), None);
+
+    // The expired id stays taken, so the marker cannot be overwritten.
+    let restarted = client.try_start_game(&session_id, &player2, &player1, &1, &1);
+    assert_battleship_error(&restarted, Error::GameAlreadyExists);
+    assert_eq!(
+        client.get_session_marker(&session_id).unwrap().start_ledger,
+        100
+    );
+}
+
+#[test]
+fn test_chat_anchors_are_limited_to_members() {
+    let (env, client, _hub, player1, player2, _board1, _board2) = setup_test();
+    let spectator = Address::generate(&env);
+    let rules = client.get_rules();
+    let hash = |byte: u8| BytesN::from_array(&env, &[byte; 32]);
+
+    let session_id = 74u32;
+    let missing = client.try_post_chat_anchor(&session_id, &player1, &hash(1));
+    assert_battleship_error(&missing, Error::GameNotFound);
+    client.start_game(&session_id, &player1, &player2, &1, &1);
+
+    assert_eq!(client.post_chat_anchor(&session_id, &player1, &hash(1)), 0);
+    let too_soon = client.try_post_chat_anchor(&session_id, &player1, &hash(2));
+    assert_battleship_error(&too_soon, Error::ChatRateLimited);
+    // Each member has their own limit; outsiders cannot post at all.
+    assert_eq!(client.post_chat_anchor(&session_id, &player2, &hash(3)), 0);
+    let outsider = client.try_post_chat_anchor(&session_id, &spectator, &hash(3));
+    assert_battleship_error(&outsider, Error::NotPlayer);
+
+    let posted_at = env.ledger().sequence();
+    env.ledger()
+        .set_sequence_number(posted_at + rules.chat_anchor_interval_ledgers);
+    assert_eq!(client.post_chat_anchor(&session_id, &player1, &hash(2)), 1);
+    assert_eq!(
+        client.get_chat_anchors(&session_id, &player1),
+        vec![
+            &env,
+            ChatAnchor {
+                hash: hash(1),
+                ledger: posted_at,
+            },
+            ChatAnchor {
+                hash: hash(2),
+                ledger: posted_at + rules.chat_anchor_interval_ledgers,
+            },
+        ]
+    );
+
+    for index in 2..rules.max_chat_anchors {
+        env.ledger()
+            .set_sequence_number(env.ledger().sequence() + rules.chat_anchor_interval_ledgers);
+        assert_eq!(
+            client.post_chat_anchor(&session_id, &player1, &hash(4)),
+            index
+        );
+    }
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + rules.chat_anchor_interval_ledgers);
+    let full = client.try_post_chat_anchor(&session_id, &player1, &hash(5));
+    assert_battleship_error(&full, Error::ChatAnchorLimitReached);
+
+    // player2 stops at the per-session cap, short of their own.
+    for index in 1..rules.max_session_chat_anchors - rules.max_chat_anchors {
+        assert_eq!(
+            client.post_chat_anchor(&session_id, &player2, &hash(6)),
+            index
+        );
+        env.ledger()
+            .set_sequence_number(env.ledger().sequence() + rules.chat_anchor_interval_ledgers);
+    }
+    let room_full = client.try_post_chat_anchor(&session_id, &player2, &hash(7));
+    assert_battleship_error(&room_full, Error::SessionChatLimitReached);
+    assert_eq!(
+        client.get_chat_anchors(&session_id, &player2).len(),
+        rules.max_session_chat_anchors - rules.max_chat_anchors
+    );
+}
+
+// Calls the state-machine property test makes, valid or not.
+#[derive(Clone, Debug)]
+enum FuzzOp {
+    Fire {
+        out_of_turn: bool,
+        x: u32,
+        y: u32,
+    },
+    Resolve {
+        is_hit: bool,
+        sunk_ship: u32,
+        valid_proof: bool,
+    },
+    // The side to move fires at one of its unshot cells, unless a shot is already pending, and the
+    // defender answers with a valid proof, so runs get far enough to end on 17 hits.
+    Volley {
+        cell: proptest::sample::Index,
+        is_hit: bool,
+    },
+    CancelShot {
+        by_player1: bool,
+    },
+    AdvanceLedger(u32),
+    CancelGame,
+}
+
+fn fuzz_op() -> impl proptest::strategy::Strategy<Value = FuzzOp> {
+    use proptest::prelude::*;
+
+    // Out-of-range coordinates, unknown ships and bad proofs are drawn on purpose.
+    prop_oneof![
+        3 => (prop::bool::weighted(0.3), 0..11u32, 0..11u32)
+            .prop_map(|(out_of_turn, x, y)| FuzzOp::Fire { out_of_turn, x, y }),
+        3 => (
+            any::<bool>(),
+            prop::sample::select(&[0u32, 0, 1, 5, 6][..]),
+            prop::bool::weighted(0.8),
+        )
+            .prop_map(|(is_hit, sunk_ship, valid_proof)| FuzzOp::Resolve {
+                is_hit,
+                sunk_ship,
+                valid_proof,
+            }),
+        6 => (any::<prop::sample::Index>(), prop::bool::weighted(0.8))
+            .prop_map(|(cell, is_hit)| FuzzOp::Volley { cell, is_hit }),
+        1 => any::<bool>().prop_map(|by_player1| FuzzOp::CancelShot { by_player1 }),
+        1 => (0..8u32).prop_map(FuzzOp::AdvanceLedger),
+    ]
+}
+
+/// `try_resolve_shot` for the pending shot of `game` (or a made-up one by player1), with a hash
+/// that matches it. Returns the defender when the resolution went through.
+fn try_resolve_fuzzed(
+    client: &BattleshipContractClient<'static>,
+    session_id: u32,
+    game: &crate::Game,
+    boards: (&BytesN<32>, &BytesN<32>),
+    is_hit: bool,
+    sunk_ship: u32,
+    valid_proof: bool,
+) -> Option<Address> {
+    let shooter = game
+        .pending_shot_shooter
+        .clone()
+        .unwrap_or_else(|| game.player1.clone());
+    let (defender, board) = if shooter == game.player1 {
+        (&game.player2, boards.1)
+    } else {
+        (&game.player1, boards.0)
+    };
+    let hash = client.build_public_inputs_hash(
+        &session_id,
+        defender,
+        &shooter,
+        &game.pending_shot_x,
+        &game.pending_shot_y,
+        &is_hit,
+        &sunk_ship,
+        board,
+    );
+    let proof = Bytes::from_array(&client.env, &[valid_proof as u8]);
+    let result = client.try_resolve_shot(&session_id, defender, &is_hit, &sunk_ship, &proof, &hash);
+    matches!(result, Ok(Ok(_))).then(|| defender.clone())
+}
+
+proptest::proptest! {
+    #![proptest_config(proptest::prelude::ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn test_random_calls_preserve_game_invariants(
+        ops in proptest::collection::vec(fuzz_op(), 40..160),
+        cancel_at in proptest::option::of(0..320usize),
+    ) {
+        let (env, client, _hub, player1, player2, board1, board2) = setup_test();
+
+        let session_id = 63u32;
+        client.start_game(&session_id, &player1, &player2, &1, &1);
+        client.commit_board(&session_id, &player1, &board1);
+        client.commit_board(&session_id, &player2, &board2);
+
+        // The hub cancels at most once, so most runs get to play on
+        for (index, op) in ops.into_iter().enumerate() {
+            let op = if cancel_at == Some(index) { FuzzOp::CancelGame } else { op };
+            let before = client.get_game(&session_id);
+            let turn_is_player1 = before.turn.as_ref() != Some(&player2);
+            let mut resolved_by = None;
+            match op {
+                FuzzOp::Fire { out_of_turn, x, y } => {
+                    let shooter = if turn_is_player1 != out_of_turn { &player1 } else { &player2 };
+                    let _ = client.try_fire(&session_id, shooter, &x, &y);
+                }
+                FuzzOp::Resolve { is_hit, sunk_ship, valid_proof } => {
+                    resolved_by = try_resolve_fuzzed(
+                        &client,
+                        session_id,
+                        &before,
+                        (&board1, &board2),
+                        is_hit,
+                        sunk_ship,
+                        valid_proof,
+                    );
+                }
+                FuzzOp::Volley { cell, is_hit } => {
+                    let (shooter, (shots, _)) = if turn_is_player1 {
+                        (&player1, before.side_bitmaps(true))
+                    } else {
+                        (&player2, before.side_bitmaps(false))
+                    };
+                    let free = 100 - shots.count_ones() as usize;
+                    let target = (0..100u32)
+                        .filter(|i| shots & (1 << i) == 0)
+                        .nth(cell.index(free.max(1)));
+                    let fired = before.pending_shot_shooter.is_some()
+                        || target.is_some_and(|i| {
+                            client.try_fire(&session_id, shooter, &(i % 10), &(i / 10)).is_ok()
+                        });
+                    if fired {
+                        let fired = client.get_game(&session_id);
+                        resolved_by = try_resolve_fuzzed(
+                            &client,
+                            session_id,
+                            &fired,
+                            (&board1, &board2),
+                            is_hit,
+                            0,
+                            true,
+                        );
+                    }
+                }
+                FuzzOp::CancelShot { by_player1 } => {
+                    let shooter = if by_player1 { &player1 } else { &player2 };
+                    let _ = client.try_cancel_shot(&session_id, shooter);
+                }
+                FuzzOp::AdvanceLedger(ledgers) => {
+                    env.ledger()
+                        .set_sequence_number(env.ledger().sequence() + ledgers);
+                }
+                FuzzOp::CancelGame => {
+                    let _ = client.try_cancel_game(&session_id);
+                }
+            }
+            let after = client.get_game(&session_id);
+
+            // Nothing moves once the game is over
+            if before.phase == GamePhase::Ended {
+                proptest::prop_assert_eq!(&after, &before);
+            }
+
+            // Hit counters agree with the hit bitmaps, which only cover resolved shots
+            proptest::prop_assert!(after.hits_on_p1 <= 17 && after.hits_on_p2 <= 17);
+            proptest::prop_assert_eq!(after.hits_on_p2, after.hits_p1_to_p2.count_ones());
+            proptest::prop_assert_eq!(after.hits_on_p1, after.hits_p2_to_p1.count_ones());
+            proptest::prop_assert_eq!(after.hits_p1_to_p2 & !after.shots_p1_to_p2, 0);
+            proptest::prop_assert_eq!(after.hits_p2_to_p1 & !after.shots_p2_to_p1, 0);
+            proptest::prop_assert_eq!(after.shots_p1_to_p2 >> 100, 0);
+            proptest::prop_assert_eq!(after.shots_p2_to_p1 >> 100, 0);
+
+            // Only the side to move has a shot in flight, and a resolution hands the turn over
+            if after.phase == GamePhase::InProgress {
+                proptest::prop_assert!(after.turn.is_some());
+                if let Some(shooter) = &after.pending_shot_shooter {
+                    proptest::prop_assert_eq!(Some(shooter), after.turn.as_ref());
+                }
+                if let Some(defender) = resolved_by {
+                    proptest::prop_assert_eq!(after.turn, Some(defender));
+                }
+            } else {
+                proptest::prop_assert_eq!(&after.turn, &None);
+            }
+
+            match &after.winner {
+                Some(winner) if *winner == player1 => {
+                    proptest::prop_assert_eq!(after.hits_on_p2, 17)
+                }
+                Some(_) => proptest::prop_assert_eq!(after.hits_on_p1, 17),
+                None => {}
+            }
+        }
+    }
+}
+
+#[test]
+fn test_get_info_reports_game_interface() {
+    let (env, client, _hub, _player1, _player2, _board1, _board2) = setup_test();
+
+    let info = client.get_info();
+    assert_eq!(info.name, String::from_str(&env, "battleship"));
+    assert_eq!(
+        info.version,
+        String::from_str(&env, env!("CARGO_PKG_VERSION"))
+    );
+    assert!(info.interfaces.contains(String::from_str(&env, "game-v1")));
+    assert!(info
+        .interfaces
+        .contains(String::from_str(&env, "game-interface-v1")));
+}
@@ -9,8 +9,9 @@
 extern crate alloc;
 
 pub use contract_types::{Groth16Error, Groth16Proof, VerificationKeyBytes};
+use ownable::Ownable;
 use soroban_sdk::{
-    Env, Vec, contract, contractimpl, contracttype,
+    Address, Bytes, BytesN, Env, String, Vec, contract, contracterror, contractimpl, contracttype,
     crypto::bn254::{Bn254G1Affine as G1Affine, Bn254G2Affine as G2Affine, Fr},
     vec,
 };
@@ -44,6 +45,81 @@ fn verification_key_from_bytes(env: &Env, vk_bytes: &VerificationKeyBytes) -> Ve
 #[derive(Clone)]
 enum DataKey {
     VerificationKey,
+    SelfCheckVector,
+    VkHistory,
+    // Bumped by `rotate_vk`; cached points are stored under the version of
+    // the key they were computed with
+    VkVersion,
+    PreparedContext(u32, BytesN<32>),
+    StagedInputs(u32, BytesN<32>),
+}
+
+/// Id of a prepared context: keccak256 of the fixed inputs' 32-byte
+/// encodings, so callers can recompute it from the values they bind.
+pub type ContextId = BytesN<32>;
+
+/// Leading public inputs folded into `ic[0]` by `prepare_context`.
+#[contracttype]
+#[derive(Clone)]
+pub struct PreparedContext {
+    pub fixed_count: u32,
+    pub partial_vk_x: G1Affine,
+}
+
+/// Prepared contexts live as long as a game (~30 days at 5s per ledger).
+const CONTEXT_TTL_LEDGERS: u32 = 518_400;
+
+/// Id of inputs staged by `stage_msm`: keccak256 of every public input's
+/// 32-byte encoding.
+pub type StagedId = BytesN<32>;
+
+/// Staged inputs only need to outlive the follow-up `finish_verify`
+/// transaction (~10 minutes).
+const STAGED_TTL_LEDGERS: u32 = 120;
+
+/// Known-good proof for the stored verification key, used by `self_check`.
+#[contracttype]
+#[derive(Clone)]
+pub struct SelfCheckVector {
+    pub proof: Groth16Proof,
+    pub public_inputs: Vec<Fr>,
+}
+
+/// Entry of the verification key audit log returned by `get_vk_history`.
+/// Key hashes are keccak256(alpha || beta || gamma || delta || ic[0] || ...)
+/// over the serialized points, so they can be recomputed from the key file.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VkChange {
+    /// Hash of the key that was replaced; None when the first key was stored.
+    pub old_hash: Option<BytesN<32>>,
+    pub new_hash: BytesN<32>,
+    pub ledger: u32,
+    /// Admin that authorized the change.
+    pub admin: Address,
+}
+
+/// Returned by `get_info` so the hub, SDK and tooling can negotiate capabilities.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractInfo {
+    pub name: String,
+    pub version: String,
+    /// Interface ids implemented by the contract, e.g. "groth16-verifier-v1".
+    pub interfaces: Vec<String>,
+    /// Source revision baked in at build time from ZKARCADE_BUILD_HASH ("dev" when unset).
+    pub build_hash: String,
+}
+
+/// Interface ids reported by `get_info`.
+const INTERFACES: [&str; 1] = ["groth16-verifier-v1"];
+
+/// Errors returned by `initialize`.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum InitError {
+    AlreadyInitialized = 1,
 }
 
 /// Groth16 verifier for BN254/Circom proofs.
@@ -52,13 +128,137 @@ pub struct CircomGroth16Verifier;
 
 #[contractimpl]
 impl CircomGroth16Verifier {
-    /// Constructor: initialize the contract with a verification key.
-    pub fn __constructor(env: Env, vk: VerificationKeyBytes) -> Result<(), Groth16Error> {
+    /// Fix the admin allowed to store the verification key. The constructor
+    /// takes no key, so the address (derived from the deployer and salt) can
+    /// be computed before the trusted setup output is final, while nobody but
+    /// this admin can pick the key that ends up behind it.
+    pub fn __constructor(env: Env, admin: Address) {
+        Ownable::init(&env, &admin);
+    }
+
+    /// Store the verification key; `verify` returns `NotInitialized` until
+    /// this has been called. Can only be called once, and only by the admin
+    /// fixed at deploy time.
+    pub fn initialize(env: Env, vk: VerificationKeyBytes) -> Result<(), InitError> {
+        let admin = Ownable::require_admin(&env);
+
         let storage = env.storage().persistent();
+        if storage.has(&DataKey::VerificationKey) {
+            return Err(InitError::AlreadyInitialized);
+        }
         storage.set(&DataKey::VerificationKey, &vk);
+        Self::record_vk_change(&env, &admin, None, &vk);
         Ok(())
     }
 
+    /// Replace the stored verification key (admin only), e.g. after a new
+    /// trusted setup. Prepared contexts and staged inputs are only keyed by
+    /// their inputs, so the ones computed with the old key are dropped and
+    /// must be prepared or staged again. Returns `NotInitialized` before
+    /// `initialize`.
+    pub fn rotate_vk(env: Env, vk: VerificationKeyBytes) -> Result<(), Groth16Error> {
+        let admin = Ownable::require_admin(&env);
+
+        let storage = env.storage().persistent();
+        let old_vk: VerificationKeyBytes = storage
+            .get(&DataKey::VerificationKey)
+            .ok_or(Groth16Error::NotInitialized)?;
+        storage.set(&DataKey::VerificationKey, &vk);
+        storage.set(&DataKey::VkVersion, &(Self::vk_version(&env) + 1));
+        Self::record_vk_change(&env, &admin, Some(&old_vk), &vk);
+        Ok(())
+    }
+
+    /// Every verification key change in order, oldest first, for tournament
+    /// organizers auditing which key games were verified against.
+    pub fn get_vk_history(env: Env) -> Vec<VkChange> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::VkHistory)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Contract name, version, interface ids and build hash.
+    pub fn get_info(env: Env) -> ContractInfo {
+        let mut interfaces = Vec::new(&env);
+        for id in INTERFACES {
+            interfaces.push_back(String::from_str(&env, id));
+        }
+        ContractInfo {
+            name: String::from_str(&env, env!("CARGO_PKG_NAME")),
+            version: String::from_str(&env, env!("CARGO_PKG_VERSION")),
+            interfaces,
+            build_hash: String::from_str(&env, option_env!("ZKARCADE_BUILD_HASH").unwrap_or("dev")),
+        }
+    }
+
+    /// Whether a verification key has been stored.
+    pub fn is_initialized(env: Env) -> bool {
+        env.storage().persistent().has(&DataKey::VerificationKey)
+    }
+
+    /// Admin fixed at deploy time, unless since transferred.
+    pub fn get_admin(env: Env) -> Address {
+        Ownable::admin(&env)
+    }
+
+    pub fn set_admin(env: Env, new_admin: Address) {
+        Ownable::set_admin(&env, &new_admin);
+    }
+
+    /// First step of an admin transfer; `new_admin` takes over once they call `accept_admin`.
+    pub fn propose_admin(env: Env, new_admin: Address) {
+        Ownable::propose_admin(&env, &new_admin);
+    }
+
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        Ownable::pending_admin(&env)
+    }
+
+    /// Second step of an admin transfer, signed by the proposed admin.
+    pub fn accept_admin(env: Env) -> Address {
+        Ownable::accept_admin(&env)
+    }
+
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        Ownable::upgrade(&env, new_wasm_hash);
+    }
+
+    /// Store the known-good proof that `self_check` replays (admin only).
+    pub fn set_self_check_vector(
+        env: Env,
+        proof: Groth16Proof,
+        public_inputs: Vec<Fr>,
+    ) -> Result<(), Groth16Error> {
+        Ownable::require_admin(&env);
+
+        env.storage().persistent().set(
+            &DataKey::SelfCheckVector,
+            &SelfCheckVector {
+                proof,
+                public_inputs,
+            },
+        );
+        Ok(())
+    }
+
+    /// Verify the stored known-good proof against the stored verification
+    /// key and return the measured result, so deploy tooling and monitors
+    /// can detect a VK/wasm mismatch right after an upgrade or key rotation.
+    /// Returns `NotInitialized` if either the key or the vector is missing.
+    pub fn self_check(env: Env) -> Result<bool, Groth16Error> {
+        let storage = env.storage().persistent();
+        let vk_bytes: VerificationKeyBytes = storage
+            .get(&DataKey::VerificationKey)
+            .ok_or(Groth16Error::NotInitialized)?;
+        let vector: SelfCheckVector = storage
+            .get(&DataKey::SelfCheckVector)
+            .ok_or(Groth16Error::NotInitialized)?;
+
+        let vk = verification_key_from_bytes(&env, &vk_bytes);
+        Ok(Self::verify_with_vk(&env, &vk, vector.proof, vector.public_inputs).unwrap_or(false))
+    }
+
     /// Verify a Groth16 proof using the stored verification key.
     pub fn verify(
         env: Env,
@@ -74,26 +274,206 @@ impl CircomGroth16Verifier {
         Self::verify_with_vk(&env, &vk, proof, public_inputs)
     }
 
+    /// Pre-multiply the IC points of the leading `fixed_inputs` (e.g. the
+    /// commitment limbs that stay the same for a whole game) so repeated
+    /// verifications only pay for the remaining inputs. Preparing the same
+    /// inputs again returns the existing id and refreshes its TTL.
+    pub fn prepare_context(env: Env, fixed_inputs: Vec<Fr>) -> Result<ContextId, Groth16Error> {
+        let vk = Self::load_vk(&env)?;
+        // ic holds one point per input plus ic[0], and at least one input
+        // must be left for `verify_with_context`
+        if fixed_inputs.is_empty() || fixed_inputs.len() + 1 >= vk.ic.len() {
+            return Err(Groth16Error::MalformedPublicInputs);
+        }
+
+        let ctx: ContextId = Self::inputs_id(&env, &fixed_inputs);
+
+        let key = DataKey::PreparedContext(Self::vk_version(&env), ctx.clone());
+        let storage = env.storage().temporary();
+        if !storage.has(&key) {
+            let ic0 = vk.ic.get(0).ok_or(Groth16Error::MalformedPublicInputs)?;
+            let partial_vk_x = Self::accumulate_inputs(&env, &vk, ic0, 0, &fixed_inputs);
+            storage.set(
+                &key,
+                &PreparedContext {
+                    fixed_count: fixed_inputs.len(),
+                    partial_vk_x,
+                },
+            );
+        }
+        storage.extend_ttl(&key, CONTEXT_TTL_LEDGERS, CONTEXT_TTL_LEDGERS);
+        Ok(ctx)
+    }
+
+    /// Verify a Groth16 proof whose leading public inputs were folded in by
+    /// `prepare_context`; `remaining_inputs` are the inputs after them.
+    /// Returns `NotInitialized` if the context is unknown, has expired or
+    /// was prepared before the last `rotate_vk`.
+    pub fn verify_with_context(
+        env: Env,
+        ctx: ContextId,
+        remaining_inputs: Vec<Fr>,
+        proof: Groth16Proof,
+    ) -> Result<bool, Groth16Error> {
+        let vk = Self::load_vk(&env)?;
+        let context: PreparedContext = env
+            .storage()
+            .temporary()
+            .get(&DataKey::PreparedContext(Self::vk_version(&env), ctx))
+            .ok_or(Groth16Error::NotInitialized)?;
+
+        if context.fixed_count + remaining_inputs.len() + 1 != vk.ic.len() {
+            return Err(Groth16Error::MalformedPublicInputs);
+        }
+
+        let vk_x = Self::accumulate_inputs(
+            &env,
+            &vk,
+            context.partial_vk_x,
+            context.fixed_count,
+            &remaining_inputs,
+        );
+        Self::check_pairing(&env, &vk, vk_x, proof)
+    }
+
+    /// First half of a verification split across two transactions for tight
+    /// resource limits: compute the prepared inputs point for `public_inputs`
+    /// and store it for `finish_verify`. Staging the same inputs again
+    /// returns the existing id and refreshes its TTL.
+    pub fn stage_msm(env: Env, public_inputs: Vec<Fr>) -> Result<StagedId, Groth16Error> {
+        let vk = Self::load_vk(&env)?;
+        if public_inputs.len() + 1 != vk.ic.len() {
+            return Err(Groth16Error::MalformedPublicInputs);
+        }
+
+        let staged_id: StagedId = Self::inputs_id(&env, &public_inputs);
+
+        let key = DataKey::StagedInputs(Self::vk_version(&env), staged_id.clone());
+        let storage = env.storage().temporary();
+        if !storage.has(&key) {
+            let ic0 = vk.ic.get(0).ok_or(Groth16Error::MalformedPublicInputs)?;
+            let vk_x = Self::accumulate_inputs(&env, &vk, ic0, 0, &public_inputs);
+            storage.set(&key, &vk_x);
+        }
+        storage.extend_ttl(&key, STAGED_TTL_LEDGERS, STAGED_TTL_LEDGERS);
+        Ok(staged_id)
+    }
+
+    /// Second half of a split verification: run only the pairing check for
+    /// `proof` against inputs staged by `stage_msm`. Returns
+    /// `NotInitialized` if the id is unknown, has expired or was staged
+    /// before the last `rotate_vk`.
+    pub fn finish_verify(
+        env: Env,
+        staged_id: StagedId,
+        proof: Groth16Proof,
+    ) -> Result<bool, Groth16Error> {
+        let vk = Self::load_vk(&env)?;
+        let vk_x: G1Affine = env
+            .storage()
+            .temporary()
+            .get(&DataKey::StagedInputs(Self::vk_version(&env), staged_id))
+            .ok_or(Groth16Error::NotInitialized)?;
+        Self::check_pairing(&env, &vk, vk_x, proof)
+    }
+
+    /// Append a change to the verification key audit log.
+    fn record_vk_change(
+        env: &Env,
+        admin: &Address,
+        old_vk: Option<&VerificationKeyBytes>,
+        new_vk: &VerificationKeyBytes,
+    ) {
+        let mut history = Self::get_vk_history(env.clone());
+        history.push_back(VkChange {
+            old_hash: old_vk.map(|vk| Self::vk_hash(env, vk)),
+            new_hash: Self::vk_hash(env, new_vk),
+            ledger: env.ledger().sequence(),
+            admin: admin.clone(),
+        });
+        env.storage().persistent().set(&DataKey::VkHistory, &history);
+    }
+
+    /// Number of `rotate_vk` calls so far.
+    fn vk_version(env: &Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::VkVersion)
+            .unwrap_or(0)
+    }
+
+    /// keccak256 over the key's serialized points, `ic` in order.
+    fn vk_hash(env: &Env, vk: &VerificationKeyBytes) -> BytesN<32> {
+        let mut encoded = Bytes::from(vk.alpha.clone());
+        encoded.append(&Bytes::from(vk.beta.clone()));
+        encoded.append(&Bytes::from(vk.gamma.clone()));
+        encoded.append(&Bytes::from(vk.delta.clone()));
+        for point in vk.ic.iter() {
+            encoded.append(&Bytes::from(point));
+        }
+        env.crypto().keccak256(&encoded).into()
+    }
+
+    /// keccak256 over the inputs' 32-byte encodings.
+    fn inputs_id(env: &Env, inputs: &Vec<Fr>) -> BytesN<32> {
+        let mut encoded = Bytes::new(env);
+        for input in inputs.iter() {
+            encoded.append(&Bytes::from(input.to_bytes()));
+        }
+        env.crypto().keccak256(&encoded).into()
+    }
+
+    fn load_vk(env: &Env) -> Result<VerificationKey, Groth16Error> {
+        let vk_bytes: VerificationKeyBytes = env
+            .storage()
+            .persistent()
+            .get(&DataKey::VerificationKey)
+            .ok_or(Groth16Error::NotInitialized)?;
+        Ok(verification_key_from_bytes(env, &vk_bytes))
+    }
+
     fn verify_with_vk(
         env: &Env,
         vk: &VerificationKey,
         proof: Groth16Proof,
         pub_inputs: Vec<Fr>,
     ) -> Result<bool, Groth16Error> {
-        let bn = env.crypto().bn254();
-
         if pub_inputs.len() + 1 != vk.ic.len() {
             return Err(Groth16Error::MalformedPublicInputs);
         }
 
-        let mut vk_x = vk.ic.get(0).ok_or(Groth16Error::MalformedPublicInputs)?;
+        let ic0 = vk.ic.get(0).ok_or(Groth16Error::MalformedPublicInputs)?;
+        let vk_x = Self::accumulate_inputs(env, vk, ic0, 0, &pub_inputs);
+        Self::check_pairing(env, vk, vk_x, proof)
+    }
+
+    /// Add `inputs[i] * ic[offset + i + 1]` to `vk_x`. Callers check that
+    /// the IC vector is long enough.
+    fn accumulate_inputs(
+        env: &Env,
+        vk: &VerificationKey,
+        mut vk_x: G1Affine,
+        offset: u32,
+        inputs: &Vec<Fr>,
+    ) -> G1Affine {
+        let bn = env.crypto().bn254();
 
-        for i in 0..pub_inputs.len() {
-            let s = pub_inputs.get(i).unwrap();
-            let v = vk.ic.get(i + 1).unwrap();
+        for i in 0..inputs.len() {
+            let s = inputs.get(i).unwrap();
+            let v = vk.ic.get(offset + i + 1).unwrap();
             let prod = bn.g1_mul(&v, &s);
             vk_x = bn.g1_add(&vk_x, &prod);
         }
+        vk_x
+    }
+
+    fn check_pairing(
+        env: &Env,
+        vk: &VerificationKey,
+        vk_x: G1Affine,
+        proof: Groth16Proof,
+    ) -> Result<bool, Groth16Error> {
+        let bn = env.crypto().bn254();
 
         // Compute the pairing check:
         // e(-A, B) * e(alpha, beta) * e(vk_x, gamma) * e(C, delta) == 1
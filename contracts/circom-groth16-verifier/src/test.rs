@@ -8,7 +8,8 @@ use ark_relations::{
 };
 use ark_std::rand::{SeedableRng, rngs::StdRng};
 use contract_types::PROOF_SIZE;
-use soroban_sdk::{Bytes, BytesN, Env, Vec};
+use soroban_sdk::testutils::{Address as _, MockAuth, MockAuthInvoke};
+use soroban_sdk::{Address, Bytes, BytesN, Env, IntoVal, String, Vec};
 use soroban_utils::{g1_bytes_from_ark, g2_bytes_from_ark, vk_bytes_from_ark};
 
 // This test file was taken from https://github.com/NethermindEth/stellar-private-payments/blob/main/contracts/circom-groth16-verifier/src/test.rs
@@ -70,13 +71,18 @@ fn serialize_proof(env: &Env, proof: &Groth16Proof) -> Bytes {
 }
 
 fn build_test(env: &Env) -> (VerificationKeyBytes, Groth16Proof, Vec<Fr>, [ArkFr; 11]) {
-    let mut rng = seeded_rng();
+    build_test_with_rng(env, &mut seeded_rng())
+}
+
+fn build_test_with_rng(
+    env: &Env,
+    rng: &mut StdRng,
+) -> (VerificationKeyBytes, Groth16Proof, Vec<Fr>, [ArkFr; 11]) {
     let inputs = [ArkFr::from(33u64); 11];
     let circuit = ElevenInputCircuit { inputs };
-    let params =
-        Groth16::<Bn254>::generate_random_parameters_with_reduction(circuit.clone(), &mut rng)
-            .expect("params failed to generate");
-    let proof = Groth16::<Bn254>::create_random_proof_with_reduction(circuit, &params, &mut rng)
+    let params = Groth16::<Bn254>::generate_random_parameters_with_reduction(circuit.clone(), rng)
+        .expect("params failed to generate");
+    let proof = Groth16::<Bn254>::create_random_proof_with_reduction(circuit, &params, rng)
         .expect("proof failed");
 
     let mut public_inputs: Vec<Fr> = Vec::new(env);
@@ -118,12 +124,22 @@ fn test_env() -> Env {
     }
 }
 
+fn register_initialized<'a>(
+    env: &Env,
+    vk_bytes: &VerificationKeyBytes,
+) -> CircomGroth16VerifierClient<'a> {
+    env.mock_all_auths();
+    let contract_id = env.register(CircomGroth16Verifier, (Address::generate(env),));
+    let client = CircomGroth16VerifierClient::new(env, &contract_id);
+    client.initialize(vk_bytes);
+    client
+}
+
 #[test]
 fn verifies_valid_proof() {
     let env = test_env();
     let (vk_bytes, proof, public_inputs, _) = build_test(&env);
-    let contract_id = env.register(CircomGroth16Verifier, (vk_bytes.clone(),));
-    let client = CircomGroth16VerifierClient::new(&env, &contract_id);
+    let client = register_initialized(&env, &vk_bytes);
 
     let result = client.try_verify(&proof, &public_inputs);
 
@@ -134,8 +150,7 @@ fn verifies_valid_proof() {
 fn rejects_wrong_public_input_length() {
     let env = test_env();
     let (vk_bytes, proof, _public_inputs, inputs) = build_test(&env);
-    let contract_id = env.register(CircomGroth16Verifier, (vk_bytes.clone(),));
-    let client = CircomGroth16VerifierClient::new(&env, &contract_id);
+    let client = register_initialized(&env, &vk_bytes);
 
     // Provide too few public inputs (length 5 instead of 11)
     let mut short_inputs: Vec<Fr> = Vec::new(&env);
@@ -150,6 +165,248 @@ fn rejects_wrong_public_input_length() {
     ));
 }
 
+#[test]
+fn lazy_initialization_is_guarded() {
+    let env = test_env();
+    env.mock_all_auths();
+    let (vk_bytes, proof, public_inputs, _) = build_test(&env);
+    let admin = Address::generate(&env);
+    let contract_id = env.register(CircomGroth16Verifier, (&admin,));
+    let client = CircomGroth16VerifierClient::new(&env, &contract_id);
+
+    assert!(!client.is_initialized());
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(
+        client.try_verify(&proof, &public_inputs),
+        Err(Ok(Groth16Error::NotInitialized))
+    );
+
+    client.initialize(&vk_bytes);
+    assert!(client.is_initialized());
+    assert_eq!(client.try_verify(&proof, &public_inputs), Ok(Ok(true)));
+
+    assert_eq!(
+        client.try_initialize(&vk_bytes),
+        Err(Ok(InitError::AlreadyInitialized))
+    );
+}
+
+#[test]
+fn initialize_requires_deploy_time_admin() {
+    let env = test_env();
+    let (vk_bytes, _, _, _) = build_test(&env);
+    let admin = Address::generate(&env);
+    let contract_id = env.register(CircomGroth16Verifier, (&admin,));
+    let client = CircomGroth16VerifierClient::new(&env, &contract_id);
+
+    // A front-runner signing for themselves cannot pick the key
+    let attacker = Address::generate(&env);
+    let result = client
+        .mock_auths(&[MockAuth {
+            address: &attacker,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "initialize",
+                args: (vk_bytes.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_initialize(&vk_bytes);
+    assert!(result.is_err());
+    assert!(!client.is_initialized());
+    assert_eq!(client.get_vk_history().len(), 0);
+
+    client
+        .mock_auths(&[MockAuth {
+            address: &admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "initialize",
+                args: (vk_bytes.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .initialize(&vk_bytes);
+    assert!(client.is_initialized());
+}
+
+#[test]
+fn vk_history_records_initial_key() {
+    let env = test_env();
+    env.mock_all_auths();
+    let (vk_bytes, _, _, _) = build_test(&env);
+    let admin = Address::generate(&env);
+    let contract_id = env.register(CircomGroth16Verifier, (&admin,));
+    let client = CircomGroth16VerifierClient::new(&env, &contract_id);
+    assert_eq!(client.get_vk_history().len(), 0);
+
+    client.initialize(&vk_bytes);
+
+    let mut encoded = Bytes::from(vk_bytes.alpha.clone());
+    encoded.append(&Bytes::from(vk_bytes.beta.clone()));
+    encoded.append(&Bytes::from(vk_bytes.gamma.clone()));
+    encoded.append(&Bytes::from(vk_bytes.delta.clone()));
+    for point in vk_bytes.ic.iter() {
+        encoded.append(&Bytes::from(point));
+    }
+    let history = client.get_vk_history();
+    assert_eq!(history.len(), 1);
+    assert_eq!(
+        history.get(0).unwrap(),
+        VkChange {
+            old_hash: None,
+            new_hash: env.crypto().keccak256(&encoded).into(),
+            ledger: env.ledger().sequence(),
+            admin,
+        }
+    );
+
+    // A rejected re-initialization leaves the log untouched
+    let _ = client.try_initialize(&vk_bytes);
+    assert_eq!(client.get_vk_history().len(), 1);
+}
+
+#[test]
+fn rotate_vk_drops_points_cached_for_old_key() {
+    let env = test_env();
+    let (vk_bytes, proof, public_inputs, _) = build_test(&env);
+    let (new_vk_bytes, new_proof, new_inputs, _) =
+        build_test_with_rng(&env, &mut StdRng::seed_from_u64(8));
+    let client = register_initialized(&env, &vk_bytes);
+
+    let uninitialized = CircomGroth16VerifierClient::new(
+        &env,
+        &env.register(CircomGroth16Verifier, (Address::generate(&env),)),
+    );
+    assert_eq!(
+        uninitialized.try_rotate_vk(&new_vk_bytes),
+        Err(Ok(Groth16Error::NotInitialized))
+    );
+
+    let fixed = public_inputs.slice(0..4);
+    let ctx = client.prepare_context(&fixed);
+    let staged_id = client.stage_msm(&public_inputs);
+
+    client.rotate_vk(&new_vk_bytes);
+
+    let history = client.get_vk_history();
+    assert_eq!(history.len(), 2);
+    let change = history.get(1).unwrap();
+    assert_eq!(change.old_hash, Some(history.get(0).unwrap().new_hash));
+    assert_ne!(change.old_hash, Some(change.new_hash));
+
+    assert_eq!(
+        client.try_verify(&proof, &public_inputs),
+        Err(Ok(Groth16Error::InvalidProof))
+    );
+    assert_eq!(client.try_verify(&new_proof, &new_inputs), Ok(Ok(true)));
+
+    // Same inputs, same ids, but the points cached for the old key are gone
+    assert_eq!(
+        client.try_verify_with_context(&ctx, &new_inputs.slice(4..), &new_proof),
+        Err(Ok(Groth16Error::NotInitialized))
+    );
+    assert_eq!(
+        client.try_finish_verify(&staged_id, &new_proof),
+        Err(Ok(Groth16Error::NotInitialized))
+    );
+    assert_eq!(client.prepare_context(&new_inputs.slice(0..4)), ctx);
+    assert_eq!(
+        client.try_verify_with_context(&ctx, &new_inputs.slice(4..), &new_proof),
+        Ok(Ok(true))
+    );
+    assert_eq!(client.stage_msm(&new_inputs), staged_id);
+    assert_eq!(
+        client.try_finish_verify(&staged_id, &new_proof),
+        Ok(Ok(true))
+    );
+}
+
+#[test]
+fn self_check_replays_known_good_proof() {
+    let env = test_env();
+    let (vk_bytes, proof, public_inputs, _) = build_test(&env);
+    let client = register_initialized(&env, &vk_bytes);
+
+    assert_eq!(
+        client.try_self_check(),
+        Err(Ok(Groth16Error::NotInitialized))
+    );
+
+    client.set_self_check_vector(&proof, &public_inputs);
+    assert!(client.self_check());
+
+    // A vector that no longer matches the key reports false instead of failing
+    let mut wrong_inputs = public_inputs.clone();
+    wrong_inputs.set(0, fr_from_ark(&env, ArkFr::from(34u64)));
+    client.set_self_check_vector(&proof, &wrong_inputs);
+    assert!(!client.self_check());
+}
+
+#[test]
+fn prepared_context_verifies_remaining_inputs() {
+    let env = test_env();
+    let (vk_bytes, proof, public_inputs, _) = build_test(&env);
+    let client = register_initialized(&env, &vk_bytes);
+
+    let fixed = public_inputs.slice(0..4);
+    let remaining = public_inputs.slice(4..);
+
+    assert_eq!(
+        client.try_verify_with_context(&BytesN::from_array(&env, &[0u8; 32]), &remaining, &proof),
+        Err(Ok(Groth16Error::NotInitialized))
+    );
+
+    let ctx = client.prepare_context(&fixed);
+    assert_eq!(client.prepare_context(&fixed), ctx);
+    assert_eq!(
+        client.try_verify_with_context(&ctx, &remaining, &proof),
+        Ok(Ok(true))
+    );
+
+    // Remaining inputs must fill the rest of the IC vector exactly
+    assert_eq!(
+        client.try_verify_with_context(&ctx, &remaining.slice(1..), &proof),
+        Err(Ok(Groth16Error::MalformedPublicInputs))
+    );
+    // Fixing every input leaves nothing to verify against
+    assert_eq!(
+        client.try_prepare_context(&public_inputs),
+        Err(Ok(Groth16Error::MalformedPublicInputs))
+    );
+}
+
+#[test]
+fn staged_msm_finishes_with_pairing_only() {
+    let env = test_env();
+    let (vk_bytes, proof, public_inputs, _) = build_test(&env);
+    let client = register_initialized(&env, &vk_bytes);
+
+    assert_eq!(
+        client.try_finish_verify(&BytesN::from_array(&env, &[0u8; 32]), &proof),
+        Err(Ok(Groth16Error::NotInitialized))
+    );
+
+    let staged_id = client.stage_msm(&public_inputs);
+    assert_eq!(client.stage_msm(&public_inputs), staged_id);
+    assert_eq!(client.try_finish_verify(&staged_id, &proof), Ok(Ok(true)));
+
+    // The staged point is bound to its inputs: other inputs fail the pairing
+    let mut wrong_inputs = public_inputs.clone();
+    wrong_inputs.set(0, fr_from_ark(&env, ArkFr::from(34u64)));
+    let wrong_id = client.stage_msm(&wrong_inputs);
+    assert_ne!(wrong_id, staged_id);
+    assert_eq!(
+        client.try_finish_verify(&wrong_id, &proof),
+        Err(Ok(Groth16Error::InvalidProof))
+    );
+
+    assert_eq!(
+        client.try_stage_msm(&public_inputs.slice(1..)),
+        Err(Ok(Groth16Error::MalformedPublicInputs))
+    );
+}
+
 #[test]
 fn groth16_proof_parsing_checks_size() {
     let env = test_env();
@@ -164,4 +421,17 @@ fn groth16_proof_parsing_checks_size() {
         Groth16Proof::try_from(truncated),
         Err(Groth16Error::MalformedProof)
     ));
-}
\ No newline at end of file
+}
+#[test]
+fn get_info_reports_verifier_interface() {
+    let env = Env::default();
+    let contract_id = env.register(CircomGroth16Verifier, (Address::generate(&env),));
+    let client = CircomGroth16VerifierClient::new(&env, &contract_id);
+
+    let info = client.get_info();
+    assert_eq!(info.name, String::from_str(&env, "circom-groth16-verifier"));
+    assert_eq!(
+        info.interfaces.get(0),
+        Some(String::from_str(&env, "groth16-verifier-v1"))
+    );
+}
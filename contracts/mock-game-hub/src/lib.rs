@@ -1,15 +1,91 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractevent, contractimpl, Address, Env};
+use soroban_sdk::{
+    contract, contracterror, contractevent, contractimpl, contracttype, vec, Address, BytesN, Env,
+    IntoVal, String, Symbol, Val, Vec,
+};
+
+use ownable::Ownable;
 
 /// Mock Game Hub contract for game studio development
 ///
 /// This contract provides the same external interface that games expect
-/// (start_game, end_game) but does nothing internally. It exists purely
+/// (start_game, end_game) and does no points accounting. It exists purely
 /// for game contracts to compile and integrate during development.
+///
+/// It also keeps a lifecycle notification registry so third-party contracts
+/// (betting markets, achievements) can react to finished games without the
+/// game contracts knowing about them. Listeners are registered by the admin
+/// and notified in their own transaction through `notify_listener`, so a
+/// failing or expensive listener can never make `end_game` fail.
 #[contract]
 pub struct MockGameHub;
 
+/// Maximum number of listeners a single game can have
+pub const MAX_LISTENERS_PER_GAME: u32 = 8;
+
+/// TTL for per-session records (~30 days)
+const SESSION_TTL_LEDGERS: u32 = 518_400;
+
+/// `GameNotFound` code shared by the game contracts' error enums
+const GAME_NOT_FOUND: u32 = 1;
+
+/// Returned by `get_info` so the hub, SDK and tooling can negotiate capabilities.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractInfo {
+    pub name: String,
+    pub version: String,
+    /// Interface ids implemented by the contract, e.g. "game-hub-v1".
+    pub interfaces: Vec<String>,
+    /// Source revision baked in at build time from ZKARCADE_BUILD_HASH ("dev" when unset).
+    pub build_hash: String,
+}
+
+/// Stakes of a started session, kept until it ends or `claim_expired`
+/// refunds them
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SessionStakes {
+    pub game_id: Address,
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+}
+
+/// Interface ids reported by `get_info`
+const INTERFACES: [&str; 2] = ["game-hub-v1", "lifecycle-listeners-v1"];
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    /// Listener addresses subscribed to a game contract
+    Listeners(Address),
+    /// Game contract that started a session
+    SessionGame(u32),
+    /// Outcome of an ended session (player1_won), kept for `notify_listener`
+    SessionResult(u32),
+    /// Set once a listener has been notified of a session
+    Notified(u32, Address),
+    /// Stakes of a session that has not ended yet (persistent, so they
+    /// outlive an expired game)
+    SessionStakes(u32),
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum HubError {
+    AlreadySubscribed = 1,
+    NotSubscribed = 2,
+    TooManyListeners = 3,
+    SessionNotEnded = 4,
+    AlreadyNotified = 5,
+    SessionNotFound = 6,
+    GameNotExpired = 7,
+}
+
 #[contractevent]
 pub struct GameStarted {
     pub session_id: u32,
@@ -26,8 +102,22 @@ pub struct GameEnded {
     pub player1_won: bool,
 }
 
+#[contractevent]
+pub struct StakesRefunded {
+    pub session_id: u32,
+    pub game_id: Address,
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+}
+
 #[contractimpl]
 impl MockGameHub {
+    pub fn __constructor(env: Env, admin: Address) {
+        Ownable::init(&env, &admin);
+    }
+
     /// Start a game session
     ///
     /// # Arguments
@@ -49,13 +139,32 @@ impl MockGameHub {
         // No auth required for mock
         GameStarted {
             session_id,
-            game_id,
-            player1,
-            player2,
+            game_id: game_id.clone(),
+            player1: player1.clone(),
+            player2: player2.clone(),
             player1_points,
             player2_points,
         }
         .publish(&env);
+
+        let stakes_key = DataKey::SessionStakes(session_id);
+        let stakes = SessionStakes {
+            game_id: game_id.clone(),
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+        };
+        let persistent = env.storage().persistent();
+        persistent.set(&stakes_key, &stakes);
+        persistent.extend_ttl(&stakes_key, SESSION_TTL_LEDGERS, SESSION_TTL_LEDGERS);
+
+        let key = DataKey::SessionGame(session_id);
+        env.storage().temporary().set(&key, &game_id);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, SESSION_TTL_LEDGERS, SESSION_TTL_LEDGERS);
+
         // bump instance ttl if required
         env.storage().instance().extend_ttl(17_280, 518_400);
     }
@@ -76,17 +185,253 @@ impl MockGameHub {
             player1_won,
         }
         .publish(&env);
+
+        // The stakes are settled, so `claim_expired` can no longer refund them
+        env.storage()
+            .persistent()
+            .remove(&DataKey::SessionStakes(session_id));
+
+        // Listeners are not called here; relayers deliver the result with
+        // `notify_listener` once they see `GameEnded`
+        let storage = env.storage().temporary();
+        if storage.has(&DataKey::SessionGame(session_id)) {
+            let key = DataKey::SessionResult(session_id);
+            storage.set(&key, &player1_won);
+            storage.extend_ttl(&key, SESSION_TTL_LEDGERS, SESSION_TTL_LEDGERS);
+        }
+    }
+
+    /// Refund the stakes of a session whose game state expired mid-match
+    ///
+    /// Anyone can claim. The game contract must report the session as
+    /// started (`was_started`) while `get_game_status` fails with
+    /// `GameNotFound`; a session that ended or was already refunded has no
+    /// stakes left (`SessionNotFound`).
+    pub fn claim_expired(env: Env, session_id: u32) -> Result<SessionStakes, HubError> {
+        let key = DataKey::SessionStakes(session_id);
+        let stakes: SessionStakes = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(HubError::SessionNotFound)?;
+
+        let args = vec![&env, session_id.into_val(&env)];
+        let started: bool = env.invoke_contract(
+            &stakes.game_id,
+            &Symbol::new(&env, "was_started"),
+            args.clone(),
+        );
+        let status = env.try_invoke_contract::<Val, soroban_sdk::Error>(
+            &stakes.game_id,
+            &Symbol::new(&env, "get_game_status"),
+            args,
+        );
+        let expired = matches!(
+            status,
+            Err(Ok(err)) if err == soroban_sdk::Error::from_contract_error(GAME_NOT_FOUND)
+        );
+        if !started || !expired {
+            return Err(HubError::GameNotExpired);
+        }
+
+        env.storage().persistent().remove(&key);
+        StakesRefunded {
+            session_id,
+            game_id: stakes.game_id.clone(),
+            player1: stakes.player1.clone(),
+            player2: stakes.player2.clone(),
+            player1_points: stakes.player1_points,
+            player2_points: stakes.player2_points,
+        }
+        .publish(&env);
+        Ok(stakes)
+    }
+
+    /// Contract name, version, interface ids and build hash
+    pub fn get_info(env: Env) -> ContractInfo {
+        let mut interfaces = Vec::new(&env);
+        for id in INTERFACES {
+            interfaces.push_back(String::from_str(&env, id));
+        }
+        ContractInfo {
+            name: String::from_str(&env, env!("CARGO_PKG_NAME")),
+            version: String::from_str(&env, env!("CARGO_PKG_VERSION")),
+            interfaces,
+            build_hash: String::from_str(&env, option_env!("ZKARCADE_BUILD_HASH").unwrap_or("dev")),
+        }
+    }
+
+    /// Subscribe a listener contract to end-of-game notifications for a game
+    /// (admin only, so nobody can fill a game's listener slots)
+    ///
+    /// The listener must expose `on_game_ended(game_id, session_id, player1_won)`.
+    pub fn subscribe(env: Env, game_id: Address, listener: Address) -> Result<(), HubError> {
+        Ownable::require_admin(&env);
+
+        let key = DataKey::Listeners(game_id.clone());
+        let mut listeners = Self::get_listeners(env.clone(), game_id);
+        if listeners.contains(&listener) {
+            return Err(HubError::AlreadySubscribed);
+        }
+        if listeners.len() >= MAX_LISTENERS_PER_GAME {
+            return Err(HubError::TooManyListeners);
+        }
+
+        listeners.push_back(listener);
+        env.storage().persistent().set(&key, &listeners);
+        Ok(())
+    }
+
+    /// Remove a listener from a game's notification list (admin only)
+    pub fn unsubscribe(env: Env, game_id: Address, listener: Address) -> Result<(), HubError> {
+        Ownable::require_admin(&env);
+
+        let key = DataKey::Listeners(game_id.clone());
+        let mut listeners = Self::get_listeners(env.clone(), game_id);
+        let index = listeners
+            .first_index_of(&listener)
+            .ok_or(HubError::NotSubscribed)?;
+
+        listeners.remove(index);
+        env.storage().persistent().set(&key, &listeners);
+        Ok(())
+    }
+
+    /// Call `on_game_ended` on one listener of an ended session
+    ///
+    /// Anyone can relay a notification, and each listener is notified at most
+    /// once per session. Each listener runs in its own transaction, so one that
+    /// fails or exhausts its budget only fails this call, never `end_game` or
+    /// the other listeners.
+    pub fn notify_listener(env: Env, session_id: u32, listener: Address) -> Result<(), HubError> {
+        let storage = env.storage().temporary();
+        let player1_won: bool = storage
+            .get(&DataKey::SessionResult(session_id))
+            .ok_or(HubError::SessionNotEnded)?;
+        let game_id: Address = storage
+            .get(&DataKey::SessionGame(session_id))
+            .ok_or(HubError::SessionNotEnded)?;
+        if !Self::get_listeners(env.clone(), game_id.clone()).contains(&listener) {
+            return Err(HubError::NotSubscribed);
+        }
+
+        let key = DataKey::Notified(session_id, listener.clone());
+        if storage.has(&key) {
+            return Err(HubError::AlreadyNotified);
+        }
+        storage.set(&key, &true);
+        storage.extend_ttl(&key, SESSION_TTL_LEDGERS, SESSION_TTL_LEDGERS);
+
+        let args = vec![
+            &env,
+            game_id.into_val(&env),
+            session_id.into_val(&env),
+            player1_won.into_val(&env),
+        ];
+        env.invoke_contract::<()>(&listener, &Symbol::new(&env, "on_game_ended"), args);
+        Ok(())
+    }
+
+    /// Get the listeners subscribed to a game
+    pub fn get_listeners(env: Env, game_id: Address) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Listeners(game_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn get_admin(env: Env) -> Address {
+        Ownable::admin(&env)
+    }
+
+    pub fn set_admin(env: Env, new_admin: Address) {
+        Ownable::set_admin(&env, &new_admin);
+    }
+
+    /// First step of an admin transfer; `new_admin` takes over once they call `accept_admin`
+    pub fn propose_admin(env: Env, new_admin: Address) {
+        Ownable::propose_admin(&env, &new_admin);
+    }
+
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        Ownable::pending_admin(&env)
+    }
+
+    /// Second step of an admin transfer, signed by the proposed admin
+    pub fn accept_admin(env: Env) -> Address {
+        Ownable::accept_admin(&env)
+    }
+
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        Ownable::upgrade(&env, new_wasm_hash);
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::testutils::{Address as _, MockAuth, MockAuthInvoke};
+
+    #[contract]
+    pub struct RecordingListener;
+
+    #[contractimpl]
+    impl RecordingListener {
+        pub fn on_game_ended(env: Env, _game_id: Address, session_id: u32, player1_won: bool) {
+            env.storage().instance().set(&session_id, &player1_won);
+        }
+
+        pub fn result(env: Env, session_id: u32) -> Option<bool> {
+            env.storage().instance().get(&session_id)
+        }
+    }
+
+    #[contracterror]
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[repr(u32)]
+    pub enum GameError {
+        GameNotFound = 1,
+    }
+
+    /// Game whose state for a session can be dropped, as if its TTL ran out
+    #[contract]
+    pub struct ExpiringGame;
+
+    #[contractimpl]
+    impl ExpiringGame {
+        pub fn start(env: Env, session_id: u32) {
+            env.storage().instance().set(&session_id, &true);
+        }
+
+        pub fn expire(env: Env, session_id: u32) {
+            env.storage().instance().set(&session_id, &false);
+        }
+
+        pub fn was_started(env: Env, session_id: u32) -> bool {
+            env.storage().instance().has(&session_id)
+        }
+
+        pub fn get_game_status(env: Env, session_id: u32) -> Result<u32, GameError> {
+            match env.storage().instance().get::<u32, bool>(&session_id) {
+                Some(true) => Ok(0),
+                _ => Err(GameError::GameNotFound),
+            }
+        }
+    }
+
+    #[contract]
+    pub struct TrappingListener;
+
+    #[contractimpl]
+    impl TrappingListener {
+        pub fn on_game_ended(_env: Env, _game_id: Address, _session_id: u32, _player1_won: bool) {
+            panic!("listener failure");
+        }
+    }
     #[test]
     fn test_start_and_end_game() {
         let env = Env::default();
-        let contract_id = env.register_contract(None, MockGameHub);
+        let contract_id = env.register(MockGameHub, (Address::generate(&env),));
         let client = MockGameHubClient::new(&env, &contract_id);
         let game_id = Address::generate(&env);
         let player1 = Address::generate(&env);
@@ -94,4 +439,145 @@ mod test {
         client.start_game(&game_id, &1, &player1, &player2, &1000, &1000);
         client.end_game(&1, &true);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_listeners_notified_with_failure_isolation() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(MockGameHub, (Address::generate(&env),));
+        let client = MockGameHubClient::new(&env, &contract_id);
+        let game_id = Address::generate(&env);
+        let player1 = Address::generate(&env);
+        let player2 = Address::generate(&env);
+
+        let trapping = env.register(TrappingListener, ());
+        let recording = env.register(RecordingListener, ());
+        let recording_client = RecordingListenerClient::new(&env, &recording);
+
+        client.subscribe(&game_id, &trapping);
+        client.subscribe(&game_id, &recording);
+        assert_eq!(
+            client.try_subscribe(&game_id, &recording),
+            Err(Ok(HubError::AlreadySubscribed))
+        );
+        assert_eq!(client.get_listeners(&game_id).len(), 2);
+
+        client.start_game(&game_id, &7, &player1, &player2, &10, &10);
+        assert_eq!(
+            client.try_notify_listener(&7, &recording),
+            Err(Ok(HubError::SessionNotEnded))
+        );
+
+        // The trapping listener is never called by end_game and only fails its own delivery
+        client.end_game(&7, &false);
+        assert!(client.try_notify_listener(&7, &trapping).is_err());
+        client.notify_listener(&7, &recording);
+        assert_eq!(recording_client.result(&7), Some(false));
+        assert_eq!(
+            client.try_notify_listener(&7, &recording),
+            Err(Ok(HubError::AlreadyNotified))
+        );
+
+        client.unsubscribe(&game_id, &recording);
+        assert_eq!(
+            client.try_unsubscribe(&game_id, &recording),
+            Err(Ok(HubError::NotSubscribed))
+        );
+        client.start_game(&game_id, &8, &player1, &player2, &10, &10);
+        client.end_game(&8, &true);
+        assert_eq!(
+            client.try_notify_listener(&8, &recording),
+            Err(Ok(HubError::NotSubscribed))
+        );
+        assert_eq!(recording_client.result(&8), None);
+    }
+
+    #[test]
+    fn test_only_admin_subscribes_listeners() {
+        let env = Env::default();
+        let admin = Address::generate(&env);
+        let contract_id = env.register(MockGameHub, (&admin,));
+        let client = MockGameHubClient::new(&env, &contract_id);
+        let game_id = Address::generate(&env);
+        let squatter = Address::generate(&env);
+
+        // A listener signing for itself cannot take one of the game's slots
+        let result = client
+            .mock_auths(&[MockAuth {
+                address: &squatter,
+                invoke: &MockAuthInvoke {
+                    contract: &contract_id,
+                    fn_name: "subscribe",
+                    args: (game_id.clone(), squatter.clone()).into_val(&env),
+                    sub_invokes: &[],
+                },
+            }])
+            .try_subscribe(&game_id, &squatter);
+        assert!(result.is_err());
+        assert_eq!(client.get_listeners(&game_id).len(), 0);
+
+        env.mock_all_auths();
+        for _ in 0..MAX_LISTENERS_PER_GAME {
+            client.subscribe(&game_id, &Address::generate(&env));
+        }
+        assert_eq!(
+            client.try_subscribe(&game_id, &squatter),
+            Err(Ok(HubError::TooManyListeners))
+        );
+    }
+
+    #[test]
+    fn test_claim_expired_refunds_stranded_stakes() {
+        let env = Env::default();
+        let contract_id = env.register(MockGameHub, (Address::generate(&env),));
+        let client = MockGameHubClient::new(&env, &contract_id);
+        let game_id = env.register(ExpiringGame, ());
+        let game = ExpiringGameClient::new(&env, &game_id);
+        let player1 = Address::generate(&env);
+        let player2 = Address::generate(&env);
+
+        assert_eq!(
+            client.try_claim_expired(&3),
+            Err(Ok(HubError::SessionNotFound))
+        );
+
+        client.start_game(&game_id, &3, &player1, &player2, &25, &40);
+        game.start(&3);
+        assert_eq!(
+            client.try_claim_expired(&3),
+            Err(Ok(HubError::GameNotExpired))
+        );
+
+        game.expire(&3);
+        let stakes = client.claim_expired(&3);
+        assert_eq!(stakes.player1, player1);
+        assert_eq!(stakes.player2_points, 40);
+        assert_eq!(
+            client.try_claim_expired(&3),
+            Err(Ok(HubError::SessionNotFound))
+        );
+
+        // Ended sessions were settled and have nothing to refund
+        client.start_game(&game_id, &4, &player1, &player2, &25, &40);
+        game.start(&4);
+        client.end_game(&4, &true);
+        game.expire(&4);
+        assert_eq!(
+            client.try_claim_expired(&4),
+            Err(Ok(HubError::SessionNotFound))
+        );
+    }
+
+    #[test]
+    fn test_get_info_reports_hub_interface() {
+        let env = Env::default();
+        let contract_id = env.register(MockGameHub, (Address::generate(&env),));
+        let client = MockGameHubClient::new(&env, &contract_id);
+
+        let info = client.get_info();
+        assert_eq!(info.name, String::from_str(&env, "mock-game-hub"));
+        assert!(info
+            .interfaces
+            .contains(String::from_str(&env, "game-hub-v1")));
+    }
+}
@@ -0,0 +1,316 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contracterror, contractevent, contractimpl, contracttype, Address, Bytes, BytesN,
+    Env, String, Vec,
+};
+
+use ownable::Ownable;
+
+/// Player profile shared by every game; leaderboard clients resolve addresses to profiles.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Profile {
+    /// 3..=24 characters from `A-Z`, `a-z`, `0-9` and `_`; unique ignoring case.
+    pub display_name: String,
+    /// URI of the avatar image (e.g. `ipfs://...`); empty when the player has none.
+    pub avatar: String,
+    /// ISO 3166-1 alpha-2 code in upper case, e.g. "AR".
+    pub country: Option<String>,
+    /// Ledger of the last `set_profile`, for the update cooldown.
+    pub updated_at_ledger: u32,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ProfileError {
+    InvalidName = 1,
+    NameTaken = 2,
+    InvalidAvatar = 3,
+    InvalidCountry = 4,
+    UpdateTooSoon = 5,
+    ProfileNotFound = 6,
+    NameBlocked = 7,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Profile(Address),
+    /// Lower-cased display name -> the player holding it.
+    NameOwner(Bytes),
+    /// Ledgers a player waits between profile updates.
+    UpdateCooldown,
+    /// Lower-cased display name removed by moderation; nobody can claim it until unblocked.
+    BlockedName(Bytes),
+    /// Ledger the player's profile was removed by moderation, for the update cooldown.
+    RemovedAt(Address),
+}
+
+/// Returned by `get_info` so the hub, SDK and tooling can negotiate capabilities.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractInfo {
+    pub name: String,
+    pub version: String,
+    /// Interface ids implemented by the contract, e.g. "profiles-v1".
+    pub interfaces: Vec<String>,
+    /// Source revision baked in at build time from ZKARCADE_BUILD_HASH ("dev" when unset).
+    pub build_hash: String,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProfileUpdated {
+    #[topic]
+    pub player: Address,
+    pub display_name: String,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProfileRemoved {
+    #[topic]
+    pub player: Address,
+}
+
+/// Interface ids reported by `get_info`
+const INTERFACES: [&str; 1] = ["profiles-v1"];
+
+pub const MIN_NAME_LEN: u32 = 3;
+pub const MAX_NAME_LEN: u32 = 24;
+pub const MAX_AVATAR_LEN: u32 = 128;
+/// Cooldown between updates when not overridden (~1 day)
+const DEFAULT_UPDATE_COOLDOWN_LEDGERS: u32 = 17_280;
+/// TTL profiles and name claims are extended to on every update (~180 days)
+const PROFILE_TTL_LEDGERS: u32 = 3_110_400;
+/// Players passed to `get_profiles` in one call
+pub const MAX_BATCH_PROFILES: u32 = 50;
+
+#[contract]
+pub struct ProfilesContract;
+
+#[contractimpl]
+impl ProfilesContract {
+    pub fn __constructor(env: Env, admin: Address) {
+        Ownable::init(&env, &admin);
+    }
+
+    /// Create or replace the caller's profile. The first profile can be set at any time; later
+    /// updates, and a new profile after a moderation removal, wait `get_update_cooldown` ledgers.
+    /// Renaming frees the old name.
+    pub fn set_profile(
+        env: Env,
+        player: Address,
+        display_name: String,
+        avatar: String,
+        country: Option<String>,
+    ) -> Result<(), ProfileError> {
+        player.require_auth();
+
+        let name = normalized_name(&env, &display_name)?;
+        if avatar.len() > MAX_AVATAR_LEN {
+            return Err(ProfileError::InvalidAvatar);
+        }
+        if let Some(country) = &country {
+            validate_country(country)?;
+        }
+
+        let storage = env.storage().persistent();
+        if storage.has(&DataKey::BlockedName(name.clone())) {
+            return Err(ProfileError::NameBlocked);
+        }
+        let name_key = DataKey::NameOwner(name);
+        if let Some(owner) = storage.get::<_, Address>(&name_key) {
+            if owner != player {
+                return Err(ProfileError::NameTaken);
+            }
+        }
+
+        let ledger = env.ledger().sequence();
+        let profile_key = DataKey::Profile(player.clone());
+        let removed_key = DataKey::RemovedAt(player.clone());
+        let previous = storage.get::<_, Profile>(&profile_key);
+        let last_change = match &previous {
+            Some(previous) => Some(previous.updated_at_ledger),
+            None => storage.get::<_, u32>(&removed_key),
+        };
+        if let Some(last_change) = last_change {
+            let cooldown = Self::get_update_cooldown(env.clone());
+            if ledger < last_change.saturating_add(cooldown) {
+                return Err(ProfileError::UpdateTooSoon);
+            }
+        }
+        storage.remove(&removed_key);
+        if let Some(previous) = previous {
+            storage.remove(&DataKey::NameOwner(normalized_name(
+                &env,
+                &previous.display_name,
+            )?));
+        }
+
+        let profile = Profile {
+            display_name: display_name.clone(),
+            avatar,
+            country,
+            updated_at_ledger: ledger,
+        };
+        storage.set(&profile_key, &profile);
+        storage.set(&name_key, &player);
+        storage.extend_ttl(&profile_key, PROFILE_TTL_LEDGERS, PROFILE_TTL_LEDGERS);
+        storage.extend_ttl(&name_key, PROFILE_TTL_LEDGERS, PROFILE_TTL_LEDGERS);
+
+        ProfileUpdated {
+            player,
+            display_name,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    pub fn get_profile(env: Env, player: Address) -> Option<Profile> {
+        env.storage().persistent().get(&DataKey::Profile(player))
+    }
+
+    /// Profiles of `players` in order (None for players without one), so leaderboards resolve a
+    /// page of addresses in one call. At most `MAX_BATCH_PROFILES` players.
+    pub fn get_profiles(env: Env, players: Vec<Address>) -> Vec<Option<Profile>> {
+        if players.len() > MAX_BATCH_PROFILES {
+            panic!("Too many players");
+        }
+        let mut profiles = Vec::new(&env);
+        for player in players.iter() {
+            profiles.push_back(Self::get_profile(env.clone(), player));
+        }
+        profiles
+    }
+
+    /// Player holding `display_name`, compared ignoring case.
+    pub fn resolve_name(env: Env, display_name: String) -> Option<Address> {
+        let name = normalized_name(&env, &display_name).ok()?;
+        env.storage().persistent().get(&DataKey::NameOwner(name))
+    }
+
+    /// Moderation: the admin removes `player`'s profile. Its name stays blocked for everyone
+    /// until `unblock_name`, and the player waits the update cooldown before a new profile.
+    pub fn remove_profile(env: Env, player: Address) -> Result<(), ProfileError> {
+        Ownable::require_admin(&env);
+
+        let storage = env.storage().persistent();
+        let profile_key = DataKey::Profile(player.clone());
+        let profile: Profile = storage
+            .get(&profile_key)
+            .ok_or(ProfileError::ProfileNotFound)?;
+        let name = normalized_name(&env, &profile.display_name)?;
+        storage.remove(&profile_key);
+        storage.remove(&DataKey::NameOwner(name.clone()));
+
+        let blocked_key = DataKey::BlockedName(name);
+        let removed_key = DataKey::RemovedAt(player.clone());
+        storage.set(&blocked_key, &true);
+        storage.set(&removed_key, &env.ledger().sequence());
+        storage.extend_ttl(&blocked_key, PROFILE_TTL_LEDGERS, PROFILE_TTL_LEDGERS);
+        storage.extend_ttl(&removed_key, PROFILE_TTL_LEDGERS, PROFILE_TTL_LEDGERS);
+
+        ProfileRemoved { player }.publish(&env);
+        Ok(())
+    }
+
+    /// Moderation: the admin lets a name removed with `remove_profile` be claimed again.
+    pub fn unblock_name(env: Env, display_name: String) -> Result<(), ProfileError> {
+        Ownable::require_admin(&env);
+        let name = normalized_name(&env, &display_name)?;
+        env.storage()
+            .persistent()
+            .remove(&DataKey::BlockedName(name));
+        Ok(())
+    }
+
+    pub fn get_info(env: Env) -> ContractInfo {
+        let mut interfaces = Vec::new(&env);
+        for id in INTERFACES {
+            interfaces.push_back(String::from_str(&env, id));
+        }
+        ContractInfo {
+            name: String::from_str(&env, env!("CARGO_PKG_NAME")),
+            version: String::from_str(&env, env!("CARGO_PKG_VERSION")),
+            interfaces,
+            build_hash: String::from_str(&env, option_env!("ZKARCADE_BUILD_HASH").unwrap_or("dev")),
+        }
+    }
+
+    pub fn get_admin(env: Env) -> Address {
+        Ownable::admin(&env)
+    }
+
+    pub fn set_admin(env: Env, new_admin: Address) {
+        Ownable::set_admin(&env, &new_admin);
+    }
+
+    /// First step of an admin transfer; `new_admin` takes over once they call `accept_admin`.
+    pub fn propose_admin(env: Env, new_admin: Address) {
+        Ownable::propose_admin(&env, &new_admin);
+    }
+
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        Ownable::pending_admin(&env)
+    }
+
+    /// Second step of an admin transfer, signed by the proposed admin.
+    pub fn accept_admin(env: Env) -> Address {
+        Ownable::accept_admin(&env)
+    }
+
+    /// Ledgers a player waits between profile updates.
+    pub fn get_update_cooldown(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::UpdateCooldown)
+            .unwrap_or(DEFAULT_UPDATE_COOLDOWN_LEDGERS)
+    }
+
+    pub fn set_update_cooldown(env: Env, ledgers: u32) {
+        Ownable::require_admin(&env);
+        env.storage()
+            .instance()
+            .set(&DataKey::UpdateCooldown, &ledgers);
+    }
+
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        Ownable::upgrade(&env, new_wasm_hash);
+    }
+}
+
+/// `display_name` lower-cased, after checking its length and characters.
+fn normalized_name(env: &Env, display_name: &String) -> Result<Bytes, ProfileError> {
+    let len = display_name.len();
+    if !(MIN_NAME_LEN..=MAX_NAME_LEN).contains(&len) {
+        return Err(ProfileError::InvalidName);
+    }
+    let mut buf = [0u8; MAX_NAME_LEN as usize];
+    let name = &mut buf[..len as usize];
+    display_name.copy_into_slice(name);
+    for byte in name.iter_mut() {
+        if !(byte.is_ascii_alphanumeric() || *byte == b'_') {
+            return Err(ProfileError::InvalidName);
+        }
+        byte.make_ascii_lowercase();
+    }
+    Ok(Bytes::from_slice(env, name))
+}
+
+fn validate_country(country: &String) -> Result<(), ProfileError> {
+    if country.len() != 2 {
+        return Err(ProfileError::InvalidCountry);
+    }
+    let mut code = [0u8; 2];
+    country.copy_into_slice(&mut code);
+    if !code.iter().all(u8::is_ascii_uppercase) {
+        return Err(ProfileError::InvalidCountry);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,152 @@
+#![cfg(test)]
+
+use crate::{Profile, ProfileError, ProfilesContract, ProfilesContractClient};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::{vec, Address, Env, String};
+
+fn setup() -> (Env, ProfilesContractClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(ProfilesContract, (&admin,));
+    let client = ProfilesContractClient::new(&env, &contract_id);
+    (env, client, admin)
+}
+
+fn text(env: &Env, value: &str) -> String {
+    String::from_str(env, value)
+}
+
+#[test]
+fn test_set_and_read_profiles() {
+    let (env, client, _admin) = setup();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let avatar = text(&env, "ipfs://avatar-alice");
+    let country = Some(text(&env, "AR"));
+    client.set_profile(&alice, &text(&env, "Alice_01"), &avatar, &country);
+
+    let expected = Profile {
+        display_name: text(&env, "Alice_01"),
+        avatar,
+        country,
+        updated_at_ledger: env.ledger().sequence(),
+    };
+    assert_eq!(client.get_profile(&alice), Some(expected.clone()));
+    assert_eq!(client.get_profile(&bob), None);
+    assert_eq!(
+        client.get_profiles(&vec![&env, bob.clone(), alice.clone()]),
+        vec![&env, None, Some(expected)]
+    );
+    assert_eq!(client.resolve_name(&text(&env, "alice_01")), Some(alice));
+    assert_eq!(client.resolve_name(&text(&env, "nobody")), None);
+}
+
+#[test]
+fn test_profile_fields_are_validated() {
+    let (env, client, _admin) = setup();
+    let alice = Address::generate(&env);
+    let avatar = text(&env, "");
+
+    for name in ["ab", "a_name_that_is_way_too_long", "two words", "émile"] {
+        let result = client.try_set_profile(&alice, &text(&env, name), &avatar, &None);
+        assert_eq!(result, Err(Ok(ProfileError::InvalidName)));
+    }
+    for country in ["ar", "ARG", "A1"] {
+        let result = client.try_set_profile(
+            &alice,
+            &text(&env, "alice"),
+            &avatar,
+            &Some(text(&env, country)),
+        );
+        assert_eq!(result, Err(Ok(ProfileError::InvalidCountry)));
+    }
+    let long_avatar = [b'a'; 129];
+    let result = client.try_set_profile(
+        &alice,
+        &text(&env, "alice"),
+        &String::from_bytes(&env, &long_avatar),
+        &None,
+    );
+    assert_eq!(result, Err(Ok(ProfileError::InvalidAvatar)));
+}
+
+#[test]
+fn test_names_are_unique_and_updates_rate_limited() {
+    let (env, client, _admin) = setup();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let avatar = text(&env, "");
+
+    client.set_profile(&alice, &text(&env, "Captain"), &avatar, &None);
+    let taken = client.try_set_profile(&bob, &text(&env, "CAPTAIN"), &avatar, &None);
+    assert_eq!(taken, Err(Ok(ProfileError::NameTaken)));
+
+    let cooldown = client.get_update_cooldown();
+    let too_soon = client.try_set_profile(&alice, &text(&env, "Admiral"), &avatar, &None);
+    assert_eq!(too_soon, Err(Ok(ProfileError::UpdateTooSoon)));
+
+    // Renaming after the cooldown frees the old name
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + cooldown);
+    client.set_profile(&alice, &text(&env, "Admiral"), &avatar, &None);
+    assert_eq!(client.resolve_name(&text(&env, "captain")), None);
+    client.set_profile(&bob, &text(&env, "Captain"), &avatar, &None);
+    assert_eq!(client.resolve_name(&text(&env, "captain")), Some(bob));
+
+    client.set_update_cooldown(&0);
+    client.set_profile(
+        &alice,
+        &text(&env, "Admiral"),
+        &text(&env, "ipfs://a"),
+        &None,
+    );
+}
+
+#[test]
+fn test_admin_removes_profile() {
+    let (env, client, _admin) = setup();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let avatar = text(&env, "");
+
+    client.set_profile(&alice, &text(&env, "rude_name"), &avatar, &None);
+    client.remove_profile(&alice);
+    assert_eq!(client.get_profile(&alice), None);
+    assert_eq!(client.resolve_name(&text(&env, "rude_name")), None);
+
+    let missing = client.try_remove_profile(&alice);
+    assert_eq!(missing, Err(Ok(ProfileError::ProfileNotFound)));
+
+    // The removed name stays blocked for everyone, whatever the case
+    let reclaimed = client.try_set_profile(&bob, &text(&env, "Rude_Name"), &avatar, &None);
+    assert_eq!(reclaimed, Err(Ok(ProfileError::NameBlocked)));
+
+    // The removed player waits out the cooldown before a new profile
+    let cooldown = client.get_update_cooldown();
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + cooldown - 1);
+    let too_soon = client.try_set_profile(&alice, &text(&env, "alice"), &avatar, &None);
+    assert_eq!(too_soon, Err(Ok(ProfileError::UpdateTooSoon)));
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 1);
+    let blocked = client.try_set_profile(&alice, &text(&env, "rude_name"), &avatar, &None);
+    assert_eq!(blocked, Err(Ok(ProfileError::NameBlocked)));
+    client.set_profile(&alice, &text(&env, "alice"), &avatar, &None);
+
+    client.unblock_name(&text(&env, "RUDE_NAME"));
+    client.set_profile(&bob, &text(&env, "rude_name"), &avatar, &None);
+    assert_eq!(client.resolve_name(&text(&env, "rude_name")), Some(bob));
+}
+
+#[test]
+fn test_get_info_reports_profiles_interface() {
+    let (env, client, admin) = setup();
+
+    assert_eq!(client.get_admin(), admin);
+    let info = client.get_info();
+    assert_eq!(info.name, text(&env, "profiles"));
+    assert_eq!(info.interfaces, vec![&env, text(&env, "profiles-v1")]);
+}
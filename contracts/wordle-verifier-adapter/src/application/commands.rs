@@ -1,7 +1,34 @@
-use soroban_sdk::{Bytes, BytesN, Env};
+use soroban_sdk::{panic_with_error, Bytes, BytesN, Env, Symbol};
 
-use crate::domain::proof::{PayloadParser, PublicInputs};
-use crate::infrastructure::Groth16VerifierGateway;
+use crate::domain::proof::{ParsedPayload, PayloadLimits, PayloadParser, PublicInputs};
+use crate::domain::{VerificationOutcome, VerifierError};
+use crate::infrastructure::{
+    AdminRepository, Groth16VerifierGateway, MetricsRepository, ProofCacheRepository,
+};
+
+use super::queries::GetExpectedInputsQuery;
+
+/// Parses a payload under the configured caps. Oversized payloads abort the
+/// call with `PayloadTooLarge`; any other parse failure yields None.
+fn parse_payload(env: &Env, proof_payload: &Bytes) -> Option<ParsedPayload> {
+    let limits = AdminRepository::get_payload_limits(env);
+    match PayloadParser::parse(env, proof_payload, &limits) {
+        Ok(p) => Some(p),
+        Err(VerifierError::PayloadTooLarge) => {
+            panic_with_error!(env, VerifierError::PayloadTooLarge)
+        }
+        Err(_) => None,
+    }
+}
+
+/// Counts `outcome` in the verification metrics and returns whether the
+/// proof was accepted
+fn record_outcome(env: &Env, outcome: VerificationOutcome) -> bool {
+    let mut metrics = MetricsRepository::get(env);
+    metrics.record(outcome);
+    MetricsRepository::save(env, &metrics);
+    outcome == VerificationOutcome::Verified
+}
 
 /// Command: Verify a ZK proof
 pub struct VerifyProofCommand;
@@ -14,15 +41,32 @@ impl VerifyProofCommand {
         public_inputs_hash: &BytesN<32>,
         proof_payload: &Bytes,
     ) -> bool {
+        record_outcome(
+            env,
+            Self::outcome(env, word_commitment, public_inputs_hash, proof_payload),
+        )
+    }
+
+    fn outcome(
+        env: &Env,
+        word_commitment: &BytesN<32>,
+        public_inputs_hash: &BytesN<32>,
+        proof_payload: &Bytes,
+    ) -> VerificationOutcome {
+        if AdminRepository::is_frozen(env) {
+            return VerificationOutcome::Frozen;
+        }
+
         // Parse the payload
-        let parsed = match PayloadParser::parse(env, proof_payload) {
-            Ok(p) => p,
-            Err(_) => return false,
+        let parsed = match parse_payload(env, proof_payload) {
+            Some(p) => p,
+            None => return VerificationOutcome::Malformed,
         };
 
         // Check expected number of public inputs
-        if parsed.public_inputs.len() != PublicInputs::EXPECTED_COUNT {
-            return false;
+        let circuit_id = Symbol::new(env, PublicInputs::RESOLVE_GUESS_CIRCUIT);
+        if Some(parsed.public_inputs.len()) != GetExpectedInputsQuery::execute(env, &circuit_id) {
+            return VerificationOutcome::Malformed;
         }
 
         // Validate binding inputs match
@@ -34,13 +78,281 @@ impl VerifyProofCommand {
         )
         .is_err()
         {
-            return false;
+            return VerificationOutcome::BindingMismatch;
         }
 
+        // Skip the pairing for a proof accepted moments ago (e.g. a retried
+        // transaction)
+        let cache_key = if ProofCacheRepository::is_enabled(env) {
+            let key =
+                ProofCacheRepository::key(env, word_commitment, public_inputs_hash, proof_payload);
+            if ProofCacheRepository::contains(env, &key) {
+                return VerificationOutcome::Verified;
+            }
+            Some(key)
+        } else {
+            None
+        };
+
         // Verify with the Groth16 verifier
-        match Groth16VerifierGateway::verify(env, &parsed.proof, &parsed.public_inputs) {
-            Ok(result) => result,
-            Err(_) => false,
+        let valid = Groth16VerifierGateway::verify(env, &parsed.proof, &parsed.public_inputs)
+            .unwrap_or_default();
+        if let (true, Some(key)) = (valid, cache_key) {
+            ProofCacheRepository::insert(env, &key);
+        }
+        if valid {
+            VerificationOutcome::Verified
+        } else {
+            VerificationOutcome::ProofInvalid
+        }
+    }
+}
+
+/// Command: Verify a word validity proof
+pub struct VerifyWordCommitmentCommand;
+
+impl VerifyWordCommitmentCommand {
+    /// Verifies that a word commitment hides a valid word (and dictionary
+    /// member when the root is non-zero). Returns false if no word validity
+    /// verifier is configured or the adapter is frozen.
+    pub fn execute(
+        env: &Env,
+        word_commitment: &BytesN<32>,
+        dictionary_root: &BytesN<32>,
+        proof_payload: &Bytes,
+    ) -> bool {
+        record_outcome(
+            env,
+            Self::outcome(env, word_commitment, dictionary_root, proof_payload),
+        )
+    }
+
+    fn outcome(
+        env: &Env,
+        word_commitment: &BytesN<32>,
+        dictionary_root: &BytesN<32>,
+        proof_payload: &Bytes,
+    ) -> VerificationOutcome {
+        if AdminRepository::is_frozen(env) {
+            return VerificationOutcome::Frozen;
+        }
+
+        let verifier_addr = match AdminRepository::get_word_commit_verifier(env) {
+            Some(addr) => addr,
+            None => return VerificationOutcome::Unconfigured,
+        };
+
+        let parsed = match parse_payload(env, proof_payload) {
+            Some(p) => p,
+            None => return VerificationOutcome::Malformed,
+        };
+
+        let circuit_id = Symbol::new(env, PublicInputs::WORD_VALIDITY_CIRCUIT);
+        if Some(parsed.public_inputs.len()) != GetExpectedInputsQuery::execute(env, &circuit_id) {
+            return VerificationOutcome::Malformed;
+        }
+
+        if PublicInputs::validate_binding(
+            env,
+            &parsed.public_inputs,
+            word_commitment,
+            dictionary_root,
+        )
+        .is_err()
+        {
+            return VerificationOutcome::BindingMismatch;
+        }
+
+        match Groth16VerifierGateway::verify_with(
+            env,
+            &verifier_addr,
+            &parsed.proof,
+            &parsed.public_inputs,
+        ) {
+            Ok(true) => VerificationOutcome::Verified,
+            _ => VerificationOutcome::ProofInvalid,
+        }
+    }
+}
+
+/// Command: Verify a whole-game audit proof
+pub struct VerifyAuditCommand;
+
+impl VerifyAuditCommand {
+    /// Verifies that every recorded feedback of a finished game is consistent
+    /// with the committed word. Returns false if no audit verifier is
+    /// configured or the adapter is frozen.
+    pub fn execute(
+        env: &Env,
+        word_commitment: &BytesN<32>,
+        audit_hash: &BytesN<32>,
+        proof_payload: &Bytes,
+    ) -> bool {
+        record_outcome(
+            env,
+            Self::outcome(env, word_commitment, audit_hash, proof_payload),
+        )
+    }
+
+    fn outcome(
+        env: &Env,
+        word_commitment: &BytesN<32>,
+        audit_hash: &BytesN<32>,
+        proof_payload: &Bytes,
+    ) -> VerificationOutcome {
+        if AdminRepository::is_frozen(env) {
+            return VerificationOutcome::Frozen;
+        }
+
+        let verifier_addr = match AdminRepository::get_audit_verifier(env) {
+            Some(addr) => addr,
+            None => return VerificationOutcome::Unconfigured,
+        };
+
+        let parsed = match parse_payload(env, proof_payload) {
+            Some(p) => p,
+            None => return VerificationOutcome::Malformed,
+        };
+
+        let circuit_id = Symbol::new(env, PublicInputs::GAME_AUDIT_CIRCUIT);
+        if Some(parsed.public_inputs.len()) != GetExpectedInputsQuery::execute(env, &circuit_id) {
+            return VerificationOutcome::Malformed;
         }
+
+        if PublicInputs::validate_binding(env, &parsed.public_inputs, word_commitment, audit_hash)
+            .is_err()
+        {
+            return VerificationOutcome::BindingMismatch;
+        }
+
+        match Groth16VerifierGateway::verify_with(
+            env,
+            &verifier_addr,
+            &parsed.proof,
+            &parsed.public_inputs,
+        ) {
+            Ok(true) => VerificationOutcome::Verified,
+            _ => VerificationOutcome::ProofInvalid,
+        }
+    }
+}
+
+/// Command: Verify a word pool proof
+pub struct VerifyPoolWordCommand;
+
+impl VerifyPoolWordCommand {
+    /// Verifies that a word commitment hides the word at `word_index` of
+    /// the pool committed as `pool_root`. Returns false if no word pool
+    /// verifier is configured or the adapter is frozen.
+    pub fn execute(
+        env: &Env,
+        word_commitment: &BytesN<32>,
+        pool_root: &BytesN<32>,
+        word_index: u32,
+        proof_payload: &Bytes,
+    ) -> bool {
+        record_outcome(
+            env,
+            Self::outcome(env, word_commitment, pool_root, word_index, proof_payload),
+        )
+    }
+
+    fn outcome(
+        env: &Env,
+        word_commitment: &BytesN<32>,
+        pool_root: &BytesN<32>,
+        word_index: u32,
+        proof_payload: &Bytes,
+    ) -> VerificationOutcome {
+        if AdminRepository::is_frozen(env) {
+            return VerificationOutcome::Frozen;
+        }
+
+        let verifier_addr = match AdminRepository::get_pool_verifier(env) {
+            Some(addr) => addr,
+            None => return VerificationOutcome::Unconfigured,
+        };
+
+        let parsed = match parse_payload(env, proof_payload) {
+            Some(p) => p,
+            None => return VerificationOutcome::Malformed,
+        };
+
+        let circuit_id = Symbol::new(env, PublicInputs::WORD_POOL_CIRCUIT);
+        if Some(parsed.public_inputs.len()) != GetExpectedInputsQuery::execute(env, &circuit_id) {
+            return VerificationOutcome::Malformed;
+        }
+
+        if PublicInputs::validate_binding(env, &parsed.public_inputs, word_commitment, pool_root)
+            .is_err()
+            || PublicInputs::validate_u32(env, &parsed.public_inputs, 4, word_index).is_err()
+        {
+            return VerificationOutcome::BindingMismatch;
+        }
+
+        match Groth16VerifierGateway::verify_with(
+            env,
+            &verifier_addr,
+            &parsed.proof,
+            &parsed.public_inputs,
+        ) {
+            Ok(true) => VerificationOutcome::Verified,
+            _ => VerificationOutcome::ProofInvalid,
+        }
+    }
+}
+
+/// Command: Override the public input count for a circuit
+pub struct SetExpectedInputsCommand;
+
+impl SetExpectedInputsCommand {
+    /// Stores `count` for `circuit_id`; a count of 0 restores the default
+    pub fn execute(env: &Env, circuit_id: &Symbol, count: u32) -> Result<(), VerifierError> {
+        if count == 0 {
+            AdminRepository::remove_expected_inputs(env, circuit_id);
+            return Ok(());
+        }
+
+        if count < PublicInputs::MIN_COUNT {
+            return Err(VerifierError::MalformedPublicInputs);
+        }
+
+        AdminRepository::set_expected_inputs(env, circuit_id, count);
+        Ok(())
+    }
+}
+
+/// Command: Override the payload caps
+pub struct SetPayloadLimitsCommand;
+
+impl SetPayloadLimitsCommand {
+    /// Stores the maximum payload length; 0 restores the default
+    pub fn set_max_payload_len(env: &Env, max_len: u32) -> Result<(), VerifierError> {
+        if max_len == 0 {
+            AdminRepository::remove_max_payload_len(env);
+            return Ok(());
+        }
+
+        if max_len < PayloadLimits::MIN_MAX_PAYLOAD_LEN {
+            return Err(VerifierError::InvalidPayloadLength);
+        }
+
+        AdminRepository::set_max_payload_len(env, max_len);
+        Ok(())
+    }
+
+    /// Stores the maximum declared public input count; 0 restores the default
+    pub fn set_max_public_inputs(env: &Env, max_count: u32) -> Result<(), VerifierError> {
+        if max_count == 0 {
+            AdminRepository::remove_max_public_inputs(env);
+            return Ok(());
+        }
+
+        if max_count < PublicInputs::MIN_COUNT {
+            return Err(VerifierError::MalformedPublicInputs);
+        }
+
+        AdminRepository::set_max_public_inputs(env, max_count);
+        Ok(())
     }
 }
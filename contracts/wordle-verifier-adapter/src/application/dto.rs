@@ -0,0 +1,40 @@
+use soroban_sdk::{contracttype, BytesN, String, Vec};
+
+/// Payload diagnosis (returned by `diagnose`). Checks run in verify order
+/// and stop at the first failure, so fields past it keep their zero values
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayloadDiagnosis {
+    /// VerifierError code of the first failed check (0 = well formed)
+    pub error_code: u32,
+    pub payload_len: u32,
+    pub max_payload_len: u32,
+    /// Public input count declared in the header
+    pub declared_inputs: u32,
+    pub max_public_inputs: u32,
+    /// Input count enforced for the circuit (0 = unknown circuit)
+    pub expected_inputs: u32,
+    /// Payload length implied by the declared input count
+    pub expected_len: u32,
+    /// Public inputs 0..3 as sent
+    pub binding_limbs: Vec<BytesN<32>>,
+    /// Word commitment reassembled from limbs 0..1
+    pub word_commitment: Option<BytesN<32>>,
+    /// Value reassembled from limbs 2..3: public inputs hash, dictionary
+    /// root or audit hash depending on the circuit
+    pub bound_hash: Option<BytesN<32>>,
+}
+
+/// Contract metadata for capability negotiation
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractInfo {
+    /// Crate name
+    pub name: String,
+    /// Semantic version
+    pub version: String,
+    /// Interface ids implemented by the contract (e.g. "verifier-adapter-v1")
+    pub interfaces: Vec<String>,
+    /// Source revision from ZKARCADE_BUILD_HASH at build time ("dev" if unset)
+    pub build_hash: String,
+}
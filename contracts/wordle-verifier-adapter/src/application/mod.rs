@@ -1,3 +1,10 @@
 mod commands;
+mod dto;
+mod queries;
 
-pub use commands::VerifyProofCommand;
+pub use commands::{
+    SetExpectedInputsCommand, SetPayloadLimitsCommand, VerifyAuditCommand, VerifyPoolWordCommand,
+    VerifyProofCommand, VerifyWordCommitmentCommand,
+};
+pub use dto::{ContractInfo, PayloadDiagnosis};
+pub use queries::{DiagnosePayloadQuery, GetExpectedInputsQuery, GetInfoQuery};
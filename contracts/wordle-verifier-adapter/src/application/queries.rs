@@ -0,0 +1,117 @@
+use soroban_sdk::{Bytes, BytesN, Env, String, Symbol, Vec};
+
+use crate::domain::proof::{PayloadParser, PublicInputs};
+use crate::domain::VerifierError;
+use crate::infrastructure::AdminRepository;
+
+use super::dto::{ContractInfo, PayloadDiagnosis};
+
+/// Query: Public input count enforced for a circuit
+pub struct GetExpectedInputsQuery;
+
+impl GetExpectedInputsQuery {
+    /// Returns the admin override if set, else the built-in default
+    /// (None for unknown circuit ids)
+    pub fn execute(env: &Env, circuit_id: &Symbol) -> Option<u32> {
+        AdminRepository::get_expected_inputs(env, circuit_id)
+            .or_else(|| PublicInputs::default_count(env, circuit_id))
+    }
+}
+
+/// Query: Diagnose a payload without verifying it
+pub struct DiagnosePayloadQuery;
+
+impl DiagnosePayloadQuery {
+    /// Runs the payload checks of the verify commands for `circuit_id` and
+    /// reports the first failure instead of returning false or aborting. While
+    /// the adapter is frozen, a payload passing every check reports `Frozen`
+    pub fn execute(env: &Env, circuit_id: &Symbol, payload: &Bytes) -> PayloadDiagnosis {
+        let limits = AdminRepository::get_payload_limits(env);
+        let mut diagnosis = PayloadDiagnosis {
+            error_code: 0,
+            payload_len: payload.len(),
+            max_payload_len: limits.max_payload_len,
+            declared_inputs: 0,
+            max_public_inputs: limits.max_public_inputs,
+            expected_inputs: GetExpectedInputsQuery::execute(env, circuit_id).unwrap_or(0),
+            expected_len: 0,
+            binding_limbs: Vec::new(env),
+            word_commitment: None,
+            bound_hash: None,
+        };
+        if let Err(err) = Self::check(env, payload, &mut diagnosis) {
+            diagnosis.error_code = err as u32;
+        } else if AdminRepository::is_frozen(env) {
+            diagnosis.error_code = VerifierError::Frozen as u32;
+        }
+        diagnosis
+    }
+
+    fn check(
+        env: &Env,
+        payload: &Bytes,
+        diagnosis: &mut PayloadDiagnosis,
+    ) -> Result<(), VerifierError> {
+        if diagnosis.payload_len > diagnosis.max_payload_len {
+            return Err(VerifierError::PayloadTooLarge);
+        }
+        diagnosis.declared_inputs = PayloadParser::declared_inputs(payload)?;
+        if diagnosis.declared_inputs > diagnosis.max_public_inputs {
+            return Err(VerifierError::PayloadTooLarge);
+        }
+        diagnosis.expected_len = PayloadParser::expected_len(diagnosis.declared_inputs)
+            .ok_or(VerifierError::MalformedProof)?;
+        if diagnosis.payload_len != diagnosis.expected_len {
+            return Err(VerifierError::InvalidPayloadLength);
+        }
+        if diagnosis.declared_inputs != diagnosis.expected_inputs {
+            return Err(VerifierError::MalformedPublicInputs);
+        }
+
+        let inputs = PayloadParser::parse(env, payload, &AdminRepository::get_payload_limits(env))?
+            .public_inputs;
+        let mut limbs = [[0u8; 32]; 4];
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let value = inputs
+                .get(i as u32)
+                .ok_or(VerifierError::MalformedPublicInputs)?
+                .to_bytes();
+            diagnosis.binding_limbs.push_back(value.clone());
+            *limb = value.to_array();
+        }
+
+        let word_commitment = PublicInputs::join_fr_limbs(&limbs[0], &limbs[1])
+            .ok_or(VerifierError::MalformedPublicInputs)?;
+        diagnosis.word_commitment = Some(BytesN::from_array(env, &word_commitment));
+        let bound_hash = PublicInputs::join_fr_limbs(&limbs[2], &limbs[3])
+            .ok_or(VerifierError::MalformedPublicInputs)?;
+        diagnosis.bound_hash = Some(BytesN::from_array(env, &bound_hash));
+        Ok(())
+    }
+}
+
+/// Query: Contract metadata
+pub struct GetInfoQuery;
+
+impl GetInfoQuery {
+    /// Interface ids this contract implements
+    const INTERFACES: [&'static str; 4] = [
+        "verifier-adapter-v1",
+        "word-validity-v1",
+        "game-audit-v1",
+        "word-pool-v1",
+    ];
+
+    pub fn execute(env: &Env) -> ContractInfo {
+        let mut interfaces = Vec::new(env);
+        for id in Self::INTERFACES {
+            interfaces.push_back(String::from_str(env, id));
+        }
+        ContractInfo {
+            name: String::from_str(env, env!("CARGO_PKG_NAME")),
+            version: String::from_str(env, env!("CARGO_PKG_VERSION")),
+            interfaces,
+            build_hash: String::from_str(env, option_env!("ZKARCADE_BUILD_HASH").unwrap_or("dev")),
+        }
+    }
+}
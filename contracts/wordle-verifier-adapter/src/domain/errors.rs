@@ -10,4 +10,6 @@ pub enum VerifierError {
     MalformedProof = 4,
     InvalidPayloadLength = 5,
     BindingMismatch = 6,
+    PayloadTooLarge = 7,
+    Frozen = 8,
 }
@@ -0,0 +1,49 @@
+use soroban_sdk::contracttype;
+
+/// How a verify call ended
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VerificationOutcome {
+    /// Proof accepted (proof cache hits included)
+    Verified,
+    /// Payload failed to parse or carried the wrong public input count
+    Malformed,
+    /// Well-formed payload bound to other values than the caller's
+    BindingMismatch,
+    /// Groth16 verifier rejected the proof
+    ProofInvalid,
+    /// Rejected while the adapter was frozen
+    Frozen,
+    /// No Groth16 verifier configured for the circuit
+    Unconfigured,
+}
+
+/// Verify calls by outcome across every verify entrypoint (returned by
+/// `get_metrics`). Counters only persist when the transaction commits: a
+/// game call that aborts on a rejected proof leaves no trace, so rejections
+/// come from direct calls such as client pre-checks. Oversized payloads
+/// abort the call and are never counted
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct VerificationMetrics {
+    pub verified: u32,
+    pub malformed: u32,
+    pub binding_mismatch: u32,
+    pub proof_invalid: u32,
+    pub frozen: u32,
+    pub unconfigured: u32,
+}
+
+impl VerificationMetrics {
+    /// Counts one verify call
+    pub fn record(&mut self, outcome: VerificationOutcome) {
+        let counter = match outcome {
+            VerificationOutcome::Verified => &mut self.verified,
+            VerificationOutcome::Malformed => &mut self.malformed,
+            VerificationOutcome::BindingMismatch => &mut self.binding_mismatch,
+            VerificationOutcome::ProofInvalid => &mut self.proof_invalid,
+            VerificationOutcome::Frozen => &mut self.frozen,
+            VerificationOutcome::Unconfigured => &mut self.unconfigured,
+        };
+        *counter = counter.saturating_add(1);
+    }
+}
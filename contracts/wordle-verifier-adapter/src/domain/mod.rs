@@ -1,5 +1,7 @@
 mod errors;
+pub mod metrics;
 pub mod proof;
 
 pub use errors::VerifierError;
+pub use metrics::{VerificationMetrics, VerificationOutcome};
 pub use proof::Groth16Proof;
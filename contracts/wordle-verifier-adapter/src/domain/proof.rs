@@ -4,7 +4,7 @@ use soroban_sdk::{
         Bn254G1Affine as G1Affine, Bn254G2Affine as G2Affine, Fr, BN254_G1_SERIALIZED_SIZE,
         BN254_G2_SERIALIZED_SIZE,
     },
-    Bytes, BytesN, Env, Vec,
+    Bytes, BytesN, Env, Symbol, Vec,
 };
 
 use super::errors::VerifierError;
@@ -33,10 +33,45 @@ pub struct ParsedPayload {
 /// - [4-8]: guess[5] (5 letters)
 /// - [9-13]: feedback[5] (5 status values)
 /// - [14]: is_correct
+///
+/// The word validity circuit uses the same binding layout with 4 inputs,
+/// where [2..3] carry the dictionary root instead of the public inputs hash.
+/// The game audit circuit also exposes 4 inputs, with [2..3] carrying the
+/// audit hash built from the full guess/feedback history.
+/// The word pool circuit exposes 5 inputs: [2..3] carry the pool root and
+/// [4] the word index drawn from the players' seeds.
 pub struct PublicInputs;
 
 impl PublicInputs {
+    /// Default counts, used until an admin overrides them per circuit id
     pub const EXPECTED_COUNT: u32 = 15;
+    pub const WORD_COMMIT_EXPECTED_COUNT: u32 = 4;
+    pub const AUDIT_EXPECTED_COUNT: u32 = 4;
+    pub const WORD_POOL_EXPECTED_COUNT: u32 = 5;
+
+    /// Binding limbs every circuit must expose
+    pub const MIN_COUNT: u32 = 4;
+
+    /// Circuit ids, matching the circuit file names
+    pub const RESOLVE_GUESS_CIRCUIT: &'static str = "resolve_guess";
+    pub const WORD_VALIDITY_CIRCUIT: &'static str = "word_validity";
+    pub const GAME_AUDIT_CIRCUIT: &'static str = "game_audit";
+    pub const WORD_POOL_CIRCUIT: &'static str = "word_pool";
+
+    /// Built-in public input count for a known circuit id
+    pub fn default_count(env: &Env, circuit_id: &Symbol) -> Option<u32> {
+        if *circuit_id == Symbol::new(env, Self::RESOLVE_GUESS_CIRCUIT) {
+            Some(Self::EXPECTED_COUNT)
+        } else if *circuit_id == Symbol::new(env, Self::WORD_VALIDITY_CIRCUIT) {
+            Some(Self::WORD_COMMIT_EXPECTED_COUNT)
+        } else if *circuit_id == Symbol::new(env, Self::GAME_AUDIT_CIRCUIT) {
+            Some(Self::AUDIT_EXPECTED_COUNT)
+        } else if *circuit_id == Symbol::new(env, Self::WORD_POOL_CIRCUIT) {
+            Some(Self::WORD_POOL_EXPECTED_COUNT)
+        } else {
+            None
+        }
+    }
 
     /// Splits a 32-byte value into hi/lo field elements
     pub fn split_u256_to_fr_limbs(value: &BytesN<32>) -> ([u8; 32], [u8; 32]) {
@@ -51,6 +86,20 @@ impl PublicInputs {
         (hi, lo)
     }
 
+    /// Joins hi/lo field elements back into a 32-byte value; None unless
+    /// both limbs are right-aligned 16-byte halves
+    pub fn join_fr_limbs(hi: &[u8; 32], lo: &[u8; 32]) -> Option<[u8; 32]> {
+        if hi[0..16] != [0u8; 16] || lo[0..16] != [0u8; 16] {
+            return None;
+        }
+
+        let mut full = [0u8; 32];
+        full[0..16].copy_from_slice(&hi[16..32]);
+        full[16..32].copy_from_slice(&lo[16..32]);
+
+        Some(full)
+    }
+
     /// Validates that binding inputs match expected values
     pub fn validate_binding(
         env: &Env,
@@ -93,6 +142,40 @@ impl PublicInputs {
             Err(VerifierError::BindingMismatch)
         }
     }
+
+    /// Validates that the input at `position` is `value` as a field element
+    pub fn validate_u32(
+        env: &Env,
+        public_inputs: &Vec<Fr>,
+        position: u32,
+        value: u32,
+    ) -> Result<(), VerifierError> {
+        let mut expected = [0u8; 32];
+        expected[28..32].copy_from_slice(&value.to_be_bytes());
+
+        match public_inputs.get(position) {
+            Some(input) if input.to_bytes() == BytesN::from_array(env, &expected) => Ok(()),
+            Some(_) => Err(VerifierError::BindingMismatch),
+            None => Err(VerifierError::MalformedPublicInputs),
+        }
+    }
+}
+
+/// Size caps checked before a payload is parsed
+pub struct PayloadLimits {
+    pub max_payload_len: u32,
+    pub max_public_inputs: u32,
+}
+
+impl PayloadLimits {
+    /// Defaults, used until an admin overrides them
+    pub const DEFAULT_MAX_PUBLIC_INPUTS: u32 = 64;
+    pub const DEFAULT_MAX_PAYLOAD_LEN: u32 =
+        PayloadParser::INPUTS_OFFSET + Self::DEFAULT_MAX_PUBLIC_INPUTS * PayloadParser::FR_BYTES;
+
+    /// Smallest payload cap that still fits the binding limbs
+    pub const MIN_MAX_PAYLOAD_LEN: u32 =
+        PayloadParser::INPUTS_OFFSET + PublicInputs::MIN_COUNT * PayloadParser::FR_BYTES;
 }
 
 /// Payload parser for proof data
@@ -100,22 +183,33 @@ pub struct PayloadParser;
 
 impl PayloadParser {
     const PAYLOAD_HEADER_BYTES: u32 = 4;
-    const FR_BYTES: u32 = 32;
+    pub const FR_BYTES: u32 = 32;
     const PROOF_BYTES: u32 =
         (BN254_G1_SERIALIZED_SIZE + BN254_G2_SERIALIZED_SIZE + BN254_G1_SERIALIZED_SIZE) as u32;
     const PROOF_OFFSET: u32 = Self::PAYLOAD_HEADER_BYTES;
     const A_OFFSET: u32 = Self::PROOF_OFFSET;
     const B_OFFSET: u32 = Self::A_OFFSET + BN254_G1_SERIALIZED_SIZE as u32;
     const C_OFFSET: u32 = Self::B_OFFSET + BN254_G2_SERIALIZED_SIZE as u32;
-    const INPUTS_OFFSET: u32 = Self::PROOF_OFFSET + Self::PROOF_BYTES;
+    pub const INPUTS_OFFSET: u32 = Self::PROOF_OFFSET + Self::PROOF_BYTES;
 
-    /// Parses a payload into proof and public inputs
-    pub fn parse(env: &Env, payload: &Bytes) -> Result<ParsedPayload, VerifierError> {
+    /// Parses a payload into proof and public inputs. Both caps in `limits`
+    /// are checked before any input is read.
+    pub fn parse(
+        env: &Env,
+        payload: &Bytes,
+        limits: &PayloadLimits,
+    ) -> Result<ParsedPayload, VerifierError> {
+        if payload.len() > limits.max_payload_len {
+            return Err(VerifierError::PayloadTooLarge);
+        }
         if payload.len() < Self::INPUTS_OFFSET {
             return Err(VerifierError::MalformedProof);
         }
 
         let public_inputs_count = Self::read_u32_be(payload, 0)?;
+        if public_inputs_count > limits.max_public_inputs {
+            return Err(VerifierError::PayloadTooLarge);
+        }
         let expected_len = Self::INPUTS_OFFSET
             .checked_add(public_inputs_count.checked_mul(Self::FR_BYTES).ok_or(VerifierError::MalformedProof)?)
             .ok_or(VerifierError::MalformedProof)?;
@@ -148,6 +242,19 @@ impl PayloadParser {
         })
     }
 
+    /// Public input count declared in the payload header
+    pub fn declared_inputs(payload: &Bytes) -> Result<u32, VerifierError> {
+        if payload.len() < Self::INPUTS_OFFSET {
+            return Err(VerifierError::MalformedProof);
+        }
+        Self::read_u32_be(payload, 0)
+    }
+
+    /// Payload length implied by `count` public inputs
+    pub fn expected_len(count: u32) -> Option<u32> {
+        Self::INPUTS_OFFSET.checked_add(count.checked_mul(Self::FR_BYTES)?)
+    }
+
     fn read_u32_be(payload: &Bytes, offset: u32) -> Result<u32, VerifierError> {
         if offset.checked_add(4).ok_or(VerifierError::MalformedProof)? > payload.len() {
             return Err(VerifierError::MalformedProof);
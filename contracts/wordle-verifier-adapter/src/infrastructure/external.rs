@@ -1,4 +1,4 @@
-use soroban_sdk::{contractclient, crypto::bn254::Fr, Env, Vec};
+use soroban_sdk::{contractclient, crypto::bn254::Fr, Address, Env, Vec};
 
 use crate::domain::{Groth16Proof, VerifierError};
 
@@ -22,7 +22,17 @@ impl Groth16VerifierGateway {
         public_inputs: &Vec<Fr>,
     ) -> Result<bool, VerifierError> {
         let verifier_addr = AdminRepository::get_verifier(env);
-        let verifier = CircomGroth16VerifierClient::new(env, &verifier_addr);
+        Self::verify_with(env, &verifier_addr, proof, public_inputs)
+    }
+
+    /// Verifies a Groth16 proof against a specific verifier contract
+    pub fn verify_with(
+        env: &Env,
+        verifier_addr: &Address,
+        proof: &Groth16Proof,
+        public_inputs: &Vec<Fr>,
+    ) -> Result<bool, VerifierError> {
+        let verifier = CircomGroth16VerifierClient::new(env, verifier_addr);
 
         Ok(verifier.verify(proof, public_inputs))
     }
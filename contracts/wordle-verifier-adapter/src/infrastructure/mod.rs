@@ -2,4 +2,4 @@ mod external;
 pub mod storage;
 
 pub use external::Groth16VerifierGateway;
-pub use storage::AdminRepository;
+pub use storage::{AdminRepository, MetricsRepository, ProofCacheRepository};
@@ -1,35 +1,222 @@
-use soroban_sdk::{contracttype, Address, Env};
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, Symbol};
+
+use crate::domain::proof::PayloadLimits;
+use crate::domain::VerificationMetrics;
 
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
-    Admin,
     Verifier,
+    WordCommitVerifier,
+    AuditVerifier,
+    PoolVerifier,
+    ExpectedInputs(Symbol),
+    MaxPayloadLen,
+    MaxPublicInputs,
+    ProofCacheEnabled,
+    /// Set by `freeze`: every proof is rejected until `unfreeze`
+    Frozen,
+    /// Bumped to orphan every cached verification at once
+    ProofCacheEpoch,
+    /// Proof accepted by the Groth16 verifier, by `ProofCacheRepository::key`
+    VerifiedProof(BytesN<32>),
+    /// Verify calls by outcome, see `VerificationMetrics`
+    Metrics,
 }
 
+/// Ledgers a cached verification stays valid (~10 minutes), long enough for
+/// a retried transaction
+pub const PROOF_CACHE_TTL_LEDGERS: u32 = 120;
+
 /// Repository for admin configuration
 pub struct AdminRepository;
 
 impl AdminRepository {
-    pub fn get_admin(env: &Env) -> Address {
+    pub fn get_verifier(env: &Env) -> Address {
         env.storage()
             .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set")
+            .get(&DataKey::Verifier)
+            .expect("Verifier not set")
+    }
+
+    pub fn set_verifier(env: &Env, verifier: &Address) {
+        env.storage().instance().set(&DataKey::Verifier, verifier);
     }
 
-    pub fn set_admin(env: &Env, admin: &Address) {
-        env.storage().instance().set(&DataKey::Admin, admin);
+    pub fn get_word_commit_verifier(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::WordCommitVerifier)
     }
 
-    pub fn get_verifier(env: &Env) -> Address {
+    pub fn set_word_commit_verifier(env: &Env, verifier: &Address) {
         env.storage()
             .instance()
-            .get(&DataKey::Verifier)
-            .expect("Verifier not set")
+            .set(&DataKey::WordCommitVerifier, verifier);
     }
 
-    pub fn set_verifier(env: &Env, verifier: &Address) {
-        env.storage().instance().set(&DataKey::Verifier, verifier);
+    pub fn get_audit_verifier(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::AuditVerifier)
+    }
+
+    pub fn set_audit_verifier(env: &Env, verifier: &Address) {
+        env.storage()
+            .instance()
+            .set(&DataKey::AuditVerifier, verifier);
+    }
+
+    pub fn get_pool_verifier(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::PoolVerifier)
+    }
+
+    pub fn set_pool_verifier(env: &Env, verifier: &Address) {
+        env.storage()
+            .instance()
+            .set(&DataKey::PoolVerifier, verifier);
+    }
+
+    pub fn get_expected_inputs(env: &Env, circuit_id: &Symbol) -> Option<u32> {
+        env.storage()
+            .instance()
+            .get(&DataKey::ExpectedInputs(circuit_id.clone()))
+    }
+
+    pub fn set_expected_inputs(env: &Env, circuit_id: &Symbol, count: u32) {
+        env.storage()
+            .instance()
+            .set(&DataKey::ExpectedInputs(circuit_id.clone()), &count);
+    }
+
+    pub fn remove_expected_inputs(env: &Env, circuit_id: &Symbol) {
+        env.storage()
+            .instance()
+            .remove(&DataKey::ExpectedInputs(circuit_id.clone()));
+    }
+
+    /// Payload caps, falling back to the defaults for unset values
+    pub fn get_payload_limits(env: &Env) -> PayloadLimits {
+        let storage = env.storage().instance();
+        PayloadLimits {
+            max_payload_len: storage
+                .get(&DataKey::MaxPayloadLen)
+                .unwrap_or(PayloadLimits::DEFAULT_MAX_PAYLOAD_LEN),
+            max_public_inputs: storage
+                .get(&DataKey::MaxPublicInputs)
+                .unwrap_or(PayloadLimits::DEFAULT_MAX_PUBLIC_INPUTS),
+        }
+    }
+
+    pub fn set_max_payload_len(env: &Env, max_len: u32) {
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxPayloadLen, &max_len);
+    }
+
+    pub fn remove_max_payload_len(env: &Env) {
+        env.storage().instance().remove(&DataKey::MaxPayloadLen);
+    }
+
+    pub fn set_max_public_inputs(env: &Env, max_count: u32) {
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxPublicInputs, &max_count);
+    }
+
+    pub fn remove_max_public_inputs(env: &Env) {
+        env.storage().instance().remove(&DataKey::MaxPublicInputs);
+    }
+
+    pub fn is_frozen(env: &Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::Frozen)
+            .unwrap_or(false)
+    }
+
+    pub fn set_frozen(env: &Env, frozen: bool) {
+        if frozen {
+            env.storage().instance().set(&DataKey::Frozen, &true);
+        } else {
+            env.storage().instance().remove(&DataKey::Frozen);
+        }
+    }
+}
+
+/// Repository for recently verified `resolve_guess` proofs
+pub struct ProofCacheRepository;
+
+impl ProofCacheRepository {
+    pub fn is_enabled(env: &Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::ProofCacheEnabled)
+            .unwrap_or(false)
+    }
+
+    pub fn set_enabled(env: &Env, enabled: bool) {
+        env.storage()
+            .instance()
+            .set(&DataKey::ProofCacheEnabled, &enabled);
+    }
+
+    /// keccak256(epoch || word_commitment || public_inputs_hash || payload)
+    pub fn key(
+        env: &Env,
+        word_commitment: &BytesN<32>,
+        public_inputs_hash: &BytesN<32>,
+        proof_payload: &Bytes,
+    ) -> BytesN<32> {
+        let mut preimage = Bytes::from_array(env, &Self::epoch(env).to_be_bytes());
+        preimage.append(word_commitment.as_ref());
+        preimage.append(public_inputs_hash.as_ref());
+        preimage.append(proof_payload);
+        env.crypto().keccak256(&preimage).into()
+    }
+
+    pub fn contains(env: &Env, key: &BytesN<32>) -> bool {
+        env.storage()
+            .temporary()
+            .has(&DataKey::VerifiedProof(key.clone()))
+    }
+
+    /// Remembers an accepted proof for `PROOF_CACHE_TTL_LEDGERS`; hits do not
+    /// extend it
+    pub fn insert(env: &Env, key: &BytesN<32>) {
+        let key = DataKey::VerifiedProof(key.clone());
+        env.storage().temporary().set(&key, &true);
+        env.storage().temporary().extend_ttl(
+            &key,
+            PROOF_CACHE_TTL_LEDGERS,
+            PROOF_CACHE_TTL_LEDGERS,
+        );
+    }
+
+    /// Evicts every entry: keys from older epochs are never looked up again
+    /// and expire with their TTL
+    pub fn clear(env: &Env) {
+        env.storage()
+            .instance()
+            .set(&DataKey::ProofCacheEpoch, &Self::epoch(env).wrapping_add(1));
+    }
+
+    fn epoch(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ProofCacheEpoch)
+            .unwrap_or(0)
+    }
+}
+
+/// Repository for verification counters
+pub struct MetricsRepository;
+
+impl MetricsRepository {
+    pub fn get(env: &Env) -> VerificationMetrics {
+        env.storage()
+            .instance()
+            .get(&DataKey::Metrics)
+            .unwrap_or_default()
+    }
+
+    pub fn save(env: &Env, metrics: &VerificationMetrics) {
+        env.storage().instance().set(&DataKey::Metrics, metrics);
     }
 }
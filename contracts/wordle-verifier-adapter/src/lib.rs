@@ -5,12 +5,23 @@ mod domain;
 mod infrastructure;
 
 // Re-export public types
-pub use domain::{Groth16Proof, VerifierError};
+pub use application::{ContractInfo, PayloadDiagnosis};
+pub use domain::{Groth16Proof, VerificationMetrics, VerifierError};
 
-use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env};
+use ownable::{Ownable, Roles};
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, Bytes, BytesN, Env, Symbol};
 
-use application::VerifyProofCommand;
-use infrastructure::AdminRepository;
+use application::{
+    DiagnosePayloadQuery, GetExpectedInputsQuery, GetInfoQuery, SetExpectedInputsCommand,
+    SetPayloadLimitsCommand, VerifyAuditCommand, VerifyPoolWordCommand, VerifyProofCommand,
+    VerifyWordCommitmentCommand,
+};
+use infrastructure::{AdminRepository, MetricsRepository, ProofCacheRepository};
+
+/// Role that may `freeze` the adapter besides the admin; only the admin can `unfreeze` it
+pub const GUARDIAN_ROLE: Symbol = symbol_short!("guardian");
+/// Role that may switch and clear the proof cache besides the admin
+pub const CACHE_ROLE: Symbol = symbol_short!("cache");
 
 #[contract]
 pub struct WordleVerifierAdapter;
@@ -19,7 +30,7 @@ pub struct WordleVerifierAdapter;
 impl WordleVerifierAdapter {
     /// Initialize adapter with admin and verifier contract addresses
     pub fn __constructor(env: Env, admin: Address, verifier: Address) {
-        AdminRepository::set_admin(&env, &admin);
+        Ownable::init(&env, &admin);
         AdminRepository::set_verifier(&env, &verifier);
     }
 
@@ -32,7 +43,7 @@ impl WordleVerifierAdapter {
     /// - bytes[196..260): proof.c (64 bytes)
     /// - bytes[260..): N public inputs, each 32 bytes
     ///
-    /// Public inputs for Wordle (15 total):
+    /// Public inputs for Wordle (15 total, see `set_expected_inputs`):
     /// - [0]: word_commitment high 16 bytes, right-aligned in 32 bytes
     /// - [1]: word_commitment low 16 bytes, right-aligned in 32 bytes
     /// - [2]: public_inputs_hash high 16 bytes, right-aligned in 32 bytes
@@ -40,6 +51,16 @@ impl WordleVerifierAdapter {
     /// - [4-8]: guess letters (5 field elements, each 0-25)
     /// - [9-13]: feedback values (5 field elements, each 0-2)
     /// - [14]: is_correct (0 or 1)
+    ///
+    /// Payloads longer than `get_max_payload_len` or declaring more than
+    /// `get_max_public_inputs` inputs abort with `PayloadTooLarge` instead of
+    /// returning false; the same caps apply to every verify entrypoint.
+    ///
+    /// With the proof cache on (`set_proof_cache`), an accepted proof is
+    /// remembered for ~10 minutes and an identical call skips the pairing.
+    ///
+    /// While the adapter is frozen (`freeze`), every verify entrypoint
+    /// returns false.
     pub fn verify(
         env: Env,
         word_commitment: BytesN<32>,
@@ -49,16 +70,126 @@ impl WordleVerifierAdapter {
         VerifyProofCommand::execute(&env, &word_commitment, &public_inputs_hash, &proof_payload)
     }
 
+    /// Verifies a word validity proof for a fresh word commitment.
+    ///
+    /// Uses the same payload format as `verify`, with 4 public inputs:
+    /// - [0..1]: word_commitment hi/lo limbs
+    /// - [2..3]: dictionary_root hi/lo limbs (all zero = no dictionary check)
+    ///
+    /// Returns false until a word validity verifier has been configured.
+    pub fn verify_word_commitment(
+        env: Env,
+        word_commitment: BytesN<32>,
+        dictionary_root: BytesN<32>,
+        proof_payload: Bytes,
+    ) -> bool {
+        VerifyWordCommitmentCommand::execute(
+            &env,
+            &word_commitment,
+            &dictionary_root,
+            &proof_payload,
+        )
+    }
+
+    /// Verifies a game audit proof for a finished game.
+    ///
+    /// Uses the same payload format as `verify`, with 4 public inputs:
+    /// - [0..1]: word_commitment hi/lo limbs
+    /// - [2..3]: audit_hash hi/lo limbs (see Wordle `build_audit_hash`)
+    ///
+    /// Returns false until an audit verifier has been configured.
+    pub fn verify_audit(
+        env: Env,
+        word_commitment: BytesN<32>,
+        audit_hash: BytesN<32>,
+        proof_payload: Bytes,
+    ) -> bool {
+        VerifyAuditCommand::execute(&env, &word_commitment, &audit_hash, &proof_payload)
+    }
+
+    /// Verifies a word pool proof for a word drawn from a committed pool.
+    ///
+    /// Uses the same payload format as `verify`, with 5 public inputs:
+    /// - [0..1]: word_commitment hi/lo limbs
+    /// - [2..3]: pool_root hi/lo limbs
+    /// - [4] word_index, the leaf the word must sit at
+    ///
+    /// Returns false until a word pool verifier has been configured.
+    pub fn verify_pool_word(
+        env: Env,
+        word_commitment: BytesN<32>,
+        pool_root: BytesN<32>,
+        word_index: u32,
+        proof_payload: Bytes,
+    ) -> bool {
+        VerifyPoolWordCommand::execute(
+            &env,
+            &word_commitment,
+            &pool_root,
+            word_index,
+            &proof_payload,
+        )
+    }
+
+    /// Contract name, version, interface ids and build hash
+    pub fn get_info(env: Env) -> ContractInfo {
+        GetInfoQuery::execute(&env)
+    }
+
+    /// Reports how the verify entrypoint for `circuit_id` (`resolve_guess`,
+    /// `word_validity`, `game_audit`, `word_pool`) would read
+    /// `proof_payload` and which check it fails, without aborting or calling
+    /// the Groth16 verifier.
+    /// Binding limbs that are not right-aligned 16-byte halves fail with
+    /// `MalformedPublicInputs`; comparing the reassembled values with the
+    /// game's is left to the caller (e.g. wordle's `check_resolution`).
+    pub fn diagnose(env: Env, circuit_id: Symbol, proof_payload: Bytes) -> PayloadDiagnosis {
+        DiagnosePayloadQuery::execute(&env, &circuit_id, &proof_payload)
+    }
+
+    /// Verify calls by outcome since deployment, across every verify
+    /// entrypoint. A spike in one bucket points at a client payload bug
+    pub fn get_metrics(env: Env) -> VerificationMetrics {
+        MetricsRepository::get(&env)
+    }
+
     // ==================== Admin Functions ====================
 
     pub fn get_admin(env: Env) -> Address {
-        AdminRepository::get_admin(&env)
+        Ownable::admin(&env)
     }
 
     pub fn set_admin(env: Env, new_admin: Address) {
-        let admin = AdminRepository::get_admin(&env);
-        admin.require_auth();
-        AdminRepository::set_admin(&env, &new_admin);
+        Ownable::set_admin(&env, &new_admin);
+    }
+
+    /// First step of an admin transfer; `new_admin` takes over once they call
+    /// `accept_admin`
+    pub fn propose_admin(env: Env, new_admin: Address) {
+        Ownable::propose_admin(&env, &new_admin);
+    }
+
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        Ownable::pending_admin(&env)
+    }
+
+    /// Completes a transfer started with `propose_admin`; signed by the nominee
+    pub fn accept_admin(env: Env) -> Address {
+        Ownable::accept_admin(&env)
+    }
+
+    /// Grants `role` (`GUARDIAN_ROLE` or `CACHE_ROLE`) to `account` (admin only)
+    pub fn grant_role(env: Env, role: Symbol, account: Address) {
+        Roles::grant(&env, &role, &account);
+    }
+
+    /// Admin only
+    pub fn revoke_role(env: Env, role: Symbol, account: Address) {
+        Roles::revoke(&env, &role, &account);
+    }
+
+    pub fn has_role(env: Env, role: Symbol, account: Address) -> bool {
+        Roles::has(&env, &role, &account)
     }
 
     pub fn get_verifier(env: Env) -> Address {
@@ -66,15 +197,126 @@ impl WordleVerifierAdapter {
     }
 
     pub fn set_verifier(env: Env, new_verifier: Address) {
-        let admin = AdminRepository::get_admin(&env);
-        admin.require_auth();
+        Ownable::require_admin(&env);
         AdminRepository::set_verifier(&env, &new_verifier);
+        ProofCacheRepository::clear(&env);
+    }
+
+    /// Groth16 verifier for the word validity circuit, if configured
+    pub fn get_word_commit_verifier(env: Env) -> Option<Address> {
+        AdminRepository::get_word_commit_verifier(&env)
+    }
+
+    pub fn set_word_commit_verifier(env: Env, new_verifier: Address) {
+        Ownable::require_admin(&env);
+        AdminRepository::set_word_commit_verifier(&env, &new_verifier);
+    }
+
+    /// Groth16 verifier for the game audit circuit, if configured
+    pub fn get_audit_verifier(env: Env) -> Option<Address> {
+        AdminRepository::get_audit_verifier(&env)
+    }
+
+    pub fn set_audit_verifier(env: Env, new_verifier: Address) {
+        Ownable::require_admin(&env);
+        AdminRepository::set_audit_verifier(&env, &new_verifier);
+    }
+
+    /// Groth16 verifier for the word pool circuit, if configured
+    pub fn get_pool_verifier(env: Env) -> Option<Address> {
+        AdminRepository::get_pool_verifier(&env)
+    }
+
+    pub fn set_pool_verifier(env: Env, new_verifier: Address) {
+        Ownable::require_admin(&env);
+        AdminRepository::set_pool_verifier(&env, &new_verifier);
+    }
+
+    /// Public input count enforced for `circuit_id` (`resolve_guess`,
+    /// `word_validity`, `game_audit`, `word_pool`), or None for an unknown
+    /// circuit
+    pub fn get_expected_inputs(env: Env, circuit_id: Symbol) -> Option<u32> {
+        GetExpectedInputsQuery::execute(&env, &circuit_id)
+    }
+
+    /// Overrides the public input count for `circuit_id` after a circuit
+    /// upgrade. A count of 0 restores the built-in default.
+    pub fn set_expected_inputs(
+        env: Env,
+        circuit_id: Symbol,
+        count: u32,
+    ) -> Result<(), VerifierError> {
+        Ownable::require_admin(&env);
+        ProofCacheRepository::clear(&env);
+        SetExpectedInputsCommand::execute(&env, &circuit_id, count)
+    }
+
+    /// Longest proof payload accepted by the verify entrypoints
+    pub fn get_max_payload_len(env: Env) -> u32 {
+        AdminRepository::get_payload_limits(&env).max_payload_len
+    }
+
+    /// Caps the payload length checked before parsing. A length of 0
+    /// restores the built-in default.
+    pub fn set_max_payload_len(env: Env, max_len: u32) -> Result<(), VerifierError> {
+        Ownable::require_admin(&env);
+        SetPayloadLimitsCommand::set_max_payload_len(&env, max_len)
+    }
+
+    /// Largest public input count a payload may declare
+    pub fn get_max_public_inputs(env: Env) -> u32 {
+        AdminRepository::get_payload_limits(&env).max_public_inputs
+    }
+
+    /// Caps the declared public input count checked before parsing. A count
+    /// of 0 restores the built-in default.
+    pub fn set_max_public_inputs(env: Env, max_count: u32) -> Result<(), VerifierError> {
+        Ownable::require_admin(&env);
+        SetPayloadLimitsCommand::set_max_public_inputs(&env, max_count)
+    }
+
+    /// Whether `verify` caches accepted proofs (off by default)
+    pub fn get_proof_cache(env: Env) -> bool {
+        ProofCacheRepository::is_enabled(&env)
+    }
+
+    /// Turns the proof cache on or off; `caller` is the admin or holds
+    /// `CACHE_ROLE`. While off, cached entries are neither read nor written
+    /// and keep expiring on their own.
+    pub fn set_proof_cache(env: Env, caller: Address, enabled: bool) {
+        Roles::require_admin_or(&env, &CACHE_ROLE, &caller);
+        ProofCacheRepository::set_enabled(&env, enabled);
+    }
+
+    /// Evicts every cached verification; `caller` is the admin or holds
+    /// `CACHE_ROLE`. `set_verifier` and `set_expected_inputs` do the same.
+    pub fn clear_proof_cache(env: Env, caller: Address) {
+        Roles::require_admin_or(&env, &CACHE_ROLE, &caller);
+        ProofCacheRepository::clear(&env);
+    }
+
+    /// Whether verification is frozen
+    pub fn is_frozen(env: Env) -> bool {
+        AdminRepository::is_frozen(&env)
+    }
+
+    /// Rejects every proof until `unfreeze`, so games cannot settle on
+    /// proofs while a circuit soundness issue is investigated. Also evicts
+    /// the proof cache. `caller` is the admin or holds `GUARDIAN_ROLE`
+    pub fn freeze(env: Env, caller: Address) {
+        Roles::require_admin_or(&env, &GUARDIAN_ROLE, &caller);
+        AdminRepository::set_frozen(&env, true);
+        ProofCacheRepository::clear(&env);
+    }
+
+    /// Lifts a `freeze`. Admin only, so a guardian cannot undo another's freeze
+    pub fn unfreeze(env: Env) {
+        Ownable::require_admin(&env);
+        AdminRepository::set_frozen(&env, false);
     }
 
     pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let admin = AdminRepository::get_admin(&env);
-        admin.require_auth();
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        Ownable::upgrade(&env, new_wasm_hash);
     }
 }
 
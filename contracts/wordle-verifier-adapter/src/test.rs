@@ -1,9 +1,14 @@
 #![cfg(test)]
 
-use crate::{Groth16Proof, WordleVerifierAdapter, WordleVerifierAdapterClient};
+use crate::{
+    Groth16Proof, VerificationMetrics, WordleVerifierAdapter, WordleVerifierAdapterClient,
+    CACHE_ROLE, GUARDIAN_ROLE,
+};
 use soroban_sdk::crypto::bn254::Fr;
-use soroban_sdk::testutils::Address as _;
-use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, Vec};
+use soroban_sdk::testutils::{Address as _, MockAuth, MockAuthInvoke};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, Address, Bytes, BytesN, Env, IntoVal, String, Symbol, Vec,
+};
 
 use crate::VerifierError;
 
@@ -13,12 +18,23 @@ pub struct MockGroth16Verifier;
 #[contractimpl]
 impl MockGroth16Verifier {
     pub fn verify(
-        _env: Env,
+        env: Env,
         _proof: Groth16Proof,
         _public_inputs: Vec<Fr>,
     ) -> Result<bool, VerifierError> {
+        let calls = Self::calls(env.clone());
+        env.storage()
+            .instance()
+            .set(&symbol_short!("calls"), &(calls + 1));
         Ok(true)
     }
+
+    pub fn calls(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("calls"))
+            .unwrap_or(0)
+    }
 }
 
 fn setup_test() -> (Env, WordleVerifierAdapterClient<'static>, Address) {
@@ -105,3 +121,408 @@ fn test_admin_functions() {
     client.set_verifier(&new_verifier);
     assert_eq!(client.get_verifier(), new_verifier);
 }
+
+#[test]
+fn test_verify_word_commitment_requires_configured_verifier() {
+    let (env, client, _admin) = setup_test();
+
+    let mut payload_bytes = [0u8; 4 + 256 + 4 * 32];
+    payload_bytes[3] = 4;
+
+    let word_commitment = BytesN::from_array(&env, &[0u8; 32]);
+    let dictionary_root = BytesN::from_array(&env, &[0u8; 32]);
+    let payload = Bytes::from_array(&env, &payload_bytes);
+
+    assert_eq!(client.get_word_commit_verifier(), None);
+    assert!(!client.verify_word_commitment(&word_commitment, &dictionary_root, &payload));
+
+    let word_verifier = env.register(MockGroth16Verifier, ());
+    client.set_word_commit_verifier(&word_verifier);
+    assert_eq!(client.get_word_commit_verifier(), Some(word_verifier));
+
+    // Zero commitment and root bind to all-zero inputs
+    assert!(client.verify_word_commitment(&word_commitment, &dictionary_root, &payload));
+
+    // The resolve_guess input count is rejected
+    let mut guess_payload_bytes = [0u8; 4 + 256 + 15 * 32];
+    guess_payload_bytes[3] = 15;
+    let guess_payload = Bytes::from_array(&env, &guess_payload_bytes);
+    assert!(!client.verify_word_commitment(&word_commitment, &dictionary_root, &guess_payload));
+}
+
+#[test]
+fn test_expected_inputs_are_admin_settable() {
+    let (env, client, _admin) = setup_test();
+
+    let resolve_guess = Symbol::new(&env, "resolve_guess");
+    let word_validity = Symbol::new(&env, "word_validity");
+    assert_eq!(client.get_expected_inputs(&resolve_guess), Some(15));
+    assert_eq!(client.get_expected_inputs(&word_validity), Some(4));
+    assert_eq!(
+        client.get_expected_inputs(&Symbol::new(&env, "other")),
+        None
+    );
+
+    // A 16-input payload with zeroed binding limbs
+    let mut payload_bytes = [0u8; 4 + 256 + 16 * 32];
+    payload_bytes[3] = 16;
+    let zero = BytesN::from_array(&env, &[0u8; 32]);
+    let payload = Bytes::from_array(&env, &payload_bytes);
+    assert!(!client.verify(&zero, &zero, &payload));
+
+    client.set_expected_inputs(&resolve_guess, &16);
+    assert_eq!(client.get_expected_inputs(&resolve_guess), Some(16));
+    assert!(client.verify(&zero, &zero, &payload));
+
+    let result = client.try_set_expected_inputs(&resolve_guess, &3);
+    assert_eq!(result, Err(Ok(VerifierError::MalformedPublicInputs)));
+
+    client.set_expected_inputs(&resolve_guess, &0);
+    assert_eq!(client.get_expected_inputs(&resolve_guess), Some(15));
+}
+
+#[test]
+fn test_verify_rejects_oversized_payload() {
+    let (env, client, _admin) = setup_test();
+    let too_large = Err(Ok(soroban_sdk::Error::from_contract_error(
+        VerifierError::PayloadTooLarge as u32,
+    )));
+    let zero = BytesN::from_array(&env, &[0u8; 32]);
+
+    assert_eq!(client.get_max_public_inputs(), 64);
+    assert_eq!(client.get_max_payload_len(), 260 + 64 * 32);
+
+    // A header declaring ~4 billion inputs is rejected before parsing
+    let mut header = [0u8; 4 + 256];
+    header[0..4].copy_from_slice(&[0xff; 4]);
+    let payload = Bytes::from_array(&env, &header);
+    assert_eq!(client.try_verify(&zero, &zero, &payload), too_large);
+
+    // So is a well-formed payload longer than the configured cap
+    let mut payload_bytes = [0u8; 4 + 256 + 15 * 32];
+    payload_bytes[3] = 15;
+    let payload = Bytes::from_array(&env, &payload_bytes);
+    assert!(client.verify(&zero, &zero, &payload));
+    client.set_max_payload_len(&(260 + 14 * 32));
+    assert_eq!(client.try_verify(&zero, &zero, &payload), too_large);
+
+    // Caps below the binding limbs are refused; 0 restores the defaults
+    let result = client.try_set_max_payload_len(&(260 + 3 * 32));
+    assert_eq!(result, Err(Ok(VerifierError::InvalidPayloadLength)));
+    let result = client.try_set_max_public_inputs(&3);
+    assert_eq!(result, Err(Ok(VerifierError::MalformedPublicInputs)));
+    client.set_max_public_inputs(&14);
+    client.set_max_payload_len(&0);
+    assert_eq!(client.try_verify(&zero, &zero, &payload), too_large);
+    client.set_max_public_inputs(&0);
+    assert!(client.verify(&zero, &zero, &payload));
+}
+
+#[test]
+fn test_diagnose_reports_failed_check_and_binding_values() {
+    let (env, client, _admin) = setup_test();
+    let resolve_guess = Symbol::new(&env, "resolve_guess");
+
+    // Word commitment 0x01.. and hash 0x02.. split into right-aligned limbs
+    let mut payload_bytes = [0u8; 4 + 256 + 15 * 32];
+    payload_bytes[3] = 15;
+    payload_bytes[260 + 16..260 + 32].copy_from_slice(&[1u8; 16]);
+    payload_bytes[292 + 16..292 + 32].copy_from_slice(&[1u8; 16]);
+    payload_bytes[324 + 16..324 + 32].copy_from_slice(&[2u8; 16]);
+    payload_bytes[356 + 16..356 + 32].copy_from_slice(&[2u8; 16]);
+    let payload = Bytes::from_array(&env, &payload_bytes);
+
+    let ok = client.diagnose(&resolve_guess, &payload);
+    assert_eq!(ok.error_code, 0);
+    assert_eq!((ok.declared_inputs, ok.expected_inputs), (15, 15));
+    assert_eq!(ok.expected_len, 260 + 15 * 32);
+    assert_eq!(ok.binding_limbs.len(), 4);
+    assert_eq!(
+        ok.word_commitment,
+        Some(BytesN::from_array(&env, &[1u8; 32]))
+    );
+    assert_eq!(ok.bound_hash, Some(BytesN::from_array(&env, &[2u8; 32])));
+
+    // The same payload is the wrong size for the 4-input word validity circuit
+    let word_validity = Symbol::new(&env, "word_validity");
+    let wrong_circuit = client.diagnose(&word_validity, &payload);
+    assert_eq!(
+        wrong_circuit.error_code,
+        VerifierError::MalformedPublicInputs as u32
+    );
+    assert_eq!(wrong_circuit.expected_inputs, 4);
+
+    let short = client.diagnose(&resolve_guess, &Bytes::from_array(&env, &[0u8; 100]));
+    assert_eq!(short.error_code, VerifierError::MalformedProof as u32);
+
+    let mut truncated = payload.clone();
+    truncated.pop_back();
+    let truncated = client.diagnose(&resolve_guess, &truncated);
+    assert_eq!(
+        truncated.error_code,
+        VerifierError::InvalidPayloadLength as u32
+    );
+    assert_eq!(truncated.expected_len, 260 + 15 * 32);
+
+    // Oversized headers are reported instead of aborting like `verify`
+    let mut header = [0u8; 4 + 256];
+    header[0..4].copy_from_slice(&[0xff; 4]);
+    let huge = client.diagnose(&resolve_guess, &Bytes::from_array(&env, &header));
+    assert_eq!(huge.error_code, VerifierError::PayloadTooLarge as u32);
+    assert_eq!(huge.declared_inputs, u32::MAX);
+
+    payload_bytes[324] = 9;
+    let unaligned = client.diagnose(&resolve_guess, &Bytes::from_array(&env, &payload_bytes));
+    assert_eq!(
+        unaligned.error_code,
+        VerifierError::MalformedPublicInputs as u32
+    );
+    assert_eq!(unaligned.binding_limbs.len(), 4);
+    assert!(unaligned.word_commitment.is_some());
+    assert_eq!(unaligned.bound_hash, None);
+}
+
+#[test]
+fn test_verify_audit_requires_configured_verifier() {
+    let (env, client, _admin) = setup_test();
+
+    let mut payload_bytes = [0u8; 4 + 256 + 4 * 32];
+    payload_bytes[3] = 4;
+
+    let word_commitment = BytesN::from_array(&env, &[0u8; 32]);
+    let audit_hash = BytesN::from_array(&env, &[0u8; 32]);
+    let payload = Bytes::from_array(&env, &payload_bytes);
+
+    assert_eq!(client.get_audit_verifier(), None);
+    assert!(!client.verify_audit(&word_commitment, &audit_hash, &payload));
+
+    let audit_verifier = env.register(MockGroth16Verifier, ());
+    client.set_audit_verifier(&audit_verifier);
+    assert_eq!(client.get_audit_verifier(), Some(audit_verifier));
+    assert_eq!(
+        client.get_expected_inputs(&Symbol::new(&env, "game_audit")),
+        Some(4)
+    );
+
+    assert!(client.verify_audit(&word_commitment, &audit_hash, &payload));
+
+    // Binding limbs must match the audit hash
+    let other_hash = BytesN::from_array(&env, &[7u8; 32]);
+    assert!(!client.verify_audit(&word_commitment, &other_hash, &payload));
+}
+
+#[test]
+fn test_verify_pool_word_binds_word_index() {
+    let (env, client, _admin) = setup_test();
+
+    let mut payload_bytes = [0u8; 4 + 256 + 5 * 32];
+    payload_bytes[3] = 5;
+    payload_bytes[4 + 256 + 5 * 32 - 1] = 9;
+
+    let word_commitment = BytesN::from_array(&env, &[0u8; 32]);
+    let pool_root = BytesN::from_array(&env, &[0u8; 32]);
+    let payload = Bytes::from_array(&env, &payload_bytes);
+
+    assert_eq!(client.get_pool_verifier(), None);
+    assert!(!client.verify_pool_word(&word_commitment, &pool_root, &9, &payload));
+
+    let pool_verifier = env.register(MockGroth16Verifier, ());
+    client.set_pool_verifier(&pool_verifier);
+    assert_eq!(
+        client.get_expected_inputs(&Symbol::new(&env, "word_pool")),
+        Some(5)
+    );
+    assert!(client.verify_pool_word(&word_commitment, &pool_root, &9, &payload));
+
+    // The proof is only good for the drawn index and the committed pool
+    assert!(!client.verify_pool_word(&word_commitment, &pool_root, &8, &payload));
+    let other_root = BytesN::from_array(&env, &[7u8; 32]);
+    assert!(!client.verify_pool_word(&word_commitment, &other_root, &9, &payload));
+}
+
+#[test]
+fn test_proof_cache_skips_repeat_verification() {
+    let (env, client, admin) = setup_test();
+    let groth16 = MockGroth16VerifierClient::new(&env, &client.get_verifier());
+
+    // A 15-input payload with zeroed binding limbs
+    let mut payload_bytes = [0u8; 4 + 256 + 15 * 32];
+    payload_bytes[3] = 15;
+    let zero = BytesN::from_array(&env, &[0u8; 32]);
+    let payload = Bytes::from_array(&env, &payload_bytes);
+
+    // Off by default: every call reaches the Groth16 verifier
+    assert!(!client.get_proof_cache());
+    assert!(client.verify(&zero, &zero, &payload));
+    assert!(client.verify(&zero, &zero, &payload));
+    assert_eq!(groth16.calls(), 2);
+
+    client.set_proof_cache(&admin, &true);
+    assert!(client.verify(&zero, &zero, &payload));
+    assert!(client.verify(&zero, &zero, &payload));
+    assert_eq!(groth16.calls(), 3);
+
+    // The key covers the whole payload, not just the binding limbs
+    payload_bytes[4 + 256 + 4 * 32 + 31] = 1;
+    let other_payload = Bytes::from_array(&env, &payload_bytes);
+    assert!(client.verify(&zero, &zero, &other_payload));
+    assert_eq!(groth16.calls(), 4);
+
+    // Reconfiguring a circuit evicts the cache, as does clear_proof_cache
+    client.set_expected_inputs(&Symbol::new(&env, "resolve_guess"), &15);
+    assert!(client.verify(&zero, &zero, &payload));
+    assert_eq!(groth16.calls(), 5);
+    client.clear_proof_cache(&admin);
+    assert!(client.verify(&zero, &zero, &payload));
+    assert!(client.verify(&zero, &zero, &payload));
+    assert_eq!(groth16.calls(), 6);
+}
+
+#[test]
+fn test_freeze_rejects_every_proof_until_unfrozen() {
+    let (env, client, admin) = setup_test();
+    let groth16 = MockGroth16VerifierClient::new(&env, &client.get_verifier());
+    client.set_word_commit_verifier(&client.get_verifier());
+
+    let mut payload_bytes = [0u8; 4 + 256 + 15 * 32];
+    payload_bytes[3] = 15;
+    let zero = BytesN::from_array(&env, &[0u8; 32]);
+    let payload = Bytes::from_array(&env, &payload_bytes);
+    let mut word_bytes = [0u8; 4 + 256 + 4 * 32];
+    word_bytes[3] = 4;
+    let word_payload = Bytes::from_array(&env, &word_bytes);
+
+    client.set_proof_cache(&admin, &true);
+    assert!(client.verify(&zero, &zero, &payload));
+    assert_eq!(groth16.calls(), 1);
+
+    assert!(!client.is_frozen());
+    client.freeze(&admin);
+    assert!(client.is_frozen());
+
+    // Cached proofs are rejected too, on every verify entrypoint
+    assert!(!client.verify(&zero, &zero, &payload));
+    assert!(!client.verify_word_commitment(&zero, &zero, &word_payload));
+    assert_eq!(groth16.calls(), 1);
+    let resolve_guess = Symbol::new(&env, "resolve_guess");
+    assert_eq!(
+        client.diagnose(&resolve_guess, &payload).error_code,
+        VerifierError::Frozen as u32
+    );
+    // Malformed payloads still report their own failure
+    assert_eq!(
+        client
+            .diagnose(&resolve_guess, &Bytes::from_array(&env, &[1u8, 2u8]))
+            .error_code,
+        VerifierError::MalformedProof as u32
+    );
+
+    client.unfreeze();
+    assert!(!client.is_frozen());
+    assert_eq!(client.diagnose(&resolve_guess, &payload).error_code, 0);
+    // The freeze evicted the cache, so the proof is verified afresh
+    assert!(client.verify(&zero, &zero, &payload));
+    assert!(client.verify_word_commitment(&zero, &zero, &word_payload));
+    assert_eq!(groth16.calls(), 3);
+}
+
+#[test]
+fn test_roles_delegate_freeze_and_proof_cache() {
+    let (env, client, _admin) = setup_test();
+    let guardian = Address::generate(&env);
+    let cache_operator = Address::generate(&env);
+
+    // Without a role, even a signed call is refused
+    assert!(client.try_freeze(&guardian).is_err());
+    assert!(client.try_clear_proof_cache(&cache_operator).is_err());
+
+    client.grant_role(&GUARDIAN_ROLE, &guardian);
+    client.grant_role(&CACHE_ROLE, &cache_operator);
+    assert!(client.has_role(&GUARDIAN_ROLE, &guardian));
+    assert!(!client.has_role(&CACHE_ROLE, &guardian));
+
+    // Each role only opens its own switches
+    assert!(client.try_set_proof_cache(&guardian, &true).is_err());
+    assert!(client.try_freeze(&cache_operator).is_err());
+    client.set_proof_cache(&cache_operator, &true);
+    client.clear_proof_cache(&cache_operator);
+    assert!(client.get_proof_cache());
+    client.freeze(&guardian);
+    assert!(client.is_frozen());
+
+    // A guardian can freeze but only the admin lifts it
+    let result = client
+        .mock_auths(&[MockAuth {
+            address: &guardian,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "unfreeze",
+                args: ().into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_unfreeze();
+    assert!(result.is_err());
+    env.mock_all_auths();
+    client.unfreeze();
+    assert!(!client.is_frozen());
+
+    client.revoke_role(&GUARDIAN_ROLE, &guardian);
+    assert!(client.try_freeze(&guardian).is_err());
+}
+
+#[test]
+fn test_metrics_count_verifications_by_outcome() {
+    let (env, client, admin) = setup_test();
+    assert_eq!(client.get_metrics(), VerificationMetrics::default());
+
+    let mut payload_bytes = [0u8; 4 + 256 + 15 * 32];
+    payload_bytes[3] = 15;
+    let zero = BytesN::from_array(&env, &[0u8; 32]);
+    let other = BytesN::from_array(&env, &[2u8; 32]);
+    let payload = Bytes::from_array(&env, &payload_bytes);
+    let mut word_bytes = [0u8; 4 + 256 + 4 * 32];
+    word_bytes[3] = 4;
+    let word_payload = Bytes::from_array(&env, &word_bytes);
+
+    assert!(client.verify(&zero, &zero, &payload));
+    assert!(!client.verify(&zero, &zero, &Bytes::new(&env)));
+    // A word validity payload sent to `verify` has the wrong input count
+    assert!(!client.verify(&zero, &zero, &word_payload));
+    assert!(!client.verify(&zero, &other, &payload));
+    // Counted across entrypoints, including unconfigured circuits
+    assert!(!client.verify_audit(&zero, &zero, &word_payload));
+    client.set_word_commit_verifier(&client.get_verifier());
+    assert!(client.verify_word_commitment(&zero, &zero, &word_payload));
+    client.freeze(&admin);
+    assert!(!client.verify(&zero, &zero, &payload));
+
+    assert_eq!(
+        client.get_metrics(),
+        VerificationMetrics {
+            verified: 2,
+            malformed: 2,
+            binding_mismatch: 1,
+            proof_invalid: 0,
+            frozen: 1,
+            unconfigured: 1,
+        }
+    );
+}
+
+#[test]
+fn test_get_info_reports_adapter_interfaces() {
+    let (env, client, _admin) = setup_test();
+
+    let info = client.get_info();
+    assert_eq!(info.name, String::from_str(&env, "wordle-verifier-adapter"));
+    assert!(info
+        .interfaces
+        .contains(String::from_str(&env, "verifier-adapter-v1")));
+    assert!(info
+        .interfaces
+        .contains(String::from_str(&env, "game-audit-v1")));
+    assert!(info
+        .interfaces
+        .contains(String::from_str(&env, "word-pool-v1")));
+}
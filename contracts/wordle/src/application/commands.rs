@@ -1,10 +1,28 @@
-use soroban_sdk::{vec, Address, Bytes, BytesN, Env, IntoVal, Vec};
+use binding_hash::BindingHashBuilder;
+use soroban_sdk::{symbol_short, vec, Address, Bytes, BytesN, Env, IntoVal, Vec};
 
-use crate::domain::{DomainError, Feedback, Game, GameOutcome, Guess};
-use crate::infrastructure::{GameHubGateway, GameRepository, VerifierGateway};
+use crate::domain::{
+    compute_feedback, set_reaction, DomainError, Emote, Feedback, Game, GameOutcome, GameStats,
+    Guess, HubSeats, SessionKey, SessionMarker, WordPool, ALPHABET_SIZE,
+};
+use crate::infrastructure::storage::AdminRepository;
+use crate::infrastructure::{
+    GameHubGateway, GameRepository, MoveReporter, PlayerSessionRepository, Reacted,
+    ReactionRepository, SessionKeyRepository, SessionMarkerRepository, StatsRepository,
+    VerifierGateway,
+};
 
 use super::dto::GuessResult;
 
+/// Feedback values as the five bytes bound into proof hashes
+fn feedback_bytes(feedback: &Vec<u32>) -> [u8; 5] {
+    let mut bytes = [0u8; 5];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = feedback.get(i as u32).unwrap_or(0) as u8;
+    }
+    bytes
+}
+
 /// Command: Start a new game
 pub struct StartGameCommand;
 
@@ -16,50 +34,266 @@ impl StartGameCommand {
         guesser: Address,
         word_setter_points: i128,
         guesser_points: i128,
-    ) -> Result<(), DomainError> {
-        // Validate self-play not allowed
-        if word_setter == guesser {
-            return Err(DomainError::SelfPlayNotAllowed);
-        }
+        alphabet_size: u32,
+    ) -> Result<u32, DomainError> {
+        let (setter_sessions, guesser_sessions) =
+            check_new_session(env, session_id, &word_setter, &guesser)?;
+        let stake_bounds = AdminRepository::get_stake_bounds(env);
+        stake_bounds.check(word_setter_points)?;
+        stake_bounds.check(guesser_points)?;
 
-        // Check game doesn't already exist
-        if GameRepository::exists(env, session_id) {
-            return Err(DomainError::GameAlreadyExists);
-        }
+        let mut game = Game::new(
+            word_setter,
+            guesser,
+            word_setter_points,
+            guesser_points,
+            alphabet_size,
+            AdminRepository::get_guess_expiry_ledgers(env),
+            env,
+        )?;
+        game.set_deadline(AdminRepository::get_game_duration_ledgers(env));
 
-        // Require auth from both players
-        word_setter.require_auth_for_args(vec![
+        // Require auth from both players (including the alphabet they play with)
+        game.word_setter.require_auth_for_args(vec![
             env,
             session_id.into_val(env),
             word_setter_points.into_val(env),
+            alphabet_size.into_val(env),
         ]);
-        guesser.require_auth_for_args(vec![
+        game.guesser.require_auth_for_args(vec![
             env,
             session_id.into_val(env),
             guesser_points.into_val(env),
+            alphabet_size.into_val(env),
         ]);
 
         // Notify Game Hub first (required ordering)
-        GameHubGateway::notify_game_started(
+        notify_hub_started(env, session_id, &game);
+
+        store_new_game(env, session_id, &game, setter_sessions, guesser_sessions);
+        Ok(session_id)
+    }
+}
+
+/// Command: Start a stakeless practice game with a cleartext word
+pub struct StartPracticeGameCommand;
+
+impl StartPracticeGameCommand {
+    /// No points and no Game Hub session; feedback is computed on-chain
+    pub fn execute(
+        env: &Env,
+        session_id: u32,
+        word_setter: Address,
+        guesser: Address,
+    ) -> Result<u32, DomainError> {
+        let (setter_sessions, guesser_sessions) =
+            check_new_session(env, session_id, &word_setter, &guesser)?;
+
+        word_setter.require_auth_for_args(vec![env, session_id.into_val(env)]);
+        guesser.require_auth_for_args(vec![env, session_id.into_val(env)]);
+
+        let mut game = Game::new(
+            word_setter,
+            guesser,
+            0,
+            0,
+            ALPHABET_SIZE,
+            AdminRepository::get_guess_expiry_ledgers(env),
             env,
-            session_id,
-            &word_setter,
-            &guesser,
+        )?;
+        game.set_deadline(AdminRepository::get_game_duration_ledgers(env));
+        game.practice = true;
+
+        store_new_game(env, session_id, &game, setter_sessions, guesser_sessions);
+        Ok(session_id)
+    }
+}
+
+/// Command: Start a co-op game with two guessers sharing the guess budget
+pub struct StartCoopGameCommand;
+
+impl StartCoopGameCommand {
+    /// The Game Hub only settles two parties, so it sees the word setter
+    /// against `guesser`, who stakes for both guessers; `co_guesser` stakes
+    /// nothing and only agrees to join the session
+    pub fn execute(
+        env: &Env,
+        session_id: u32,
+        word_setter: Address,
+        guesser: Address,
+        co_guesser: Address,
+        word_setter_points: i128,
+        guesser_points: i128,
+    ) -> Result<u32, DomainError> {
+        let (setter_sessions, guesser_sessions) =
+            check_new_session(env, session_id, &word_setter, &guesser)?;
+        let stake_bounds = AdminRepository::get_stake_bounds(env);
+        stake_bounds.check(word_setter_points)?;
+        stake_bounds.check(guesser_points)?;
+        let mut co_guesser_sessions = PlayerSessionRepository::load(env, &co_guesser);
+        let max_active = AdminRepository::get_max_active_games(env);
+        if max_active > 0 && co_guesser_sessions.len() >= max_active {
+            return Err(DomainError::TooManyActiveGames);
+        }
+
+        let mut game = Game::new(
+            word_setter,
+            guesser,
             word_setter_points,
             guesser_points,
-        );
+            ALPHABET_SIZE,
+            AdminRepository::get_guess_expiry_ledgers(env),
+            env,
+        )?;
+        game.set_deadline(AdminRepository::get_game_duration_ledgers(env));
+        game.add_co_guesser(co_guesser.clone())?;
+
+        game.word_setter.require_auth_for_args(vec![
+            env,
+            session_id.into_val(env),
+            word_setter_points.into_val(env),
+        ]);
+        game.guesser.require_auth_for_args(vec![
+            env,
+            session_id.into_val(env),
+            guesser_points.into_val(env),
+        ]);
+        co_guesser.require_auth_for_args(vec![env, session_id.into_val(env)]);
+
+        notify_hub_started(env, session_id, &game);
+
+        store_new_game(env, session_id, &game, setter_sessions, guesser_sessions);
+        co_guesser_sessions.push_back(session_id);
+        PlayerSessionRepository::save(env, &co_guesser, &co_guesser_sessions);
+        Ok(session_id)
+    }
+}
 
-        // Create and save game
-        let game = Game::new(
+/// Command: Start a game with explicit Game Hub seats
+pub struct StartSeatedGameCommand;
+
+impl StartSeatedGameCommand {
+    /// Like `StartGameCommand` over A-Z, but `seats` fixes which of the
+    /// word setter and guesser the hub sees as player1; both players sign
+    /// the seating along with their stake
+    pub fn execute(
+        env: &Env,
+        session_id: u32,
+        word_setter: Address,
+        guesser: Address,
+        word_setter_points: i128,
+        guesser_points: i128,
+        seats: HubSeats,
+    ) -> Result<u32, DomainError> {
+        let (setter_sessions, guesser_sessions) =
+            check_new_session(env, session_id, &word_setter, &guesser)?;
+        let stake_bounds = AdminRepository::get_stake_bounds(env);
+        stake_bounds.check(word_setter_points)?;
+        stake_bounds.check(guesser_points)?;
+
+        let mut game = Game::new(
             word_setter,
             guesser,
             word_setter_points,
             guesser_points,
+            ALPHABET_SIZE,
+            AdminRepository::get_guess_expiry_ledgers(env),
             env,
         )?;
+        game.set_deadline(AdminRepository::get_game_duration_ledgers(env));
+        game.seat_at_hub(seats.clone())?;
 
-        GameRepository::save(env, session_id, &game);
-        Ok(())
+        game.word_setter.require_auth_for_args(vec![
+            env,
+            session_id.into_val(env),
+            word_setter_points.into_val(env),
+            seats.clone().into_val(env),
+        ]);
+        game.guesser.require_auth_for_args(vec![
+            env,
+            session_id.into_val(env),
+            guesser_points.into_val(env),
+            seats.into_val(env),
+        ]);
+
+        notify_hub_started(env, session_id, &game);
+
+        store_new_game(env, session_id, &game, setter_sessions, guesser_sessions);
+        Ok(session_id)
+    }
+}
+
+/// Opens the Game Hub session with players and stakes in seat order
+fn notify_hub_started(env: &Env, session_id: u32, game: &Game) {
+    let (player1_points, player2_points) = game.hub_points();
+    GameHubGateway::notify_game_started(
+        env,
+        session_id,
+        &game.hub_seats.player1,
+        &game.hub_seats.player2,
+        player1_points,
+        player2_points,
+    );
+}
+
+/// Checks that a new game can be opened and returns both players' active
+/// sessions
+fn check_new_session(
+    env: &Env,
+    session_id: u32,
+    word_setter: &Address,
+    guesser: &Address,
+) -> Result<(Vec<u32>, Vec<u32>), DomainError> {
+    // Validate self-play not allowed
+    if word_setter == guesser {
+        return Err(DomainError::SelfPlayNotAllowed);
+    }
+
+    // Check game doesn't already exist, nor ever did: the marker outlives the
+    // game, so an expired session cannot be restarted over it
+    if GameRepository::exists(env, session_id) || SessionMarkerRepository::exists(env, session_id) {
+        return Err(DomainError::GameAlreadyExists);
+    }
+
+    // Enforce the per-player concurrent session cap
+    let setter_sessions = PlayerSessionRepository::load(env, word_setter);
+    let guesser_sessions = PlayerSessionRepository::load(env, guesser);
+    let max_active = AdminRepository::get_max_active_games(env);
+    if max_active > 0
+        && (setter_sessions.len() >= max_active || guesser_sessions.len() >= max_active)
+    {
+        return Err(DomainError::TooManyActiveGames);
+    }
+
+    Ok((setter_sessions, guesser_sessions))
+}
+
+/// Saves a new game and adds it to both players' active sessions
+fn store_new_game(
+    env: &Env,
+    session_id: u32,
+    game: &Game,
+    mut setter_sessions: Vec<u32>,
+    mut guesser_sessions: Vec<u32>,
+) {
+    setter_sessions.push_back(session_id);
+    guesser_sessions.push_back(session_id);
+    PlayerSessionRepository::save(env, &game.word_setter, &setter_sessions);
+    PlayerSessionRepository::save(env, &game.guesser, &guesser_sessions);
+
+    GameRepository::save(env, session_id, game);
+    SessionMarkerRepository::save(env, session_id, &SessionMarker::new(game));
+    let mut metrics = StatsRepository::load_metrics(env);
+    metrics.record_start(game);
+    StatsRepository::save_metrics(env, &metrics);
+}
+
+/// Requires auth from the player's active session key, or the player
+/// themselves when none is set
+fn require_move_auth(env: &Env, session_id: u32, player: &Address) {
+    match SessionKeyRepository::load(env, session_id, player) {
+        Some(session_key) => session_key.key.require_auth(),
+        None => player.require_auth(),
     }
 }
 
@@ -75,14 +309,278 @@ impl CommitWordCommand {
     ) -> Result<(), DomainError> {
         player.require_auth();
 
+        if AdminRepository::get_require_word_proof(env) {
+            return Err(DomainError::WordProofRequired);
+        }
+
         let mut game = GameRepository::load(env, session_id)?;
         game.commit_word(&player, word_commitment)?;
+        MoveReporter::record(
+            env,
+            session_id,
+            &mut game,
+            symbol_short!("commit"),
+            Some(player),
+        );
         GameRepository::save(env, session_id, &game);
 
         Ok(())
     }
 }
 
+/// Command: Commit secret word with a word validity proof
+pub struct CommitWordWithProofCommand;
+
+impl CommitWordWithProofCommand {
+    pub fn execute(
+        env: &Env,
+        session_id: u32,
+        player: Address,
+        word_commitment: BytesN<32>,
+        proof_payload: Bytes,
+    ) -> Result<(), DomainError> {
+        player.require_auth();
+
+        let mut game = GameRepository::load(env, session_id)?;
+        game.commit_word(&player, word_commitment.clone())?;
+
+        // Verify ZK proof that the commitment hides a valid (dictionary) word
+        if !VerifierGateway::verify_word_commitment(env, &word_commitment, &proof_payload) {
+            return Err(DomainError::InvalidProof);
+        }
+
+        game.word_proven = true;
+        MoveReporter::record(
+            env,
+            session_id,
+            &mut game,
+            symbol_short!("commit"),
+            Some(player),
+        );
+        GameRepository::save(env, session_id, &game);
+
+        Ok(())
+    }
+}
+
+/// Command: Commit the pool the word will be drawn from
+pub struct CommitWordPoolCommand;
+
+impl CommitWordPoolCommand {
+    pub fn execute(
+        env: &Env,
+        session_id: u32,
+        player: Address,
+        pool_root: BytesN<32>,
+        pool_size: u32,
+        seed_commitment: BytesN<32>,
+    ) -> Result<(), DomainError> {
+        player.require_auth();
+
+        let mut game = GameRepository::load(env, session_id)?;
+        let pool = WordPool::new(pool_root, pool_size, seed_commitment)?;
+        game.commit_word_pool(&player, pool)?;
+        MoveReporter::record(
+            env,
+            session_id,
+            &mut game,
+            symbol_short!("poolcmt"),
+            Some(player),
+        );
+        GameRepository::save(env, session_id, &game);
+
+        Ok(())
+    }
+}
+
+/// Command: Guesser adds their seed to the word draw
+pub struct ContributePoolSeedCommand;
+
+impl ContributePoolSeedCommand {
+    pub fn execute(
+        env: &Env,
+        session_id: u32,
+        guesser: Address,
+        seed: BytesN<32>,
+    ) -> Result<(), DomainError> {
+        guesser.require_auth();
+
+        let mut game = GameRepository::load(env, session_id)?;
+        game.contribute_pool_seed(&guesser, seed)?;
+        MoveReporter::record(
+            env,
+            session_id,
+            &mut game,
+            symbol_short!("poolseed"),
+            Some(guesser),
+        );
+        GameRepository::save(env, session_id, &game);
+
+        Ok(())
+    }
+}
+
+/// Command: Word setter reveals their seed, drawing the word index
+pub struct RevealPoolSeedCommand;
+
+impl RevealPoolSeedCommand {
+    pub fn execute(
+        env: &Env,
+        session_id: u32,
+        player: Address,
+        seed: BytesN<32>,
+    ) -> Result<u32, DomainError> {
+        player.require_auth();
+
+        let mut game = GameRepository::load(env, session_id)?;
+        let commitment = Self::build_pool_seed_commitment(env, session_id, &player, &seed);
+        let word_index = game.reveal_pool_seed(env, &player, seed, &commitment)?;
+        MoveReporter::record(
+            env,
+            session_id,
+            &mut game,
+            symbol_short!("poolrev"),
+            Some(player),
+        );
+        GameRepository::save(env, session_id, &game);
+
+        Ok(word_index)
+    }
+
+    /// Builds the hash a word setter commits their seed to: session_id ||
+    /// seed || word_setter
+    pub fn build_pool_seed_commitment(
+        env: &Env,
+        session_id: u32,
+        word_setter: &Address,
+        seed: &BytesN<32>,
+    ) -> BytesN<32> {
+        BindingHashBuilder::new(env, session_id)
+            .commitment(seed)
+            .address(word_setter)
+            .finish()
+    }
+}
+
+/// Command: Commit the word drawn from the pool with a membership proof
+pub struct CommitPoolWordCommand;
+
+impl CommitPoolWordCommand {
+    pub fn execute(
+        env: &Env,
+        session_id: u32,
+        player: Address,
+        word_commitment: BytesN<32>,
+        proof_payload: Bytes,
+    ) -> Result<(), DomainError> {
+        player.require_auth();
+
+        let mut game = GameRepository::load(env, session_id)?;
+        let (pool_root, word_index) = game.commit_pool_word(&player, word_commitment.clone())?;
+
+        // Verify ZK proof that the commitment hides the pool's drawn word
+        if !VerifierGateway::verify_pool_word(
+            env,
+            &word_commitment,
+            &pool_root,
+            word_index,
+            &proof_payload,
+        ) {
+            return Err(DomainError::InvalidProof);
+        }
+
+        MoveReporter::record(
+            env,
+            session_id,
+            &mut game,
+            symbol_short!("commit"),
+            Some(player),
+        );
+        GameRepository::save(env, session_id, &game);
+
+        Ok(())
+    }
+}
+
+/// Command: Set a practice game's word in clear
+pub struct CommitPracticeWordCommand;
+
+impl CommitPracticeWordCommand {
+    pub fn execute(
+        env: &Env,
+        session_id: u32,
+        player: Address,
+        word_letters: BytesN<5>,
+    ) -> Result<(), DomainError> {
+        player.require_auth();
+
+        let mut game = GameRepository::load(env, session_id)?;
+        game.commit_practice_word(&player, word_letters)?;
+        MoveReporter::record(
+            env,
+            session_id,
+            &mut game,
+            symbol_short!("commit"),
+            Some(player),
+        );
+        GameRepository::save(env, session_id, &game);
+
+        Ok(())
+    }
+}
+
+/// Command: Finalize a finished game with a whole-game audit proof
+pub struct FinalizeWithAuditCommand;
+
+impl FinalizeWithAuditCommand {
+    pub fn execute(env: &Env, session_id: u32, proof_payload: Bytes) -> Result<(), DomainError> {
+        let mut game = GameRepository::load_with_history(env, session_id)?;
+        let word_setter = game.word_setter.clone();
+        word_setter.require_auth();
+
+        game.mark_audited(&word_setter)?;
+
+        // Verify ZK proof that every feedback matched the committed word
+        let word_commitment = game.get_word_commitment()?;
+        let audit_hash = Self::build_audit_hash(env, session_id, &game, &word_commitment);
+        if !VerifierGateway::verify_audit(env, &word_commitment, &audit_hash, &proof_payload) {
+            return Err(DomainError::InvalidProof);
+        }
+
+        MoveReporter::record(
+            env,
+            session_id,
+            &mut game,
+            symbol_short!("audit"),
+            Some(word_setter),
+        );
+        GameRepository::save(env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Builds the audit hash over the full guess/feedback history
+    pub fn build_audit_hash(
+        env: &Env,
+        session_id: u32,
+        game: &Game,
+        word_commitment: &BytesN<32>,
+    ) -> BytesN<32> {
+        let mut hash = BindingHashBuilder::new(env, session_id)
+            .u8(game.guess_count as u8)
+            .u8(game.alphabet_size as u8);
+        for (guess, feedback) in game.guesses.iter().zip(game.feedbacks.iter()) {
+            hash = hash
+                .bytes(&guess.to_array())
+                .bytes(&feedback_bytes(&feedback));
+        }
+        hash.commitment(word_commitment)
+            .address(&game.word_setter)
+            .address(&game.guesser)
+            .finish()
+    }
+}
+
 /// Command: Submit a guess
 pub struct GuessCommand;
 
@@ -93,17 +591,95 @@ impl GuessCommand {
         guesser: Address,
         guess_letters: BytesN<5>,
     ) -> Result<(), DomainError> {
-        guesser.require_auth();
+        let mut game = GameRepository::load(env, session_id)?;
+        require_move_auth(env, session_id, &guesser);
+        let guess = Guess::new(guess_letters, game.alphabet_size)?;
+        game.submit_guess(&guesser, &guess, env.ledger().sequence())?;
+        MoveReporter::record(
+            env,
+            session_id,
+            &mut game,
+            symbol_short!("guess"),
+            Some(guesser),
+        );
+        GameRepository::save(env, session_id, &game);
+
+        Ok(())
+    }
+}
+
+/// Command: Commit to a guess without revealing its letters
+pub struct CommitGuessCommand;
 
-        let guess = Guess::new(guess_letters)?;
+impl CommitGuessCommand {
+    pub fn execute(
+        env: &Env,
+        session_id: u32,
+        guesser: Address,
+        commitment: BytesN<32>,
+    ) -> Result<(), DomainError> {
         let mut game = GameRepository::load(env, session_id)?;
-        game.submit_guess(&guesser, &guess)?;
+        require_move_auth(env, session_id, &guesser);
+        game.commit_guess(&guesser, commitment, env.ledger().sequence())?;
+        MoveReporter::record(
+            env,
+            session_id,
+            &mut game,
+            symbol_short!("guesscmt"),
+            Some(guesser),
+        );
         GameRepository::save(env, session_id, &game);
 
         Ok(())
     }
 }
 
+/// Command: Reveal a committed guess so the word setter can resolve it
+pub struct RevealGuessCommand;
+
+impl RevealGuessCommand {
+    pub fn execute(
+        env: &Env,
+        session_id: u32,
+        guesser: Address,
+        guess_letters: BytesN<5>,
+        salt: BytesN<32>,
+    ) -> Result<(), DomainError> {
+        let mut game = GameRepository::load(env, session_id)?;
+        require_move_auth(env, session_id, &guesser);
+        let guess = Guess::new(guess_letters.clone(), game.alphabet_size)?;
+        let commitment =
+            Self::build_guess_commitment(env, session_id, &guesser, &guess_letters, &salt);
+        game.reveal_guess(&guesser, &guess, &commitment, env.ledger().sequence())?;
+        MoveReporter::record(
+            env,
+            session_id,
+            &mut game,
+            symbol_short!("guessrev"),
+            Some(guesser),
+        );
+        GameRepository::save(env, session_id, &game);
+
+        Ok(())
+    }
+
+    /// Builds the hash a guesser commits to: session_id || letters || salt
+    /// || guesser
+    pub fn build_guess_commitment(
+        env: &Env,
+        session_id: u32,
+        guesser: &Address,
+        guess_letters: &BytesN<5>,
+        salt: &BytesN<32>,
+    ) -> BytesN<32> {
+        BindingHashBuilder::new(env, session_id)
+            .bytes(&guess_letters.to_array())
+            .commitment(salt)
+            .address(guesser)
+            .finish()
+    }
+}
+
 /// Command: Resolve a guess with ZK proof
 pub struct ResolveGuessCommand;
 
@@ -118,15 +694,15 @@ impl ResolveGuessCommand {
         public_inputs_hash: BytesN<32>,
     ) -> Result<GuessResult, DomainError> {
         let mut game = GameRepository::load(env, session_id)?;
+        require_move_auth(env, session_id, &game.word_setter);
+        game.require_proofs()?;
 
         // Validate feedback format
         let _ = Feedback::from_vec(&feedback)?;
 
         // Get required data for verification
         let word_commitment = game.get_word_commitment()?;
-        let guess_letters = game
-            .get_pending_guess()
-            .ok_or(DomainError::NoPendingGuess)?;
+        let guess_letters = game.require_pending_guess()?;
 
         // Verify public inputs hash
         let expected_hash = Self::build_public_inputs_hash(
@@ -138,6 +714,7 @@ impl ResolveGuessCommand {
             &feedback,
             is_correct,
             &word_commitment,
+            game.alphabet_size,
         );
 
         if expected_hash != public_inputs_hash {
@@ -150,39 +727,15 @@ impl ResolveGuessCommand {
             return Err(DomainError::InvalidProof);
         }
 
-        // Manually update game state (avoiding Env::default() in domain)
-        game.guesses.push_back(guess_letters);
-        game.feedbacks.push_back(feedback.clone());
-        game.guess_count += 1;
-        game.pending_guess = None;
-
-        let outcome = if is_correct {
-            game.phase = crate::domain::GamePhase::Ended;
-            game.winner = Some(game.guesser.clone());
-            GameOutcome::GuesserWins
-        } else if game.guess_count >= crate::domain::game::MAX_GUESSES {
-            game.phase = crate::domain::GamePhase::Ended;
-            game.winner = Some(game.word_setter.clone());
-            GameOutcome::WordSetterWins
-        } else {
-            GameOutcome::Continue
-        };
-
-        // Notify Game Hub if game ended
-        if outcome.is_game_over() {
-            let word_setter_won = !game.guesser_won();
-            GameHubGateway::notify_game_ended(env, session_id, word_setter_won);
-        }
-
-        GameRepository::save(env, session_id, &game);
-
-        Ok(GuessResult {
-            guess_number: game.guess_count,
+        Ok(record_resolution(
+            env,
+            session_id,
+            &mut game,
+            Some(word_setter),
+            guess_letters,
             feedback,
             is_correct,
-            winner: game.winner.clone(),
-            game_ended: outcome.is_game_over(),
-        })
+        ))
     }
 
     /// Builds the public inputs hash for verification
@@ -195,24 +748,368 @@ impl ResolveGuessCommand {
         feedback: &Vec<u32>,
         is_correct: bool,
         word_commitment: &BytesN<32>,
+        alphabet_size: u32,
     ) -> BytesN<32> {
-        let mut fixed = [0u8; 15];
-        fixed[0..4].copy_from_slice(&session_id.to_be_bytes());
+        BindingHashBuilder::new(env, session_id)
+            .bytes(&guess_letters.to_array())
+            .bytes(&feedback_bytes(feedback))
+            .flag(is_correct)
+            .u8(alphabet_size as u8)
+            .commitment(word_commitment)
+            .address(word_setter)
+            .address(guesser)
+            .finish()
+    }
+}
+
+/// Command: Resolve a practice game's pending guess from its cleartext word
+pub struct ResolvePracticeGuessCommand;
+
+impl ResolvePracticeGuessCommand {
+    /// Callable by anyone: the feedback is computed, not claimed
+    pub fn execute(env: &Env, session_id: u32) -> Result<GuessResult, DomainError> {
+        let mut game = GameRepository::load(env, session_id)?;
+        let word = game.get_practice_word()?;
+        let guess_letters = game.require_pending_guess()?;
+        let guess = Guess::new(guess_letters.clone(), game.alphabet_size)?;
+
+        let feedback = compute_feedback(&word, &guess);
+        let is_correct = feedback.is_all_correct();
+        Ok(record_resolution(
+            env,
+            session_id,
+            &mut game,
+            None,
+            guess_letters,
+            feedback.to_vec(env),
+            is_correct,
+        ))
+    }
+}
+
+/// Records a resolved guess, settles the outcome and notifies the Game Hub
+/// when the game is over (manually, avoiding Env::default() in domain).
+/// `resolver` is the word setter, or None when anyone may resolve
+fn record_resolution(
+    env: &Env,
+    session_id: u32,
+    game: &mut Game,
+    resolver: Option<Address>,
+    guess_letters: BytesN<5>,
+    feedback: Vec<u32>,
+    is_correct: bool,
+) -> GuessResult {
+    game.guess_actors.push_back(game.guesser_due().clone());
+    game.guesses.push_back(guess_letters);
+    game.feedbacks.push_back(feedback.clone());
+    game.guess_count += 1;
+    game.pending_guess = None;
+
+    let outcome = if is_correct {
+        game.phase = crate::domain::GamePhase::Ended;
+        game.winner = Some(game.guesser.clone());
+        GameOutcome::GuesserWins
+    } else if game.guess_count >= crate::domain::game::MAX_GUESSES {
+        game.phase = crate::domain::GamePhase::Ended;
+        game.winner = Some(game.word_setter.clone());
+        GameOutcome::WordSetterWins
+    } else {
+        GameOutcome::Continue
+    };
+
+    // Notify Game Hub if game ended; practice games have no hub session
+    if outcome.is_game_over() {
+        let player1_won = game.hub_player1_won();
+        game.hub_notified =
+            game.practice || GameHubGateway::try_notify_game_ended(env, session_id, player1_won);
+        release_sessions(env, session_id, game, false);
+        archive_stats(env, session_id, game);
+    }
+
+    MoveReporter::record(env, session_id, game, symbol_short!("resolve"), resolver);
+    GameRepository::save(env, session_id, game);
+
+    GuessResult {
+        guess_number: game.guess_count,
+        feedback,
+        is_correct,
+        winner: game.winner.clone(),
+        game_ended: outcome.is_game_over(),
+        move_count: game.move_count,
+    }
+}
+
+/// Drops a finished game from every player's active sessions and counts
+/// how it ended, `timed_out` when a deadline or reveal window settled it
+fn release_sessions(env: &Env, session_id: u32, game: &Game, timed_out: bool) {
+    PlayerSessionRepository::remove(env, &game.word_setter, session_id);
+    PlayerSessionRepository::remove(env, &game.guesser, session_id);
+    if let Some(co_guesser) = &game.co_guesser {
+        PlayerSessionRepository::remove(env, co_guesser, session_id);
+    }
+    let mut metrics = StatsRepository::load_metrics(env);
+    metrics.record_end(game);
+    if timed_out {
+        metrics.record_timeout(game);
+    }
+    StatsRepository::save_metrics(env, &metrics);
+}
+
+/// Archives the stats of a game that just ended with a winner; practice
+/// games are skipped so they cannot be farmed for stats
+fn archive_stats(env: &Env, session_id: u32, game: &Game) {
+    if game.practice {
+        return;
+    }
+    // Stats count the feedback of every guess, not just those loaded; a game
+    // whose early guesses already expired is left unarchived
+    let mut game = game.clone();
+    if GameRepository::load_history(env, session_id, &mut game).is_err() {
+        return;
+    }
+    let stats = GameStats::from_game(&game, env.ledger().sequence());
+    StatsRepository::archive(env, session_id, &game.guesser, &stats);
+}
 
-        let guess_arr = guess_letters.to_array();
-        fixed[4..9].copy_from_slice(&guess_arr);
+/// Command: Retract a pending guess before the word setter resolves it
+pub struct RetractGuessCommand;
 
-        for i in 0..5 {
-            fixed[9 + i] = feedback.get(i as u32).unwrap_or(0) as u8;
+impl RetractGuessCommand {
+    pub fn execute(env: &Env, session_id: u32, guesser: Address) -> Result<(), DomainError> {
+        let mut game = GameRepository::load(env, session_id)?;
+        require_move_auth(env, session_id, &guesser);
+        game.retract_guess(&guesser, env.ledger().sequence())?;
+        MoveReporter::record(
+            env,
+            session_id,
+            &mut game,
+            symbol_short!("retract"),
+            Some(guesser),
+        );
+        GameRepository::save(env, session_id, &game);
+
+        Ok(())
+    }
+}
+
+/// Command: Expire a pending guess the word setter never resolved
+pub struct ExpirePendingGuessCommand;
+
+impl ExpirePendingGuessCommand {
+    /// Callable by anyone once the expiry window has passed; returns the
+    /// word setter's strike count
+    pub fn execute(env: &Env, session_id: u32) -> Result<u32, DomainError> {
+        let mut game = GameRepository::load(env, session_id)?;
+        let outcome = game.expire_pending_guess(env.ledger().sequence())?;
+
+        // Notify Game Hub if the setter forfeited
+        if outcome.is_game_over() {
+            let player1_won = game.hub_player1_won();
+            game.hub_notified = game.practice
+                || GameHubGateway::try_notify_game_ended(env, session_id, player1_won);
+            release_sessions(env, session_id, &game, false);
+            archive_stats(env, session_id, &game);
         }
 
-        fixed[14] = if is_correct { 1 } else { 0 };
+        MoveReporter::record(env, session_id, &mut game, symbol_short!("expire"), None);
+        GameRepository::save(env, session_id, &game);
+
+        Ok(game.setter_strikes)
+    }
+}
+
+/// Command: End a game whose committed guess was never revealed
+pub struct ExpireGuessCommitmentCommand;
+
+impl ExpireGuessCommitmentCommand {
+    /// Callable by anyone once the reveal window has passed; returns the
+    /// winner (the word setter)
+    pub fn execute(env: &Env, session_id: u32) -> Result<Address, DomainError> {
+        let mut game = GameRepository::load(env, session_id)?;
+        let winner = game.expire_guess_commitment(env.ledger().sequence())?;
+
+        let player1_won = game.hub_player1_won();
+        game.hub_notified =
+            game.practice || GameHubGateway::try_notify_game_ended(env, session_id, player1_won);
+        release_sessions(env, session_id, &game, true);
+        archive_stats(env, session_id, &game);
+
+        MoveReporter::record(env, session_id, &mut game, symbol_short!("lapse"), None);
+        GameRepository::save(env, session_id, &game);
+
+        Ok(winner)
+    }
+}
 
-        let mut payload = Bytes::from_array(env, &fixed);
-        payload.append(&Bytes::from_array(env, &word_commitment.to_array()));
-        payload.append(&word_setter.to_string().to_bytes());
-        payload.append(&guesser.to_string().to_bytes());
+/// Command: End a game that outlived its deadline
+pub struct FinalizeExpiredCommand;
+
+impl FinalizeExpiredCommand {
+    /// Callable by anyone once the deadline has passed; returns the winner
+    pub fn execute(env: &Env, session_id: u32) -> Result<Address, DomainError> {
+        let mut game = GameRepository::load(env, session_id)?;
+        let winner = game.finalize_expired(env.ledger().sequence())?;
+
+        let player1_won = game.hub_player1_won();
+        game.hub_notified =
+            game.practice || GameHubGateway::try_notify_game_ended(env, session_id, player1_won);
+        release_sessions(env, session_id, &game, true);
+        archive_stats(env, session_id, &game);
+
+        MoveReporter::record(env, session_id, &mut game, symbol_short!("deadline"), None);
+        GameRepository::save(env, session_id, &game);
+
+        Ok(winner)
+    }
+}
+
+/// Command: Re-send a game result the Game Hub failed to accept
+pub struct RetryHubNotifyCommand;
+
+impl RetryHubNotifyCommand {
+    /// Callable by anyone; if the hub still fails the call reverts and the
+    /// game stays unnotified
+    pub fn execute(env: &Env, session_id: u32) -> Result<(), DomainError> {
+        let mut game = GameRepository::load(env, session_id)?;
+        game.mark_hub_notified()?;
+
+        GameHubGateway::notify_game_ended(env, session_id, game.hub_player1_won());
+        GameRepository::save(env, session_id, &game);
+
+        Ok(())
+    }
+}
+
+/// Command: Cancel a running game on behalf of the Game Hub
+pub struct CancelGameCommand;
+
+impl CancelGameCommand {
+    /// Only the Game Hub can cancel; since it initiated the cancel it is not
+    /// notified back
+    pub fn execute(env: &Env, session_id: u32) -> Result<(), DomainError> {
+        let mut game = GameRepository::load(env, session_id)?;
+        game.cancel()?;
 
-        env.crypto().keccak256(&payload).into()
+        AdminRepository::get_game_hub(env).require_auth();
+
+        release_sessions(env, session_id, &game, false);
+        MoveReporter::record(env, session_id, &mut game, symbol_short!("cancel"), None);
+        GameRepository::save(env, session_id, &game);
+
+        Ok(())
+    }
+}
+
+/// Command: Settle timeouts and report the winner for `GameInterface`
+pub struct EvaluateGameCommand;
+
+impl EvaluateGameCommand {
+    /// Ends a game past its deadline (as `finalize_expired` would) or with
+    /// an unrevealed guess commitment (as `expire_guess_commitment` would),
+    /// or expires an overdue pending guess (as `expire_pending_guess`
+    /// would), then returns the winner: None while running or after a cancel
+    pub fn execute(env: &Env, session_id: u32) -> Result<Option<Address>, DomainError> {
+        let game = GameRepository::load(env, session_id)?;
+        let ledger = env.ledger().sequence();
+        if game.past_deadline(ledger) {
+            return FinalizeExpiredCommand::execute(env, session_id).map(Some);
+        }
+        if game.guess_commitment_overdue(ledger) {
+            return ExpireGuessCommitmentCommand::execute(env, session_id).map(Some);
+        }
+        if !game.pending_guess_overdue(ledger) {
+            return Ok(game.winner);
+        }
+
+        ExpirePendingGuessCommand::execute(env, session_id)?;
+        Ok(GameRepository::load(env, session_id)?.winner)
+    }
+}
+
+/// Command: Authorize a session key to sign a player's moves
+pub struct AuthorizeSessionKeyCommand;
+
+impl AuthorizeSessionKeyCommand {
+    pub fn execute(
+        env: &Env,
+        session_id: u32,
+        player: Address,
+        key: Address,
+        expiry: u32,
+    ) -> Result<(), DomainError> {
+        player.require_auth();
+
+        let game = GameRepository::load(env, session_id)?;
+        if game.phase == crate::domain::GamePhase::Ended {
+            return Err(DomainError::GameAlreadyEnded);
+        }
+        if player != game.word_setter
+            && player != game.guesser
+            && game.co_guesser.as_ref() != Some(&player)
+        {
+            return Err(DomainError::NotPlayer);
+        }
+
+        let session_key = SessionKey::new(&player, key, expiry, env.ledger().sequence())?;
+        SessionKeyRepository::save(env, session_id, &player, &session_key);
+
+        Ok(())
+    }
+}
+
+/// Command: React to a resolved guess with an emote
+pub struct ReactCommand;
+
+impl ReactCommand {
+    /// Records `player`'s emote on the resolved guess at `guess_index`,
+    /// replacing their earlier one. Any player of the game may react, also
+    /// after it has ended
+    pub fn execute(
+        env: &Env,
+        session_id: u32,
+        player: Address,
+        guess_index: u32,
+        emote_code: u32,
+    ) -> Result<(), DomainError> {
+        player.require_auth();
+
+        let emote = Emote::from_u32(emote_code)?;
+        let game = GameRepository::load(env, session_id)?;
+        if player != game.word_setter
+            && player != game.guesser
+            && game.co_guesser.as_ref() != Some(&player)
+        {
+            return Err(DomainError::NotPlayer);
+        }
+        if guess_index >= game.guess_count {
+            return Err(DomainError::GuessNotFound);
+        }
+
+        let mut reactions = ReactionRepository::load(env, session_id, guess_index);
+        set_reaction(
+            &mut reactions,
+            player.clone(),
+            emote,
+            env.ledger().sequence(),
+        );
+        ReactionRepository::save(env, session_id, guess_index, &reactions);
+        Reacted {
+            session_id,
+            player,
+            guess_index,
+            emote_code,
+        }
+        .publish(env);
+        Ok(())
+    }
+}
+
+/// Command: Revoke a player's session key
+pub struct RevokeSessionKeyCommand;
+
+impl RevokeSessionKeyCommand {
+    pub fn execute(env: &Env, session_id: u32, player: Address) {
+        player.require_auth();
+        SessionKeyRepository::remove(env, session_id, &player);
     }
 }
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
 
 /// Result of resolving a guess (returned to frontend)
 #[contracttype]
@@ -14,4 +14,39 @@ pub struct GuessResult {
     pub winner: Option<Address>,
     /// Whether the game has ended
     pub game_ended: bool,
+    /// Sequence number of this resolution among the session's moves
+    pub move_count: u32,
+}
+
+/// Dry-run diagnosis of a resolution (returned by `check_resolution`).
+/// Checks run in `resolve_guess` order and stop at the first failure, so
+/// fields past it keep their zero values
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResolutionCheck {
+    /// Error code `resolve_guess` would fail with (0 = would be accepted)
+    pub error_code: u32,
+    /// Pending guess being resolved
+    pub guess: Option<BytesN<5>>,
+    pub word_commitment: Option<BytesN<32>>,
+    /// Hash the contract binds the proof to
+    pub expected_hash: Option<BytesN<32>>,
+    pub hash_matches: bool,
+    pub proof_valid: bool,
+    /// Contract error the verifier aborted with (0 if none)
+    pub verifier_error_code: u32,
+}
+
+/// Contract metadata for capability negotiation
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractInfo {
+    /// Crate name
+    pub name: String,
+    /// Semantic version
+    pub version: String,
+    /// Interface ids implemented by the contract (e.g. "game-v1")
+    pub interfaces: Vec<String>,
+    /// Source revision from ZKARCADE_BUILD_HASH at build time ("dev" if unset)
+    pub build_hash: String,
 }
@@ -3,7 +3,19 @@ mod dto;
 mod queries;
 
 pub use commands::{
-    CommitWordCommand, GuessCommand, ResolveGuessCommand, StartGameCommand,
+    AuthorizeSessionKeyCommand, CancelGameCommand, CommitGuessCommand, CommitPoolWordCommand,
+    CommitPracticeWordCommand, CommitWordCommand, CommitWordPoolCommand,
+    CommitWordWithProofCommand, ContributePoolSeedCommand, EvaluateGameCommand,
+    ExpireGuessCommitmentCommand, ExpirePendingGuessCommand, FinalizeExpiredCommand,
+    FinalizeWithAuditCommand, GuessCommand, ReactCommand, ResolveGuessCommand,
+    ResolvePracticeGuessCommand, RetractGuessCommand, RetryHubNotifyCommand, RevealGuessCommand,
+    RevealPoolSeedCommand, RevokeSessionKeyCommand, StartCoopGameCommand, StartGameCommand,
+    StartPracticeGameCommand, StartSeatedGameCommand,
+};
+pub use dto::{ContractInfo, GuessResult, ResolutionCheck};
+pub use queries::{
+    CheckResolutionQuery, ComputeFeedbackQuery, GetActiveSessionsQuery, GetArchivedStatsQuery,
+    GetAuditHashQuery, GetGameQuery, GetGameStatusQuery, GetInfoQuery, GetMetricsQuery,
+    GetMoveCountQuery, GetPlayerStatsQuery, GetReactionsQuery, GetRulesQuery, GetSessionKeyQuery,
+    GetSessionMarkerQuery, GetShareGridQuery,
 };
-pub use dto::GuessResult;
-pub use queries::{GetGameQuery, GetRulesQuery};
@@ -1,22 +1,312 @@
-use soroban_sdk::Env;
+use soroban_sdk::{Address, Bytes, BytesN, Env, String, Vec};
 
-use crate::domain::{DomainError, Game, GameRules};
-use crate::infrastructure::GameRepository;
+use crate::domain::game::MAX_GUESSES;
+use crate::domain::{
+    compute_feedback, DomainError, Feedback, FeedbackStatus, Game, GamePhase, GameRules, GameStats,
+    GameStatus, Guess, Metrics, PlayerStats, Reaction, SessionKey, SessionMarker, Word,
+    MAX_ALPHABET_SIZE,
+};
+use crate::infrastructure::{
+    GameRepository, PlayerSessionRepository, ReactionRepository, SessionKeyRepository,
+    SessionMarkerRepository, StatsRepository, VerifierGateway,
+};
+
+use super::commands::{FinalizeWithAuditCommand, ResolveGuessCommand};
+use super::dto::{ContractInfo, ResolutionCheck};
 
 /// Query: Get game state
 pub struct GetGameQuery;
 
 impl GetGameQuery {
     pub fn execute(env: &Env, session_id: u32) -> Result<Game, DomainError> {
-        GameRepository::load(env, session_id)
+        GameRepository::load_with_history(env, session_id)
+    }
+}
+
+/// Query: Lifecycle status for `GameInterface`
+pub struct GetGameStatusQuery;
+
+impl GetGameStatusQuery {
+    pub fn execute(env: &Env, session_id: u32) -> Result<GameStatus, DomainError> {
+        Ok(GameRepository::load(env, session_id)?.status())
     }
 }
 
-/// Query: Get game rules
+/// Query: Get the rules a session was started with
 pub struct GetRulesQuery;
 
 impl GetRulesQuery {
-    pub fn execute() -> GameRules {
-        GameRules::default()
+    pub fn execute(env: &Env, session_id: u32) -> Result<GameRules, DomainError> {
+        Ok(GameRepository::load(env, session_id)?.rules())
+    }
+}
+
+/// Query: Number of actions applied to a game
+pub struct GetMoveCountQuery;
+
+impl GetMoveCountQuery {
+    pub fn execute(env: &Env, session_id: u32) -> Result<u32, DomainError> {
+        Ok(GameRepository::load(env, session_id)?.move_count)
+    }
+}
+
+/// Query: Get a player's active sessions
+pub struct GetActiveSessionsQuery;
+
+impl GetActiveSessionsQuery {
+    pub fn execute(env: &Env, player: &Address) -> Vec<u32> {
+        PlayerSessionRepository::load(env, player)
+    }
+}
+
+/// Query: A player's active session key
+pub struct GetSessionKeyQuery;
+
+impl GetSessionKeyQuery {
+    pub fn execute(env: &Env, session_id: u32, player: &Address) -> Option<SessionKey> {
+        SessionKeyRepository::load(env, session_id, player)
+    }
+}
+
+/// Query: Archived stats of a finished game
+pub struct GetArchivedStatsQuery;
+
+impl GetArchivedStatsQuery {
+    pub fn execute(env: &Env, session_id: u32) -> Result<GameStats, DomainError> {
+        StatsRepository::load_archived(env, session_id)
+    }
+}
+
+/// Query: A guesser's stats aggregated over archived games
+pub struct GetPlayerStatsQuery;
+
+impl GetPlayerStatsQuery {
+    pub fn execute(env: &Env, player: &Address) -> PlayerStats {
+        StatsRepository::load_player(env, player)
+    }
+}
+
+/// Query: Operator counters
+pub struct GetMetricsQuery;
+
+impl GetMetricsQuery {
+    pub fn execute(env: &Env) -> Metrics {
+        StatsRepository::load_metrics(env)
+    }
+}
+
+/// Query: Emotes players left on one resolved guess
+pub struct GetReactionsQuery;
+
+impl GetReactionsQuery {
+    pub fn execute(env: &Env, session_id: u32, guess_index: u32) -> Vec<Reaction> {
+        ReactionRepository::load(env, session_id, guess_index)
+    }
+}
+
+/// Query: Persistent marker of a started session, if any
+pub struct GetSessionMarkerQuery;
+
+impl GetSessionMarkerQuery {
+    pub fn execute(env: &Env, session_id: u32) -> Option<SessionMarker> {
+        SessionMarkerRepository::load(env, session_id)
+    }
+
+    /// Whether the session was ever started, even if its game has expired
+    pub fn was_started(env: &Env, session_id: u32) -> bool {
+        SessionMarkerRepository::exists(env, session_id)
+    }
+}
+
+/// Query: Audit hash a game audit proof must bind to
+pub struct GetAuditHashQuery;
+
+impl GetAuditHashQuery {
+    pub fn execute(env: &Env, session_id: u32) -> Result<BytesN<32>, DomainError> {
+        let game = GameRepository::load_with_history(env, session_id)?;
+        let word_commitment = game.get_word_commitment()?;
+        Ok(FinalizeWithAuditCommand::build_audit_hash(
+            env,
+            session_id,
+            &game,
+            &word_commitment,
+        ))
+    }
+}
+
+/// Query: Dry-run a resolution without changing state
+pub struct CheckResolutionQuery;
+
+impl CheckResolutionQuery {
+    /// Runs the checks of `ResolveGuessCommand` (except auth) and reports
+    /// the first one that fails, with the values the contract expected
+    pub fn execute(
+        env: &Env,
+        session_id: u32,
+        feedback: Vec<u32>,
+        is_correct: bool,
+        proof_payload: Bytes,
+        public_inputs_hash: BytesN<32>,
+    ) -> ResolutionCheck {
+        let mut check = ResolutionCheck {
+            error_code: 0,
+            guess: None,
+            word_commitment: None,
+            expected_hash: None,
+            hash_matches: false,
+            proof_valid: false,
+            verifier_error_code: 0,
+        };
+        if let Err(err) = Self::check_hash(
+            env,
+            session_id,
+            &feedback,
+            is_correct,
+            &public_inputs_hash,
+            &mut check,
+        ) {
+            check.error_code = err as u32;
+            return check;
+        }
+
+        let word_commitment = check.word_commitment.clone().unwrap();
+        let (valid, verifier_error_code) = VerifierGateway::diagnose_proof(
+            env,
+            &word_commitment,
+            &public_inputs_hash,
+            &proof_payload,
+        );
+        check.proof_valid = valid;
+        check.verifier_error_code = verifier_error_code;
+        if !valid {
+            check.error_code = DomainError::InvalidProof as u32;
+        }
+        check
+    }
+
+    fn check_hash(
+        env: &Env,
+        session_id: u32,
+        feedback: &Vec<u32>,
+        is_correct: bool,
+        public_inputs_hash: &BytesN<32>,
+        check: &mut ResolutionCheck,
+    ) -> Result<(), DomainError> {
+        let game = GameRepository::load(env, session_id)?;
+        Feedback::from_vec(feedback)?;
+
+        let word_commitment = game.get_word_commitment()?;
+        check.word_commitment = Some(word_commitment.clone());
+        let guess_letters = game.require_pending_guess()?;
+        check.guess = Some(guess_letters.clone());
+
+        let expected_hash = ResolveGuessCommand::build_public_inputs_hash(
+            env,
+            session_id,
+            &game.word_setter,
+            &game.guesser,
+            &guess_letters,
+            feedback,
+            is_correct,
+            &word_commitment,
+            game.alphabet_size,
+        );
+        check.hash_matches = expected_hash == *public_inputs_hash;
+        check.expected_hash = Some(expected_hash);
+        if !check.hash_matches {
+            return Err(DomainError::InvalidPublicInputsHash);
+        }
+        Ok(())
+    }
+}
+
+/// Query: Canonical feedback for a word and guess
+pub struct ComputeFeedbackQuery;
+
+impl ComputeFeedbackQuery {
+    /// Same rules as the resolve_guess circuit; letters may use the whole
+    /// circuit range (below `MAX_ALPHABET_SIZE`)
+    pub fn execute(
+        env: &Env,
+        word_letters: &BytesN<5>,
+        guess_letters: &BytesN<5>,
+    ) -> Result<Vec<u32>, DomainError> {
+        let word = Word::new(word_letters.to_array(), MAX_ALPHABET_SIZE)?;
+        let guess = Guess::new(guess_letters.clone(), MAX_ALPHABET_SIZE)?;
+        Ok(compute_feedback(&word, &guess).to_vec(env))
+    }
+}
+
+/// Query: Share card for a finished game
+pub struct GetShareGridQuery;
+
+impl GetShareGridQuery {
+    /// Renders `Wordle #<session> <n>/6` (or `X/6` when the guesser lost),
+    /// a blank line, then one row of emoji squares per guess, as UTF-8
+    pub fn execute(env: &Env, session_id: u32) -> Result<Bytes, DomainError> {
+        let game = GameRepository::load_with_history(env, session_id)?;
+        if game.phase != GamePhase::Ended {
+            return Err(DomainError::GameNotEnded);
+        }
+
+        let mut grid = Bytes::from_slice(env, b"Wordle #");
+        Self::push_u32(&mut grid, session_id);
+        grid.push_back(b' ');
+        if game.guesser_won() {
+            Self::push_u32(&mut grid, game.guess_count);
+        } else {
+            grid.push_back(b'X');
+        }
+        grid.push_back(b'/');
+        Self::push_u32(&mut grid, MAX_GUESSES);
+        grid.push_back(b'\n');
+
+        for feedback in game.feedbacks.iter() {
+            grid.push_back(b'\n');
+            for value in feedback.iter() {
+                let status = FeedbackStatus::from_u32(value)?;
+                grid.extend_from_slice(status.share_square());
+            }
+        }
+
+        Ok(grid)
+    }
+
+    fn push_u32(bytes: &mut Bytes, value: u32) {
+        let mut digits = [0u8; 10];
+        let mut len = 0;
+        let mut rest = value;
+        loop {
+            digits[len] = b'0' + (rest % 10) as u8;
+            len += 1;
+            rest /= 10;
+            if rest == 0 {
+                break;
+            }
+        }
+        for digit in digits[..len].iter().rev() {
+            bytes.push_back(*digit);
+        }
+    }
+}
+
+/// Query: Contract metadata
+pub struct GetInfoQuery;
+
+impl GetInfoQuery {
+    /// Interface ids this contract implements
+    const INTERFACES: [&'static str; 3] = ["game-v1", "game-interface-v1", "wordle-v1"];
+
+    pub fn execute(env: &Env) -> ContractInfo {
+        let mut interfaces = Vec::new(env);
+        for id in Self::INTERFACES {
+            interfaces.push_back(String::from_str(env, id));
+        }
+        ContractInfo {
+            name: String::from_str(env, env!("CARGO_PKG_NAME")),
+            version: String::from_str(env, env!("CARGO_PKG_VERSION")),
+            interfaces,
+            build_hash: String::from_str(env, option_env!("ZKARCADE_BUILD_HASH").unwrap_or("dev")),
+        }
     }
 }
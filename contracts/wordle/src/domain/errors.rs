@@ -34,4 +34,71 @@ pub enum DomainError {
     // Verification errors
     InvalidPublicInputsHash = 17,
     InvalidProof = 18,
+
+    // Session limit errors
+    TooManyActiveGames = 19,
+
+    // Word proof errors
+    WordProofRequired = 20,
+
+    // Audit errors
+    GameNotEnded = 21,
+    AlreadyAudited = 22,
+
+    // Alphabet errors
+    InvalidAlphabetSize = 23,
+
+    // Session key errors
+    InvalidSessionKey = 24,
+
+    // Guess expiry errors
+    GuessExpiryDisabled = 25,
+    GuessNotExpired = 26,
+
+    // Guess retraction errors
+    RetractWindowClosed = 27,
+    RetractionUsed = 28,
+
+    // Hub notification errors
+    HubAlreadyNotified = 29,
+
+    // Practice mode errors
+    NotPracticeGame = 30,
+    PracticeGameUnsupported = 31,
+
+    // Statistics errors
+    StatsNotArchived = 32,
+
+    // Co-op errors
+    NotGuessersTurn = 33,
+
+    // Stake errors
+    InvalidStake = 34,
+
+    // Hub seating errors
+    InvalidHubSeats = 35,
+
+    // Deadline errors
+    DeadlineDisabled = 36,
+    DeadlineNotReached = 37,
+
+    // Guess commitment errors
+    GuessNotRevealed = 38,
+    InvalidGuessReveal = 39,
+    RevealWindowClosed = 40,
+    RevealWindowOpen = 41,
+
+    // Word pool errors
+    WordPoolAlreadyCommitted = 42,
+    InvalidWordPoolSize = 43,
+    NoWordPool = 44,
+    SeedAlreadyContributed = 45,
+    SeedNotContributed = 46,
+    InvalidSeedReveal = 47,
+    WordIndexNotDrawn = 48,
+    WordPoolRequired = 49,
+
+    // Reaction errors
+    InvalidEmote = 50,
+    GuessNotFound = 51,
 }
@@ -1,7 +1,7 @@
 use soroban_sdk::{Env, Vec};
 
 use super::errors::DomainError;
-use super::word::WORD_LENGTH;
+use super::word::{Guess, Word, WORD_LENGTH};
 
 /// Feedback status for each letter position
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -28,6 +28,15 @@ impl FeedbackStatus {
     pub fn as_u32(&self) -> u32 {
         *self as u32
     }
+
+    /// UTF-8 bytes of the share-card square for this status
+    pub fn share_square(&self) -> &'static [u8] {
+        match self {
+            FeedbackStatus::Absent => "\u{2B1B}".as_bytes(),
+            FeedbackStatus::Present => "\u{1F7E8}".as_bytes(),
+            FeedbackStatus::Correct => "\u{1F7E9}".as_bytes(),
+        }
+    }
 }
 
 /// Represents feedback for a complete guess (5 positions)
@@ -82,6 +91,35 @@ impl Feedback {
     }
 }
 
+/// Computes the feedback for `guess` against `word` with the duplicate-letter
+/// rules of `AssertGuessFeedback` (wordle_utils.circom): a non-green letter is
+/// present while the word has more non-green copies of it than earlier guess
+/// positions already marked (green or present) with that letter
+pub fn compute_feedback(word: &Word, guess: &Guess) -> Feedback {
+    let word = word.letters();
+    let guess = guess.to_array();
+    let mut statuses = [FeedbackStatus::Absent; 5];
+
+    for i in 0..5 {
+        if guess[i] == word[i] {
+            statuses[i] = FeedbackStatus::Correct;
+            continue;
+        }
+
+        let available = (0..5)
+            .filter(|&j| word[j] == guess[i] && word[j] != guess[j])
+            .count();
+        let used = (0..i)
+            .filter(|&j| guess[j] == guess[i] && statuses[j] != FeedbackStatus::Absent)
+            .count();
+        if available > used {
+            statuses[i] = FeedbackStatus::Present;
+        }
+    }
+
+    Feedback { statuses }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -99,4 +137,39 @@ mod tests {
         );
         assert!(FeedbackStatus::from_u32(3).is_err());
     }
+
+    fn statuses(word: [u8; 5], guess: [u8; 5]) -> [u32; 5] {
+        let env = Env::default();
+        let word = Word::new(word, 26).unwrap();
+        let guess = Guess::new(soroban_sdk::BytesN::from_array(&env, &guess), 26).unwrap();
+        compute_feedback(&word, &guess)
+            .statuses()
+            .map(|status| status.as_u32())
+    }
+
+    #[test]
+    fn test_compute_feedback_marks_greens_and_yellows() {
+        // word HELLO, guess HOLES
+        assert_eq!(
+            statuses([7, 4, 11, 11, 14], [7, 14, 11, 4, 18]),
+            [2, 1, 2, 1, 0]
+        );
+        assert_eq!(statuses([0, 1, 2, 3, 4], [0, 1, 2, 3, 4]), [2, 2, 2, 2, 2]);
+        assert_eq!(statuses([0, 1, 2, 3, 4], [5, 6, 7, 8, 9]), [0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_compute_feedback_duplicate_letters() {
+        // Word has one A: only the first of two yellow candidates is marked
+        assert_eq!(statuses([1, 0, 2, 3, 4], [0, 5, 0, 6, 7]), [1, 0, 0, 0, 0]);
+        // Word has two A's: both are marked
+        assert_eq!(statuses([1, 0, 2, 0, 4], [0, 5, 6, 7, 0]), [1, 0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn test_share_squares_are_utf8_emoji() {
+        assert_eq!(FeedbackStatus::Absent.share_square(), "⬛".as_bytes());
+        assert_eq!(FeedbackStatus::Present.share_square(), "🟨".as_bytes());
+        assert_eq!(FeedbackStatus::Correct.share_square(), "🟩".as_bytes());
+    }
 }
@@ -2,11 +2,23 @@ use soroban_sdk::{contracttype, Address, BytesN, Vec};
 
 use super::errors::DomainError;
 use super::feedback::Feedback;
-use super::word::{Guess, WordCommitment, ALPHABET_SIZE, WORD_LENGTH};
+use super::word::{
+    validate_alphabet_size, Guess, Word, WordCommitment, ALPHABET_SIZE, WORD_LENGTH,
+};
+use super::word_pool::WordPool;
 
 /// Maximum number of guesses allowed
 pub const MAX_GUESSES: u32 = 6;
 
+/// Expired guesses the word setter may let through before forfeiting
+pub const MAX_SETTER_STRIKES: u32 = 3;
+
+/// Ledgers after submitting during which the guesser may retract a guess
+pub const GUESS_RETRACT_WINDOW_LEDGERS: u32 = 3;
+
+/// Ledgers after `commit_guess` during which the guesser must reveal it
+pub const GUESS_REVEAL_WINDOW_LEDGERS: u32 = 10;
+
 /// Game lifecycle phases
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -19,7 +31,21 @@ pub enum GamePhase {
     Ended,
 }
 
-/// Game rules (immutable configuration)
+/// Lifecycle status reported through `GameInterface`; every game uses the
+/// same variants
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GameStatus {
+    /// Waiting for the word commitment
+    Setup,
+    InProgress,
+    /// Ended with a winner
+    Ended,
+    /// Ended by `cancel_game`, without a winner
+    Cancelled,
+}
+
+/// Game rules (immutable per-session configuration)
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct GameRules {
@@ -30,14 +56,30 @@ pub struct GameRules {
 
 impl Default for GameRules {
     fn default() -> Self {
+        Self::with_alphabet(ALPHABET_SIZE)
+    }
+}
+
+impl GameRules {
+    /// Standard rules over an alphabet of `alphabet_size` letters
+    pub fn with_alphabet(alphabet_size: u32) -> Self {
         Self {
             word_length: WORD_LENGTH,
             max_guesses: MAX_GUESSES,
-            alphabet_size: ALPHABET_SIZE,
+            alphabet_size,
         }
     }
 }
 
+/// Which player sits in each Game Hub seat, fixed at start; `end_game`
+/// reports whether `player1` won
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HubSeats {
+    pub player1: Address,
+    pub player2: Address,
+}
+
 /// Game aggregate - core domain entity
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -48,16 +90,53 @@ pub struct Game {
     pub word_setter_points: i128,
     pub guesser_points: i128,
 
+    // Letters are 0..alphabet_size (26 = Latin A-Z)
+    pub alphabet_size: u32,
+    // Ledgers the setter has to resolve a guess (0 = never expires)
+    pub guess_expiry_ledgers: u32,
+    pub started_at_ledger: u32,
+    // Ledger after which `finalize_expired` ends the game (0 = no deadline)
+    pub deadline_ledger: u32,
+
     // Game state
     pub phase: GamePhase,
     pub word_commitment: Option<BytesN<32>>,
+    pub word_proven: bool,
+    pub audited: bool,
     pub guess_count: u32,
     pub pending_guess: Option<BytesN<5>>,
+    pub pending_guess_ledger: u32,
+    // Hash of a committed guess until the guesser reveals its letters
+    pub guess_commitment: Option<BytesN<32>>,
+    pub guess_commitment_ledger: u32,
+    pub setter_strikes: u32,
     pub winner: Option<Address>,
+    // False while a finished game's result has not reached the Game Hub
+    pub hub_notified: bool,
+    // Setter and guesser as the Game Hub sees them (setter first unless the
+    // session was started with explicit seats)
+    pub hub_seats: HubSeats,
+    // Practice games keep the word in clear and compute feedback on-chain
+    pub practice: bool,
+    pub practice_word: Option<BytesN<5>>,
+    // Co-op games: second guesser alternating with `guesser` (who opens and
+    // stands for both at the Game Hub) over the shared guess budget
+    pub co_guesser: Option<Address>,
+    // Pool games: the word is drawn from a committed pool by both players'
+    // seeds and must be committed with `commit_pool_word`
+    pub word_pool: WordPool,
 
     // History
     pub guesses: Vec<BytesN<5>>,
+    // Who submitted each resolved guess
+    pub guess_actors: Vec<Address>,
     pub feedbacks: Vec<Vec<u32>>,
+    // One retraction per game; the ledger shows the setter when it was pulled
+    pub retracted_guess: Option<BytesN<5>>,
+    pub retracted_at_ledger: u32,
+    // Actions applied so far; each one publishes a `GameMove` event with
+    // the new count
+    pub move_count: u32,
 }
 
 impl Game {
@@ -67,24 +146,50 @@ impl Game {
         guesser: Address,
         word_setter_points: i128,
         guesser_points: i128,
+        alphabet_size: u32,
+        guess_expiry_ledgers: u32,
         env: &soroban_sdk::Env,
     ) -> Result<Self, DomainError> {
         if word_setter == guesser {
             return Err(DomainError::SelfPlayNotAllowed);
         }
+        validate_alphabet_size(alphabet_size)?;
 
         Ok(Self {
+            hub_seats: HubSeats {
+                player1: word_setter.clone(),
+                player2: guesser.clone(),
+            },
             word_setter,
             guesser,
             word_setter_points,
             guesser_points,
+            alphabet_size,
+            guess_expiry_ledgers,
+            started_at_ledger: env.ledger().sequence(),
+            deadline_ledger: 0,
             phase: GamePhase::WaitingForWord,
             word_commitment: None,
+            word_proven: false,
+            audited: false,
             guess_count: 0,
             pending_guess: None,
+            pending_guess_ledger: 0,
+            guess_commitment: None,
+            guess_commitment_ledger: 0,
+            setter_strikes: 0,
             winner: None,
+            hub_notified: false,
+            practice: false,
+            practice_word: None,
+            co_guesser: None,
+            word_pool: WordPool::default(),
             guesses: Vec::new(env),
+            guess_actors: Vec::new(env),
             feedbacks: Vec::new(env),
+            retracted_guess: None,
+            retracted_at_ledger: 0,
+            move_count: 0,
         })
     }
 
@@ -97,34 +202,383 @@ impl Game {
         self.ensure_not_ended()?;
         self.ensure_phase(GamePhase::WaitingForWord)?;
         self.ensure_is_word_setter(player)?;
+        self.require_proofs()?;
 
         if self.word_commitment.is_some() {
             return Err(DomainError::WordAlreadyCommitted);
         }
+        if self.word_pool.is_active() {
+            return Err(DomainError::WordPoolRequired);
+        }
 
         self.word_commitment = Some(commitment);
         self.phase = GamePhase::InProgress;
         Ok(())
     }
 
-    /// Submits a guess (guesser only)
-    pub fn submit_guess(&mut self, player: &Address, guess: &Guess) -> Result<(), DomainError> {
+    /// Fixes the pool the word will be drawn from, together with the hash of
+    /// the setter's seed (word setter only)
+    pub fn commit_word_pool(
+        &mut self,
+        player: &Address,
+        pool: WordPool,
+    ) -> Result<(), DomainError> {
         self.ensure_not_ended()?;
-        self.ensure_phase(GamePhase::InProgress)?;
-        self.ensure_is_guesser(player)?;
+        self.ensure_phase(GamePhase::WaitingForWord)?;
+        self.ensure_is_word_setter(player)?;
+        self.require_proofs()?;
 
-        if self.pending_guess.is_some() {
-            return Err(DomainError::PendingGuessExists);
+        if self.word_pool.is_active() {
+            return Err(DomainError::WordPoolAlreadyCommitted);
         }
 
-        if self.guess_count >= MAX_GUESSES {
-            return Err(DomainError::MaxGuessesReached);
+        self.word_pool = pool;
+        Ok(())
+    }
+
+    /// Adds the guesser's seed to the word draw (guesser only)
+    pub fn contribute_pool_seed(
+        &mut self,
+        player: &Address,
+        seed: BytesN<32>,
+    ) -> Result<(), DomainError> {
+        self.ensure_not_ended()?;
+        self.ensure_phase(GamePhase::WaitingForWord)?;
+        if *player != self.guesser {
+            return Err(DomainError::NotGuesser);
+        }
+
+        self.word_pool.contribute_seed(seed)
+    }
+
+    /// Opens the setter's seed and draws the word index (word setter only).
+    /// `commitment` is the hash rebuilt from the revealed seed
+    pub fn reveal_pool_seed(
+        &mut self,
+        env: &soroban_sdk::Env,
+        player: &Address,
+        seed: BytesN<32>,
+        commitment: &BytesN<32>,
+    ) -> Result<u32, DomainError> {
+        self.ensure_not_ended()?;
+        self.ensure_phase(GamePhase::WaitingForWord)?;
+        self.ensure_is_word_setter(player)?;
+
+        self.word_pool.reveal_seed(env, seed, commitment)
+    }
+
+    /// Commits the word drawn from the pool (word setter only); returns the
+    /// pool root and word index the membership proof must open
+    pub fn commit_pool_word(
+        &mut self,
+        player: &Address,
+        commitment: WordCommitment,
+    ) -> Result<(BytesN<32>, u32), DomainError> {
+        self.ensure_not_ended()?;
+        self.ensure_phase(GamePhase::WaitingForWord)?;
+        self.ensure_is_word_setter(player)?;
+
+        let (root, word_index) = self.word_pool.require_draw()?;
+
+        self.word_commitment = Some(commitment);
+        self.word_proven = true;
+        self.phase = GamePhase::InProgress;
+        Ok((root, word_index))
+    }
+
+    /// Sets the word of a practice game in clear (word setter only)
+    pub fn commit_practice_word(
+        &mut self,
+        player: &Address,
+        word: BytesN<5>,
+    ) -> Result<(), DomainError> {
+        self.ensure_not_ended()?;
+        self.ensure_phase(GamePhase::WaitingForWord)?;
+        self.ensure_is_word_setter(player)?;
+
+        if !self.practice {
+            return Err(DomainError::NotPracticeGame);
+        }
+        if self.practice_word.is_some() {
+            return Err(DomainError::WordAlreadyCommitted);
+        }
+        Word::new(word.to_array(), self.alphabet_size)?;
+
+        self.practice_word = Some(word);
+        self.phase = GamePhase::InProgress;
+        Ok(())
+    }
+
+    /// Clear word of a practice game
+    pub fn get_practice_word(&self) -> Result<Word, DomainError> {
+        if !self.practice {
+            return Err(DomainError::NotPracticeGame);
+        }
+        let word = self
+            .practice_word
+            .clone()
+            .ok_or(DomainError::WordNotCommitted)?;
+        Word::new(word.to_array(), self.alphabet_size)
+    }
+
+    /// Adds a second guesser before the word is committed (co-op mode)
+    pub fn add_co_guesser(&mut self, co_guesser: Address) -> Result<(), DomainError> {
+        self.ensure_phase(GamePhase::WaitingForWord)?;
+
+        if co_guesser == self.word_setter || co_guesser == self.guesser {
+            return Err(DomainError::SelfPlayNotAllowed);
+        }
+
+        self.co_guesser = Some(co_guesser);
+        Ok(())
+    }
+
+    /// Seats the players at the Game Hub explicitly; `seats` must hold the
+    /// word setter and the guesser, in either order
+    pub fn seat_at_hub(&mut self, seats: HubSeats) -> Result<(), DomainError> {
+        self.ensure_phase(GamePhase::WaitingForWord)?;
+
+        let setter_first = seats.player1 == self.word_setter && seats.player2 == self.guesser;
+        let guesser_first = seats.player1 == self.guesser && seats.player2 == self.word_setter;
+        if !setter_first && !guesser_first {
+            return Err(DomainError::InvalidHubSeats);
+        }
+
+        self.hub_seats = seats;
+        Ok(())
+    }
+
+    /// Stakes in hub seat order: (player1_points, player2_points)
+    pub fn hub_points(&self) -> (i128, i128) {
+        if self.hub_seats.player1 == self.word_setter {
+            (self.word_setter_points, self.guesser_points)
+        } else {
+            (self.guesser_points, self.word_setter_points)
+        }
+    }
+
+    /// `player1_won` as reported to the Game Hub for the recorded winner
+    pub fn hub_player1_won(&self) -> bool {
+        self.winner.as_ref() == Some(&self.hub_seats.player1)
+    }
+
+    /// Guesser due to play the next guess: in co-op games the guessers
+    /// alternate, `guesser` first
+    pub fn guesser_due(&self) -> &Address {
+        match &self.co_guesser {
+            Some(co_guesser) if self.guess_count % 2 == 1 => co_guesser,
+            _ => &self.guesser,
+        }
+    }
+
+    /// Fails for practice games, which take no commitments or proofs
+    pub fn require_proofs(&self) -> Result<(), DomainError> {
+        if self.practice {
+            return Err(DomainError::PracticeGameUnsupported);
+        }
+        Ok(())
+    }
+
+    /// Submits a guess (guesser only) at ledger `ledger`
+    pub fn submit_guess(
+        &mut self,
+        player: &Address,
+        guess: &Guess,
+        ledger: u32,
+    ) -> Result<(), DomainError> {
+        self.ensure_can_guess(player)?;
+
+        self.pending_guess = Some(guess.letters().clone());
+        self.pending_guess_ledger = ledger;
+        Ok(())
+    }
+
+    /// Commits to a guess without showing its letters (guesser only); it
+    /// takes the turn like `submit_guess` and must be revealed within
+    /// `GUESS_REVEAL_WINDOW_LEDGERS`
+    pub fn commit_guess(
+        &mut self,
+        player: &Address,
+        commitment: BytesN<32>,
+        ledger: u32,
+    ) -> Result<(), DomainError> {
+        self.ensure_can_guess(player)?;
+
+        self.guess_commitment = Some(commitment);
+        self.guess_commitment_ledger = ledger;
+        Ok(())
+    }
+
+    /// Opens the committed guess at `ledger`, making it the pending guess.
+    /// `commitment` is the hash rebuilt from the revealed letters and salt
+    pub fn reveal_guess(
+        &mut self,
+        player: &Address,
+        guess: &Guess,
+        commitment: &BytesN<32>,
+        ledger: u32,
+    ) -> Result<(), DomainError> {
+        self.ensure_not_ended()?;
+        self.ensure_phase(GamePhase::InProgress)?;
+        self.ensure_guessers_turn(player)?;
+
+        let committed = self
+            .guess_commitment
+            .clone()
+            .ok_or(DomainError::NoPendingGuess)?;
+        if ledger > self.reveal_window_end() {
+            return Err(DomainError::RevealWindowClosed);
+        }
+        if committed != *commitment {
+            return Err(DomainError::InvalidGuessReveal);
         }
 
+        self.guess_commitment = None;
         self.pending_guess = Some(guess.letters().clone());
+        self.pending_guess_ledger = ledger;
         Ok(())
     }
 
+    /// Ends the game against a guesser who let a committed guess go
+    /// unrevealed past the reveal window; returns the winner (the setter)
+    pub fn expire_guess_commitment(&mut self, ledger: u32) -> Result<Address, DomainError> {
+        self.ensure_not_ended()?;
+        self.ensure_phase(GamePhase::InProgress)?;
+
+        if self.guess_commitment.is_none() {
+            return Err(DomainError::NoPendingGuess);
+        }
+        if ledger <= self.reveal_window_end() {
+            return Err(DomainError::RevealWindowOpen);
+        }
+
+        self.guess_commitment = None;
+        self.phase = GamePhase::Ended;
+        self.winner = Some(self.word_setter.clone());
+        Ok(self.word_setter.clone())
+    }
+
+    /// Whether `expire_guess_commitment` would succeed at `ledger`
+    pub fn guess_commitment_overdue(&self, ledger: u32) -> bool {
+        self.phase == GamePhase::InProgress
+            && self.guess_commitment.is_some()
+            && ledger > self.reveal_window_end()
+    }
+
+    /// Withdraws the pending guess within `GUESS_RETRACT_WINDOW_LEDGERS` of
+    /// submitting it; the guess is not counted and the retraction stays in
+    /// the game history so the setter's silence can't be read as stalling
+    pub fn retract_guess(&mut self, player: &Address, ledger: u32) -> Result<(), DomainError> {
+        self.ensure_not_ended()?;
+        self.ensure_phase(GamePhase::InProgress)?;
+        self.ensure_guessers_turn(player)?;
+
+        let guess_letters = self
+            .pending_guess
+            .clone()
+            .ok_or(DomainError::NoPendingGuess)?;
+        if self.retracted_guess.is_some() {
+            return Err(DomainError::RetractionUsed);
+        }
+        let window_end = self
+            .pending_guess_ledger
+            .saturating_add(GUESS_RETRACT_WINDOW_LEDGERS);
+        if ledger > window_end {
+            return Err(DomainError::RetractWindowClosed);
+        }
+
+        self.pending_guess = None;
+        self.retracted_guess = Some(guess_letters);
+        self.retracted_at_ledger = ledger;
+        Ok(())
+    }
+
+    /// Drops a pending guess the word setter left unresolved past the
+    /// expiry window: the guess is not counted, the setter gets a strike,
+    /// and the guesser wins once the setter reaches `MAX_SETTER_STRIKES`
+    pub fn expire_pending_guess(&mut self, ledger: u32) -> Result<GameOutcome, DomainError> {
+        self.ensure_not_ended()?;
+        self.ensure_phase(GamePhase::InProgress)?;
+
+        if self.guess_expiry_ledgers == 0 {
+            return Err(DomainError::GuessExpiryDisabled);
+        }
+        if self.pending_guess.is_none() {
+            return Err(DomainError::NoPendingGuess);
+        }
+        let expires_at = self
+            .pending_guess_ledger
+            .saturating_add(self.guess_expiry_ledgers);
+        if ledger < expires_at {
+            return Err(DomainError::GuessNotExpired);
+        }
+
+        self.pending_guess = None;
+        self.setter_strikes += 1;
+
+        if self.setter_strikes >= MAX_SETTER_STRIKES {
+            self.phase = GamePhase::Ended;
+            self.winner = Some(self.guesser.clone());
+            Ok(GameOutcome::GuesserWins)
+        } else {
+            Ok(GameOutcome::Continue)
+        }
+    }
+
+    /// Sets the deadline `duration_ledgers` after the start (0 = no deadline)
+    pub fn set_deadline(&mut self, duration_ledgers: u32) {
+        self.deadline_ledger = if duration_ledgers == 0 {
+            0
+        } else {
+            self.started_at_ledger.saturating_add(duration_ledgers)
+        };
+    }
+
+    /// Ends a game still running after its deadline against the party who
+    /// failed to act: the word setter loses while the word is uncommitted or
+    /// a guess awaits resolution, otherwise the guesser loses. Returns the
+    /// winner
+    pub fn finalize_expired(&mut self, ledger: u32) -> Result<Address, DomainError> {
+        self.ensure_not_ended()?;
+
+        if self.deadline_ledger == 0 {
+            return Err(DomainError::DeadlineDisabled);
+        }
+        if ledger <= self.deadline_ledger {
+            return Err(DomainError::DeadlineNotReached);
+        }
+
+        let setter_stalled =
+            self.phase == GamePhase::WaitingForWord || self.pending_guess.is_some();
+        self.phase = GamePhase::Ended;
+        self.pending_guess = None;
+        self.guess_commitment = None;
+
+        let winner = if setter_stalled {
+            self.guesser.clone()
+        } else {
+            self.word_setter.clone()
+        };
+        self.winner = Some(winner.clone());
+        Ok(winner)
+    }
+
+    /// Whether `finalize_expired` would succeed at `ledger`
+    pub fn past_deadline(&self, ledger: u32) -> bool {
+        self.phase != GamePhase::Ended && self.deadline_ledger > 0 && ledger > self.deadline_ledger
+    }
+
+    /// Whether `expire_pending_guess` would succeed at `ledger`
+    pub fn pending_guess_overdue(&self, ledger: u32) -> bool {
+        self.phase == GamePhase::InProgress
+            && self.guess_expiry_ledgers > 0
+            && self.pending_guess.is_some()
+            && ledger
+                >= self
+                    .pending_guess_ledger
+                    .saturating_add(self.guess_expiry_ledgers)
+    }
+
     /// Resolves a pending guess with verified feedback
     pub fn resolve_guess(
         &mut self,
@@ -136,18 +590,16 @@ impl Game {
         self.ensure_phase(GamePhase::InProgress)?;
         self.ensure_is_word_setter(player)?;
 
-        let guess_letters = self
-            .pending_guess
-            .clone()
-            .ok_or(DomainError::NoPendingGuess)?;
+        let guess_letters = self.require_pending_guess()?;
 
         // Validate feedback matches is_correct flag
         feedback.validate_correctness(is_correct)?;
 
         // Record guess and feedback
+        self.guess_actors.push_back(self.guesser_due().clone());
         self.guesses.push_back(guess_letters);
-        self.feedbacks
-            .push_back(feedback.to_vec(&soroban_sdk::Env::default()));
+        let feedback_vec = feedback.to_vec(self.feedbacks.env());
+        self.feedbacks.push_back(feedback_vec);
         self.guess_count += 1;
         self.pending_guess = None;
 
@@ -171,7 +623,7 @@ impl Game {
         if self.feedbacks.len() > 0 {
             // Remove last (placeholder) and add real one
             let len = self.feedbacks.len();
-            let mut new_feedbacks = Vec::new(&soroban_sdk::Env::default());
+            let mut new_feedbacks = Vec::new(self.feedbacks.env());
             for i in 0..(len - 1) {
                 new_feedbacks.push_back(self.feedbacks.get(i).unwrap());
             }
@@ -203,10 +655,33 @@ impl Game {
         Ok(())
     }
 
-    fn ensure_is_guesser(&self, player: &Address) -> Result<(), DomainError> {
-        if *player != self.guesser {
+    fn ensure_can_guess(&self, player: &Address) -> Result<(), DomainError> {
+        self.ensure_not_ended()?;
+        self.ensure_phase(GamePhase::InProgress)?;
+        self.ensure_guessers_turn(player)?;
+
+        if self.pending_guess.is_some() || self.guess_commitment.is_some() {
+            return Err(DomainError::PendingGuessExists);
+        }
+
+        if self.guess_count >= MAX_GUESSES {
+            return Err(DomainError::MaxGuessesReached);
+        }
+        Ok(())
+    }
+
+    fn reveal_window_end(&self) -> u32 {
+        self.guess_commitment_ledger
+            .saturating_add(GUESS_REVEAL_WINDOW_LEDGERS)
+    }
+
+    fn ensure_guessers_turn(&self, player: &Address) -> Result<(), DomainError> {
+        if *player != self.guesser && self.co_guesser.as_ref() != Some(player) {
             return Err(DomainError::NotGuesser);
         }
+        if player != self.guesser_due() {
+            return Err(DomainError::NotGuessersTurn);
+        }
         Ok(())
     }
 
@@ -222,6 +697,79 @@ impl Game {
         self.pending_guess.clone()
     }
 
+    /// Gets the pending guess, failing while it is still committed
+    pub fn require_pending_guess(&self) -> Result<BytesN<5>, DomainError> {
+        if self.guess_commitment.is_some() {
+            return Err(DomainError::GuessNotRevealed);
+        }
+        self.pending_guess
+            .clone()
+            .ok_or(DomainError::NoPendingGuess)
+    }
+
+    /// Marks a finished game as audited (word setter only)
+    pub fn mark_audited(&mut self, player: &Address) -> Result<(), DomainError> {
+        self.ensure_is_word_setter(player)?;
+
+        if self.phase != GamePhase::Ended {
+            return Err(DomainError::GameNotEnded);
+        }
+
+        if self.audited {
+            return Err(DomainError::AlreadyAudited);
+        }
+
+        self.audited = true;
+        Ok(())
+    }
+
+    /// Records that the Game Hub received the result of a finished game
+    pub fn mark_hub_notified(&mut self) -> Result<(), DomainError> {
+        if self.phase != GamePhase::Ended {
+            return Err(DomainError::GameNotEnded);
+        }
+
+        if self.hub_notified {
+            return Err(DomainError::HubAlreadyNotified);
+        }
+
+        self.hub_notified = true;
+        Ok(())
+    }
+
+    /// Ends a running game without a winner
+    pub fn cancel(&mut self) -> Result<(), DomainError> {
+        self.ensure_not_ended()?;
+
+        self.phase = GamePhase::Ended;
+        self.pending_guess = None;
+        self.guess_commitment = None;
+        // The hub initiated the cancel, so it already knows
+        self.hub_notified = true;
+        Ok(())
+    }
+
+    /// Counts one more action on the game and returns its sequence number
+    pub fn next_move(&mut self) -> u32 {
+        self.move_count += 1;
+        self.move_count
+    }
+
+    /// Status as reported through `GameInterface`
+    pub fn status(&self) -> GameStatus {
+        match self.phase {
+            GamePhase::WaitingForWord => GameStatus::Setup,
+            GamePhase::InProgress => GameStatus::InProgress,
+            GamePhase::Ended if self.winner.is_some() => GameStatus::Ended,
+            GamePhase::Ended => GameStatus::Cancelled,
+        }
+    }
+
+    /// Rules this session was started with
+    pub fn rules(&self) -> GameRules {
+        GameRules::with_alphabet(self.alphabet_size)
+    }
+
     /// Checks if guesser won
     pub fn guesser_won(&self) -> bool {
         self.winner.as_ref() == Some(&self.guesser)
@@ -244,3 +792,186 @@ impl GameOutcome {
         matches!(self, GameOutcome::GuesserWins | GameOutcome::WordSetterWins)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::Env;
+
+    /// Calls the property test makes on a game, valid or not
+    #[derive(Clone, Debug)]
+    enum Op {
+        SubmitGuess {
+            by_setter: bool,
+            letters: [u8; 5],
+        },
+        CommitGuess {
+            by_setter: bool,
+            salt: u8,
+        },
+        RevealGuess {
+            matching: bool,
+            letters: [u8; 5],
+        },
+        ResolveGuess {
+            by_guesser: bool,
+            statuses: [u32; 5],
+            is_correct: bool,
+        },
+        RetractGuess,
+        ExpirePendingGuess,
+        ExpireGuessCommitment,
+        FinalizeExpired,
+        AdvanceLedger(u32),
+        Cancel,
+    }
+
+    /// Letters past the 26-letter alphabet and feedback values above 2 are
+    /// drawn on purpose
+    fn op() -> impl Strategy<Value = Op> {
+        let letters = || prop::array::uniform5(0..28u8);
+        let statuses = prop_oneof![
+            1 => Just([2u32; 5]),
+            8 => prop::array::uniform5(0..3u32),
+            1 => prop::array::uniform5(0..4u32),
+        ];
+        prop_oneof![
+            4 => (prop::bool::weighted(0.1), letters())
+                .prop_map(|(by_setter, letters)| Op::SubmitGuess { by_setter, letters }),
+            2 => (prop::bool::weighted(0.1), any::<u8>())
+                .prop_map(|(by_setter, salt)| Op::CommitGuess { by_setter, salt }),
+            2 => (prop::bool::weighted(0.8), letters())
+                .prop_map(|(matching, letters)| Op::RevealGuess { matching, letters }),
+            5 => (prop::bool::weighted(0.1), statuses, prop::bool::weighted(0.2)).prop_map(
+                |(by_guesser, statuses, is_correct)| Op::ResolveGuess {
+                    by_guesser,
+                    statuses,
+                    is_correct,
+                }
+            ),
+            1 => Just(Op::RetractGuess),
+            1 => Just(Op::ExpirePendingGuess),
+            1 => Just(Op::ExpireGuessCommitment),
+            1 => Just(Op::FinalizeExpired),
+            2 => (0..8u32).prop_map(Op::AdvanceLedger),
+        ]
+    }
+
+    fn apply(env: &Env, game: &mut Game, op: Op, ledger: &mut u32) -> Result<(), DomainError> {
+        let (setter, guesser) = (game.word_setter.clone(), game.guesser.clone());
+        let pick = |setter_plays: bool| if setter_plays { &setter } else { &guesser };
+        match op {
+            Op::SubmitGuess { by_setter, letters } => {
+                let guess = Guess::new(BytesN::from_array(env, &letters), game.alphabet_size)?;
+                game.submit_guess(pick(by_setter), &guess, *ledger)
+            }
+            Op::CommitGuess { by_setter, salt } => {
+                let commitment = BytesN::from_array(env, &[salt; 32]);
+                game.commit_guess(pick(by_setter), commitment, *ledger)
+            }
+            Op::RevealGuess { matching, letters } => {
+                let guess = Guess::new(BytesN::from_array(env, &letters), game.alphabet_size)?;
+                let commitment = match &game.guess_commitment {
+                    Some(commitment) if matching => commitment.clone(),
+                    _ => BytesN::from_array(env, &[0xff; 32]),
+                };
+                game.reveal_guess(&guesser, &guess, &commitment, *ledger)
+            }
+            Op::ResolveGuess {
+                by_guesser,
+                statuses,
+                is_correct,
+            } => {
+                let feedback = Feedback::from_vec(&Vec::from_array(env, statuses))?;
+                game.resolve_guess(pick(!by_guesser), &feedback, is_correct)
+                    .map(|_| ())
+            }
+            Op::RetractGuess => game.retract_guess(&guesser, *ledger),
+            Op::ExpirePendingGuess => game.expire_pending_guess(*ledger).map(|_| ()),
+            Op::ExpireGuessCommitment => game.expire_guess_commitment(*ledger).map(|_| ()),
+            Op::FinalizeExpired => game.finalize_expired(*ledger).map(|_| ()),
+            Op::AdvanceLedger(ledgers) => {
+                *ledger += ledgers;
+                Ok(())
+            }
+            Op::Cancel => game.cancel(),
+        }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(128))]
+
+        #[test]
+        fn test_random_calls_preserve_game_invariants(
+            ops in prop::collection::vec(op(), 1..100),
+            guess_expiry_ledgers in prop_oneof![Just(0u32), 5..20u32],
+            duration_ledgers in prop_oneof![Just(0u32), 150..400u32],
+            cancel_at in prop::option::of(0..200usize),
+        ) {
+            let env = Env::default();
+            let setter = Address::generate(&env);
+            let guesser = Address::generate(&env);
+            let mut game = Game::new(
+                setter.clone(),
+                guesser.clone(),
+                1,
+                1,
+                ALPHABET_SIZE,
+                guess_expiry_ledgers,
+                &env,
+            )
+            .unwrap();
+            game.set_deadline(duration_ledgers);
+            game.commit_word(&setter, BytesN::from_array(&env, &[7; 32])).unwrap();
+
+            let mut ledger = game.started_at_ledger;
+            for (index, op) in ops.into_iter().enumerate() {
+                // The hub cancels at most once, so most runs get to play on
+                let op = if cancel_at == Some(index) { Op::Cancel } else { op };
+                let advances = matches!(op, Op::AdvanceLedger(_));
+                let before = game.clone();
+                let result = apply(&env, &mut game, op, &mut ledger);
+
+                // A rejected call leaves the game as it was, and an ended
+                // game rejects everything
+                if result.is_err() {
+                    prop_assert_eq!(&game, &before);
+                }
+                if before.phase == GamePhase::Ended && !advances {
+                    prop_assert!(result.is_err());
+                }
+
+                // History holds exactly the resolved guesses
+                prop_assert!(game.guess_count <= MAX_GUESSES);
+                prop_assert_eq!(game.guesses.len(), game.guess_count);
+                prop_assert_eq!(game.guess_actors.len(), game.guess_count);
+                prop_assert_eq!(game.feedbacks.len(), game.guess_count);
+                prop_assert!(game.setter_strikes <= MAX_SETTER_STRIKES);
+                prop_assert!(game.pending_guess.is_none() || game.guess_commitment.is_none());
+
+                // Only the last guess can be all green, and it wins the game
+                // for the guesser
+                let all_correct = |feedback: Vec<u32>| feedback.iter().all(|s| s == 2);
+                for (i, feedback) in game.feedbacks.iter().enumerate() {
+                    let last = i as u32 + 1 == game.feedbacks.len();
+                    if all_correct(feedback) {
+                        prop_assert!(last);
+                        prop_assert_eq!(game.winner.as_ref(), Some(&guesser));
+                    }
+                }
+                match &game.winner {
+                    None => {}
+                    Some(winner) => {
+                        prop_assert_eq!(&game.phase, &GamePhase::Ended);
+                        prop_assert!(*winner == setter || *winner == guesser);
+                    }
+                }
+                if game.phase != GamePhase::Ended {
+                    prop_assert!(game.winner.is_none());
+                }
+            }
+        }
+    }
+}
@@ -1,9 +1,21 @@
 mod errors;
 mod feedback;
 pub mod game;
+mod reaction;
+mod session_key;
+mod session_marker;
+mod stake;
+mod stats;
 mod word;
+mod word_pool;
 
 pub use errors::DomainError;
-pub use feedback::Feedback;
-pub use game::{Game, GameOutcome, GamePhase, GameRules};
-pub use word::Guess;
+pub use feedback::{compute_feedback, Feedback, FeedbackStatus};
+pub use game::{Game, GameOutcome, GamePhase, GameRules, GameStatus, HubSeats};
+pub use reaction::{set_reaction, Emote, Reaction};
+pub use session_key::SessionKey;
+pub use session_marker::{SessionMarker, StorageTier};
+pub use stake::StakeBounds;
+pub use stats::{GameStats, Metrics, PlayerStats};
+pub use word::{Guess, Word, ALPHABET_SIZE, MAX_ALPHABET_SIZE};
+pub use word_pool::WordPool;
@@ -0,0 +1,56 @@
+use soroban_sdk::{contracttype, Address, Vec};
+
+use super::errors::DomainError;
+
+/// Emotes a player can react to a guess with
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Emote {
+    ThumbsUp = 0,
+    Laugh = 1,
+    Surprised = 2,
+    Sad = 3,
+    Fire = 4,
+    Clap = 5,
+}
+
+impl Emote {
+    pub fn from_u32(value: u32) -> Result<Self, DomainError> {
+        match value {
+            0 => Ok(Emote::ThumbsUp),
+            1 => Ok(Emote::Laugh),
+            2 => Ok(Emote::Surprised),
+            3 => Ok(Emote::Sad),
+            4 => Ok(Emote::Fire),
+            5 => Ok(Emote::Clap),
+            _ => Err(DomainError::InvalidEmote),
+        }
+    }
+
+    pub fn as_u32(&self) -> u32 {
+        *self as u32
+    }
+}
+
+/// A player's emote on one resolved guess
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Reaction {
+    pub player: Address,
+    pub emote_code: u32,
+    pub ledger: u32,
+}
+
+/// Records `player`'s emote, replacing their earlier one, so a guess holds
+/// at most one reaction per player
+pub fn set_reaction(reactions: &mut Vec<Reaction>, player: Address, emote: Emote, ledger: u32) {
+    let reaction = Reaction {
+        player,
+        emote_code: emote.as_u32(),
+        ledger,
+    };
+    match reactions.iter().position(|r| r.player == reaction.player) {
+        Some(index) => reactions.set(index as u32, reaction),
+        None => reactions.push_back(reaction),
+    }
+}
@@ -0,0 +1,32 @@
+use soroban_sdk::{contracttype, Address};
+
+use super::errors::DomainError;
+
+/// Key a player lets sign their moves until ledger `expiry` (exclusive)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SessionKey {
+    pub key: Address,
+    pub expiry: u32,
+}
+
+impl SessionKey {
+    /// Creates a session key for `player`, rejecting the player's own
+    /// address and expiries that are not in the future
+    pub fn new(
+        player: &Address,
+        key: Address,
+        expiry: u32,
+        current_ledger: u32,
+    ) -> Result<Self, DomainError> {
+        if key == *player || expiry <= current_ledger {
+            return Err(DomainError::InvalidSessionKey);
+        }
+        Ok(Self { key, expiry })
+    }
+
+    /// Whether the key can still sign at `ledger`
+    pub fn is_active(&self, ledger: u32) -> bool {
+        ledger < self.expiry
+    }
+}
@@ -0,0 +1,36 @@
+use soroban_sdk::{contracttype, Address};
+
+use super::game::Game;
+
+/// Storage tier a game's entries were written to
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StorageTier {
+    /// Expires with its TTL unless a write extends it; every game is stored
+    /// here
+    Temporary,
+}
+
+/// Persistent record of a started session. It outlives the game entries, so
+/// the hub can refund stakes stranded by an expired game and auditors can
+/// still see who played and when
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SessionMarker {
+    pub word_setter: Address,
+    pub guesser: Address,
+    pub start_ledger: u32,
+    pub tier: StorageTier,
+}
+
+impl SessionMarker {
+    /// Marker for a freshly started game
+    pub fn new(game: &Game) -> Self {
+        Self {
+            word_setter: game.word_setter.clone(),
+            guesser: game.guesser.clone(),
+            start_ledger: game.started_at_ledger,
+            tier: StorageTier::Temporary,
+        }
+    }
+}
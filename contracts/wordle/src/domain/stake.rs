@@ -0,0 +1,31 @@
+use super::errors::DomainError;
+
+/// Bounds each player's points must fall within to start a ranked game
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct StakeBounds {
+    pub min: i128,
+    /// 0 = no cap
+    pub max: i128,
+}
+
+impl StakeBounds {
+    /// Smallest stake accepted until the admin sets one
+    pub const DEFAULT_MIN: i128 = 1;
+
+    /// Validates admin-supplied bounds: a positive minimum, and a maximum
+    /// that is either 0 or not below it
+    pub fn new(min: i128, max: i128) -> Result<Self, DomainError> {
+        if min <= 0 || max < 0 || (max > 0 && max < min) {
+            return Err(DomainError::InvalidStake);
+        }
+        Ok(Self { min, max })
+    }
+
+    /// Checks one player's stake against the bounds
+    pub fn check(&self, points: i128) -> Result<(), DomainError> {
+        if points < self.min || (self.max > 0 && points > self.max) {
+            return Err(DomainError::InvalidStake);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,178 @@
+use soroban_sdk::{contracttype, Env, Vec};
+
+use super::game::{Game, GameStatus, MAX_GUESSES, MAX_SETTER_STRIKES};
+
+/// Whether the guesser found the word; a guesser win by setter forfeit is
+/// not a solve
+fn solved(game: &Game) -> bool {
+    game.guesser_won() && game.setter_strikes < MAX_SETTER_STRIKES
+}
+
+/// Summary of a finished game, archived when it ends
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameStats {
+    pub guesses_used: u32,
+    pub solved: bool,
+    // Letters marked correct / present over every resolved guess
+    pub greens: u32,
+    pub yellows: u32,
+    // Ledgers from `start_game` to the end of the game
+    pub ledgers_played: u32,
+}
+
+impl GameStats {
+    /// Stats of `game` as it ends at ledger `ended_at`
+    pub fn from_game(game: &Game, ended_at: u32) -> Self {
+        let mut greens = 0;
+        let mut yellows = 0;
+        for feedback in game.feedbacks.iter() {
+            for status in feedback.iter() {
+                match status {
+                    2 => greens += 1,
+                    1 => yellows += 1,
+                    _ => {}
+                }
+            }
+        }
+
+        Self {
+            guesses_used: game.guess_count,
+            solved: solved(game),
+            greens,
+            yellows,
+            ledgers_played: ended_at.saturating_sub(game.started_at_ledger),
+        }
+    }
+}
+
+/// Guesser statistics aggregated over every archived game
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PlayerStats {
+    pub games_played: u32,
+    pub games_solved: u32,
+    pub total_guesses: u32,
+    pub total_greens: u32,
+    pub total_yellows: u32,
+    // Summed over solved games only
+    pub total_solve_ledgers: u32,
+    // Running averages, x100 for two decimals (e.g. 412 = 4.12 guesses)
+    pub avg_guesses_x100: u32,
+    pub avg_greens_x100: u32,
+    pub avg_yellows_x100: u32,
+    // Average ledgers to solve, over solved games
+    pub avg_solve_ledgers: u32,
+}
+
+impl PlayerStats {
+    /// Folds one more finished game into the totals and averages
+    pub fn record(&mut self, game: &GameStats) {
+        self.games_played += 1;
+        self.total_guesses += game.guesses_used;
+        self.total_greens += game.greens;
+        self.total_yellows += game.yellows;
+        if game.solved {
+            self.games_solved += 1;
+            self.total_solve_ledgers += game.ledgers_played;
+        }
+
+        self.avg_guesses_x100 = self.total_guesses * 100 / self.games_played;
+        self.avg_greens_x100 = self.total_greens * 100 / self.games_played;
+        self.avg_yellows_x100 = self.total_yellows * 100 / self.games_played;
+        self.avg_solve_ledgers = self
+            .total_solve_ledgers
+            .checked_div(self.games_solved)
+            .unwrap_or(0);
+    }
+}
+
+/// Operator counters returned by `get_metrics`. Invalid proofs are not
+/// counted: a failing call rolls back its writes, so they only show up as
+/// `ErrorContext` events of failed calls
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Metrics {
+    pub games_started: u32,
+    pub practice_games_started: u32,
+    // Games ended with a winner
+    pub games_ended: u32,
+    // Games ended by `cancel_game`, without a winner
+    pub games_cancelled: u32,
+    pub games_solved: u32,
+    // Solved games by guesses used: entry i counts solves in i + 1 guesses
+    pub solved_in: Vec<u32>,
+    // Average guesses per solved game, x100 for two decimals
+    pub avg_solve_guesses_x100: u32,
+    // Practice games ended, however they ended; kept apart like the starts
+    pub practice_games_ended: u32,
+    // Of `games_ended`, those settled by `finalize_expired` or
+    // `expire_guess_commitment`
+    pub games_timed_out: u32,
+}
+
+impl Metrics {
+    /// All counters at zero
+    pub fn new(env: &Env) -> Self {
+        let mut solved_in = Vec::new(env);
+        for _ in 0..MAX_GUESSES {
+            solved_in.push_back(0);
+        }
+        Self {
+            games_started: 0,
+            practice_games_started: 0,
+            games_ended: 0,
+            games_cancelled: 0,
+            games_solved: 0,
+            solved_in,
+            avg_solve_guesses_x100: 0,
+            practice_games_ended: 0,
+            games_timed_out: 0,
+        }
+    }
+
+    /// Counts a game that has just been started
+    pub fn record_start(&mut self, game: &Game) {
+        if game.practice {
+            self.practice_games_started += 1;
+        } else {
+            self.games_started += 1;
+        }
+    }
+
+    /// Counts a game that has just ended, bucketing solves by guesses used;
+    /// practice games only count as ended
+    pub fn record_end(&mut self, game: &Game) {
+        if game.practice {
+            self.practice_games_ended += 1;
+            return;
+        }
+        if game.status() == GameStatus::Cancelled {
+            self.games_cancelled += 1;
+            return;
+        }
+        self.games_ended += 1;
+        if !solved(game) || game.guess_count == 0 {
+            return;
+        }
+
+        let bucket = game.guess_count.min(MAX_GUESSES) - 1;
+        let solves = self.solved_in.get(bucket).unwrap_or(0);
+        self.solved_in.set(bucket, solves + 1);
+        self.games_solved += 1;
+
+        let mut total_guesses = 0;
+        for (i, solves) in self.solved_in.iter().enumerate() {
+            total_guesses += (i as u32 + 1) * solves;
+        }
+        self.avg_solve_guesses_x100 = total_guesses * 100 / self.games_solved;
+    }
+
+    /// Counts a ranked game that has just ended on a timeout, on top of
+    /// `record_end`
+    pub fn record_timeout(&mut self, game: &Game) {
+        if !game.practice {
+            self.games_timed_out += 1;
+        }
+    }
+}
@@ -5,25 +5,39 @@ use super::errors::DomainError;
 /// Word length constant
 pub const WORD_LENGTH: u32 = 5;
 
-/// Alphabet size (A-Z = 0-25)
+/// Default alphabet size (Latin A-Z = 0-25)
 pub const ALPHABET_SIZE: u32 = 26;
 
+/// Smallest per-session alphabet a game can be started with
+pub const MIN_ALPHABET_SIZE: u32 = 2;
+
+/// Largest per-session alphabet (letter range checked by the circuits)
+pub const MAX_ALPHABET_SIZE: u32 = 64;
+
+/// Validates a per-session alphabet size (e.g. 26 Latin, 33 Cyrillic)
+pub fn validate_alphabet_size(alphabet_size: u32) -> Result<(), DomainError> {
+    if !(MIN_ALPHABET_SIZE..=MAX_ALPHABET_SIZE).contains(&alphabet_size) {
+        return Err(DomainError::InvalidAlphabetSize);
+    }
+    Ok(())
+}
+
 /// Represents a committed word (hash of word + salt)
 pub type WordCommitment = BytesN<32>;
 
-/// Represents the secret word (5 letters, each 0-25)
-/// Note: The actual word is never stored on-chain, only committed via hash
-#[allow(dead_code)]
+/// Represents the secret word (5 letters, each below the game's alphabet size)
+/// Note: Only practice games store the word on-chain; regular games commit
+/// to it via hash
 #[derive(Clone, Debug)]
 pub struct Word {
     letters: [u8; 5],
 }
 
-#[allow(dead_code)]
 impl Word {
-    pub fn new(letters: [u8; 5]) -> Result<Self, DomainError> {
+    pub fn new(letters: [u8; 5], alphabet_size: u32) -> Result<Self, DomainError> {
+        validate_alphabet_size(alphabet_size)?;
         for letter in letters.iter() {
-            if *letter >= ALPHABET_SIZE as u8 {
+            if *letter as u32 >= alphabet_size {
                 return Err(DomainError::InvalidLetterValue);
             }
         }
@@ -35,17 +49,18 @@ impl Word {
     }
 }
 
-/// Represents a guess attempt (5 letters, each 0-25)
+/// Represents a guess attempt (5 letters, each below the game's alphabet size)
 #[derive(Clone, Debug)]
 pub struct Guess {
     letters: BytesN<5>,
 }
 
 impl Guess {
-    pub fn new(letters: BytesN<5>) -> Result<Self, DomainError> {
+    pub fn new(letters: BytesN<5>, alphabet_size: u32) -> Result<Self, DomainError> {
+        validate_alphabet_size(alphabet_size)?;
         let arr = letters.to_array();
         for letter in arr.iter() {
-            if *letter >= ALPHABET_SIZE as u8 {
+            if *letter as u32 >= alphabet_size {
                 return Err(DomainError::InvalidLetterValue);
             }
         }
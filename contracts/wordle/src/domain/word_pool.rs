@@ -0,0 +1,108 @@
+use soroban_sdk::{contracttype, Bytes, BytesN, Env};
+
+use super::errors::DomainError;
+
+/// Largest pool a setter may commit: the leaves of the depth-14 tree the
+/// `word_pool` circuit opens
+pub const MAX_WORD_POOL_SIZE: u32 = 1 << 14;
+
+/// Word pool committed by the setter before the word is drawn (all unset
+/// outside pool games). The word index is derived from both players' seeds,
+/// so the setter cannot pick the word per opponent once the pool is fixed
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct WordPool {
+    // Merkle root over the candidate words (same leaf/node hashing as the
+    // dictionary tree of `word_validity`)
+    pub root: Option<BytesN<32>>,
+    pub size: u32,
+    // Hash of the setter's seed, fixed together with the pool
+    pub setter_seed_commitment: Option<BytesN<32>>,
+    pub guesser_seed: Option<BytesN<32>>,
+    pub setter_seed: Option<BytesN<32>>,
+    // keccak256(setter_seed || guesser_seed) mod size, once both are known
+    pub word_index: Option<u32>,
+}
+
+impl WordPool {
+    /// Pool of `size` words under `root`; the setter's seed stays hidden
+    /// behind `setter_seed_commitment` until the guesser has contributed
+    pub fn new(
+        root: BytesN<32>,
+        size: u32,
+        setter_seed_commitment: BytesN<32>,
+    ) -> Result<Self, DomainError> {
+        if size == 0 || size > MAX_WORD_POOL_SIZE {
+            return Err(DomainError::InvalidWordPoolSize);
+        }
+        Ok(Self {
+            root: Some(root),
+            size,
+            setter_seed_commitment: Some(setter_seed_commitment),
+            guesser_seed: None,
+            setter_seed: None,
+            word_index: None,
+        })
+    }
+
+    /// Whether the setter committed a pool
+    pub fn is_active(&self) -> bool {
+        self.root.is_some()
+    }
+
+    /// Fails outside pool games
+    fn ensure_active(&self) -> Result<(), DomainError> {
+        if !self.is_active() {
+            return Err(DomainError::NoWordPool);
+        }
+        Ok(())
+    }
+
+    /// Records the guesser's seed, in clear
+    pub fn contribute_seed(&mut self, seed: BytesN<32>) -> Result<(), DomainError> {
+        self.ensure_active()?;
+        if self.guesser_seed.is_some() {
+            return Err(DomainError::SeedAlreadyContributed);
+        }
+        self.guesser_seed = Some(seed);
+        Ok(())
+    }
+
+    /// Opens the setter's seed and draws the word index. `commitment` is the
+    /// hash rebuilt from the revealed seed
+    pub fn reveal_seed(
+        &mut self,
+        env: &Env,
+        seed: BytesN<32>,
+        commitment: &BytesN<32>,
+    ) -> Result<u32, DomainError> {
+        self.ensure_active()?;
+        if self.word_index.is_some() {
+            return Err(DomainError::SeedAlreadyContributed);
+        }
+        let guesser_seed = self
+            .guesser_seed
+            .clone()
+            .ok_or(DomainError::SeedNotContributed)?;
+        if self.setter_seed_commitment.as_ref() != Some(commitment) {
+            return Err(DomainError::InvalidSeedReveal);
+        }
+
+        let mut preimage = Bytes::from_array(env, &seed.to_array());
+        preimage.extend_from_array(&guesser_seed.to_array());
+        let digest = env.crypto().keccak256(&preimage).to_array();
+        let draw = u32::from_be_bytes([digest[0], digest[1], digest[2], digest[3]]);
+        let word_index = draw % self.size;
+
+        self.setter_seed = Some(seed);
+        self.word_index = Some(word_index);
+        Ok(word_index)
+    }
+
+    /// Pool root and drawn word index, failing until both seeds are in
+    pub fn require_draw(&self) -> Result<(BytesN<32>, u32), DomainError> {
+        let root = self.root.clone().ok_or(DomainError::NoWordPool)?;
+        let word_index = self.word_index.ok_or(DomainError::WordIndexNotDrawn)?;
+        Ok((root, word_index))
+    }
+}
@@ -0,0 +1,100 @@
+use soroban_sdk::{contractevent, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::domain::{DomainError, Game};
+
+/// Namespace shared by every error code this contract returns
+pub const ERROR_NAMESPACE: Symbol = symbol_short!("wordle");
+
+/// Published right before an entrypoint returns a domain error.
+///
+/// Events of a failed invocation are not committed, but they are returned by
+/// transaction simulation and failed-transaction meta for support tooling.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ErrorContext {
+    #[topic]
+    pub namespace: Symbol,
+    #[topic]
+    pub session_id: u32,
+    pub action: Symbol,
+    pub code: u32,
+    pub caller: Option<Address>,
+    /// Numeric summary of the offending call (letters, feedback, flags)
+    pub args: Vec<u32>,
+}
+
+/// Publisher for error diagnostics
+pub struct ErrorReporter;
+
+impl ErrorReporter {
+    /// Publishes an `ErrorContext` event and hands the error back to the caller
+    pub fn report(
+        env: &Env,
+        session_id: u32,
+        action: Symbol,
+        caller: Option<Address>,
+        args: Vec<u32>,
+        error: DomainError,
+    ) -> DomainError {
+        ErrorContext {
+            namespace: ERROR_NAMESPACE,
+            session_id,
+            action,
+            code: error as u32,
+            caller,
+            args,
+        }
+        .publish(env);
+        error
+    }
+}
+
+/// Published by every action that changes a game. `move_count` numbers the
+/// session's actions from 1 with no gaps, so clients can order events and
+/// spot missed ones
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameMove {
+    #[topic]
+    pub session_id: u32,
+    pub move_count: u32,
+    pub action: Symbol,
+    /// Player who acted (None when anyone may call, e.g. an expiry)
+    pub actor: Option<Address>,
+}
+
+/// Publisher for game actions
+pub struct MoveReporter;
+
+impl MoveReporter {
+    /// Counts an action on `game` and publishes its `GameMove` event
+    pub fn record(
+        env: &Env,
+        session_id: u32,
+        game: &mut Game,
+        action: Symbol,
+        actor: Option<Address>,
+    ) {
+        GameMove {
+            session_id,
+            move_count: game.next_move(),
+            action,
+            actor,
+        }
+        .publish(env);
+    }
+}
+
+/// Published by `react`, so the frontend can show emotes as they arrive
+/// Reactions are not game actions and do not bump `move_count`
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Reacted {
+    #[topic]
+    pub session_id: u32,
+    #[topic]
+    pub player: Address,
+    /// Index of the resolved guess reacted to (0 = first guess)
+    pub guess_index: u32,
+    pub emote_code: u32,
+}
@@ -1,6 +1,8 @@
+use soroban_sdk::xdr::ScErrorType;
 use soroban_sdk::{contractclient, Address, Bytes, BytesN, Env};
 
 use super::storage::AdminRepository;
+use crate::domain::{DomainError, GameStatus};
 
 /// Game Hub contract interface
 #[allow(dead_code)] // Trait is used by contractclient macro
@@ -29,6 +31,49 @@ pub trait VerifierAdapterContract {
         public_inputs_hash: BytesN<32>,
         proof_payload: Bytes,
     ) -> bool;
+
+    fn verify_word_commitment(
+        env: Env,
+        word_commitment: BytesN<32>,
+        dictionary_root: BytesN<32>,
+        proof_payload: Bytes,
+    ) -> bool;
+
+    fn verify_audit(
+        env: Env,
+        word_commitment: BytesN<32>,
+        audit_hash: BytesN<32>,
+        proof_payload: Bytes,
+    ) -> bool;
+
+    fn verify_pool_word(
+        env: Env,
+        word_commitment: BytesN<32>,
+        pool_root: BytesN<32>,
+        word_index: u32,
+        proof_payload: Bytes,
+    ) -> bool;
+}
+
+/// Lifecycle surface shared by every arcade game, so the hub's tournament
+/// module can drive any registered game through one client instead of
+/// hard-coding each game's function names
+#[contractclient(name = "GameInterfaceClient")]
+pub trait GameInterface {
+    fn start_game(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<u32, DomainError>;
+
+    fn evaluate_game(env: Env, session_id: u32) -> Result<Option<Address>, DomainError>;
+
+    fn cancel_game(env: Env, session_id: u32) -> Result<(), DomainError>;
+
+    fn get_game_status(env: Env, session_id: u32) -> Result<GameStatus, DomainError>;
 }
 
 /// Gateway for interacting with Game Hub
@@ -39,10 +84,10 @@ impl GameHubGateway {
     pub fn notify_game_started(
         env: &Env,
         session_id: u32,
-        word_setter: &Address,
-        guesser: &Address,
-        word_setter_points: i128,
-        guesser_points: i128,
+        player1: &Address,
+        player2: &Address,
+        player1_points: i128,
+        player2_points: i128,
     ) {
         let hub_addr = AdminRepository::get_game_hub(env);
         let hub = GameHubClient::new(env, &hub_addr);
@@ -50,19 +95,28 @@ impl GameHubGateway {
         hub.start_game(
             &env.current_contract_address(),
             &session_id,
-            word_setter,
-            guesser,
-            &word_setter_points,
-            &guesser_points,
+            player1,
+            player2,
+            &player1_points,
+            &player2_points,
         );
     }
 
     /// Notifies Game Hub that a game has ended
-    pub fn notify_game_ended(env: &Env, session_id: u32, word_setter_won: bool) {
+    pub fn notify_game_ended(env: &Env, session_id: u32, player1_won: bool) {
+        let hub_addr = AdminRepository::get_game_hub(env);
+        let hub = GameHubClient::new(env, &hub_addr);
+
+        hub.end_game(&session_id, &player1_won);
+    }
+
+    /// Notifies Game Hub that a game has ended without letting a failing hub
+    /// revert the caller; returns whether the hub accepted the result
+    pub fn try_notify_game_ended(env: &Env, session_id: u32, player1_won: bool) -> bool {
         let hub_addr = AdminRepository::get_game_hub(env);
         let hub = GameHubClient::new(env, &hub_addr);
 
-        hub.end_game(&session_id, &word_setter_won);
+        hub.try_end_game(&session_id, &player1_won).is_ok()
     }
 }
 
@@ -82,4 +136,64 @@ impl VerifierGateway {
 
         verifier.verify(word_commitment, public_inputs_hash, proof_payload)
     }
+
+    /// Like `verify_proof`, but never aborts: returns whether the proof
+    /// verified and the contract error code the verifier aborted with (0 if
+    /// it returned normally)
+    pub fn diagnose_proof(
+        env: &Env,
+        word_commitment: &BytesN<32>,
+        public_inputs_hash: &BytesN<32>,
+        proof_payload: &Bytes,
+    ) -> (bool, u32) {
+        let verifier_addr = AdminRepository::get_verifier(env);
+        let verifier = VerifierAdapterClient::new(env, &verifier_addr);
+
+        match verifier.try_verify(word_commitment, public_inputs_hash, proof_payload) {
+            Ok(Ok(valid)) => (valid, 0),
+            Err(Ok(err)) if err.is_type(ScErrorType::Contract) => (false, err.get_code()),
+            _ => (false, 0),
+        }
+    }
+
+    /// Verifies a word validity proof against the configured dictionary root
+    pub fn verify_word_commitment(
+        env: &Env,
+        word_commitment: &BytesN<32>,
+        proof_payload: &Bytes,
+    ) -> bool {
+        let verifier_addr = AdminRepository::get_verifier(env);
+        let verifier = VerifierAdapterClient::new(env, &verifier_addr);
+        let dictionary_root = AdminRepository::get_dictionary_root(env);
+
+        verifier.verify_word_commitment(word_commitment, &dictionary_root, proof_payload)
+    }
+
+    /// Verifies a whole-game audit proof
+    pub fn verify_audit(
+        env: &Env,
+        word_commitment: &BytesN<32>,
+        audit_hash: &BytesN<32>,
+        proof_payload: &Bytes,
+    ) -> bool {
+        let verifier_addr = AdminRepository::get_verifier(env);
+        let verifier = VerifierAdapterClient::new(env, &verifier_addr);
+
+        verifier.verify_audit(word_commitment, audit_hash, proof_payload)
+    }
+
+    /// Verifies that a word commitment hides the word at `word_index` of
+    /// the pool committed as `pool_root`
+    pub fn verify_pool_word(
+        env: &Env,
+        word_commitment: &BytesN<32>,
+        pool_root: &BytesN<32>,
+        word_index: u32,
+        proof_payload: &Bytes,
+    ) -> bool {
+        let verifier_addr = AdminRepository::get_verifier(env);
+        let verifier = VerifierAdapterClient::new(env, &verifier_addr);
+
+        verifier.verify_pool_word(word_commitment, pool_root, &word_index, proof_payload)
+    }
 }
@@ -1,5 +1,10 @@
+mod events;
 mod external;
 pub mod storage;
 
-pub use external::{GameHubGateway, VerifierGateway};
-pub use storage::GameRepository;
+pub use events::{ErrorContext, ErrorReporter, GameMove, MoveReporter, Reacted};
+pub use external::{GameHubGateway, GameInterface, GameInterfaceClient, VerifierGateway};
+pub use storage::{
+    GameRepository, PlayerSessionRepository, ReactionRepository, SessionKeyRepository,
+    SessionMarkerRepository, StatsRepository,
+};
@@ -1,24 +1,64 @@
-use soroban_sdk::{contracttype, Address, Env};
+use soroban_sdk::{contracttype, Address, BytesN, Env, Vec};
 
-use crate::domain::{DomainError, Game};
+use crate::domain::{
+    DomainError, Game, GamePhase, GameStats, Metrics, PlayerStats, Reaction, SessionKey,
+    SessionMarker, StakeBounds,
+};
 
 /// Storage keys for contract data
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
-    /// Game state by session ID
+    /// Game state by session ID, without the guess history
     Game(u32),
+    /// One resolved guess of a game (session_id, index)
+    Guess(u32, u32),
     /// Game Hub contract address
     GameHubAddress,
     /// Verifier adapter contract address
     VerifierAddress,
-    /// Admin address
-    Admin,
+    /// Active session IDs per player
+    PlayerSessions(Address),
+    /// Maximum concurrent sessions per player (0 = unlimited)
+    MaxActiveGames,
+    /// Dictionary Merkle root that word validity proofs are checked against
+    DictionaryRoot,
+    /// Whether plain `commit_word` is disabled in favour of proven commitments
+    RequireWordProof,
+    /// Ledgers a word setter has to resolve a guess in new games (0 = no expiry)
+    GuessExpiryLedgers,
+    /// Ledgers from start to the deadline of new games (0 = no deadline)
+    GameDurationLedgers,
+    /// Smallest stake each player must put up in a ranked game
+    MinStake,
+    /// Largest stake each player may put up in a ranked game (0 = no cap)
+    MaxStake,
+    /// Session key a player authorized to sign moves (session_id, player)
+    SessionKey(u32, Address),
+    /// Stats of a finished game, kept after the game expires
+    ArchivedStats(u32),
+    /// Guesser stats aggregated over archived games
+    PlayerStats(Address),
+    /// Operator counters read by `get_metrics`
+    Metrics,
+    /// Who started a session and when, kept after the game expires
+    SessionMarker(u32),
+    /// Players' emotes on one resolved guess (session_id, guess_index)
+    Reactions(u32, u32),
 }
 
 /// TTL for game storage (~30 days)
 pub const GAME_TTL_LEDGERS: u32 = 518_400;
 
+/// A resolved guess as stored under `DataKey::Guess`
+#[contracttype]
+#[derive(Clone)]
+pub struct GuessRecord {
+    pub guess: BytesN<5>,
+    pub actor: Address,
+    pub feedback: Vec<u32>,
+}
+
 /// Repository pattern for game persistence
 pub struct GameRepository;
 
@@ -29,7 +69,8 @@ impl GameRepository {
         env.storage().temporary().has(&key)
     }
 
-    /// Loads a game from storage
+    /// Loads a game from storage without its guess history. Commands only
+    /// append to the history, so `save` writes the new guesses on their own
     pub fn load(env: &Env, session_id: u32) -> Result<Game, DomainError> {
         let key = DataKey::Game(session_id);
         env.storage()
@@ -38,31 +79,262 @@ impl GameRepository {
             .ok_or(DomainError::GameNotFound)
     }
 
-    /// Saves a game to storage with TTL extension
+    /// Loads a game from storage, guess history included
+    pub fn load_with_history(env: &Env, session_id: u32) -> Result<Game, DomainError> {
+        let mut game = Self::load(env, session_id)?;
+        Self::load_history(env, session_id, &mut game)?;
+        Ok(game)
+    }
+
+    /// Reads the stored guesses in front of the ones `game` holds in memory
+    pub fn load_history(env: &Env, session_id: u32, game: &mut Game) -> Result<(), DomainError> {
+        let stored = game.guess_count - game.guesses.len();
+        let mut guesses = Vec::new(env);
+        let mut guess_actors = Vec::new(env);
+        let mut feedbacks = Vec::new(env);
+        for index in 0..stored {
+            let record: GuessRecord = env
+                .storage()
+                .temporary()
+                .get(&DataKey::Guess(session_id, index))
+                .ok_or(DomainError::GameNotFound)?;
+            guesses.push_back(record.guess);
+            guess_actors.push_back(record.actor);
+            feedbacks.push_back(record.feedback);
+        }
+        guesses.append(&game.guesses);
+        guess_actors.append(&game.guess_actors);
+        feedbacks.append(&game.feedbacks);
+        game.guesses = guesses;
+        game.guess_actors = guess_actors;
+        game.feedbacks = feedbacks;
+        Ok(())
+    }
+
+    /// Saves a game to storage with TTL extension. The guess history goes
+    /// to one entry per guess, written once with its own TTL, so the game
+    /// entry rewritten on every call stays the same size as the game
+    /// progresses and older guesses are not touched again
     pub fn save(env: &Env, session_id: u32, game: &Game) {
+        let storage = env.storage().temporary();
+
+        // `game` holds the last guesses of the history, loaded or not; only
+        // the newest are missing, so stop at the first one stored
+        let offset = game.guess_count - game.guesses.len();
+        let mut first_new = game.guesses.len();
+        while first_new > 0 && !storage.has(&DataKey::Guess(session_id, offset + first_new - 1)) {
+            first_new -= 1;
+        }
+        for index in first_new..game.guesses.len() {
+            let record = GuessRecord {
+                guess: game.guesses.get_unchecked(index),
+                actor: game.guess_actors.get_unchecked(index),
+                feedback: game.feedbacks.get_unchecked(index),
+            };
+            let key = DataKey::Guess(session_id, offset + index);
+            storage.set(&key, &record);
+            storage.extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        }
+
+        let mut stored = game.clone();
+        stored.guesses = Vec::new(env);
+        stored.guess_actors = Vec::new(env);
+        stored.feedbacks = Vec::new(env);
         let key = DataKey::Game(session_id);
-        env.storage().temporary().set(&key, game);
+        storage.set(&key, &stored);
+        storage.extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+
+    /// Saves `game` over whatever is stored under `session_id`, dropping the
+    /// guess entries of the previous game first since `save` only appends
+    #[cfg(any(test, feature = "game-import"))]
+    pub fn replace(env: &Env, session_id: u32, game: &Game) {
+        for index in 0..crate::domain::game::MAX_GUESSES {
+            env.storage()
+                .temporary()
+                .remove(&DataKey::Guess(session_id, index));
+        }
+        Self::save(env, session_id, game);
+    }
+}
+
+/// Repository for the per-player active session index
+pub struct PlayerSessionRepository;
+
+impl PlayerSessionRepository {
+    /// Loads active sessions, skipping games that ended or expired
+    pub fn load(env: &Env, player: &Address) -> Vec<u32> {
+        let stored: Vec<u32> = env
+            .storage()
+            .temporary()
+            .get(&DataKey::PlayerSessions(player.clone()))
+            .unwrap_or(Vec::new(env));
+
+        let mut active = Vec::new(env);
+        for session_id in stored.iter() {
+            if let Ok(game) = GameRepository::load(env, session_id) {
+                if game.phase != GamePhase::Ended {
+                    active.push_back(session_id);
+                }
+            }
+        }
+        active
+    }
+
+    /// Saves the active session list with TTL extension
+    pub fn save(env: &Env, player: &Address, sessions: &Vec<u32>) {
+        let key = DataKey::PlayerSessions(player.clone());
+        if sessions.is_empty() {
+            env.storage().temporary().remove(&key);
+            return;
+        }
+        env.storage().temporary().set(&key, sessions);
         env.storage()
             .temporary()
             .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
     }
+
+    /// Removes a session from a player's active list
+    pub fn remove(env: &Env, player: &Address, session_id: u32) {
+        let mut sessions = Self::load(env, player);
+        if let Some(index) = sessions.first_index_of(session_id) {
+            sessions.remove(index);
+        }
+        Self::save(env, player, &sessions);
+    }
 }
 
-/// Repository for admin configuration
-pub struct AdminRepository;
+/// Repository for per-session player keys
+pub struct SessionKeyRepository;
 
-impl AdminRepository {
-    pub fn get_admin(env: &Env) -> Address {
+impl SessionKeyRepository {
+    /// Loads the player's session key, ignoring one that has expired
+    pub fn load(env: &Env, session_id: u32, player: &Address) -> Option<SessionKey> {
+        let stored: Option<SessionKey> = env
+            .storage()
+            .temporary()
+            .get(&DataKey::SessionKey(session_id, player.clone()));
+        stored.filter(|session_key| session_key.is_active(env.ledger().sequence()))
+    }
+
+    /// Saves a session key with TTL extension
+    pub fn save(env: &Env, session_id: u32, player: &Address, session_key: &SessionKey) {
+        let key = DataKey::SessionKey(session_id, player.clone());
+        env.storage().temporary().set(&key, session_key);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+
+    pub fn remove(env: &Env, session_id: u32, player: &Address) {
+        env.storage()
+            .temporary()
+            .remove(&DataKey::SessionKey(session_id, player.clone()));
+    }
+}
+
+/// Repository for emote reactions, keyed by resolved guess rather than by
+/// move log sequence number
+pub struct ReactionRepository;
+
+impl ReactionRepository {
+    pub fn load(env: &Env, session_id: u32, guess_index: u32) -> Vec<Reaction> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::Reactions(session_id, guess_index))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Saves a guess's reactions with TTL extension
+    pub fn save(env: &Env, session_id: u32, guess_index: u32, reactions: &Vec<Reaction>) {
+        let key = DataKey::Reactions(session_id, guess_index);
+        env.storage().temporary().set(&key, reactions);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+}
+
+/// Repository for started-session markers, in persistent storage so they
+/// outlive the temporary game state
+pub struct SessionMarkerRepository;
+
+impl SessionMarkerRepository {
+    pub fn exists(env: &Env, session_id: u32) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::SessionMarker(session_id))
+    }
+
+    pub fn load(env: &Env, session_id: u32) -> Option<SessionMarker> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SessionMarker(session_id))
+    }
+
+    pub fn save(env: &Env, session_id: u32, marker: &SessionMarker) {
+        let key = DataKey::SessionMarker(session_id);
+        env.storage().persistent().set(&key, marker);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+}
+
+/// Repository for finished-game stats, in persistent storage so profiles
+/// outlive the temporary game state
+pub struct StatsRepository;
+
+impl StatsRepository {
+    pub fn load_archived(env: &Env, session_id: u32) -> Result<GameStats, DomainError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ArchivedStats(session_id))
+            .ok_or(DomainError::StatsNotArchived)
+    }
+
+    /// Archives a game's stats and folds them into the guesser's aggregate
+    pub fn archive(env: &Env, session_id: u32, guesser: &Address, stats: &GameStats) {
+        let key = DataKey::ArchivedStats(session_id);
+        env.storage().persistent().set(&key, stats);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
+        let mut player_stats = Self::load_player(env, guesser);
+        player_stats.record(stats);
+        let key = DataKey::PlayerStats(guesser.clone());
+        env.storage().persistent().set(&key, &player_stats);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+    }
+
+    /// Aggregate stats for `player` (all zero before their first game)
+    pub fn load_player(env: &Env, player: &Address) -> PlayerStats {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PlayerStats(player.clone()))
+            .unwrap_or_default()
+    }
+
+    /// Operator counters (all zero before the first game)
+    pub fn load_metrics(env: &Env) -> Metrics {
         env.storage()
             .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set")
+            .get(&DataKey::Metrics)
+            .unwrap_or_else(|| Metrics::new(env))
     }
 
-    pub fn set_admin(env: &Env, admin: &Address) {
-        env.storage().instance().set(&DataKey::Admin, admin);
+    pub fn save_metrics(env: &Env, metrics: &Metrics) {
+        env.storage().instance().set(&DataKey::Metrics, metrics);
     }
+}
 
+/// Repository for admin configuration
+pub struct AdminRepository;
+
+impl AdminRepository {
     pub fn get_game_hub(env: &Env) -> Address {
         env.storage()
             .instance()
@@ -88,4 +360,84 @@ impl AdminRepository {
             .instance()
             .set(&DataKey::VerifierAddress, address);
     }
+
+    pub fn get_max_active_games(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxActiveGames)
+            .unwrap_or(0)
+    }
+
+    pub fn set_max_active_games(env: &Env, max_active_games: u32) {
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxActiveGames, &max_active_games);
+    }
+
+    /// Dictionary root for word validity proofs (all zero = no dictionary)
+    pub fn get_dictionary_root(env: &Env) -> BytesN<32> {
+        env.storage()
+            .instance()
+            .get(&DataKey::DictionaryRoot)
+            .unwrap_or(BytesN::from_array(env, &[0u8; 32]))
+    }
+
+    pub fn set_dictionary_root(env: &Env, root: &BytesN<32>) {
+        env.storage().instance().set(&DataKey::DictionaryRoot, root);
+    }
+
+    pub fn get_require_word_proof(env: &Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::RequireWordProof)
+            .unwrap_or(false)
+    }
+
+    pub fn set_require_word_proof(env: &Env, required: bool) {
+        env.storage()
+            .instance()
+            .set(&DataKey::RequireWordProof, &required);
+    }
+
+    pub fn get_guess_expiry_ledgers(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::GuessExpiryLedgers)
+            .unwrap_or(0)
+    }
+
+    pub fn set_guess_expiry_ledgers(env: &Env, ledgers: u32) {
+        env.storage()
+            .instance()
+            .set(&DataKey::GuessExpiryLedgers, &ledgers);
+    }
+
+    pub fn get_game_duration_ledgers(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::GameDurationLedgers)
+            .unwrap_or(0)
+    }
+
+    pub fn set_game_duration_ledgers(env: &Env, ledgers: u32) {
+        env.storage()
+            .instance()
+            .set(&DataKey::GameDurationLedgers, &ledgers);
+    }
+
+    pub fn get_stake_bounds(env: &Env) -> StakeBounds {
+        let storage = env.storage().instance();
+        StakeBounds {
+            min: storage
+                .get(&DataKey::MinStake)
+                .unwrap_or(StakeBounds::DEFAULT_MIN),
+            max: storage.get(&DataKey::MaxStake).unwrap_or(0),
+        }
+    }
+
+    pub fn set_stake_bounds(env: &Env, bounds: &StakeBounds) {
+        let storage = env.storage().instance();
+        storage.set(&DataKey::MinStake, &bounds.min);
+        storage.set(&DataKey::MaxStake, &bounds.max);
+    }
 }
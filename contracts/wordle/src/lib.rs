@@ -5,48 +5,138 @@ mod domain;
 mod infrastructure;
 
 // Re-export public types for contract interface
-pub use application::GuessResult;
-pub use domain::{DomainError as Error, Game, GamePhase, GameRules};
+pub use application::{ContractInfo, GuessResult, ResolutionCheck};
+pub use domain::{
+    DomainError as Error, Game, GamePhase, GameRules, GameStats, GameStatus, HubSeats, Metrics,
+    PlayerStats, Reaction, SessionKey, SessionMarker, StorageTier, WordPool,
+};
+pub use infrastructure::{ErrorContext, GameInterface, GameInterfaceClient, GameMove, Reacted};
+
+use ownable::Ownable;
+use soroban_sdk::{contract, contractimpl, symbol_short, vec, Address, Bytes, BytesN, Env, Vec};
 
-use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, Vec};
+use domain::{StakeBounds, ALPHABET_SIZE};
 
 use application::{
-    CommitWordCommand, GetGameQuery, GetRulesQuery, GuessCommand, ResolveGuessCommand,
-    StartGameCommand,
+    AuthorizeSessionKeyCommand, CancelGameCommand, CheckResolutionQuery, CommitGuessCommand,
+    CommitPoolWordCommand, CommitPracticeWordCommand, CommitWordCommand, CommitWordPoolCommand,
+    CommitWordWithProofCommand, ComputeFeedbackQuery, ContributePoolSeedCommand,
+    EvaluateGameCommand, ExpireGuessCommitmentCommand, ExpirePendingGuessCommand,
+    FinalizeExpiredCommand, FinalizeWithAuditCommand, GetActiveSessionsQuery,
+    GetArchivedStatsQuery, GetAuditHashQuery, GetGameQuery, GetGameStatusQuery, GetInfoQuery,
+    GetMetricsQuery, GetMoveCountQuery, GetPlayerStatsQuery, GetReactionsQuery, GetRulesQuery,
+    GetSessionKeyQuery, GetSessionMarkerQuery, GetShareGridQuery, GuessCommand, ReactCommand,
+    ResolveGuessCommand, ResolvePracticeGuessCommand, RetractGuessCommand, RetryHubNotifyCommand,
+    RevealGuessCommand, RevealPoolSeedCommand, RevokeSessionKeyCommand, StartCoopGameCommand,
+    StartGameCommand, StartPracticeGameCommand, StartSeatedGameCommand,
 };
 use infrastructure::storage::AdminRepository;
+use infrastructure::ErrorReporter;
 
 #[contract]
 pub struct WordleContract;
 
+#[contractimpl]
+impl GameInterface for WordleContract {
+    /// Start a new game between two players over the Latin A-Z alphabet;
+    /// returns the session id
+    fn start_game(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<u32, Error> {
+        Self::start_game_with_alphabet(
+            env,
+            session_id,
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+            ALPHABET_SIZE,
+        )
+    }
+
+    /// Expires an overdue pending guess, so a tournament driver can advance
+    /// a stalled game, then returns the winner (None while running or after
+    /// `cancel_game`)
+    fn evaluate_game(env: Env, session_id: u32) -> Result<Option<Address>, Error> {
+        EvaluateGameCommand::execute(&env, session_id).map_err(|err| {
+            ErrorReporter::report(
+                &env,
+                session_id,
+                symbol_short!("evaluate"),
+                None,
+                Vec::new(&env),
+                err,
+            )
+        })
+    }
+
+    /// Game Hub ends a running game without a winner (e.g. a tournament
+    /// no-show); the hub is not notified back
+    fn cancel_game(env: Env, session_id: u32) -> Result<(), Error> {
+        CancelGameCommand::execute(&env, session_id).map_err(|err| {
+            ErrorReporter::report(
+                &env,
+                session_id,
+                symbol_short!("cancel"),
+                None,
+                Vec::new(&env),
+                err,
+            )
+        })
+    }
+
+    /// Setup, InProgress, Ended or Cancelled
+    fn get_game_status(env: Env, session_id: u32) -> Result<GameStatus, Error> {
+        GetGameStatusQuery::execute(&env, session_id)
+    }
+}
+
 #[contractimpl]
 impl WordleContract {
     /// Initialize contract with admin, game hub, and verifier addresses
     pub fn __constructor(env: Env, admin: Address, game_hub: Address, verifier: Address) {
-        AdminRepository::set_admin(&env, &admin);
+        Ownable::init(&env, &admin);
         AdminRepository::set_game_hub(&env, &game_hub);
         AdminRepository::set_verifier(&env, &verifier);
     }
 
     // ==================== Game Commands ====================
 
-    /// Start a new game between two players
-    pub fn start_game(
+    /// Start a new game whose letters are 0..alphabet_size (e.g. 33 for
+    /// Cyrillic); the letter mapping is up to the frontend
+    pub fn start_game_with_alphabet(
         env: Env,
         session_id: u32,
         player1: Address,
         player2: Address,
         player1_points: i128,
         player2_points: i128,
-    ) -> Result<(), Error> {
+        alphabet_size: u32,
+    ) -> Result<u32, Error> {
         StartGameCommand::execute(
             &env,
             session_id,
-            player1,
+            player1.clone(),
             player2,
             player1_points,
             player2_points,
+            alphabet_size,
         )
+        .map_err(|err| {
+            ErrorReporter::report(
+                &env,
+                session_id,
+                symbol_short!("start"),
+                Some(player1),
+                vec![&env, alphabet_size],
+                err,
+            )
+        })
     }
 
     /// Word setter commits their secret word
@@ -56,7 +146,144 @@ impl WordleContract {
         player: Address,
         word_commitment: BytesN<32>,
     ) -> Result<(), Error> {
-        CommitWordCommand::execute(&env, session_id, player, word_commitment)
+        CommitWordCommand::execute(&env, session_id, player.clone(), word_commitment).map_err(
+            |err| {
+                ErrorReporter::report(
+                    &env,
+                    session_id,
+                    symbol_short!("commit"),
+                    Some(player),
+                    Vec::new(&env),
+                    err,
+                )
+            },
+        )
+    }
+
+    /// Word setter commits their secret word with a proof that it is a
+    /// valid 5-letter word (and in the dictionary, if a root is configured)
+    pub fn commit_word_with_proof(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        word_commitment: BytesN<32>,
+        proof_payload: Bytes,
+    ) -> Result<(), Error> {
+        CommitWordWithProofCommand::execute(
+            &env,
+            session_id,
+            player.clone(),
+            word_commitment,
+            proof_payload,
+        )
+        .map_err(|err| {
+            ErrorReporter::report(
+                &env,
+                session_id,
+                symbol_short!("commitprf"),
+                Some(player),
+                Vec::new(&env),
+                err,
+            )
+        })
+    }
+
+    /// Word setter fixes a Merkle root over `pool_size` candidate words and
+    /// the hash of a seed (see `build_pool_seed_commitment`). The word is
+    /// then drawn by both players' seeds instead of picked per opponent
+    pub fn commit_word_pool(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        pool_root: BytesN<32>,
+        pool_size: u32,
+        seed_commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        CommitWordPoolCommand::execute(
+            &env,
+            session_id,
+            player.clone(),
+            pool_root,
+            pool_size,
+            seed_commitment,
+        )
+        .map_err(|err| {
+            ErrorReporter::report(
+                &env,
+                session_id,
+                symbol_short!("poolcmt"),
+                Some(player),
+                vec![&env, pool_size],
+                err,
+            )
+        })
+    }
+
+    /// Guesser adds their seed to the word draw of a pool game
+    pub fn contribute_pool_seed(
+        env: Env,
+        session_id: u32,
+        guesser: Address,
+        seed: BytesN<32>,
+    ) -> Result<(), Error> {
+        ContributePoolSeedCommand::execute(&env, session_id, guesser.clone(), seed).map_err(|err| {
+            ErrorReporter::report(
+                &env,
+                session_id,
+                symbol_short!("poolseed"),
+                Some(guesser),
+                Vec::new(&env),
+                err,
+            )
+        })
+    }
+
+    /// Word setter reveals their seed once the guesser's is in; returns the
+    /// drawn word index, keccak256(setter_seed || guesser_seed) mod pool size
+    pub fn reveal_pool_seed(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        seed: BytesN<32>,
+    ) -> Result<u32, Error> {
+        RevealPoolSeedCommand::execute(&env, session_id, player.clone(), seed).map_err(|err| {
+            ErrorReporter::report(
+                &env,
+                session_id,
+                symbol_short!("poolrev"),
+                Some(player),
+                Vec::new(&env),
+                err,
+            )
+        })
+    }
+
+    /// Word setter commits the drawn word with a proof that it is the leaf
+    /// at the drawn index of the committed pool
+    pub fn commit_pool_word(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        word_commitment: BytesN<32>,
+        proof_payload: Bytes,
+    ) -> Result<(), Error> {
+        CommitPoolWordCommand::execute(
+            &env,
+            session_id,
+            player.clone(),
+            word_commitment,
+            proof_payload,
+        )
+        .map_err(|err| {
+            ErrorReporter::report(
+                &env,
+                session_id,
+                symbol_short!("poolword"),
+                Some(player),
+                Vec::new(&env),
+                err,
+            )
+        })
     }
 
     /// Guesser submits a guess
@@ -66,7 +293,22 @@ impl WordleContract {
         guesser: Address,
         guess_letters: BytesN<5>,
     ) -> Result<(), Error> {
-        GuessCommand::execute(&env, session_id, guesser, guess_letters)
+        GuessCommand::execute(&env, session_id, guesser.clone(), guess_letters.clone()).map_err(
+            |err| {
+                let mut args = Vec::new(&env);
+                for letter in guess_letters.to_array().iter() {
+                    args.push_back(*letter as u32);
+                }
+                ErrorReporter::report(
+                    &env,
+                    session_id,
+                    symbol_short!("guess"),
+                    Some(guesser),
+                    args,
+                    err,
+                )
+            },
+        )
     }
 
     /// Word setter resolves a guess with ZK proof
@@ -82,12 +324,362 @@ impl WordleContract {
         ResolveGuessCommand::execute(
             &env,
             session_id,
-            word_setter,
-            feedback,
+            word_setter.clone(),
+            feedback.clone(),
             is_correct,
             proof_payload,
             public_inputs_hash,
         )
+        .map_err(|err| {
+            let mut args = feedback;
+            args.push_back(is_correct as u32);
+            ErrorReporter::report(
+                &env,
+                session_id,
+                symbol_short!("resolve"),
+                Some(word_setter),
+                args,
+                err,
+            )
+        })
+    }
+
+    /// Start a co-op game over A-Z: `guesser` and `co_guesser` alternate
+    /// guesses (`guesser` first) against one word setter within the shared
+    /// 6-guess budget. The Game Hub settles the word setter against
+    /// `guesser`, who stands for both guessers
+    pub fn start_coop_game(
+        env: Env,
+        session_id: u32,
+        word_setter: Address,
+        guesser: Address,
+        co_guesser: Address,
+        word_setter_points: i128,
+        guesser_points: i128,
+    ) -> Result<u32, Error> {
+        StartCoopGameCommand::execute(
+            &env,
+            session_id,
+            word_setter.clone(),
+            guesser,
+            co_guesser,
+            word_setter_points,
+            guesser_points,
+        )
+        .map_err(|err| {
+            ErrorReporter::report(
+                &env,
+                session_id,
+                symbol_short!("coop"),
+                Some(word_setter),
+                Vec::new(&env),
+                err,
+            )
+        })
+    }
+
+    /// Start a game over A-Z with explicit Game Hub seats: `seats` names
+    /// which of the word setter and guesser is the hub's player1, and
+    /// `end_game` reports from that mapping. Both players sign the seating
+    pub fn start_seated_game(
+        env: Env,
+        session_id: u32,
+        word_setter: Address,
+        guesser: Address,
+        word_setter_points: i128,
+        guesser_points: i128,
+        seats: HubSeats,
+    ) -> Result<u32, Error> {
+        StartSeatedGameCommand::execute(
+            &env,
+            session_id,
+            word_setter.clone(),
+            guesser,
+            word_setter_points,
+            guesser_points,
+            seats,
+        )
+        .map_err(|err| {
+            ErrorReporter::report(
+                &env,
+                session_id,
+                symbol_short!("seated"),
+                Some(word_setter),
+                Vec::new(&env),
+                err,
+            )
+        })
+    }
+
+    /// Start a stakeless practice game over A-Z: no points, no Game Hub and
+    /// no proofs. The word is set in clear and feedback computed on-chain
+    pub fn start_practice_game(
+        env: Env,
+        session_id: u32,
+        word_setter: Address,
+        guesser: Address,
+    ) -> Result<u32, Error> {
+        StartPracticeGameCommand::execute(&env, session_id, word_setter.clone(), guesser).map_err(
+            |err| {
+                ErrorReporter::report(
+                    &env,
+                    session_id,
+                    symbol_short!("practice"),
+                    Some(word_setter),
+                    Vec::new(&env),
+                    err,
+                )
+            },
+        )
+    }
+
+    /// Word setter sets a practice game's word in clear
+    pub fn commit_practice_word(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        word_letters: BytesN<5>,
+    ) -> Result<(), Error> {
+        CommitPracticeWordCommand::execute(&env, session_id, player.clone(), word_letters).map_err(
+            |err| {
+                ErrorReporter::report(
+                    &env,
+                    session_id,
+                    symbol_short!("pcommit"),
+                    Some(player),
+                    Vec::new(&env),
+                    err,
+                )
+            },
+        )
+    }
+
+    /// Resolve a practice game's pending guess with feedback computed from
+    /// the clear word. Anyone may call
+    pub fn resolve_practice_guess(env: Env, session_id: u32) -> Result<GuessResult, Error> {
+        ResolvePracticeGuessCommand::execute(&env, session_id).map_err(|err| {
+            ErrorReporter::report(
+                &env,
+                session_id,
+                symbol_short!("presolve"),
+                None,
+                Vec::new(&env),
+                err,
+            )
+        })
+    }
+
+    /// Guesser commits to a guess without revealing it (see
+    /// `build_guess_commitment`); the letters follow with `reveal_guess`
+    /// within `GUESS_REVEAL_WINDOW_LEDGERS`, so observers can't study the
+    /// guess before it is resolved
+    pub fn commit_guess(
+        env: Env,
+        session_id: u32,
+        guesser: Address,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        CommitGuessCommand::execute(&env, session_id, guesser.clone(), commitment).map_err(|err| {
+            ErrorReporter::report(
+                &env,
+                session_id,
+                symbol_short!("guesscmt"),
+                Some(guesser),
+                Vec::new(&env),
+                err,
+            )
+        })
+    }
+
+    /// Guesser reveals the committed guess; it then waits for resolution
+    /// like a submitted guess
+    pub fn reveal_guess(
+        env: Env,
+        session_id: u32,
+        guesser: Address,
+        guess_letters: BytesN<5>,
+        salt: BytesN<32>,
+    ) -> Result<(), Error> {
+        RevealGuessCommand::execute(
+            &env,
+            session_id,
+            guesser.clone(),
+            guess_letters.clone(),
+            salt,
+        )
+        .map_err(|err| {
+            let mut args = Vec::new(&env);
+            for letter in guess_letters.to_array().iter() {
+                args.push_back(*letter as u32);
+            }
+            ErrorReporter::report(
+                &env,
+                session_id,
+                symbol_short!("guessrev"),
+                Some(guesser),
+                args,
+                err,
+            )
+        })
+    }
+
+    /// Withdraw the pending guess within `GUESS_RETRACT_WINDOW_LEDGERS` of
+    /// submitting it, once per game; the retraction is kept on the game
+    pub fn retract_guess(env: Env, session_id: u32, guesser: Address) -> Result<(), Error> {
+        RetractGuessCommand::execute(&env, session_id, guesser.clone()).map_err(|err| {
+            ErrorReporter::report(
+                &env,
+                session_id,
+                symbol_short!("retract"),
+                Some(guesser),
+                Vec::new(&env),
+                err,
+            )
+        })
+    }
+
+    /// Drop a guess the word setter left unresolved for `guess_expiry_ledgers`:
+    /// the guess is not counted and the setter takes a strike (three forfeit).
+    /// Anyone may call; returns the setter's strike count
+    pub fn expire_pending_guess(env: Env, session_id: u32) -> Result<u32, Error> {
+        ExpirePendingGuessCommand::execute(&env, session_id).map_err(|err| {
+            ErrorReporter::report(
+                &env,
+                session_id,
+                symbol_short!("expire"),
+                None,
+                Vec::new(&env),
+                err,
+            )
+        })
+    }
+
+    /// End a game still running after its deadline (`game_duration_ledgers`
+    /// from the start): the word setter loses if the word is uncommitted or
+    /// a guess is unresolved, otherwise the guesser loses. Anyone may call;
+    /// returns the winner
+    pub fn finalize_expired(env: Env, session_id: u32) -> Result<Address, Error> {
+        FinalizeExpiredCommand::execute(&env, session_id).map_err(|err| {
+            ErrorReporter::report(
+                &env,
+                session_id,
+                symbol_short!("deadline"),
+                None,
+                Vec::new(&env),
+                err,
+            )
+        })
+    }
+
+    /// End a game whose committed guess was not revealed within
+    /// `GUESS_REVEAL_WINDOW_LEDGERS`: the guesser forfeits. Anyone may call;
+    /// returns the winner
+    pub fn expire_guess_commitment(env: Env, session_id: u32) -> Result<Address, Error> {
+        ExpireGuessCommitmentCommand::execute(&env, session_id).map_err(|err| {
+            ErrorReporter::report(
+                &env,
+                session_id,
+                symbol_short!("lapse"),
+                None,
+                Vec::new(&env),
+                err,
+            )
+        })
+    }
+
+    /// Re-send the result of a finished game whose Game Hub notification
+    /// failed (`hub_notified` is false). Anyone may call
+    pub fn retry_hub_notify(env: Env, session_id: u32) -> Result<(), Error> {
+        RetryHubNotifyCommand::execute(&env, session_id).map_err(|err| {
+            ErrorReporter::report(
+                &env,
+                session_id,
+                symbol_short!("retryhub"),
+                None,
+                Vec::new(&env),
+                err,
+            )
+        })
+    }
+
+    /// Word setter proves, after the game ended, that every recorded feedback
+    /// was consistent with the committed word
+    pub fn finalize_with_audit(
+        env: Env,
+        session_id: u32,
+        proof_payload: Bytes,
+    ) -> Result<(), Error> {
+        FinalizeWithAuditCommand::execute(&env, session_id, proof_payload).map_err(|err| {
+            ErrorReporter::report(
+                &env,
+                session_id,
+                symbol_short!("audit"),
+                None,
+                Vec::new(&env),
+                err,
+            )
+        })
+    }
+
+    /// Let `key` sign `guess` / `resolve_guess` for `player` in this session
+    /// until ledger `expiry`, replacing any previous key
+    pub fn authorize_session_key(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        key: Address,
+        expiry: u32,
+    ) -> Result<(), Error> {
+        AuthorizeSessionKeyCommand::execute(&env, session_id, player.clone(), key, expiry).map_err(
+            |err| {
+                ErrorReporter::report(
+                    &env,
+                    session_id,
+                    symbol_short!("sesskey"),
+                    Some(player),
+                    vec![&env, expiry],
+                    err,
+                )
+            },
+        )
+    }
+
+    /// React to the resolved guess at `guess_index` (0 = first guess) with an
+    /// emote (0 thumbs up, 1 laugh, 2 surprised, 3 sad, 4 fire, 5 clap),
+    /// replacing the player's earlier reaction to it. Reactions are kept per
+    /// resolved guess, not in the move log: they are not game actions, take no
+    /// `GameMove` sequence number and leave `move_count` unchanged. Published
+    /// as a `Reacted` event
+    pub fn react(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        guess_index: u32,
+        emote_code: u32,
+    ) -> Result<(), Error> {
+        ReactCommand::execute(&env, session_id, player.clone(), guess_index, emote_code).map_err(
+            |err| {
+                ErrorReporter::report(
+                    &env,
+                    session_id,
+                    symbol_short!("react"),
+                    Some(player),
+                    vec![&env, guess_index, emote_code],
+                    err,
+                )
+            },
+        )
+    }
+
+    /// Emotes the players left on the resolved guess at `guess_index`, one per
+    /// player
+    pub fn get_reactions(env: Env, session_id: u32, guess_index: u32) -> Vec<Reaction> {
+        GetReactionsQuery::execute(&env, session_id, guess_index)
+    }
+
+    /// Drop the player's session key so their wallet signs moves again
+    pub fn revoke_session_key(env: Env, session_id: u32, player: Address) {
+        RevokeSessionKeyCommand::execute(&env, session_id, player)
     }
 
     // ==================== Queries ====================
@@ -97,9 +689,95 @@ impl WordleContract {
         GetGameQuery::execute(&env, session_id)
     }
 
-    /// Get game rules
-    pub fn get_rules(_env: Env) -> GameRules {
-        GetRulesQuery::execute()
+    /// Get the rules a session was started with (including its alphabet size)
+    pub fn get_rules(env: Env, session_id: u32) -> Result<GameRules, Error> {
+        GetRulesQuery::execute(&env, session_id)
+    }
+
+    /// Actions applied to a game so far: the `move_count` of its latest
+    /// `GameMove` event
+    pub fn get_move_count(env: Env, session_id: u32) -> Result<u32, Error> {
+        GetMoveCountQuery::execute(&env, session_id)
+    }
+
+    /// Get the sessions a player is currently engaged in
+    pub fn get_active_sessions(env: Env, player: Address) -> Vec<u32> {
+        GetActiveSessionsQuery::execute(&env, &player)
+    }
+
+    /// Session key currently signing for `player`, if not expired
+    pub fn get_session_key(env: Env, session_id: u32, player: Address) -> Option<SessionKey> {
+        GetSessionKeyQuery::execute(&env, session_id, &player)
+    }
+
+    /// Guesses used, greens, yellows and ledgers played of a game that ended
+    /// with a winner; kept in persistent storage after the game expires
+    pub fn get_archived_stats(env: Env, session_id: u32) -> Result<GameStats, Error> {
+        GetArchivedStatsQuery::execute(&env, session_id)
+    }
+
+    /// Whether `session_id` was ever started here. Kept in persistent
+    /// storage, so once `get_game` fails with `GameNotFound` for a started
+    /// session, the hub knows the game expired and can refund the stakes
+    pub fn was_started(env: Env, session_id: u32) -> bool {
+        GetSessionMarkerQuery::was_started(&env, session_id)
+    }
+
+    /// Players, start ledger and storage tier of a started session, for
+    /// post-mortem audits of games that have expired (None if never started)
+    pub fn get_session_marker(env: Env, session_id: u32) -> Option<SessionMarker> {
+        GetSessionMarkerQuery::execute(&env, session_id)
+    }
+
+    /// Totals and running averages over every archived game the player
+    /// guessed in (all zero before the first one)
+    pub fn get_player_wordle_stats(env: Env, player: Address) -> PlayerStats {
+        GetPlayerStatsQuery::execute(&env, &player)
+    }
+
+    /// Games started, ended and cancelled since deployment, with solved
+    /// games bucketed by guesses used, for operator health checks without an
+    /// indexer
+    pub fn get_metrics(env: Env) -> Metrics {
+        GetMetricsQuery::execute(&env)
+    }
+
+    /// Share card for a finished game, rendered from the on-chain feedback
+    /// history as UTF-8 (header line, blank line, one emoji row per guess)
+    pub fn get_share_grid(env: Env, session_id: u32) -> Result<Bytes, Error> {
+        GetShareGridQuery::execute(&env, session_id)
+    }
+
+    /// Feedback (0 = absent, 1 = present, 2 = correct) for `guess_letters`
+    /// against `word_letters` with the resolve_guess circuit's duplicate-letter
+    /// rules, for differential testing of circuits and clients
+    pub fn compute_feedback(
+        env: Env,
+        word_letters: BytesN<5>,
+        guess_letters: BytesN<5>,
+    ) -> Result<Vec<u32>, Error> {
+        ComputeFeedbackQuery::execute(&env, &word_letters, &guess_letters)
+    }
+
+    /// Dry-run `resolve_guess` without changing state: reports the error it
+    /// would fail with, the hash the contract expects and the verifier's
+    /// answer, so provers can be debugged through simulation before paying fees
+    pub fn check_resolution(
+        env: Env,
+        session_id: u32,
+        feedback: Vec<u32>,
+        is_correct: bool,
+        proof_payload: Bytes,
+        public_inputs_hash: BytesN<32>,
+    ) -> ResolutionCheck {
+        CheckResolutionQuery::execute(
+            &env,
+            session_id,
+            feedback,
+            is_correct,
+            proof_payload,
+            public_inputs_hash,
+        )
     }
 
     /// Build public inputs hash (utility for frontend)
@@ -112,6 +790,7 @@ impl WordleContract {
         feedback: Vec<u32>,
         is_correct: bool,
         word_commitment: BytesN<32>,
+        alphabet_size: u32,
     ) -> BytesN<32> {
         ResolveGuessCommand::build_public_inputs_hash(
             &env,
@@ -122,19 +801,70 @@ impl WordleContract {
             &feedback,
             is_correct,
             &word_commitment,
+            alphabet_size,
+        )
+    }
+
+    /// Build the hash `commit_guess` takes (utility for frontend)
+    pub fn build_guess_commitment(
+        env: Env,
+        session_id: u32,
+        guesser: Address,
+        guess_letters: BytesN<5>,
+        salt: BytesN<32>,
+    ) -> BytesN<32> {
+        RevealGuessCommand::build_guess_commitment(
+            &env,
+            session_id,
+            &guesser,
+            &guess_letters,
+            &salt,
         )
     }
 
+    /// Build the seed hash `commit_word_pool` takes (utility for frontend)
+    pub fn build_pool_seed_commitment(
+        env: Env,
+        session_id: u32,
+        word_setter: Address,
+        seed: BytesN<32>,
+    ) -> BytesN<32> {
+        RevealPoolSeedCommand::build_pool_seed_commitment(&env, session_id, &word_setter, &seed)
+    }
+
+    /// Build the audit hash for a game's recorded history (utility for frontend)
+    pub fn build_audit_hash(env: Env, session_id: u32) -> Result<BytesN<32>, Error> {
+        GetAuditHashQuery::execute(&env, session_id)
+    }
+
+    /// Contract name, version, interface ids and build hash
+    pub fn get_info(env: Env) -> ContractInfo {
+        GetInfoQuery::execute(&env)
+    }
+
     // ==================== Admin Functions ====================
 
     pub fn get_admin(env: Env) -> Address {
-        AdminRepository::get_admin(&env)
+        Ownable::admin(&env)
     }
 
     pub fn set_admin(env: Env, new_admin: Address) {
-        let admin = AdminRepository::get_admin(&env);
-        admin.require_auth();
-        AdminRepository::set_admin(&env, &new_admin);
+        Ownable::set_admin(&env, &new_admin);
+    }
+
+    /// First step of an admin transfer; `new_admin` takes over once they call
+    /// `accept_admin`
+    pub fn propose_admin(env: Env, new_admin: Address) {
+        Ownable::propose_admin(&env, &new_admin);
+    }
+
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        Ownable::pending_admin(&env)
+    }
+
+    /// Completes a transfer started with `propose_admin`; signed by the nominee
+    pub fn accept_admin(env: Env) -> Address {
+        Ownable::accept_admin(&env)
     }
 
     pub fn get_hub(env: Env) -> Address {
@@ -142,8 +872,7 @@ impl WordleContract {
     }
 
     pub fn set_hub(env: Env, new_hub: Address) {
-        let admin = AdminRepository::get_admin(&env);
-        admin.require_auth();
+        Ownable::require_admin(&env);
         AdminRepository::set_game_hub(&env, &new_hub);
     }
 
@@ -152,15 +881,103 @@ impl WordleContract {
     }
 
     pub fn set_verifier(env: Env, new_verifier: Address) {
-        let admin = AdminRepository::get_admin(&env);
-        admin.require_auth();
+        Ownable::require_admin(&env);
         AdminRepository::set_verifier(&env, &new_verifier);
     }
 
+    /// Maximum concurrent sessions per player (0 = unlimited)
+    pub fn get_max_active_games(env: Env) -> u32 {
+        AdminRepository::get_max_active_games(&env)
+    }
+
+    pub fn set_max_active_games(env: Env, max_active_games: u32) {
+        Ownable::require_admin(&env);
+        AdminRepository::set_max_active_games(&env, max_active_games);
+    }
+
+    /// Dictionary root word validity proofs are checked against (all zero = none)
+    pub fn get_dictionary_root(env: Env) -> BytesN<32> {
+        AdminRepository::get_dictionary_root(&env)
+    }
+
+    pub fn set_dictionary_root(env: Env, root: BytesN<32>) {
+        Ownable::require_admin(&env);
+        AdminRepository::set_dictionary_root(&env, &root);
+    }
+
+    /// Whether word setters must use `commit_word_with_proof`
+    pub fn get_require_word_proof(env: Env) -> bool {
+        AdminRepository::get_require_word_proof(&env)
+    }
+
+    pub fn set_require_word_proof(env: Env, required: bool) {
+        Ownable::require_admin(&env);
+        AdminRepository::set_require_word_proof(&env, required);
+    }
+
+    /// Ledgers word setters have to resolve a guess in new games (0 = no expiry)
+    pub fn get_guess_expiry_ledgers(env: Env) -> u32 {
+        AdminRepository::get_guess_expiry_ledgers(&env)
+    }
+
+    pub fn set_guess_expiry_ledgers(env: Env, ledgers: u32) {
+        Ownable::require_admin(&env);
+        AdminRepository::set_guess_expiry_ledgers(&env, ledgers);
+    }
+
+    /// Ledgers from the start to the deadline of new games (0 = no deadline)
+    pub fn get_game_duration_ledgers(env: Env) -> u32 {
+        AdminRepository::get_game_duration_ledgers(&env)
+    }
+
+    pub fn set_game_duration_ledgers(env: Env, ledgers: u32) {
+        Ownable::require_admin(&env);
+        AdminRepository::set_game_duration_ledgers(&env, ledgers);
+    }
+
+    /// Smallest stake each player must put up in a ranked game (1 unless set)
+    pub fn get_min_stake(env: Env) -> i128 {
+        AdminRepository::get_stake_bounds(&env).min
+    }
+
+    pub fn set_min_stake(env: Env, min_stake: i128) -> Result<(), Error> {
+        Ownable::require_admin(&env);
+        let current = AdminRepository::get_stake_bounds(&env);
+        let bounds = StakeBounds::new(min_stake, current.max)?;
+        AdminRepository::set_stake_bounds(&env, &bounds);
+        Ok(())
+    }
+
+    /// Largest stake each player may put up in a ranked game (0 = no cap)
+    pub fn get_max_stake(env: Env) -> i128 {
+        AdminRepository::get_stake_bounds(&env).max
+    }
+
+    pub fn set_max_stake(env: Env, max_stake: i128) -> Result<(), Error> {
+        Ownable::require_admin(&env);
+        let current = AdminRepository::get_stake_bounds(&env);
+        let bounds = StakeBounds::new(current.min, max_stake)?;
+        AdminRepository::set_stake_bounds(&env, &bounds);
+        Ok(())
+    }
+
     pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let admin = AdminRepository::get_admin(&env);
-        admin.require_auth();
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        Ownable::upgrade(&env, new_wasm_hash);
+    }
+}
+
+/// Debugging entrypoints, only compiled with the `game-import` feature (and
+/// in unit tests). Never enable the feature in a deployed build
+#[cfg(any(test, feature = "game-import"))]
+#[contractimpl]
+impl WordleContract {
+    /// Stores `game`, as exported by `get_game`, under `session_id` in place
+    /// of any game there, so a reported stuck session can be reproduced
+    /// locally against the real contract code. The state is taken as is:
+    /// the players' active sessions are not restored
+    pub fn import_game(env: Env, session_id: u32, game: Game) {
+        Ownable::require_admin(&env);
+        infrastructure::storage::GameRepository::replace(&env, session_id, &game);
     }
 }
 
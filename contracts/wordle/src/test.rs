@@ -1,8 +1,16 @@
 #![cfg(test)]
 
-use crate::{Error, GamePhase, WordleContract, WordleContractClient};
-use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Bytes, BytesN, Env, Vec};
+use crate::domain::game::GUESS_REVEAL_WINDOW_LEDGERS;
+use crate::infrastructure::storage::{DataKey, GAME_TTL_LEDGERS};
+use crate::{
+    Error, GameMove, GamePhase, GameStats, GameStatus, HubSeats, Metrics, PlayerStats, Reacted,
+    Reaction, SessionMarker, StorageTier, WordleContract, WordleContractClient,
+};
+use soroban_sdk::testutils::{storage::Temporary as _, Address as _, Events as _, Ledger as _};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, panic_with_error, symbol_short, Address, Bytes, BytesN,
+    Env, Event as _, String, Vec,
+};
 
 // Feedback constants
 const ABSENT: u32 = 0;
@@ -14,6 +22,9 @@ const CORRECT: u32 = 2;
 enum HubDataKey {
     Started(u32),
     Ended(u32),
+    Player1(u32),
+    Player1Won(u32),
+    Failing,
 }
 
 #[contract]
@@ -25,7 +36,7 @@ impl MockGameHub {
         env: Env,
         _game_id: Address,
         session_id: u32,
-        _player1: Address,
+        player1: Address,
         _player2: Address,
         _player1_points: i128,
         _player2_points: i128,
@@ -33,12 +44,30 @@ impl MockGameHub {
         env.storage()
             .persistent()
             .set(&HubDataKey::Started(session_id), &true);
+        env.storage()
+            .persistent()
+            .set(&HubDataKey::Player1(session_id), &player1);
     }
 
-    pub fn end_game(env: Env, session_id: u32, _player1_won: bool) {
+    pub fn end_game(env: Env, session_id: u32, player1_won: bool) {
+        if env
+            .storage()
+            .instance()
+            .get(&HubDataKey::Failing)
+            .unwrap_or(false)
+        {
+            panic!("hub unavailable");
+        }
         env.storage()
             .persistent()
             .set(&HubDataKey::Ended(session_id), &true);
+        env.storage()
+            .persistent()
+            .set(&HubDataKey::Player1Won(session_id), &player1_won);
+    }
+
+    pub fn set_failing(env: Env, failing: bool) {
+        env.storage().instance().set(&HubDataKey::Failing, &failing);
     }
 
     pub fn was_started(env: Env, session_id: u32) -> bool {
@@ -48,6 +77,18 @@ impl MockGameHub {
             .unwrap_or(false)
     }
 
+    pub fn player1(env: Env, session_id: u32) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&HubDataKey::Player1(session_id))
+    }
+
+    pub fn player1_won(env: Env, session_id: u32) -> Option<bool> {
+        env.storage()
+            .persistent()
+            .get(&HubDataKey::Player1Won(session_id))
+    }
+
     pub fn was_ended(env: Env, session_id: u32) -> bool {
         env.storage()
             .persistent()
@@ -62,7 +103,7 @@ pub struct MockVerifier;
 #[contractimpl]
 impl MockVerifier {
     pub fn verify(
-        _env: Env,
+        env: Env,
         _word_commitment: BytesN<32>,
         _public_inputs_hash: BytesN<32>,
         proof_payload: Bytes,
@@ -70,8 +111,39 @@ impl MockVerifier {
         if proof_payload.len() == 0 {
             return false;
         }
-        // Convention for tests: first byte 1 => valid proof
-        proof_payload.get(0).unwrap() == 1
+        // Convention for tests: first byte 1 => valid proof, 2 => abort like a malformed payload
+        match proof_payload.get(0).unwrap() {
+            2 => panic_with_error!(&env, soroban_sdk::Error::from_contract_error(4)),
+            first => first == 1,
+        }
+    }
+
+    pub fn verify_word_commitment(
+        _env: Env,
+        _word_commitment: BytesN<32>,
+        _dictionary_root: BytesN<32>,
+        proof_payload: Bytes,
+    ) -> bool {
+        proof_payload.get(0) == Some(1)
+    }
+
+    pub fn verify_audit(
+        _env: Env,
+        _word_commitment: BytesN<32>,
+        _audit_hash: BytesN<32>,
+        proof_payload: Bytes,
+    ) -> bool {
+        proof_payload.get(0) == Some(1)
+    }
+
+    pub fn verify_pool_word(
+        _env: Env,
+        _word_commitment: BytesN<32>,
+        _pool_root: BytesN<32>,
+        _word_index: u32,
+        proof_payload: Bytes,
+    ) -> bool {
+        proof_payload.get(0) == Some(1)
     }
 }
 
@@ -122,6 +194,36 @@ fn assert_wordle_error<T, E>(
     }
 }
 
+fn failed_error_context(env: &Env) -> Option<(u32, u32)> {
+    use soroban_sdk::xdr::{ContractEventBody, ScVal};
+
+    // Events of failed calls are dropped by `events().all()`, so read the host buffer directly.
+    let events = env.host().get_events().unwrap().0;
+    events.iter().rev().find_map(|e| {
+        if !e.failed_call {
+            return None;
+        }
+        let ContractEventBody::V0(body) = &e.event.body;
+        match body.topics.first() {
+            Some(ScVal::Symbol(name)) if name.to_utf8_string_lossy() == "error_context" => {}
+            _ => return None,
+        }
+        let session_id = match body.topics.get(2) {
+            Some(ScVal::U32(v)) => *v,
+            _ => return None,
+        };
+        let ScVal::Map(Some(map)) = &body.data else {
+            return None;
+        };
+        map.iter().find_map(|entry| match (&entry.key, &entry.val) {
+            (ScVal::Symbol(k), ScVal::U32(code)) if k.to_utf8_string_lossy() == "code" => {
+                Some((session_id, *code))
+            }
+            _ => None,
+        })
+    })
+}
+
 fn valid_proof(env: &Env) -> Bytes {
     Bytes::from_array(env, &[1u8])
 }
@@ -161,6 +263,7 @@ fn resolve_pending(
         feedback,
         &is_correct,
         word_commitment,
+        &client.get_game(&session_id).alphabet_size,
     );
 
     client.resolve_guess(&session_id, word_setter, feedback, &is_correct, proof, &hash);
@@ -242,7 +345,96 @@ fn test_guesser_wins_on_correct_guess() {
     let game = client.get_game(&session_id);
     assert_eq!(game.phase, GamePhase::Ended);
     assert_eq!(game.winner, Some(guesser));
+    assert!(game.hub_notified);
+    assert!(hub.was_ended(&session_id));
+    // The setter sits in the hub's player1 seat by default
+    assert_eq!(hub.player1_won(&session_id), Some(false));
+}
+
+#[test]
+fn test_seated_game_reports_result_by_hub_seat() {
+    let (env, client, hub, word_setter, guesser, word_commitment) = setup_test();
+
+    let stranger = Address::generate(&env);
+    let bad_seats = HubSeats {
+        player1: guesser.clone(),
+        player2: stranger,
+    };
+    let result = client.try_start_seated_game(&6, &word_setter, &guesser, &1, &1, &bad_seats);
+    assert_wordle_error(&result, Error::InvalidHubSeats);
+
+    // The guesser takes the hub's player1 seat
+    let session_id = 6u32;
+    let seats = HubSeats {
+        player1: guesser.clone(),
+        player2: word_setter.clone(),
+    };
+    client.start_seated_game(&session_id, &word_setter, &guesser, &1, &2, &seats);
+    assert_eq!(hub.player1(&session_id), Some(guesser.clone()));
+    assert_eq!(client.get_game(&session_id).hub_seats, seats);
+
+    client.commit_word(&session_id, &word_setter, &word_commitment);
+    let guess = make_guess(&env, [0, 1, 2, 3, 4]);
+    client.guess(&session_id, &guesser, &guess);
+    let feedback = make_feedback(&env, [CORRECT, CORRECT, CORRECT, CORRECT, CORRECT]);
+    resolve_pending(
+        &client,
+        session_id,
+        &word_setter,
+        &guesser,
+        &guess,
+        &feedback,
+        true,
+        &word_commitment,
+        &valid_proof(&env),
+    );
+
+    assert_eq!(client.get_game(&session_id).winner, Some(guesser));
+    assert_eq!(hub.player1_won(&session_id), Some(true));
+}
+
+#[test]
+fn test_failed_hub_notification_is_retried() {
+    let (env, client, hub, word_setter, guesser, word_commitment) = setup_test();
+
+    let session_id = 3u32;
+    client.start_game(&session_id, &word_setter, &guesser, &1, &1);
+    client.commit_word(&session_id, &word_setter, &word_commitment);
+    hub.set_failing(&true);
+
+    let guess = make_guess(&env, [0, 1, 2, 3, 4]);
+    client.guess(&session_id, &guesser, &guess);
+    let feedback = make_feedback(&env, [CORRECT, CORRECT, CORRECT, CORRECT, CORRECT]);
+    resolve_pending(
+        &client,
+        session_id,
+        &word_setter,
+        &guesser,
+        &guess,
+        &feedback,
+        true,
+        &word_commitment,
+        &valid_proof(&env),
+    );
+
+    // The win is recorded even though the hub rejected end_game
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, GamePhase::Ended);
+    assert_eq!(game.winner, Some(guesser));
+    assert!(!game.hub_notified);
+    assert!(!hub.was_ended(&session_id));
+
+    // While the hub keeps failing the retry reverts
+    assert!(client.try_retry_hub_notify(&session_id).is_err());
+    assert!(!client.get_game(&session_id).hub_notified);
+
+    hub.set_failing(&false);
+    client.retry_hub_notify(&session_id);
+    assert!(client.get_game(&session_id).hub_notified);
     assert!(hub.was_ended(&session_id));
+
+    let again = client.try_retry_hub_notify(&session_id);
+    assert_wordle_error(&again, Error::HubAlreadyNotified);
 }
 
 #[test]
@@ -359,6 +551,7 @@ fn test_reject_invalid_hash_or_proof() {
         &feedback,
         &false,
         &word_commitment,
+        &26,
     );
     let bad_proof_result = client.try_resolve_guess(
         &session_id,
@@ -471,12 +664,18 @@ fn test_feedback_with_present_and_correct() {
 
 #[test]
 fn test_rules_expose_wordle_settings() {
-    let (_env, client, _hub, _word_setter, _guesser, _word_commitment) = setup_test();
+    let (_env, client, _hub, word_setter, guesser, _word_commitment) = setup_test();
+
+    let session_id = 12u32;
+    client.start_game(&session_id, &word_setter, &guesser, &1, &1);
 
-    let rules = client.get_rules();
+    let rules = client.get_rules(&session_id);
     assert_eq!(rules.word_length, 5);
     assert_eq!(rules.max_guesses, 6);
     assert_eq!(rules.alphabet_size, 26);
+
+    let missing = client.try_get_rules(&999u32);
+    assert_wordle_error(&missing, Error::GameNotFound);
 }
 
 #[test]
@@ -539,3 +738,1429 @@ fn test_invalid_feedback_value_rejected() {
     );
     assert_wordle_error(&result, Error::InvalidFeedbackValue);
 }
+
+#[test]
+fn test_failed_call_publishes_error_context() {
+    let (env, client, _hub, word_setter, guesser, word_commitment) = setup_test();
+
+    let session_id = 20u32;
+    client.start_game(&session_id, &word_setter, &guesser, &1, &1);
+    client.commit_word(&session_id, &word_setter, &word_commitment);
+
+    let result = client.try_guess(&session_id, &guesser, &make_guess(&env, [0, 1, 2, 3, 26]));
+    assert_wordle_error(&result, Error::InvalidLetterValue);
+    assert_eq!(
+        failed_error_context(&env),
+        Some((session_id, Error::InvalidLetterValue as u32))
+    );
+}
+
+#[test]
+fn test_max_active_games_per_player() {
+    let (env, client, _hub, word_setter, guesser, word_commitment) = setup_test();
+    let other = Address::generate(&env);
+
+    assert_eq!(client.get_max_active_games(), 0);
+    client.set_max_active_games(&1);
+
+    client.start_game(&21, &word_setter, &guesser, &1, &1);
+    assert_eq!(client.get_active_sessions(&word_setter).len(), 1);
+    assert_eq!(client.get_active_sessions(&guesser).len(), 1);
+
+    let result = client.try_start_game(&22, &guesser, &other, &1, &1);
+    assert_wordle_error(&result, Error::TooManyActiveGames);
+
+    // Ending the game releases the slot for both players.
+    client.commit_word(&21, &word_setter, &word_commitment);
+    let guess = make_guess(&env, [0, 1, 2, 3, 4]);
+    client.guess(&21, &guesser, &guess);
+    let feedback = make_feedback(&env, [CORRECT, CORRECT, CORRECT, CORRECT, CORRECT]);
+    resolve_pending(
+        &client,
+        21,
+        &word_setter,
+        &guesser,
+        &guess,
+        &feedback,
+        true,
+        &word_commitment,
+        &valid_proof(&env),
+    );
+    assert_eq!(client.get_active_sessions(&guesser).len(), 0);
+
+    client.start_game(&22, &guesser, &other, &1, &1);
+}
+
+#[test]
+fn test_start_game_enforces_stake_bounds() {
+    let (env, client, _hub, word_setter, guesser, _word_commitment) = setup_test();
+
+    assert_eq!(client.get_min_stake(), 1);
+    assert_eq!(client.get_max_stake(), 0);
+    let result = client.try_start_game(&31, &word_setter, &guesser, &0, &1);
+    assert_wordle_error(&result, Error::InvalidStake);
+
+    client.set_min_stake(&10);
+    client.set_max_stake(&100);
+    let result = client.try_start_game(&31, &word_setter, &guesser, &10, &101);
+    assert_wordle_error(&result, Error::InvalidStake);
+    let co_guesser = Address::generate(&env);
+    let result = client.try_start_coop_game(&31, &word_setter, &guesser, &co_guesser, &9, &10);
+    assert_wordle_error(&result, Error::InvalidStake);
+    client.start_game(&31, &word_setter, &guesser, &10, &100);
+
+    // Bounds stay positive and ordered; a max of 0 lifts the cap
+    assert_wordle_error(&client.try_set_min_stake(&0), Error::InvalidStake);
+    assert_wordle_error(&client.try_set_min_stake(&101), Error::InvalidStake);
+    assert_wordle_error(&client.try_set_max_stake(&9), Error::InvalidStake);
+    client.set_max_stake(&0);
+    client.start_game(&32, &word_setter, &guesser, &10, &1_000_000);
+
+    // Practice games stake nothing
+    client.start_practice_game(&33, &word_setter, &guesser);
+}
+
+#[test]
+fn test_commit_word_with_proof() {
+    let (env, client, _hub, word_setter, guesser, word_commitment) = setup_test();
+
+    let session_id = 40u32;
+    client.start_game(&session_id, &word_setter, &guesser, &1, &1);
+
+    let bad = client.try_commit_word_with_proof(
+        &session_id,
+        &word_setter,
+        &word_commitment,
+        &invalid_proof(&env),
+    );
+    assert_wordle_error(&bad, Error::InvalidProof);
+    assert_eq!(
+        client.get_game(&session_id).phase,
+        GamePhase::WaitingForWord
+    );
+
+    client.commit_word_with_proof(
+        &session_id,
+        &word_setter,
+        &word_commitment,
+        &valid_proof(&env),
+    );
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, GamePhase::InProgress);
+    assert_eq!(game.word_commitment, Some(word_commitment));
+    assert!(game.word_proven);
+}
+
+#[test]
+fn test_require_word_proof_blocks_plain_commit() {
+    let (env, client, _hub, word_setter, guesser, word_commitment) = setup_test();
+
+    let root = BytesN::from_array(&env, &[5u8; 32]);
+    client.set_dictionary_root(&root);
+    assert_eq!(client.get_dictionary_root(), root);
+
+    client.set_require_word_proof(&true);
+    assert!(client.get_require_word_proof());
+
+    let session_id = 41u32;
+    client.start_game(&session_id, &word_setter, &guesser, &1, &1);
+
+    let result = client.try_commit_word(&session_id, &word_setter, &word_commitment);
+    assert_wordle_error(&result, Error::WordProofRequired);
+
+    client.commit_word_with_proof(
+        &session_id,
+        &word_setter,
+        &word_commitment,
+        &valid_proof(&env),
+    );
+    assert!(client.get_game(&session_id).word_proven);
+}
+
+#[test]
+fn test_word_pool_draws_word_from_both_seeds() {
+    let (env, client, _hub, word_setter, guesser, word_commitment) = setup_test();
+
+    let session_id = 67u32;
+    client.start_game(&session_id, &word_setter, &guesser, &1, &1);
+
+    let pool_root = BytesN::from_array(&env, &[4u8; 32]);
+    let setter_seed = BytesN::from_array(&env, &[8u8; 32]);
+    let guesser_seed = BytesN::from_array(&env, &[9u8; 32]);
+    let seed_commitment =
+        client.build_pool_seed_commitment(&session_id, &word_setter, &setter_seed);
+
+    let empty =
+        client.try_commit_word_pool(&session_id, &word_setter, &pool_root, &0, &seed_commitment);
+    assert_wordle_error(&empty, Error::InvalidWordPoolSize);
+    client.commit_word_pool(
+        &session_id,
+        &word_setter,
+        &pool_root,
+        &1000,
+        &seed_commitment,
+    );
+
+    // The word can no longer be picked freely
+    let plain = client.try_commit_word(&session_id, &word_setter, &word_commitment);
+    assert_wordle_error(&plain, Error::WordPoolRequired);
+
+    // The setter's seed only opens after the guesser's is in
+    let early = client.try_reveal_pool_seed(&session_id, &word_setter, &setter_seed);
+    assert_wordle_error(&early, Error::SeedNotContributed);
+    client.contribute_pool_seed(&session_id, &guesser, &guesser_seed);
+    let again = client.try_contribute_pool_seed(&session_id, &guesser, &setter_seed);
+    assert_wordle_error(&again, Error::SeedAlreadyContributed);
+
+    let not_drawn = client.try_commit_pool_word(
+        &session_id,
+        &word_setter,
+        &word_commitment,
+        &valid_proof(&env),
+    );
+    assert_wordle_error(&not_drawn, Error::WordIndexNotDrawn);
+
+    let wrong = client.try_reveal_pool_seed(&session_id, &word_setter, &guesser_seed);
+    assert_wordle_error(&wrong, Error::InvalidSeedReveal);
+    let word_index = client.reveal_pool_seed(&session_id, &word_setter, &setter_seed);
+
+    let mut preimage = Bytes::from_array(&env, &setter_seed.to_array());
+    preimage.extend_from_array(&guesser_seed.to_array());
+    let digest = env.crypto().keccak256(&preimage).to_array();
+    let expected = u32::from_be_bytes([digest[0], digest[1], digest[2], digest[3]]) % 1000;
+    assert_eq!(word_index, expected);
+
+    let bad = client.try_commit_pool_word(
+        &session_id,
+        &word_setter,
+        &word_commitment,
+        &invalid_proof(&env),
+    );
+    assert_wordle_error(&bad, Error::InvalidProof);
+    client.commit_pool_word(
+        &session_id,
+        &word_setter,
+        &word_commitment,
+        &valid_proof(&env),
+    );
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, GamePhase::InProgress);
+    assert!(game.word_proven);
+    let pool = game.word_pool;
+    assert_eq!(pool.word_index, Some(word_index));
+    assert_eq!(pool.setter_seed, Some(setter_seed));
+}
+
+#[test]
+fn test_finalize_with_audit() {
+    let (env, client, _hub, word_setter, guesser, word_commitment) = setup_test();
+
+    let session_id = 42u32;
+    client.start_game(&session_id, &word_setter, &guesser, &1, &1);
+    client.commit_word(&session_id, &word_setter, &word_commitment);
+
+    let guess = make_guess(&env, [7, 4, 11, 11, 14]);
+    client.guess(&session_id, &guesser, &guess);
+
+    // Audits are only accepted once the game is over
+    let early = client.try_finalize_with_audit(&session_id, &valid_proof(&env));
+    assert_wordle_error(&early, Error::GameNotEnded);
+
+    let feedback = make_feedback(&env, [CORRECT, CORRECT, CORRECT, CORRECT, CORRECT]);
+    resolve_pending(
+        &client,
+        session_id,
+        &word_setter,
+        &guesser,
+        &guess,
+        &feedback,
+        true,
+        &word_commitment,
+        &valid_proof(&env),
+    );
+
+    let hash = client.build_audit_hash(&session_id);
+    assert_ne!(hash, BytesN::from_array(&env, &[0u8; 32]));
+
+    let bad = client.try_finalize_with_audit(&session_id, &invalid_proof(&env));
+    assert_wordle_error(&bad, Error::InvalidProof);
+    assert!(!client.get_game(&session_id).audited);
+
+    client.finalize_with_audit(&session_id, &valid_proof(&env));
+    assert!(client.get_game(&session_id).audited);
+
+    let again = client.try_finalize_with_audit(&session_id, &valid_proof(&env));
+    assert_wordle_error(&again, Error::AlreadyAudited);
+}
+
+#[test]
+fn test_share_grid_renders_feedback_history() {
+    let (env, client, _hub, word_setter, guesser, word_commitment) = setup_test();
+
+    let session_id = 43u32;
+    client.start_game(&session_id, &word_setter, &guesser, &1, &1);
+    client.commit_word(&session_id, &word_setter, &word_commitment);
+
+    let first = make_guess(&env, [7, 4, 11, 11, 14]);
+    client.guess(&session_id, &guesser, &first);
+
+    let early = client.try_get_share_grid(&session_id);
+    assert_wordle_error(&early, Error::GameNotEnded);
+
+    let feedback = make_feedback(&env, [CORRECT, PRESENT, ABSENT, ABSENT, ABSENT]);
+    resolve_pending(
+        &client,
+        session_id,
+        &word_setter,
+        &guesser,
+        &first,
+        &feedback,
+        false,
+        &word_commitment,
+        &valid_proof(&env),
+    );
+
+    let second = make_guess(&env, [7, 0, 15, 15, 24]);
+    client.guess(&session_id, &guesser, &second);
+    let feedback = make_feedback(&env, [CORRECT, CORRECT, CORRECT, CORRECT, CORRECT]);
+    resolve_pending(
+        &client,
+        session_id,
+        &word_setter,
+        &guesser,
+        &second,
+        &feedback,
+        true,
+        &word_commitment,
+        &valid_proof(&env),
+    );
+
+    let expected = Bytes::from_slice(&env, "Wordle #43 2/6\n\n🟩🟨⬛⬛⬛\n🟩🟩🟩🟩🟩".as_bytes());
+    assert_eq!(client.get_share_grid(&session_id), expected);
+}
+
+#[test]
+fn test_cyrillic_alphabet_session() {
+    let (env, client, _hub, word_setter, guesser, word_commitment) = setup_test();
+
+    let invalid = client.try_start_game_with_alphabet(&40u32, &word_setter, &guesser, &1, &1, &65);
+    assert_wordle_error(&invalid, Error::InvalidAlphabetSize);
+
+    let session_id = 41u32;
+    client.start_game_with_alphabet(&session_id, &word_setter, &guesser, &1, &1, &33);
+    assert_eq!(client.get_rules(&session_id).alphabet_size, 33);
+    client.commit_word(&session_id, &word_setter, &word_commitment);
+
+    // Letter 33 is outside the 33-letter alphabet, 32 is its last letter
+    let outside = client.try_guess(&session_id, &guesser, &make_guess(&env, [0, 1, 2, 3, 33]));
+    assert_wordle_error(&outside, Error::InvalidLetterValue);
+
+    let guess = make_guess(&env, [30, 31, 32, 26, 27]);
+    client.guess(&session_id, &guesser, &guess);
+
+    let feedback = make_feedback(&env, [ABSENT, ABSENT, ABSENT, ABSENT, ABSENT]);
+    let latin_hash = client.build_public_inputs_hash(
+        &session_id,
+        &word_setter,
+        &guesser,
+        &guess,
+        &feedback,
+        &false,
+        &word_commitment,
+        &26,
+    );
+    let wrong_alphabet = client.try_resolve_guess(
+        &session_id,
+        &word_setter,
+        &feedback,
+        &false,
+        &valid_proof(&env),
+        &latin_hash,
+    );
+    assert_wordle_error(&wrong_alphabet, Error::InvalidPublicInputsHash);
+
+    resolve_pending(
+        &client,
+        session_id,
+        &word_setter,
+        &guesser,
+        &guess,
+        &feedback,
+        false,
+        &word_commitment,
+        &valid_proof(&env),
+    );
+    assert_eq!(client.get_game(&session_id).guess_count, 1);
+}
+
+#[test]
+fn test_session_key_signs_moves_until_revoked() {
+    let (env, client, _hub, word_setter, guesser, word_commitment) = setup_test();
+
+    let session_id = 42u32;
+    client.start_game(&session_id, &word_setter, &guesser, &1, &1);
+    client.commit_word(&session_id, &word_setter, &word_commitment);
+
+    let hot_key = Address::generate(&env);
+    let expired = client.try_authorize_session_key(&session_id, &guesser, &hot_key, &100);
+    assert_wordle_error(&expired, Error::InvalidSessionKey);
+    let outsider = client.try_authorize_session_key(&session_id, &hot_key, &hot_key, &200);
+    assert_wordle_error(&outsider, Error::NotPlayer);
+
+    client.authorize_session_key(&session_id, &guesser, &hot_key, &200);
+    assert_eq!(
+        client.get_session_key(&session_id, &guesser).unwrap().key,
+        hot_key
+    );
+
+    let guess = make_guess(&env, [0, 1, 2, 3, 4]);
+    client.guess(&session_id, &guesser, &guess);
+    assert_eq!(env.auths().first().unwrap().0, hot_key);
+
+    // The word setter has no session key and signs the resolution itself
+    let feedback = make_feedback(&env, [ABSENT, ABSENT, ABSENT, ABSENT, ABSENT]);
+    resolve_pending(
+        &client,
+        session_id,
+        &word_setter,
+        &guesser,
+        &guess,
+        &feedback,
+        false,
+        &word_commitment,
+        &valid_proof(&env),
+    );
+    assert_eq!(env.auths().first().unwrap().0, word_setter);
+
+    client.revoke_session_key(&session_id, &guesser);
+    assert_eq!(client.get_session_key(&session_id, &guesser), None);
+    client.guess(&session_id, &guesser, &make_guess(&env, [5, 6, 7, 8, 9]));
+    assert_eq!(env.auths().first().unwrap().0, guesser);
+}
+
+#[test]
+fn test_expired_guesses_strike_the_word_setter() {
+    let (env, client, hub, word_setter, guesser, word_commitment) = setup_test();
+
+    let session_id = 43u32;
+    client.start_game(&session_id, &word_setter, &guesser, &1, &1);
+    client.commit_word(&session_id, &word_setter, &word_commitment);
+    client.guess(&session_id, &guesser, &make_guess(&env, [0, 1, 2, 3, 4]));
+
+    // Games started without an expiry window keep the guess pending
+    let disabled = client.try_expire_pending_guess(&session_id);
+    assert_wordle_error(&disabled, Error::GuessExpiryDisabled);
+
+    client.set_guess_expiry_ledgers(&10);
+    assert_eq!(client.get_guess_expiry_ledgers(), 10);
+
+    let session_id = 44u32;
+    client.start_game(&session_id, &word_setter, &guesser, &1, &1);
+    client.commit_word(&session_id, &word_setter, &word_commitment);
+
+    for strike in 1..=3u32 {
+        client.guess(&session_id, &guesser, &make_guess(&env, [0, 1, 2, 3, 4]));
+        let early = client.try_expire_pending_guess(&session_id);
+        assert_wordle_error(&early, Error::GuessNotExpired);
+
+        let ledger = env.ledger().sequence() + 10;
+        env.ledger().set_sequence_number(ledger);
+        assert_eq!(client.expire_pending_guess(&session_id), strike);
+
+        let game = client.get_game(&session_id);
+        assert_eq!(game.guess_count, 0);
+        assert_eq!(game.pending_guess, None);
+    }
+
+    // Third strike forfeits the game to the guesser
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, GamePhase::Ended);
+    assert_eq!(game.winner, Some(guesser.clone()));
+    assert!(hub.was_ended(&session_id));
+}
+
+#[test]
+fn test_game_interface_status_and_hub_cancel() {
+    let (env, client, hub, word_setter, guesser, word_commitment) = setup_test();
+
+    let session_id = 41u32;
+    assert_eq!(
+        client.start_game(&session_id, &word_setter, &guesser, &1, &1),
+        session_id
+    );
+    assert_eq!(client.get_game_status(&session_id), GameStatus::Setup);
+    client.commit_word(&session_id, &word_setter, &word_commitment);
+    client.guess(&session_id, &guesser, &make_guess(&env, [0, 1, 2, 3, 4]));
+    assert_eq!(client.get_game_status(&session_id), GameStatus::InProgress);
+    assert_eq!(client.evaluate_game(&session_id), None);
+
+    // Only the hub can cancel, and it is not notified back
+    client.cancel_game(&session_id);
+    assert_eq!(env.auths().first().unwrap().0, hub.address);
+    assert!(!hub.was_ended(&session_id));
+    assert_eq!(client.get_game_status(&session_id), GameStatus::Cancelled);
+    assert_eq!(client.evaluate_game(&session_id), None);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.phase, GamePhase::Ended);
+    assert_eq!(game.pending_guess, None);
+    assert!(game.hub_notified);
+    assert!(client.get_active_sessions(&guesser).is_empty());
+
+    let again = client.try_cancel_game(&session_id);
+    assert_wordle_error(&again, Error::GameAlreadyEnded);
+    let missing = client.try_get_game_status(&(session_id + 1));
+    assert_wordle_error(&missing, Error::GameNotFound);
+}
+
+#[test]
+fn test_save_extends_ttl_of_new_guess_only() {
+    let (env, client, _hub, word_setter, guesser, word_commitment) = setup_test();
+    env.ledger().set_min_temp_entry_ttl(16);
+
+    let session_id = 69u32;
+    client.start_game(&session_id, &word_setter, &guesser, &1, &1);
+    client.commit_word(&session_id, &word_setter, &word_commitment);
+
+    let feedback = make_feedback(&env, [CORRECT, PRESENT, ABSENT, ABSENT, ABSENT]);
+    for (i, letters) in [[7, 4, 11, 11, 14], [7, 0, 15, 15, 24]].iter().enumerate() {
+        if i == 1 {
+            env.ledger()
+                .set_sequence_number(env.ledger().sequence() + 1_000);
+        }
+        let guess = make_guess(&env, *letters);
+        client.guess(&session_id, &guesser, &guess);
+        resolve_pending(
+            &client,
+            session_id,
+            &word_setter,
+            &guesser,
+            &guess,
+            &feedback,
+            false,
+            &word_commitment,
+            &valid_proof(&env),
+        );
+    }
+
+    // The first guess kept the TTL it was written with
+    env.as_contract(&client.address, || {
+        let ttl = |key: DataKey| env.storage().temporary().get_ttl(&key);
+        assert_eq!(ttl(DataKey::Guess(session_id, 0)), GAME_TTL_LEDGERS - 1_000);
+        assert_eq!(ttl(DataKey::Guess(session_id, 1)), GAME_TTL_LEDGERS);
+        assert_eq!(ttl(DataKey::Game(session_id)), GAME_TTL_LEDGERS);
+    });
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.guesses.len(), 2);
+    assert_eq!(game.guess_actors.len(), 2);
+    assert_eq!(game.feedbacks.get(1), Some(feedback));
+}
+
+#[test]
+fn test_evaluate_game_settles_overdue_guesses() {
+    let (env, client, hub, word_setter, guesser, word_commitment) = setup_test();
+
+    client.set_guess_expiry_ledgers(&10);
+    let session_id = 42u32;
+    client.start_game(&session_id, &word_setter, &guesser, &1, &1);
+    client.commit_word(&session_id, &word_setter, &word_commitment);
+
+    for strike in 1..=3u32 {
+        client.guess(&session_id, &guesser, &make_guess(&env, [0, 1, 2, 3, 4]));
+
+        // Within the window evaluation leaves the pending guess alone
+        assert_eq!(client.evaluate_game(&session_id), None);
+        assert!(client.get_game(&session_id).pending_guess.is_some());
+
+        let ledger = env.ledger().sequence() + 10;
+        env.ledger().set_sequence_number(ledger);
+        let winner = client.evaluate_game(&session_id);
+        let game = client.get_game(&session_id);
+        assert_eq!(game.setter_strikes, strike);
+        assert_eq!(game.pending_guess, None);
+        if strike < 3 {
+            assert_eq!(winner, None);
+            assert_eq!(client.get_game_status(&session_id), GameStatus::InProgress);
+        } else {
+            assert_eq!(winner, Some(guesser.clone()));
+        }
+    }
+
+    assert_eq!(client.get_game_status(&session_id), GameStatus::Ended);
+    assert!(hub.was_ended(&session_id));
+}
+
+#[test]
+fn test_finalize_expired_adjudicates_the_stalled_party() {
+    let (env, client, hub, word_setter, guesser, word_commitment) = setup_test();
+
+    // Games started without a deadline never expire
+    let session_id = 50u32;
+    client.start_game(&session_id, &word_setter, &guesser, &1, &1);
+    let disabled = client.try_finalize_expired(&session_id);
+    assert_wordle_error(&disabled, Error::DeadlineDisabled);
+
+    client.set_game_duration_ledgers(&100);
+    assert_eq!(client.get_game_duration_ledgers(), 100);
+    let deadline = env.ledger().sequence() + 100;
+
+    // Unresolved guess: the word setter loses
+    let pending = 51u32;
+    client.start_game(&pending, &word_setter, &guesser, &1, &1);
+    assert_eq!(client.get_game(&pending).deadline_ledger, deadline);
+    client.commit_word(&pending, &word_setter, &word_commitment);
+    client.guess(&pending, &guesser, &make_guess(&env, [0, 1, 2, 3, 4]));
+
+    // Guesser's turn with nothing pending: the guesser loses
+    let idle = 52u32;
+    client.start_game(&idle, &guesser, &word_setter, &1, &1);
+    client.commit_word(&idle, &guesser, &word_commitment);
+
+    // Word never committed: the word setter loses, through evaluate_game too
+    let uncommitted = 53u32;
+    client.start_game(&uncommitted, &word_setter, &guesser, &1, &1);
+
+    env.ledger().set_sequence_number(deadline);
+    let early = client.try_finalize_expired(&pending);
+    assert_wordle_error(&early, Error::DeadlineNotReached);
+    assert_eq!(client.evaluate_game(&uncommitted), None);
+
+    env.ledger().set_sequence_number(deadline + 1);
+    assert_eq!(client.finalize_expired(&pending), guesser);
+    let game = client.get_game(&pending);
+    assert_eq!(game.phase, GamePhase::Ended);
+    assert_eq!(game.pending_guess, None);
+    assert!(game.hub_notified);
+    assert_eq!(hub.player1_won(&pending), Some(false));
+
+    assert_eq!(client.finalize_expired(&idle), guesser);
+    assert_eq!(hub.player1_won(&idle), Some(true));
+
+    assert_eq!(client.evaluate_game(&uncommitted), Some(guesser.clone()));
+    assert_eq!(client.get_game_status(&uncommitted), GameStatus::Ended);
+    assert!(client.get_active_sessions(&guesser).contains(session_id));
+    assert!(!client.get_active_sessions(&guesser).contains(uncommitted));
+
+    let again = client.try_finalize_expired(&pending);
+    assert_wordle_error(&again, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_committed_guess_is_revealed_before_resolution() {
+    let (env, client, hub, word_setter, guesser, word_commitment) = setup_test();
+
+    let session_id = 64u32;
+    client.start_game(&session_id, &word_setter, &guesser, &1, &1);
+    client.commit_word(&session_id, &word_setter, &word_commitment);
+
+    let guess = make_guess(&env, [7, 4, 11, 11, 14]);
+    let salt = BytesN::from_array(&env, &[9u8; 32]);
+    let commitment = client.build_guess_commitment(&session_id, &guesser, &guess, &salt);
+    client.commit_guess(&session_id, &guesser, &commitment);
+
+    // The letters stay hidden and the turn is taken until the reveal
+    let committed = client.get_game(&session_id);
+    assert_eq!(committed.guess_commitment, Some(commitment.clone()));
+    assert_eq!(committed.pending_guess, None);
+    let second = client.try_guess(&session_id, &guesser, &guess);
+    assert_wordle_error(&second, Error::PendingGuessExists);
+    let feedback = make_feedback(&env, [ABSENT, ABSENT, ABSENT, ABSENT, ABSENT]);
+    let hash = client.build_public_inputs_hash(
+        &session_id,
+        &word_setter,
+        &guesser,
+        &guess,
+        &feedback,
+        &false,
+        &word_commitment,
+        &26,
+    );
+    let early = client.try_resolve_guess(
+        &session_id,
+        &word_setter,
+        &feedback,
+        &false,
+        &valid_proof(&env),
+        &hash,
+    );
+    assert_wordle_error(&early, Error::GuessNotRevealed);
+
+    let other = make_guess(&env, [7, 4, 11, 11, 15]);
+    let wrong = client.try_reveal_guess(&session_id, &guesser, &other, &salt);
+    assert_wordle_error(&wrong, Error::InvalidGuessReveal);
+    let open = client.try_expire_guess_commitment(&session_id);
+    assert_wordle_error(&open, Error::RevealWindowOpen);
+
+    client.reveal_guess(&session_id, &guesser, &guess, &salt);
+    let revealed = client.get_game(&session_id);
+    assert_eq!(revealed.guess_commitment, None);
+    assert_eq!(revealed.pending_guess, Some(guess.clone()));
+    resolve_pending(
+        &client,
+        session_id,
+        &word_setter,
+        &guesser,
+        &guess,
+        &feedback,
+        false,
+        &word_commitment,
+        &valid_proof(&env),
+    );
+    let resolved = client.get_game(&session_id);
+    assert_eq!(resolved.guesses.get(0), Some(guess.clone()));
+    // Word commit, guess commit, reveal and resolution
+    assert_eq!(resolved.move_count, 4);
+
+    // A commitment left unrevealed past the window forfeits the game
+    client.commit_guess(&session_id, &guesser, &commitment);
+    let opened_at = env.ledger().sequence();
+    env.ledger()
+        .set_sequence_number(opened_at + GUESS_REVEAL_WINDOW_LEDGERS + 1);
+    let late = client.try_reveal_guess(&session_id, &guesser, &guess, &salt);
+    assert_wordle_error(&late, Error::RevealWindowClosed);
+
+    assert_eq!(client.evaluate_game(&session_id), Some(word_setter.clone()));
+    let ended = client.get_game(&session_id);
+    assert_eq!(ended.phase, GamePhase::Ended);
+    assert_eq!(ended.guess_commitment, None);
+    assert_eq!(hub.player1_won(&session_id), Some(true));
+}
+
+#[test]
+fn test_guesser_retracts_pending_guess_once() {
+    let (env, client, _hub, word_setter, guesser, word_commitment) = setup_test();
+
+    let session_id = 45u32;
+    client.start_game(&session_id, &word_setter, &guesser, &1, &1);
+    client.commit_word(&session_id, &word_setter, &word_commitment);
+
+    let no_guess = client.try_retract_guess(&session_id, &guesser);
+    assert_wordle_error(&no_guess, Error::NoPendingGuess);
+
+    let misclick = make_guess(&env, [0, 1, 2, 3, 4]);
+    client.guess(&session_id, &guesser, &misclick);
+    let not_guesser = client.try_retract_guess(&session_id, &word_setter);
+    assert_wordle_error(&not_guesser, Error::NotGuesser);
+
+    env.ledger().set_sequence_number(103);
+    client.retract_guess(&session_id, &guesser);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.pending_guess, None);
+    assert_eq!(game.guess_count, 0);
+    assert_eq!(game.retracted_guess, Some(misclick));
+    assert_eq!(game.retracted_at_ledger, 103);
+
+    // One retraction per game
+    client.guess(&session_id, &guesser, &make_guess(&env, [5, 6, 7, 8, 9]));
+    let used = client.try_retract_guess(&session_id, &guesser);
+    assert_wordle_error(&used, Error::RetractionUsed);
+
+    // The window closes `GUESS_RETRACT_WINDOW_LEDGERS` after the guess
+    let session_id = 46u32;
+    client.start_game(&session_id, &word_setter, &guesser, &1, &1);
+    client.commit_word(&session_id, &word_setter, &word_commitment);
+    client.guess(&session_id, &guesser, &make_guess(&env, [0, 1, 2, 3, 4]));
+    env.ledger().set_sequence_number(107);
+    let late = client.try_retract_guess(&session_id, &guesser);
+    assert_wordle_error(&late, Error::RetractWindowClosed);
+}
+
+#[test]
+fn test_check_resolution_diagnoses_without_resolving() {
+    let (env, client, _hub, word_setter, guesser, word_commitment) = setup_test();
+
+    let session_id = 47u32;
+    client.start_game(&session_id, &word_setter, &guesser, &1, &1);
+    client.commit_word(&session_id, &word_setter, &word_commitment);
+
+    let feedback = make_feedback(&env, [CORRECT, ABSENT, PRESENT, ABSENT, ABSENT]);
+    let zero_hash = BytesN::from_array(&env, &[0u8; 32]);
+    let proof = Bytes::from_array(&env, &[1u8]);
+    let idle = client.check_resolution(&session_id, &feedback, &false, &proof, &zero_hash);
+    assert_eq!(idle.error_code, Error::NoPendingGuess as u32);
+    assert_eq!(idle.word_commitment, Some(word_commitment.clone()));
+
+    let guess_letters = make_guess(&env, [0, 1, 2, 3, 4]);
+    client.guess(&session_id, &guesser, &guess_letters);
+    let hash = client.build_public_inputs_hash(
+        &session_id,
+        &word_setter,
+        &guesser,
+        &guess_letters,
+        &feedback,
+        &false,
+        &word_commitment,
+        &26,
+    );
+
+    // A hash built for another outcome is reported with the one the contract expects
+    let mismatch = client.check_resolution(&session_id, &feedback, &true, &proof, &hash);
+    assert_eq!(mismatch.error_code, Error::InvalidPublicInputsHash as u32);
+    assert_eq!(mismatch.guess, Some(guess_letters.clone()));
+    assert!(!mismatch.hash_matches);
+    assert_ne!(mismatch.expected_hash, Some(hash.clone()));
+
+    let bad_feedback = make_feedback(&env, [3, 0, 0, 0, 0]);
+    let invalid = client.check_resolution(&session_id, &bad_feedback, &false, &proof, &hash);
+    assert_eq!(invalid.error_code, Error::InvalidFeedbackValue as u32);
+
+    let rejected = Bytes::from_array(&env, &[0u8]);
+    let rejected = client.check_resolution(&session_id, &feedback, &false, &rejected, &hash);
+    assert_eq!(rejected.error_code, Error::InvalidProof as u32);
+    assert!(rejected.hash_matches);
+    assert_eq!(rejected.verifier_error_code, 0);
+
+    let malformed = Bytes::from_array(&env, &[2u8]);
+    let aborted = client.check_resolution(&session_id, &feedback, &false, &malformed, &hash);
+    assert_eq!(aborted.error_code, Error::InvalidProof as u32);
+    assert_eq!(aborted.verifier_error_code, 4);
+
+    let ok = client.check_resolution(&session_id, &feedback, &false, &proof, &hash);
+    assert_eq!(ok.error_code, 0);
+    assert!(ok.proof_valid);
+
+    // The dry runs left the guess pending
+    let game = client.get_game(&session_id);
+    assert_eq!(game.pending_guess, Some(guess_letters));
+    assert_eq!(game.guess_count, 0);
+    client.resolve_guess(&session_id, &word_setter, &feedback, &false, &proof, &hash);
+}
+
+#[test]
+fn test_practice_game_computes_feedback_on_chain() {
+    let (env, client, hub, word_setter, guesser, word_commitment) = setup_test();
+
+    let session_id = 60u32;
+    client.start_practice_game(&session_id, &word_setter, &guesser);
+    assert!(!hub.was_started(&session_id));
+    let committed = client.try_commit_word(&session_id, &word_setter, &word_commitment);
+    assert_wordle_error(&committed, Error::PracticeGameUnsupported);
+
+    // HELLO, in clear
+    let word = make_guess(&env, [7, 4, 11, 11, 14]);
+    client.commit_practice_word(&session_id, &word_setter, &word);
+
+    // HOLES
+    client.guess(&session_id, &guesser, &make_guess(&env, [7, 14, 11, 4, 18]));
+    let claimed = client.try_resolve_guess(
+        &session_id,
+        &word_setter,
+        &make_feedback(&env, [ABSENT; 5]),
+        &false,
+        &valid_proof(&env),
+        &BytesN::from_array(&env, &[0u8; 32]),
+    );
+    assert_wordle_error(&claimed, Error::PracticeGameUnsupported);
+    let result = client.resolve_practice_guess(&session_id);
+    assert_eq!(
+        result.feedback,
+        make_feedback(&env, [CORRECT, PRESENT, CORRECT, PRESENT, ABSENT])
+    );
+    assert!(!result.is_correct);
+
+    client.guess(&session_id, &guesser, &word);
+    let result = client.resolve_practice_guess(&session_id);
+    assert!(result.is_correct);
+    assert!(result.game_ended);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.winner, Some(guesser.clone()));
+    assert!(game.hub_notified);
+    assert!(!hub.was_ended(&session_id));
+    assert_wordle_error(
+        &client.try_get_archived_stats(&session_id),
+        Error::StatsNotArchived,
+    );
+    assert_eq!(
+        client.get_player_wordle_stats(&guesser),
+        PlayerStats::default()
+    );
+
+    // Practice entrypoints do not apply to proven games
+    let ranked = 61u32;
+    client.start_game(&ranked, &word_setter, &guesser, &1, &1);
+    let clear = client.try_commit_practice_word(&ranked, &word_setter, &word);
+    assert_wordle_error(&clear, Error::NotPracticeGame);
+    client.commit_word(&ranked, &word_setter, &word_commitment);
+    client.guess(&ranked, &guesser, &word);
+    let computed = client.try_resolve_practice_guess(&ranked);
+    assert_wordle_error(&computed, Error::NotPracticeGame);
+}
+
+#[test]
+fn test_compute_feedback_matches_circuit_rules() {
+    let (env, client, _hub, _word_setter, _guesser, _word_commitment) = setup_test();
+
+    // HELLO / HOLES
+    assert_eq!(
+        client.compute_feedback(
+            &make_guess(&env, [7, 4, 11, 11, 14]),
+            &make_guess(&env, [7, 14, 11, 4, 18])
+        ),
+        make_feedback(&env, [CORRECT, PRESENT, CORRECT, PRESENT, ABSENT])
+    );
+    // One spare A in the word: only the first unmatched A is present
+    assert_eq!(
+        client.compute_feedback(
+            &make_guess(&env, [1, 0, 2, 3, 4]),
+            &make_guess(&env, [0, 5, 0, 6, 7])
+        ),
+        make_feedback(&env, [PRESENT, ABSENT, ABSENT, ABSENT, ABSENT])
+    );
+    // An earlier green of the same letter uses up the present budget, as in
+    // AssertGuessFeedback: the A at position 1 is absent although the word
+    // still has an unmatched A at position 4
+    assert_eq!(
+        client.compute_feedback(
+            &make_guess(&env, [0, 1, 2, 3, 0]),
+            &make_guess(&env, [0, 0, 5, 6, 7])
+        ),
+        make_feedback(&env, [CORRECT, ABSENT, ABSENT, ABSENT, ABSENT])
+    );
+    // Letters range over the whole circuit alphabet
+    assert_eq!(
+        client.compute_feedback(
+            &make_guess(&env, [63, 1, 2, 3, 4]),
+            &make_guess(&env, [5, 63, 2, 8, 9])
+        ),
+        make_feedback(&env, [ABSENT, PRESENT, CORRECT, ABSENT, ABSENT])
+    );
+
+    let out_of_range = client.try_compute_feedback(
+        &make_guess(&env, [64, 1, 2, 3, 4]),
+        &make_guess(&env, [0; 5]),
+    );
+    assert_wordle_error(&out_of_range, Error::InvalidLetterValue);
+}
+
+#[test]
+fn test_finished_games_archive_guesser_stats() {
+    let (env, client, _hub, word_setter, guesser, word_commitment) = setup_test();
+
+    // Solved on the second guess, 20 ledgers after the start
+    let session_id = 60u32;
+    client.start_game(&session_id, &word_setter, &guesser, &1, &1);
+    client.commit_word(&session_id, &word_setter, &word_commitment);
+    let rounds = [
+        ([CORRECT, PRESENT, ABSENT, ABSENT, ABSENT], false),
+        ([CORRECT, CORRECT, CORRECT, CORRECT, CORRECT], true),
+    ];
+    for (feedback, is_correct) in rounds {
+        assert_wordle_error(
+            &client.try_get_archived_stats(&session_id),
+            Error::StatsNotArchived,
+        );
+        env.ledger()
+            .set_sequence_number(env.ledger().sequence() + 10);
+        let guess = make_guess(&env, [0, 1, 2, 3, 4]);
+        client.guess(&session_id, &guesser, &guess);
+        resolve_pending(
+            &client,
+            session_id,
+            &word_setter,
+            &guesser,
+            &guess,
+            &make_feedback(&env, feedback),
+            is_correct,
+            &word_commitment,
+            &valid_proof(&env),
+        );
+    }
+    assert_eq!(
+        client.get_archived_stats(&session_id),
+        GameStats {
+            guesses_used: 2,
+            solved: true,
+            greens: 6,
+            yellows: 1,
+            ledgers_played: 20,
+        }
+    );
+
+    // Six misses with one yellow each
+    let session_id = 61u32;
+    client.start_game(&session_id, &word_setter, &guesser, &1, &1);
+    client.commit_word(&session_id, &word_setter, &word_commitment);
+    for _ in 0..6 {
+        let guess = make_guess(&env, [5, 6, 7, 8, 9]);
+        client.guess(&session_id, &guesser, &guess);
+        resolve_pending(
+            &client,
+            session_id,
+            &word_setter,
+            &guesser,
+            &guess,
+            &make_feedback(&env, [ABSENT, PRESENT, ABSENT, ABSENT, ABSENT]),
+            false,
+            &word_commitment,
+            &valid_proof(&env),
+        );
+    }
+    let missed = client.get_archived_stats(&session_id);
+    assert!(!missed.solved);
+    assert_eq!(
+        (missed.guesses_used, missed.greens, missed.yellows),
+        (6, 0, 6)
+    );
+
+    assert_eq!(
+        client.get_player_wordle_stats(&guesser),
+        PlayerStats {
+            games_played: 2,
+            games_solved: 1,
+            total_guesses: 8,
+            total_greens: 6,
+            total_yellows: 7,
+            total_solve_ledgers: 20,
+            avg_guesses_x100: 400,
+            avg_greens_x100: 300,
+            avg_yellows_x100: 350,
+            avg_solve_ledgers: 20,
+        }
+    );
+    // Only the guesser's performance is aggregated
+    assert_eq!(
+        client.get_player_wordle_stats(&word_setter),
+        PlayerStats::default()
+    );
+}
+
+#[test]
+fn test_metrics_bucket_solved_games_by_guesses() {
+    let (env, client, _hub, word_setter, guesser, word_commitment) = setup_test();
+    assert_eq!(client.get_metrics(), Metrics::new(&env));
+
+    // Solved on the first and on the third guess; the rejected proof leaves no trace
+    for (session_id, misses) in [(68u32, 0), (69u32, 2)] {
+        client.start_game(&session_id, &word_setter, &guesser, &1, &1);
+        client.commit_word(&session_id, &word_setter, &word_commitment);
+        for round in 0..=misses {
+            let is_correct = round == misses;
+            let statuses = if is_correct {
+                [CORRECT; 5]
+            } else {
+                [ABSENT; 5]
+            };
+            let guess = make_guess(&env, [0, 1, 2, 3, 4]);
+            client.guess(&session_id, &guesser, &guess);
+            if is_correct {
+                let hash = client.build_public_inputs_hash(
+                    &session_id,
+                    &word_setter,
+                    &guesser,
+                    &guess,
+                    &make_feedback(&env, statuses),
+                    &true,
+                    &word_commitment,
+                    &26,
+                );
+                let rejected = client.try_resolve_guess(
+                    &session_id,
+                    &word_setter,
+                    &make_feedback(&env, statuses),
+                    &true,
+                    &invalid_proof(&env),
+                    &hash,
+                );
+                assert_wordle_error(&rejected, Error::InvalidProof);
+            }
+            resolve_pending(
+                &client,
+                session_id,
+                &word_setter,
+                &guesser,
+                &guess,
+                &make_feedback(&env, statuses),
+                is_correct,
+                &word_commitment,
+                &valid_proof(&env),
+            );
+        }
+    }
+
+    client.start_game(&70, &word_setter, &guesser, &1, &1);
+    client.cancel_game(&70);
+
+    // A solved practice game only counts as a practice game ended
+    let word = make_guess(&env, [7, 4, 11, 11, 14]);
+    client.start_practice_game(&71, &word_setter, &guesser);
+    client.commit_practice_word(&71, &word_setter, &word);
+    client.guess(&71, &guesser, &word);
+    assert!(client.resolve_practice_guess(&71).game_ended);
+
+    // Word never committed: the deadline settles it
+    client.set_game_duration_ledgers(&10);
+    client.start_game(&72, &word_setter, &guesser, &1, &1);
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 11);
+    assert_eq!(client.finalize_expired(&72), guesser);
+
+    let metrics = client.get_metrics();
+    assert_eq!(
+        (
+            metrics.games_started,
+            metrics.practice_games_started,
+            metrics.games_ended,
+            metrics.games_cancelled,
+            metrics.games_solved,
+            metrics.practice_games_ended,
+            metrics.games_timed_out,
+        ),
+        (4, 1, 3, 1, 2, 1, 1)
+    );
+    assert_eq!(metrics.solved_in, soroban_sdk::vec![&env, 1, 0, 1, 0, 0, 0]);
+    assert_eq!(metrics.avg_solve_guesses_x100, 200);
+}
+
+#[test]
+fn test_coop_guessers_alternate_over_shared_budget() {
+    let (env, client, hub, word_setter, guesser, word_commitment) = setup_test();
+    let co_guesser = Address::generate(&env);
+
+    let session_id = 62u32;
+    let clash = client.try_start_coop_game(&session_id, &word_setter, &guesser, &guesser, &1, &1);
+    assert_wordle_error(&clash, Error::SelfPlayNotAllowed);
+
+    client.start_coop_game(&session_id, &word_setter, &guesser, &co_guesser, &1, &1);
+    assert!(hub.was_started(&session_id));
+    assert_eq!(client.get_active_sessions(&co_guesser).len(), 1);
+    client.commit_word(&session_id, &word_setter, &word_commitment);
+
+    let close = [CORRECT, PRESENT, ABSENT, ABSENT, ABSENT];
+    let rounds = [
+        (&guesser, [ABSENT; 5], false),
+        (&co_guesser, close, false),
+        (&guesser, [CORRECT; 5], true),
+    ];
+    for (actor, feedback, is_correct) in rounds {
+        let guess = make_guess(&env, [0, 1, 2, 3, 4]);
+        let other = if *actor == guesser {
+            &co_guesser
+        } else {
+            &guesser
+        };
+        let out_of_turn = client.try_guess(&session_id, other, &guess);
+        assert_wordle_error(&out_of_turn, Error::NotGuessersTurn);
+
+        client.guess(&session_id, actor, &guess);
+        resolve_pending(
+            &client,
+            session_id,
+            &word_setter,
+            &guesser,
+            &guess,
+            &make_feedback(&env, feedback),
+            is_correct,
+            &word_commitment,
+            &valid_proof(&env),
+        );
+    }
+
+    let stranger = Address::generate(&env);
+    let result = client.try_guess(&session_id, &stranger, &make_guess(&env, [0, 1, 2, 3, 4]));
+    assert_wordle_error(&result, Error::GameAlreadyEnded);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(
+        game.guess_actors,
+        Vec::from_array(&env, [guesser.clone(), co_guesser.clone(), guesser.clone()])
+    );
+    // The lead guesser stands for the pair at the hub
+    assert_eq!(game.winner, Some(guesser.clone()));
+    assert!(hub.was_ended(&session_id));
+    assert_eq!(client.get_active_sessions(&co_guesser).len(), 0);
+}
+
+#[test]
+fn test_co_guesser_authorizes_session_key() {
+    let (env, client, _hub, word_setter, guesser, word_commitment) = setup_test();
+    let co_guesser = Address::generate(&env);
+
+    let session_id = 68u32;
+    client.start_coop_game(&session_id, &word_setter, &guesser, &co_guesser, &1, &1);
+    client.commit_word(&session_id, &word_setter, &word_commitment);
+
+    let hot_key = Address::generate(&env);
+    client.authorize_session_key(&session_id, &co_guesser, &hot_key, &200);
+    assert_eq!(
+        client
+            .get_session_key(&session_id, &co_guesser)
+            .unwrap()
+            .key,
+        hot_key
+    );
+
+    let guess = make_guess(&env, [0, 1, 2, 3, 4]);
+    client.guess(&session_id, &guesser, &guess);
+    assert_eq!(env.auths().first().unwrap().0, guesser);
+    resolve_pending(
+        &client,
+        session_id,
+        &word_setter,
+        &guesser,
+        &guess,
+        &make_feedback(&env, [ABSENT; 5]),
+        false,
+        &word_commitment,
+        &valid_proof(&env),
+    );
+
+    // The co-guesser's turn is signed by its session key
+    client.guess(&session_id, &co_guesser, &guess);
+    assert_eq!(env.auths().first().unwrap().0, hot_key);
+}
+
+#[test]
+fn test_move_count_sequences_every_action() {
+    let (env, client, _hub, word_setter, guesser, word_commitment) = setup_test();
+
+    let session_id = 63u32;
+    client.start_game(&session_id, &word_setter, &guesser, &1, &1);
+    assert_eq!(client.get_move_count(&session_id), 0);
+    client.commit_word(&session_id, &word_setter, &word_commitment);
+
+    let misclick = make_guess(&env, [0, 1, 2, 3, 4]);
+    client.guess(&session_id, &guesser, &misclick);
+    client.retract_guess(&session_id, &guesser);
+    let guess = make_guess(&env, [5, 6, 7, 8, 9]);
+    client.guess(&session_id, &guesser, &guess);
+    assert_eq!(
+        env.events().all().events().last().unwrap(),
+        &GameMove {
+            session_id,
+            move_count: 4,
+            action: symbol_short!("guess"),
+            actor: Some(guesser.clone()),
+        }
+        .to_xdr(&env, &client.address)
+    );
+
+    let feedback = make_feedback(&env, [ABSENT; 5]);
+    let hash = client.build_public_inputs_hash(
+        &session_id,
+        &word_setter,
+        &guesser,
+        &guess,
+        &feedback,
+        &false,
+        &word_commitment,
+        &26,
+    );
+    let result = client.resolve_guess(
+        &session_id,
+        &word_setter,
+        &feedback,
+        &false,
+        &valid_proof(&env),
+        &hash,
+    );
+    assert_eq!(result.move_count, 5);
+    assert_eq!(client.get_move_count(&session_id), 5);
+
+    // Failed calls leave the counter untouched
+    let no_guess = client.try_retract_guess(&session_id, &guesser);
+    assert_wordle_error(&no_guess, Error::NoPendingGuess);
+    assert_eq!(client.get_game(&session_id).move_count, 5);
+}
+
+#[test]
+fn test_imported_game_reproduces_stuck_session() {
+    use soroban_sdk::xdr::{FromXdr, ToXdr};
+
+    let (env, client, _hub, word_setter, guesser, word_commitment) = setup_test();
+    let session_id = 66u32;
+    client.start_game(&session_id, &word_setter, &guesser, &1, &1);
+    client.commit_word(&session_id, &word_setter, &word_commitment);
+    let first = make_guess(&env, [0, 1, 2, 3, 4]);
+    client.guess(&session_id, &guesser, &first);
+    resolve_pending(
+        &client,
+        session_id,
+        &word_setter,
+        &guesser,
+        &first,
+        &make_feedback(&env, [ABSENT; 5]),
+        false,
+        &word_commitment,
+        &valid_proof(&env),
+    );
+    client.guess(&session_id, &guesser, &make_guess(&env, [5, 6, 7, 8, 9]));
+
+    // Export the session as a bug report would, then load it into another env
+    let exported = client.get_game(&session_id).to_xdr(&env);
+    let mut buf = [0u8; 4096];
+    let len = exported.len() as usize;
+    exported.copy_into_slice(&mut buf[..len]);
+
+    let (local_env, local, _hub, setter, other, commitment) = setup_test();
+    // A longer local game under the same id must not leak into the import
+    local.start_game(&session_id, &setter, &other, &1, &1);
+    local.commit_word(&session_id, &setter, &commitment);
+    for letter in 0..2u8 {
+        let guess = make_guess(&local_env, [letter; 5]);
+        local.guess(&session_id, &other, &guess);
+        resolve_pending(
+            &local,
+            session_id,
+            &setter,
+            &other,
+            &guess,
+            &make_feedback(&local_env, [ABSENT; 5]),
+            false,
+            &commitment,
+            &valid_proof(&local_env),
+        );
+    }
+
+    let game =
+        crate::Game::from_xdr(&local_env, &Bytes::from_slice(&local_env, &buf[..len])).unwrap();
+    local.import_game(&session_id, &game);
+    assert_eq!(local_env.auths().first().unwrap().0, local.get_admin());
+    assert_eq!(local.get_game(&session_id), game);
+
+    let pending = game.pending_guess.clone().unwrap();
+    resolve_pending(
+        &local,
+        session_id,
+        &game.word_setter,
+        &game.guesser,
+        &pending,
+        &make_feedback(&local_env, [CORRECT; 5]),
+        true,
+        game.word_commitment.as_ref().unwrap(),
+        &valid_proof(&local_env),
+    );
+    let after = local.get_game(&session_id);
+    assert_eq!(after.guess_count, 2);
+    assert_eq!(after.guesses.get(0), game.guesses.get(0));
+    assert_eq!(after.winner, Some(game.guesser));
+}
+
+#[test]
+fn test_session_marker_outlives_expired_game() {
+    use crate::infrastructure::storage::DataKey;
+
+    let (env, client, _hub, word_setter, guesser, _word_commitment) = setup_test();
+
+    let session_id = 72u32;
+    assert!(!client.was_started(&session_id));
+    client.start_game(&session_id, &word_setter, &guesser, &1, &1);
+    assert!(client.was_started(&session_id));
+
+    // Expire the temporary game entry as if its TTL had run out mid-match
+    env.as_contract(&client.address, || {
+        env.storage().temporary().remove(&DataKey::Game(session_id));
+    });
+    assert_eq!(
+        client.try_get_game(&session_id),
+        Err(Ok(Error::GameNotFound))
+    );
+    assert!(client.was_started(&session_id));
+    assert_eq!(
+        client.get_session_marker(&session_id),
+        Some(SessionMarker {
+            word_setter: word_setter.clone(),
+            guesser: guesser.clone(),
+            start_ledger: env.ledger().sequence(),
+            tier: StorageTier::Temporary,
+        })
+    );
+    assert_eq!(client.get_session_marker(&73), None);
+
+    // The expired id stays taken, so the marker cannot be overwritten
+    assert_eq!(
+        client.try_start_game(&session_id, &guesser, &word_setter, &1, &1),
+        Err(Ok(Error::GameAlreadyExists))
+    );
+    assert_eq!(
+        client.get_session_marker(&session_id).unwrap().word_setter,
+        word_setter
+    );
+}
+
+#[test]
+fn test_react_records_one_emote_per_player_and_guess() {
+    let (env, client, _hub, word_setter, guesser, word_commitment) = setup_test();
+
+    let session_id = 74u32;
+    client.start_game(&session_id, &word_setter, &guesser, &1, &1);
+    client.commit_word(&session_id, &word_setter, &word_commitment);
+    let guess = make_guess(&env, [0, 1, 2, 3, 4]);
+    client.guess(&session_id, &guesser, &guess);
+    resolve_pending(
+        &client,
+        session_id,
+        &word_setter,
+        &guesser,
+        &guess,
+        &make_feedback(&env, [ABSENT; 5]),
+        false,
+        &word_commitment,
+        &valid_proof(&env),
+    );
+    let move_count = client.get_move_count(&session_id);
+
+    client.react(&session_id, &word_setter, &0, &4);
+    client.react(&session_id, &guesser, &0, &0);
+    // Reacting again replaces the player's earlier emote
+    client.react(&session_id, &word_setter, &0, &5);
+    assert_eq!(
+        env.events().all().events().last().unwrap(),
+        &Reacted {
+            session_id,
+            player: word_setter.clone(),
+            guess_index: 0,
+            emote_code: 5,
+        }
+        .to_xdr(&env, &client.address)
+    );
+    let ledger = env.ledger().sequence();
+    assert_eq!(
+        client.get_reactions(&session_id, &0),
+        soroban_sdk::vec![
+            &env,
+            Reaction {
+                player: word_setter.clone(),
+                emote_code: 5,
+                ledger,
+            },
+            Reaction {
+                player: guesser.clone(),
+                emote_code: 0,
+                ledger,
+            },
+        ]
+    );
+    assert_eq!(client.get_move_count(&session_id), move_count);
+
+    assert_eq!(
+        client.try_react(&session_id, &guesser, &0, &6),
+        Err(Ok(Error::InvalidEmote))
+    );
+    assert_eq!(
+        client.try_react(&session_id, &guesser, &1, &0),
+        Err(Ok(Error::GuessNotFound))
+    );
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_react(&session_id, &stranger, &0, &0),
+        Err(Ok(Error::NotPlayer))
+    );
+}
+
+#[test]
+fn test_get_info_reports_game_interface() {
+    let (env, client, _hub, _word_setter, _guesser, _word_commitment) = setup_test();
+
+    let info = client.get_info();
+    assert_eq!(info.name, String::from_str(&env, "wordle"));
+    assert_eq!(
+        info.version,
+        String::from_str(&env, env!("CARGO_PKG_VERSION"))
+    );
+    assert!(info.interfaces.contains(String::from_str(&env, "game-v1")));
+    assert!(info
+        .interfaces
+        .contains(String::from_str(&env, "game-interface-v1")));
+}
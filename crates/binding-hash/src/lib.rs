@@ -0,0 +1,76 @@
+#![no_std]
+
+//! Keccak-256 binding hashes shared by the arcade games.
+//!
+//! A game binds each proof to its on-chain context by hashing the session id, the move's scalar
+//! fields (big-endian), the 32-byte commitments and the players' strkey strings, and passing the
+//! digest as public inputs 2..3. `BindingHashBuilder` appends those pieces in call order, so the
+//! byte layout of every hash reads off its call chain and provers can rebuild it field by field.
+
+use soroban_sdk::{Address, Bytes, BytesN, Env};
+
+pub struct BindingHashBuilder {
+    env: Env,
+    payload: Bytes,
+}
+
+impl BindingHashBuilder {
+    /// Starts a hash with the big-endian session id, which every layout leads with.
+    pub fn new(env: &Env, session_id: u32) -> Self {
+        Self {
+            env: env.clone(),
+            payload: Bytes::from_array(env, &session_id.to_be_bytes()),
+        }
+    }
+
+    /// One raw byte, for values known to fit (letters, ship ids, counts).
+    pub fn u8(self, value: u8) -> Self {
+        self.bytes(&[value])
+    }
+
+    pub fn u32(self, value: u32) -> Self {
+        self.bytes(&value.to_be_bytes())
+    }
+
+    pub fn u128(self, value: u128) -> Self {
+        self.bytes(&value.to_be_bytes())
+    }
+
+    /// `1` or `0` in a single byte.
+    pub fn flag(self, value: bool) -> Self {
+        self.u8(value as u8)
+    }
+
+    /// A board cell as two big-endian u32s.
+    pub fn coord(self, x: u32, y: u32) -> Self {
+        self.u32(x).u32(y)
+    }
+
+    pub fn bytes(mut self, value: &[u8]) -> Self {
+        self.payload.extend_from_slice(value);
+        self
+    }
+
+    pub fn commitment(mut self, value: &BytesN<32>) -> Self {
+        self.payload.append(value.as_ref());
+        self
+    }
+
+    /// The address's strkey (`G...` / `C...`) as ASCII bytes.
+    pub fn address(mut self, value: &Address) -> Self {
+        self.payload.append(&value.to_string().to_bytes());
+        self
+    }
+
+    /// Bytes hashed so far, for provers comparing their own encoding.
+    pub fn payload(&self) -> Bytes {
+        self.payload.clone()
+    }
+
+    pub fn finish(self) -> BytesN<32> {
+        self.env.crypto().keccak256(&self.payload).into()
+    }
+}
+
+#[cfg(test)]
+mod test;
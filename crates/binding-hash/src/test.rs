@@ -0,0 +1,148 @@
+#![cfg(test)]
+
+extern crate std;
+
+use crate::BindingHashBuilder;
+use sha3::{Digest, Keccak256};
+use soroban_sdk::{Address, BytesN, Env};
+use std::vec::Vec;
+
+const PLAYER_A: &str = "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF";
+const PLAYER_B: &str = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD2KM";
+
+fn setup() -> (Env, Address, Address, BytesN<32>) {
+    let env = Env::default();
+    let a = Address::from_str(&env, PLAYER_A);
+    let b = Address::from_str(&env, PLAYER_B);
+    let commitment = BytesN::from_array(&env, &[0x5au8; 32]);
+    (env, a, b, commitment)
+}
+
+// Independent keccak256 over a hand-assembled layout. The pinned digests below were produced by
+// the games' hash builders before they moved onto this crate.
+fn keccak(parts: &[&[u8]]) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize().into()
+}
+
+fn hex(digest: &BytesN<32>) -> std::string::String {
+    digest
+        .to_array()
+        .iter()
+        .map(|b| std::format!("{b:02x}"))
+        .collect()
+}
+
+#[test]
+fn test_battleship_shot_layout() {
+    let (env, defender, shooter, board) = setup();
+
+    let digest = BindingHashBuilder::new(&env, 7)
+        .coord(3, 4)
+        .flag(true)
+        .u32(2)
+        .commitment(&board)
+        .address(&defender)
+        .address(&shooter)
+        .finish();
+
+    // session_id || x || y || is_hit || sunk_ship || board || defender || shooter
+    let mut fixed = [0u8; 17];
+    fixed[0..4].copy_from_slice(&7u32.to_be_bytes());
+    fixed[4..8].copy_from_slice(&3u32.to_be_bytes());
+    fixed[8..12].copy_from_slice(&4u32.to_be_bytes());
+    fixed[12] = 1;
+    fixed[13..17].copy_from_slice(&2u32.to_be_bytes());
+    let expected = keccak(&[
+        &fixed,
+        &[0x5a; 32],
+        PLAYER_A.as_bytes(),
+        PLAYER_B.as_bytes(),
+    ]);
+    assert_eq!(digest.to_array(), expected);
+    assert_eq!(
+        hex(&digest),
+        "7fc0a9cab25987cd6b8e54d040d13f74ea2b4db7736443eef94a6bfcd8359b1a"
+    );
+}
+
+#[test]
+fn test_battleship_disclosed_shot_appends_placement() {
+    let (env, defender, shooter, board) = setup();
+
+    let base = BindingHashBuilder::new(&env, 7)
+        .coord(3, 4)
+        .flag(true)
+        .u32(2)
+        .commitment(&board)
+        .address(&defender)
+        .address(&shooter);
+    let plain = base.payload();
+    let digest = base.bytes(&[1, 4, 1]).finish();
+
+    let mut payload: Vec<u8> = plain.iter().collect();
+    payload.extend_from_slice(&[1, 4, 1]);
+    assert_eq!(digest.to_array(), keccak(&[&payload]));
+}
+
+#[test]
+fn test_battleship_consistency_layout() {
+    let (env, defender, _shooter, board) = setup();
+    let shots: u128 = (1 << 99) | 0b1011;
+    let hits: u128 = 0b1001;
+
+    let digest = BindingHashBuilder::new(&env, 7)
+        .u128(shots)
+        .u128(hits)
+        .commitment(&board)
+        .address(&defender)
+        .finish();
+
+    let expected = keccak(&[
+        &7u32.to_be_bytes(),
+        &shots.to_be_bytes(),
+        &hits.to_be_bytes(),
+        &[0x5a; 32],
+        PLAYER_A.as_bytes(),
+    ]);
+    assert_eq!(digest.to_array(), expected);
+}
+
+#[test]
+fn test_wordle_guess_layout() {
+    let (env, setter, guesser, word) = setup();
+    let guess = [7u8, 4, 11, 11, 14];
+    let feedback = [2u8, 0, 1, 0, 0];
+
+    let digest = BindingHashBuilder::new(&env, 9)
+        .bytes(&guess)
+        .bytes(&feedback)
+        .flag(false)
+        .u8(26)
+        .commitment(&word)
+        .address(&setter)
+        .address(&guesser)
+        .finish();
+
+    // session_id || guess[5] || feedback[5] || is_correct || alphabet_size || word || setter || guesser
+    let mut fixed = [0u8; 16];
+    fixed[0..4].copy_from_slice(&9u32.to_be_bytes());
+    fixed[4..9].copy_from_slice(&guess);
+    fixed[9..14].copy_from_slice(&feedback);
+    fixed[14] = 0;
+    fixed[15] = 26;
+    let expected = keccak(&[
+        &fixed,
+        &[0x5a; 32],
+        PLAYER_A.as_bytes(),
+        PLAYER_B.as_bytes(),
+    ]);
+    assert_eq!(digest.to_array(), expected);
+    assert_eq!(
+        hex(&digest),
+        "d7e1d0dd24d456544c16d6986f24be5531328454b4563d7943a3596408861790"
+    );
+}
@@ -0,0 +1,128 @@
+#![no_std]
+
+//! Admin ownership shared by the arcade contracts.
+//!
+//! Every admin-gated contract keeps one admin address in instance storage, checks its signature
+//! before configuration changes and lets it swap the wasm. `Ownable` does that once, adds a
+//! two-step transfer (`propose_admin` / `accept_admin`) so a typo cannot hand the contract to an
+//! address nobody controls, and `Roles` grants narrower permissions the admin can delegate (the
+//! verifier adapters let a `guardian` freeze them and a `cache` operator manage the proof cache).
+
+use soroban_sdk::{contracttype, Address, BytesN, Env, Symbol};
+
+/// Instance-storage keys. `Admin` encodes exactly like the `DataKey::Admin` variant the
+/// contracts used before, so deployed instances keep their admin across the upgrade.
+#[contracttype]
+#[derive(Clone)]
+pub enum OwnableKey {
+    Admin,
+    PendingAdmin,
+    Role(Symbol, Address),
+}
+
+pub struct Ownable;
+
+impl Ownable {
+    /// Stores the first admin; called from the contract constructor.
+    pub fn init(env: &Env, admin: &Address) {
+        env.storage().instance().set(&OwnableKey::Admin, admin);
+    }
+
+    pub fn admin(env: &Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&OwnableKey::Admin)
+            .expect("Admin not set")
+    }
+
+    /// Requires the admin's signature and returns the admin.
+    pub fn require_admin(env: &Env) -> Address {
+        let admin = Self::admin(env);
+        admin.require_auth();
+        admin
+    }
+
+    /// Immediate transfer signed by the current admin. Drops any pending proposal.
+    pub fn set_admin(env: &Env, new_admin: &Address) {
+        Self::require_admin(env);
+        let storage = env.storage().instance();
+        storage.set(&OwnableKey::Admin, new_admin);
+        storage.remove(&OwnableKey::PendingAdmin);
+    }
+
+    /// First step of a transfer: the admin nominates `new_admin`, who takes over only once they
+    /// sign `accept_admin`. A new proposal replaces the previous one.
+    pub fn propose_admin(env: &Env, new_admin: &Address) {
+        Self::require_admin(env);
+        env.storage()
+            .instance()
+            .set(&OwnableKey::PendingAdmin, new_admin);
+    }
+
+    pub fn pending_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&OwnableKey::PendingAdmin)
+    }
+
+    /// Second step: the nominee signs and becomes the admin. Returns the new admin.
+    pub fn accept_admin(env: &Env) -> Address {
+        let pending = Self::pending_admin(env).expect("No pending admin");
+        pending.require_auth();
+        let storage = env.storage().instance();
+        storage.set(&OwnableKey::Admin, &pending);
+        storage.remove(&OwnableKey::PendingAdmin);
+        pending
+    }
+
+    /// Admin-only wasm upgrade.
+    pub fn upgrade(env: &Env, new_wasm_hash: BytesN<32>) {
+        Self::require_admin(env);
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+}
+
+/// Named permissions (e.g. `symbol_short!("operator")`) the admin grants to other accounts.
+/// The admin itself holds no role implicitly.
+pub struct Roles;
+
+impl Roles {
+    pub fn has(env: &Env, role: &Symbol, account: &Address) -> bool {
+        env.storage()
+            .instance()
+            .has(&OwnableKey::Role(role.clone(), account.clone()))
+    }
+
+    /// Admin only.
+    pub fn grant(env: &Env, role: &Symbol, account: &Address) {
+        Ownable::require_admin(env);
+        env.storage()
+            .instance()
+            .set(&OwnableKey::Role(role.clone(), account.clone()), &true);
+    }
+
+    /// Admin only. Revoking a role the account does not hold is a no-op.
+    pub fn revoke(env: &Env, role: &Symbol, account: &Address) {
+        Ownable::require_admin(env);
+        env.storage()
+            .instance()
+            .remove(&OwnableKey::Role(role.clone(), account.clone()));
+    }
+
+    /// Requires `account` to hold `role` and to sign.
+    pub fn require(env: &Env, role: &Symbol, account: &Address) {
+        if !Self::has(env, role, account) {
+            panic!("Missing role");
+        }
+        account.require_auth();
+    }
+
+    /// Like `require`, but the admin passes without holding `role`.
+    pub fn require_admin_or(env: &Env, role: &Symbol, account: &Address) {
+        if *account != Ownable::admin(env) && !Self::has(env, role, account) {
+            panic!("Missing role");
+        }
+        account.require_auth();
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,163 @@
+#![cfg(test)]
+
+use crate::{Ownable, OwnableKey, Roles};
+use soroban_sdk::testutils::{Address as _, MockAuth, MockAuthInvoke};
+use soroban_sdk::xdr::ScVal;
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short, Address, Env, IntoVal, Symbol, TryFromVal,
+    Val,
+};
+
+#[contract]
+pub struct OwnedContract;
+
+#[contractimpl]
+impl OwnedContract {
+    pub fn __constructor(env: Env, admin: Address) {
+        Ownable::init(&env, &admin);
+    }
+
+    pub fn get_admin(env: Env) -> Address {
+        Ownable::admin(&env)
+    }
+
+    pub fn set_admin(env: Env, new_admin: Address) {
+        Ownable::set_admin(&env, &new_admin);
+    }
+
+    pub fn propose_admin(env: Env, new_admin: Address) {
+        Ownable::propose_admin(&env, &new_admin);
+    }
+
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        Ownable::pending_admin(&env)
+    }
+
+    pub fn accept_admin(env: Env) -> Address {
+        Ownable::accept_admin(&env)
+    }
+
+    pub fn grant_role(env: Env, role: Symbol, account: Address) {
+        Roles::grant(&env, &role, &account);
+    }
+
+    pub fn revoke_role(env: Env, role: Symbol, account: Address) {
+        Roles::revoke(&env, &role, &account);
+    }
+
+    pub fn has_role(env: Env, role: Symbol, account: Address) -> bool {
+        Roles::has(&env, &role, &account)
+    }
+
+    pub fn operate(env: Env, operator: Address) {
+        Roles::require(&env, &symbol_short!("operator"), &operator);
+    }
+
+    pub fn supervise(env: Env, caller: Address) {
+        Roles::require_admin_or(&env, &symbol_short!("operator"), &caller);
+    }
+}
+
+// Key layout the contracts used before adopting the crate.
+#[contracttype]
+enum LegacyKey {
+    Admin,
+}
+
+fn setup() -> (Env, OwnedContractClient<'static>, Address) {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let contract_id = env.register(OwnedContract, (&admin,));
+    let client = OwnedContractClient::new(&env, &contract_id);
+    (env, client, admin)
+}
+
+#[test]
+fn test_admin_key_matches_legacy_layout() {
+    let env = Env::default();
+    let legacy: Val = LegacyKey::Admin.into_val(&env);
+    let current: Val = OwnableKey::Admin.into_val(&env);
+    assert_eq!(
+        ScVal::try_from_val(&env, &legacy).unwrap(),
+        ScVal::try_from_val(&env, &current).unwrap()
+    );
+}
+
+#[test]
+fn test_two_step_transfer_needs_the_nominee() {
+    let (env, client, admin) = setup();
+    env.mock_all_auths();
+    let nominee = Address::generate(&env);
+
+    client.propose_admin(&nominee);
+    assert_eq!(client.get_pending_admin(), Some(nominee.clone()));
+    assert_eq!(client.get_admin(), admin);
+
+    // Only the nominee's signature completes the transfer
+    let result = client
+        .mock_auths(&[MockAuth {
+            address: &admin,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "accept_admin",
+                args: ().into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_accept_admin();
+    assert!(result.is_err());
+
+    env.mock_all_auths();
+    assert_eq!(client.accept_admin(), nominee);
+    assert_eq!(client.get_admin(), nominee);
+    assert_eq!(client.get_pending_admin(), None);
+    assert!(client.try_accept_admin().is_err());
+}
+
+#[test]
+fn test_set_admin_drops_pending_proposal() {
+    let (env, client, _admin) = setup();
+    env.mock_all_auths();
+    let nominee = Address::generate(&env);
+    let replacement = Address::generate(&env);
+
+    client.propose_admin(&nominee);
+    client.set_admin(&replacement);
+    assert_eq!(client.get_admin(), replacement);
+    assert_eq!(client.get_pending_admin(), None);
+}
+
+#[test]
+fn test_roles_are_granted_by_admin() {
+    let (env, client, _admin) = setup();
+    env.mock_all_auths();
+    let operator = Address::generate(&env);
+    let role = symbol_short!("operator");
+
+    assert!(!client.has_role(&role, &operator));
+    assert!(client.try_operate(&operator).is_err());
+
+    client.grant_role(&role, &operator);
+    assert!(client.has_role(&role, &operator));
+    client.operate(&operator);
+
+    client.revoke_role(&role, &operator);
+    assert!(!client.has_role(&role, &operator));
+    assert!(client.try_operate(&operator).is_err());
+}
+
+#[test]
+fn test_admin_passes_role_checks_that_allow_it() {
+    let (env, client, admin) = setup();
+    env.mock_all_auths();
+    let operator = Address::generate(&env);
+    let role = symbol_short!("operator");
+
+    // The admin holds no role, but `require_admin_or` lets it through
+    assert!(client.try_operate(&admin).is_err());
+    client.supervise(&admin);
+    assert!(client.try_supervise(&operator).is_err());
+
+    client.grant_role(&role, &operator);
+    client.supervise(&operator);
+}
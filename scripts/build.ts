@@ -22,6 +22,15 @@ const ROOT = join(import.meta.dir, "..");
 // Ensure cwd is project root so stellar/cargo resolve paths correctly (avoids "Failed to read module")
 process.chdir(ROOT);
 
+// Baked into every contract's `get_info().build_hash`; falls back to "dev" inside the contract.
+if (!process.env.ZKARCADE_BUILD_HASH) {
+  try {
+    process.env.ZKARCADE_BUILD_HASH = (await $`git rev-parse --short=12 HEAD`.quiet().text()).trim();
+  } catch {
+    // Not a git checkout; leave unset
+  }
+}
+
 function usage() {
   console.log(`
 Usage: bun run build [contract-name...]
@@ -3,7 +3,7 @@
 /**
  * Build script for Circom circuits.
  *
- * Battleship circuits: board_commit, resolve_shot
+ * Battleship circuits: board_commit, resolve_shot, board_recommit
  * Wordle circuits: word_commit, resolve_guess
  *
  * Compiles circuits with circom using circomlib from node_modules.
@@ -27,9 +27,15 @@ const CIRCUITS = [
   // Battleship circuits
   "board_commit",
   "resolve_shot",
+  "resolve_shot_disclosed",
+  "board_recommit",
+  "board_consistency",
+  "board_reveal",
   // Wordle circuits
   "word_commit",
+  "word_validity",
   "resolve_guess",
+  "game_audit",
 ] as const;
 
 async function main() {
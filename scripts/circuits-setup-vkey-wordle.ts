@@ -114,7 +114,7 @@ async function main() {
 
   console.log("\nDone. Next:");
   console.log("  bun run scripts/circuits-vkey-to-soroban.ts circuits/build/vkey_wordle.json --out circuits/build/vkey_wordle_soroban.json");
-  console.log("Then deploy circom-groth16-verifier with --vk-file-path circuits/build/vkey_wordle_soroban.json");
+  console.log("Then deploy circom-groth16-verifier and call initialize with --vk-file-path circuits/build/vkey_wordle_soroban.json");
   console.log("and deploy wordle-verifier-adapter with --verifier <that_verifier_id>.");
 }
 
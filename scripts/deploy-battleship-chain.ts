@@ -99,13 +99,16 @@ async function main() {
 
   console.log("🚀 Deploying Battleship verifier chain (circom-groth16-verifier → adapter → battleship)...\n");
 
-  // 1. Deploy circom-groth16-verifier with vk
+  // 1. Deploy circom-groth16-verifier, then initialize it with the vk (deployer is its admin)
   console.log("Deploying circom-groth16-verifier...");
   const groth16Wasm = wasmPath(CONTRACTS[0].wasmName);
   const uploadGroth16 = await $`stellar contract upload --wasm ${groth16Wasm} --source-account ${deployerSecret} --network ${NETWORK}`.text();
   const groth16Hash = uploadGroth16.trim();
-  const deployGroth16 = await $`stellar contract deploy --wasm-hash ${groth16Hash} --source-account ${deployerSecret} --network ${NETWORK} -- --vk ${vkeyArg}`.text();
+  const { Keypair } = await import("@stellar/stellar-sdk");
+  const deployerAddress = Keypair.fromSecret(deployerSecret).publicKey();
+  const deployGroth16 = await $`stellar contract deploy --wasm-hash ${groth16Hash} --source-account ${deployerSecret} --network ${NETWORK} -- --admin ${deployerAddress}`.text();
   const circomGroth16VerifierId = deployGroth16.trim();
+  await $`stellar contract invoke --id ${circomGroth16VerifierId} --source-account ${deployerSecret} --network ${NETWORK} -- initialize --vk ${vkeyArg}`.text();
   console.log(`✅ circom-groth16-verifier: ${circomGroth16VerifierId}\n`);
 
   // 2. Deploy battleship-verifier-adapter with admin + verifier
@@ -326,7 +326,7 @@ if (mock && shouldEnsureMock) {
     console.log(`Deploying ${mock.packageName}...`);
     try {
       const result =
-        await $`stellar contract deploy --wasm ${mock.wasmPath} --source-account ${adminSecret} --network ${NETWORK}`.text();
+        await $`stellar contract deploy --wasm ${mock.wasmPath} --source-account ${adminSecret} --network ${NETWORK} -- --admin ${adminAddress}`.text();
       mockGameHubId = result.trim();
       deployed[mock.packageName] = mockGameHubId;
       console.log(`✅ ${mock.packageName} deployed: ${mockGameHubId}\n`);
@@ -365,8 +365,9 @@ if (needsBattleshipChain) {
 
   const groth16Wasm = wasmPath("circom_groth16_verifier");
   const uploadGroth16 = await $`stellar contract upload --wasm ${groth16Wasm} --source-account ${adminSecret} --network ${NETWORK}`.text();
-  const deployGroth16 = await $`stellar contract deploy --wasm-hash ${uploadGroth16.trim()} --source-account ${adminSecret} --network ${NETWORK} -- --vk ${vkeyArg}`.text();
+  const deployGroth16 = await $`stellar contract deploy --wasm-hash ${uploadGroth16.trim()} --source-account ${adminSecret} --network ${NETWORK} -- --admin ${adminAddress}`.text();
   const circomGroth16VerifierId = deployGroth16.trim();
+  await $`stellar contract invoke --id ${circomGroth16VerifierId} --source-account ${adminSecret} --network ${NETWORK} -- initialize --vk ${vkeyArg}`.text();
   deployed["circom-groth16-verifier"] = circomGroth16VerifierId;
   console.log(`✅ circom-groth16-verifier: ${circomGroth16VerifierId}\n`);
 
@@ -404,8 +405,9 @@ if (needsWordleChain) {
 
   const groth16Wasm = wasmPath("circom_groth16_verifier");
   const installGroth16Wordle = await $`stellar contract install --wasm ${groth16Wasm} --source-account ${adminSecret} --network ${NETWORK}`.text();
-  const deployGroth16Wordle = await $`stellar contract deploy --wasm-hash ${installGroth16Wordle.trim()} --source-account ${adminSecret} --network ${NETWORK} -- --vk ${wordleVkeyArg}`.text();
+  const deployGroth16Wordle = await $`stellar contract deploy --wasm-hash ${installGroth16Wordle.trim()} --source-account ${adminSecret} --network ${NETWORK} -- --admin ${adminAddress}`.text();
   deployed["circom-groth16-verifier-wordle"] = deployGroth16Wordle.trim();
+  await $`stellar contract invoke --id ${deployed["circom-groth16-verifier-wordle"]} --source-account ${adminSecret} --network ${NETWORK} -- initialize --vk ${wordleVkeyArg}`.text();
   console.log(`✅ circom-groth16-verifier (Wordle): ${deployed["circom-groth16-verifier-wordle"]}\n`);
 }
 
@@ -426,7 +428,8 @@ for (const contract of orderedContracts) {
         console.error("   bun run circuits:build && bun run circuits:setup-vkey -- --ptau circuits/build/ptau.ptau && bun run circuits:vkey-to-soroban");
         process.exit(1);
       }
-      constructorArgs = ["--vk-file-path", VKEY_SOROBAN_PATH];
+      // Deployed with only the admin; the admin sets the VK via initialize below
+      constructorArgs = ["--admin", adminAddress];
     } else if (contract.packageName === "battleship-verifier-adapter") {
       const verifierId = deployed["circom-groth16-verifier"];
       if (!verifierId) {
@@ -463,6 +466,9 @@ for (const contract of orderedContracts) {
     const deployResult =
       await $`stellar contract deploy --wasm-hash ${wasmHash} --source-account ${adminSecret} --network ${NETWORK} -- ${constructorArgs}`.text();
     const contractId = deployResult.trim();
+    if (contract.packageName === "circom-groth16-verifier") {
+      await $`stellar contract invoke --id ${contractId} --source-account ${adminSecret} --network ${NETWORK} -- initialize --vk-file-path ${VKEY_SOROBAN_PATH}`.text();
+    }
     deployed[contract.packageName] = contractId;
     console.log(`✅ ${contract.packageName} deployed: ${contractId}\n`);
   } catch (error) {
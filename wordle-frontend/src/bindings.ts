@@ -18,6 +18,8 @@ export interface Game {
   guesser: string;
   word_setter_points: string;
   guesser_points: string;
+  /** Letters are 0..alphabet_size - 1 (26 = Latin A-Z). */
+  alphabet_size: number;
   phase: GamePhase;
   word_commitment: string | null;
   guess_count: number;
@@ -43,7 +45,7 @@ export const FEEDBACK = {
 
 export interface WordleClient {
   get_game(params: { session_id: number }): Promise<{ result: { ok: () => Game } }>;
-  get_rules(): Promise<{ result: GameRules }>;
+  get_rules(params: { session_id: number }): Promise<{ result: { ok: () => GameRules } }>;
   start_game(params: {
     session_id: number;
     player1: string;
@@ -77,6 +79,7 @@ export interface WordleClient {
     feedback: number[];
     is_correct: boolean;
     word_commitment: Uint8Array;
+    alphabet_size: number;
   }): Promise<{ result: Uint8Array }>;
 }
 
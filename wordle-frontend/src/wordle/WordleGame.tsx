@@ -313,12 +313,14 @@ export function WordleGame({ userAddress, player1Address, player2Address }: Word
       pendingGuessBytes,
       [...computedFeedback],
       isCorrectResolve,
-      wordCommitmentBytes
+      wordCommitmentBytes,
+      game.alphabet_size
     );
     const { hi: hashHi, lo: hashLo } = bytes32ToHiLo(publicInputsHash);
     return {
       word: wordArr,
       salt: resolveSalt || '1',
+      alphabet_size: game.alphabet_size,
       guess: guessArr,
       feedback: [...computedFeedback],
       is_correct: isCorrectResolve ? 1 : 0,
@@ -379,6 +381,7 @@ export function WordleGame({ userAddress, player1Address, player2Address }: Word
       const proofPayload = await generateResolveGuessProof({
         word: input.word as number[],
         salt: String(input.salt),
+        alphabet_size: Number(input.alphabet_size),
         guess: input.guess as number[],
         feedback: input.feedback as number[],
         is_correct: Number(input.is_correct),
@@ -400,7 +403,8 @@ export function WordleGame({ userAddress, player1Address, player2Address }: Word
         pendingGuessBytes!,
         [...computedFeedback],
         isCorrectResolve,
-        gameWordCommitment
+        gameWordCommitment,
+        gameToUse.alphabet_size
       );
       setResolvePhase('submitting');
       const signer = getContractSigner();
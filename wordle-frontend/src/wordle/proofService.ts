@@ -128,6 +128,7 @@ export async function getWordCommitmentBytesFromWasm(
 export interface ResolveGuessWitnessInput {
   word: number[];
   salt: string;
+  alphabet_size: number;
   guess: number[];
   feedback: number[];
   is_correct: number;
@@ -175,16 +175,16 @@ export class WordleService {
     }
   }
 
-  async getRules(): Promise<GameRules> {
+  async getRules(sessionId: number): Promise<GameRules> {
     if (!this.client) {
       return {
         word_length: WORD_LENGTH,
         max_guesses: MAX_GUESSES,
-        alphabet_size: 26,
+        alphabet_size: ALPHABET.length,
       };
     }
-    const tx = await this.client.get_rules();
-    return (tx as { result: GameRules }).result;
+    const tx = await this.client.get_rules({ session_id: sessionId });
+    return tx.result.ok();
   }
 
   /**
@@ -451,7 +451,8 @@ export class WordleService {
     guessLetters: Uint8Array,
     feedback: number[],
     isCorrect: boolean,
-    wordCommitment: Uint8Array
+    wordCommitment: Uint8Array,
+    alphabetSize: number = ALPHABET.length
   ): Promise<Uint8Array> {
     if (!this.client) throw new Error('Wordle contract not configured.');
     const tx = await this.client.build_public_inputs_hash({
@@ -462,6 +463,7 @@ export class WordleService {
       feedback,
       is_correct: isCorrect,
       word_commitment: wordCommitment,
+      alphabet_size: alphabetSize,
     });
     const result = (tx as { result?: Uint8Array }).result;
     if (result) return result;